@@ -19,13 +19,27 @@
 //!
 //! You *could* use all of these intrinsics with completely unaligned memory if you set the SCTLR,
 //! the system control register. Since we do not provide this guarantee, we do not allow that. To load
-//! unaligned floating point data, use an appropriate `u8xN` type and reinterpret the vector.
+//! unaligned floating point data, use the wrappers in the [`bytes`] module which load through an
+//! appropriate `u8xN` type and reinterpret the vector.
 //!
 //! See: <https://developer.arm.com/documentation/ddi0597/2025-06/SIMD-FP-Instructions/> on VLD1
 #![cfg(any(target_arch = "aarch64", target_arch = "arm64ec"))]
 // Use all variants of registers.
 use core::arch::aarch64::{self as arch, *};
 
+pub mod bytes;
+pub mod ext;
+#[cfg(feature = "nightly")]
+pub mod simd;
+pub mod slice;
+pub mod token;
+
+pub use crate::common_traits::{
+    Is16BitsReadable, Is16BitsUnaligned, Is16CellUnaligned, Is32BitsReadable, Is32BitsUnaligned,
+    Is32CellUnaligned, Is64BitsReadable, Is64BitsUnaligned, Is64CellUnaligned, Is128BitsReadable,
+    Is128BitsUnaligned, Is128CellUnaligned, Readable, Unaligned, UnalignedCell,
+};
+
 // Most of this is generated via macro due to the respective nature. The macro identifies to which
 // kind of internal we want to expand by an introductory keyword (load, store) followed by a
 // sequence of wrapper instantiations. To review this, the basic structure is:
@@ -40,7 +54,36 @@ use core::arch::aarch64::{self as arch, *};
 // Each block also expects a `size` macro to perform some compile-time verification of the typing.
 // Mostly we verify that types have exactly the register size and thus fit the expected memory
 // access. This is only enabled on test/check builds.
+//
+// A block may name a capability token after its size, as in `token: Neon;`, to also implement a
+// method of the same name on the token for each wrapper of a `load` or `store` block.
 macro_rules! vld_n_replicate_k {
+    (
+        unsafe: $kind:ident;
+        size: $size:ident;
+        token: $token:ident;
+
+        $(
+            $(#[$meta:meta])* fn $intrinsic:ident(_: &[$base_ty:ty; $n:literal][..$len:literal] as $realty:ty) -> $ret:ty;
+        )*
+    ) => {
+        vld_n_replicate_k! {
+            unsafe: $kind;
+            size: $size;
+
+            $(
+                $(#[$meta])* fn $intrinsic(_: &[$base_ty; $n][..$len] as $realty) -> $ret;
+            )*
+        }
+
+        #[cfg(any(target_arch = "aarch64", target_arch = "arm64ec"))]
+        impl $crate::aarch64::token::$token {
+            $(
+                vld_n_replicate_k!(@ token $kind $intrinsic: ($realty) -> $ret);
+            )*
+        }
+    };
+
     (
         // So we have one unsafe keyword in the pre-expansion.
         unsafe: $kind:ident;
@@ -63,6 +106,28 @@ macro_rules! vld_n_replicate_k {
     // - `n` the number of elements in one structure
     // - `len` the number of structures being loaded
     // - `ret` the register type to which we may broadcast
+    (@ token load $intrinsic:ident: ($realty:ty) -> $ret:ty) => {
+        #[doc = concat!(
+            "Calls [`", stringify!($intrinsic), "`] with the target features of the token.",
+        )]
+        #[inline]
+        pub fn $intrinsic(self, from: &$realty) -> $ret {
+            // Safety: the token exists, so the target features of the wrapper are available.
+            unsafe { $intrinsic(from) }
+        }
+    };
+
+    (@ token store $intrinsic:ident: ($realty:ty) -> $ret:ty) => {
+        #[doc = concat!(
+            "Calls [`", stringify!($intrinsic), "`] with the target features of the token.",
+        )]
+        #[inline]
+        pub fn $intrinsic(self, into: &mut $realty, val: $ret) {
+            // Safety: the token exists, so the target features of the wrapper are available.
+            unsafe { $intrinsic(into, val) }
+        }
+    };
+
     (@ load // Internal expansion for load-like intrinsics.
         $(#[$meta:meta])*
         $intrinsic:ident: ([$base_ty:ty; $n:literal][..$registers:literal] | $realty:ty) -> $ret:ty
@@ -102,6 +167,115 @@ macro_rules! vld_n_replicate_k {
             unsafe { arch::$intrinsic(::core::ptr::from_mut(into).cast(), val) }
         }
     };
+
+    (@ store_uninit // Internal expansion for store-like intrinsics into `MaybeUninit` memory.
+        $(#[$meta:meta])*
+        $intrinsic:ident: ([$base_ty:ty; $n:literal][..$registers:literal] | $realty:ty) -> $ret:ty
+        $([$size:ident])?
+    ) => {
+        $(#[$meta])*
+        #[inline]
+        #[cfg(any(target_arch = "aarch64", target_arch = "arm64ec"))]
+        #[target_feature(enable = "neon")]
+        pub fn $intrinsic(
+            into: &mut ::core::mem::MaybeUninit<$realty>,
+            val: $ret,
+        ) -> &mut $realty {
+            $(
+                $size!($registers registers [[$base_ty; $n]; $registers] as $realty);
+            )?
+
+            // Safety: Review the macro use and macro construction. We match up types to the
+            // intrinsics being used. The store writes all bytes of `$realty`, which is valid for
+            // any bit pattern, so the memory is initialized afterwards.
+            unsafe {
+                arch::$intrinsic(into.as_mut_ptr().cast(), val);
+                into.assume_init_mut()
+            }
+        }
+    };
+
+    (@ load_cell // Internal expansion for load-like intrinsics on `Cell` types.
+        $(#[$meta:meta])*
+        $intrinsic:ident: ([$base_ty:ty; $n:literal][..$registers:literal] | $realty:ty) -> $ret:ty
+        $([$size:ident])?
+    ) => {
+        $(#[$meta])*
+        #[inline]
+        #[cfg(any(target_arch = "aarch64", target_arch = "arm64ec"))]
+        #[target_feature(enable = "neon")]
+        pub fn $intrinsic<C: $crate::aarch64::cell::IsCellOf<$realty>>(from: &C) -> $ret {
+            $(
+                $size!($registers registers [[$base_ty; $n]; $registers] as $realty);
+            )?
+
+            // Safety: Review the macro use and macro construction. We match up types to the
+            // intrinsics being used. `C` has the layout of `$realty` by the trait bound.
+            unsafe { arch::$intrinsic(::core::ptr::from_ref(from).cast()) }
+        }
+    };
+
+    (@ store_cell // Internal expansion for store-like intrinsics on `Cell` types.
+        $(#[$meta:meta])*
+        $intrinsic:ident: ([$base_ty:ty; $n:literal][..$registers:literal] | $realty:ty) -> $ret:ty
+        $([$size:ident])?
+    ) => {
+        $(#[$meta])*
+        #[inline]
+        #[cfg(any(target_arch = "aarch64", target_arch = "arm64ec"))]
+        #[target_feature(enable = "neon")]
+        pub fn $intrinsic<C: $crate::aarch64::cell::IsCellOf<$realty>>(into: &C, val: $ret) {
+            $(
+                $size!($registers registers [[$base_ty; $n]; $registers] as $realty);
+            )?
+
+            // Safety: Review the macro use and macro construction. We match up types to the
+            // intrinsics being used. `C` has the layout of `$realty` by the trait bound and its
+            // interior mutability permits the write through a shared reference.
+            unsafe { arch::$intrinsic(::core::ptr::from_ref(into).cast_mut().cast(), val) }
+        }
+    };
+
+    (@ load_half // Internal expansion for load-like intrinsics on `half` types.
+        $(#[$meta:meta])*
+        $intrinsic:ident: ([$base_ty:ty; $n:literal][..$registers:literal] | $realty:ty) -> $ret:ty
+        $([$size:ident])?
+    ) => {
+        $(#[$meta])*
+        #[inline]
+        #[cfg(any(target_arch = "aarch64", target_arch = "arm64ec"))]
+        #[target_feature(enable = "neon")]
+        pub fn $intrinsic<H: $crate::aarch64::half::IsHalfOf<$realty>>(from: &H) -> $ret {
+            $(
+                $size!($registers registers [[$base_ty; $n]; $registers] as $realty);
+            )?
+
+            // Safety: Review the macro use and macro construction. We match up types to the
+            // intrinsics being used. `H` has the layout of `$realty` by the trait bound.
+            unsafe { arch::$intrinsic(::core::ptr::from_ref(from).cast()) }
+        }
+    };
+
+    (@ store_half // Internal expansion for store-like intrinsics on `half` types.
+        $(#[$meta:meta])*
+        $intrinsic:ident: ([$base_ty:ty; $n:literal][..$registers:literal] | $realty:ty) -> $ret:ty
+        $([$size:ident])?
+    ) => {
+        $(#[$meta])*
+        #[inline]
+        #[cfg(any(target_arch = "aarch64", target_arch = "arm64ec"))]
+        #[target_feature(enable = "neon")]
+        pub fn $intrinsic<H: $crate::aarch64::half::IsHalfOf<$realty>>(into: &mut H, val: $ret) {
+            $(
+                $size!($registers registers [[$base_ty; $n]; $registers] as $realty);
+            )?
+
+            // Safety: Review the macro use and macro construction. We match up types to the
+            // intrinsics being used. `H` has the layout of `$realty` by the trait bound and is
+            // valid for any bit pattern.
+            unsafe { arch::$intrinsic(::core::ptr::from_mut(into).cast(), val) }
+        }
+    };
 }
 
 macro_rules! assert_size_8bytes {
@@ -134,6 +308,15 @@ macro_rules! various_sizes {
     ($n:literal registers $ty:ty as $real:ty) => {};
 }
 
+// Declared after the macros above so that the modules can instantiate them for `Cell`, `half`,
+// `Complex` and `MaybeUninit` types.
+pub mod cell;
+#[cfg(feature = "num-complex")]
+pub mod complex;
+#[cfg(feature = "half")]
+pub mod half;
+pub mod uninit;
+
 // There are four fundamental types of loads:
 // - `vldN[q]_<ty>` which loads an array of structures of N elements of type <ty>, as many as
 //   fill the 8-byte or with q 16-byte registers. Eg. vld2q_f32 would load 8 total values, each
@@ -153,6 +336,7 @@ vld_n_replicate_k! {
     unsafe: load;
     // Loads full registers, so 8 bytes per register
     size: assert_size_8bytes;
+    token: Neon;
 
     /// Load an array of 8 `u8` values to one 8-byte register.
     fn vld1_u8(_: &[u8; 8][..1] as [u8; 8]) -> uint8x8_t;
@@ -243,6 +427,7 @@ vld_n_replicate_k! {
     unsafe: load;
     // Loads full registers, so 16 bytes per register
     size: assert_size_16bytes;
+    token: Neon;
 
     /// Load an array of 16 `u8` values to one 16-byte register.
     fn vld1q_u8(_: &[u8; 16][..1] as [u8; 16]) -> uint8x16_t;
@@ -333,6 +518,7 @@ vld_n_replicate_k! {
     unsafe: load;
     // Loads full registers, so 16 bytes per register
     size: assert_size_16bytes;
+    token: Neon;
 
     /// Load `u8` data to two registers, with de-interleaving.
     fn vld2q_u8(_: &[u8; 16][..2] as [u8; 32]) -> uint8x16x2_t;
@@ -402,6 +588,7 @@ vld_n_replicate_k! {
     unsafe: store;
     // Stores full registers, so 8 bytes per register
     size: assert_size_8bytes;
+    token: Neon;
 
     /// Store an array of 8 `u8` values from one 8-byte register.
     fn vst1_u8(_: &[u8; 8][..1] as [u8; 8]) -> uint8x8_t;
@@ -492,6 +679,7 @@ vld_n_replicate_k! {
     unsafe: store;
     // Stores full registers, so 16 bytes per register
     size: assert_size_16bytes;
+    token: Neon;
 
     /// Store an array of 16 `u8` values to one 16-byte register.
     fn vst1q_u8(_: &[u8; 16][..1] as [u8; 16]) -> uint8x16_t;
@@ -582,6 +770,7 @@ vld_n_replicate_k! {
     unsafe: store;
     // Stores full registers, so 16 bytes per register
     size: assert_size_16bytes;
+    token: Neon;
 
     /// Store `u8` data from two registers, with interleaving.
     fn vst2q_u8(_: &[u8; 16][..2] as [u8; 32]) -> uint8x16x2_t;
@@ -650,6 +839,7 @@ vld_n_replicate_k! {
 vld_n_replicate_k! {
     unsafe: load;
     size: various_sizes;
+    token: Neon;
 
     /// Load one single-element `i8` and replicate to all lanes.
     fn vld1_dup_s8(_: &[i8; 1][..1] as i8) -> int8x8_t;
@@ -745,6 +935,7 @@ vld_n_replicate_k! {
 vld_n_replicate_k! {
     unsafe: load;
     size: various_sizes;
+    token: Neon;
 
     /// Load one single-element `i8` and replicate to all lanes.
     fn vld1q_dup_s8(_: &[i8; 1][..1] as i8) -> int8x16_t;
@@ -841,6 +1032,8 @@ vld_n_replicate_k! {
 mod tests {
     use core::arch::aarch64 as arch;
 
+    use crate::{Readable, Register, Unaligned};
+
     // Generate a test for an intrinsic. The primary use of tests is that they execute under Miri,
     // which eliminates most forms of type confusion we could have inadvertently introduced by
     // mismatching intrinsic types and exposed memory types. The syntax for this macro also formats
@@ -853,13 +1046,12 @@ mod tests {
             #[cfg(target_feature = "neon")]
             $(#[$attr])*
             fn $testname() {
-                fn assert_eq<const N: usize>(v: $ty, val: [$base; N]) {
-                    assert!(core::mem::size_of::<$ty>() == core::mem::size_of::<[$base; N]>());
-                    // Safety: transmuting a SIMD vector to its array representation which are Pod.
-                    // This can not utilized `transmute` since the size of `val` is polymorphic,
-                    // and the compiler rejects a transmute it can not statically prove to be
-                    // between equivalently sized types, e.g. dependently sized type on `N`.
-                    let v = unsafe { core::mem::transmute_copy::<$ty, [$base; N]>(&v) };
+                fn assert_eq<const BYTES: usize, const N: usize>(v: $ty, val: [$base; N])
+                where
+                    $ty: Register<BYTES>,
+                    [$base; N]: Unaligned<BYTES>,
+                {
+                    let v: [$base; N] = v.to_array();
                     assert_eq!(v, val);
                 }
 
@@ -985,13 +1177,12 @@ mod tests {
             #[cfg(target_feature = "neon")]
             $(#[$attr])*
             fn $testname() {
-                fn generate<const N: usize>(val: &[$base; N]) -> $ty {
-                    assert!(core::mem::size_of::<$ty>() == core::mem::size_of::<[$base; N]>());
-                    // Safety: transmuting array representation to a SIMD vector, both are Pod.
-                    // This can not utilize `transmute` since the size of `val` is polymorphic,
-                    // and the compiler rejects a transmute it can not statically prove to be
-                    // between equivalently sized types, e.g. dependently sized type on `N`.
-                    unsafe { core::mem::transmute_copy::<[$base; N], $ty>(val) }
+                fn generate<const BYTES: usize, const N: usize>(val: &[$base; N]) -> $ty
+                where
+                    $ty: Register<BYTES>,
+                    [$base; N]: Readable<BYTES>,
+                {
+                    <$ty>::from_array(*val)
                 }
 
                 fn result_init<T>() -> T {
@@ -1142,8 +1333,7 @@ mod tests {
                     const V: usize = S / $n;
                     const _: () = assert!(V * $n == S);
 
-                    // Transmute between vector register and its array representation.
-                    let val = unsafe { ::core::mem::transmute::<$ty1, [$base; S]>(val) };
+                    let val: [$base; S] = val.to_array();
 
                     for (data, &expected) in val.chunks_exact(V).zip(&expected) {
                         let expected: [$base; V] = [expected; V];
@@ -1325,18 +1515,14 @@ mod tests {
             #[cfg(target_feature = "neon")]
             $(#[$attr])*
             fn $testname() {
-                fn assert_eq_regs<const LANES: usize, const REGS: usize>(
+                fn assert_eq_regs<const BYTES: usize, const LANES: usize, const REGS: usize>(
                     result: $ty,
                     expected: [[$base; LANES]; REGS],
-                ) {
-                    assert!(
-                        core::mem::size_of::<$ty>()
-                            == core::mem::size_of::<[[$base; LANES]; REGS]>()
-                    );
-                    // Safety: transmuting a SIMD vector tuple to its array representation.
-                    // See the safety comment for `transmute_copy` in the `test_vld1_from_slice` macro for more details.
-                    let result =
-                        unsafe { core::mem::transmute_copy::<$ty, [[$base; LANES]; REGS]>(&result) };
+                ) where
+                    $ty: Register<BYTES>,
+                    [[$base; LANES]; REGS]: Unaligned<BYTES>,
+                {
+                    let result: [[$base; LANES]; REGS] = result.to_array();
                     assert_eq!(result, expected);
                 }
 
@@ -1359,7 +1545,7 @@ mod tests {
                     });
 
                     let result: $ty = super::$intrinsic(&source);
-                    assert_eq_regs::<LANES, REGS>(result, expected);
+                    assert_eq_regs(result, expected);
                 }
 
                 unsafe { test() }
@@ -1422,16 +1608,14 @@ mod tests {
             #[cfg(target_feature = "neon")]
             $(#[$attr])*
             fn $testname() {
-                fn generate_regs<const LANES: usize, const REGS: usize>(
+                fn generate_regs<const BYTES: usize, const LANES: usize, const REGS: usize>(
                     source: [[$base; LANES]; REGS],
-                ) -> $ty {
-                    assert!(
-                        core::mem::size_of::<$ty>()
-                            == core::mem::size_of::<[[$base; LANES]; REGS]>()
-                    );
-                    // Safety: transmuting array representation to a SIMD vector tuple.
-                    // See the safety comment for `transmute_copy` in the `test_vst1_from_slice` macro for more details.
-                    unsafe { core::mem::transmute_copy::<[[$base; LANES]; REGS], $ty>(&source) }
+                ) -> $ty
+                where
+                    $ty: Register<BYTES>,
+                    [[$base; LANES]; REGS]: Readable<BYTES>,
+                {
+                    <$ty>::from_array(source)
                 }
 
                 #[target_feature(enable = "neon")]
@@ -1452,7 +1636,7 @@ mod tests {
                     // Expected result after interleaving store: [0, 1, 2, ..., TOTAL-1]
                     let expected: [$base; TOTAL] = core::array::from_fn(|i| i as $base);
 
-                    let argument = generate_regs::<LANES, REGS>(source);
+                    let argument = generate_regs(source);
                     let mut result: [$base; TOTAL] = [0 as $base; TOTAL];
                     super::$intrinsic(&mut result, argument);
                     assert_eq!(result, expected);
@@ -0,0 +1,283 @@
+//! Functions generic over byte-compatible source and destination types.
+//!
+//! The wrappers in the parent module match the element type of the memory operand to the element
+//! type of the register, which makes `vld1q_f32` require a `&[f32; 4]`. The functions here instead
+//! accept any type implementing [`Is64BitsUnaligned`] or [`Is128BitsUnaligned`], such as
+//! `&[u8; 16]` read from a file buffer. Loads additionally accept the load-only
+//! [`Is64BitsReadable`] and [`Is128BitsReadable`] types.
+//!
+//! Internally every access is a `vld1[q]_u8` / `vst1[q]_u8` followed or preceded by a
+//! `vreinterpret[q]`. Byte accesses are always element aligned, so the `SCTLR.A` caveat described
+//! in the [parent module](super) does not apply.
+//!
+//! On little-endian targets the lanes equal those of the element-typed wrappers for the same
+//! memory. On big-endian targets such as `aarch64_be` they do not: the element loads and stores
+//! swap the bytes of each lane, while `vreinterpret` keeps the bits of the byte register, so every
+//! lane wider than a byte holds its bytes in memory order. There, `bytes::vld1q_u16(&a)` equals
+//! `vrev16q_u8` applied to the bytes of `vld1q_u16(&a)`, and likewise for the other widths.
+//!
+//! ```rust
+//! # #[cfg(target_feature = "neon")]
+//! # unsafe { load_floats() }
+//! use safe_unaligned_simd::aarch64::bytes;
+//!
+//! #[target_feature(enable = "neon")]
+//! fn load_floats() {
+//!     let mut buffer = [0u8; 17];
+//!     buffer[1..].copy_from_slice(&[1.0f32, 2.0, 3.0, 4.0].map(f32::to_ne_bytes).as_flattened());
+//!
+//!     let floats = bytes::vld1q_f32::<[u8; 16]>(buffer[1..].try_into().unwrap());
+//!
+//!     let mut out = [0u8; 16];
+//!     bytes::vst1q_f32(&mut out, floats);
+//!     assert_eq!(out, buffer[1..]);
+//! }
+//! ```
+#![allow(
+    // Boilerplate that would repeat each function description for little benefit
+    clippy::missing_safety_doc
+)]
+use core::arch::aarch64::{self as arch, *};
+use core::ptr;
+
+use super::{Is64BitsReadable, Is64BitsUnaligned, Is128BitsReadable, Is128BitsUnaligned};
+
+// Generates wrappers which access memory as bytes and reinterpret the register afterwards. The
+// basic structure is:
+//
+//   fn vld1q_f32() -> float32x4_t => vreinterpretq_f32_u8;
+//
+// which expands to a load of `T: $bound` through `$bytes` followed by the named reinterpret. For
+// stores, the reinterpret turns the argument into the byte register before the access.
+macro_rules! vld1_bytes {
+    (
+        unsafe: load;
+        bound: $bound:ident;
+        via: $bytes:ident;
+
+        $(
+            $(#[$meta:meta])* fn $intrinsic:ident() -> $ret:ty => $cast:path;
+        )*
+    ) => {
+        $(
+            $(#[$meta])*
+            #[inline]
+            #[target_feature(enable = "neon")]
+            pub fn $intrinsic<T: $bound>(from: &T) -> $ret {
+                // Safety: `T` is exactly as large as the register by the bound, and byte loads
+                // have no alignment requirement.
+                $cast(unsafe { arch::$bytes(ptr::from_ref(from).cast()) })
+            }
+        )*
+    };
+
+    (
+        unsafe: store;
+        bound: $bound:ident;
+        via: $bytes:ident;
+
+        $(
+            $(#[$meta:meta])* fn $intrinsic:ident(val: $val:ty) => $cast:path;
+        )*
+    ) => {
+        $(
+            $(#[$meta])*
+            #[inline]
+            #[target_feature(enable = "neon")]
+            pub fn $intrinsic<T: $bound>(into: &mut T, val: $val) {
+                // Safety: `T` is exactly as large as the register by the bound, and byte stores
+                // have no alignment requirement.
+                unsafe { arch::$bytes(ptr::from_mut(into).cast(), $cast(val)) }
+            }
+        )*
+    };
+}
+
+vld1_bytes! {
+    unsafe: load;
+    bound: Is64BitsReadable;
+    via: vld1_u8;
+
+    /// Load 8 bytes of data to one `uint8x8_t` register.
+    fn vld1_u8() -> uint8x8_t => core::convert::identity;
+    /// Load 8 bytes of data to one `int8x8_t` register.
+    fn vld1_s8() -> int8x8_t => vreinterpret_s8_u8;
+    /// Load 8 bytes of data to one `uint16x4_t` register.
+    fn vld1_u16() -> uint16x4_t => vreinterpret_u16_u8;
+    /// Load 8 bytes of data to one `int16x4_t` register.
+    fn vld1_s16() -> int16x4_t => vreinterpret_s16_u8;
+    /// Load 8 bytes of data to one `uint32x2_t` register.
+    fn vld1_u32() -> uint32x2_t => vreinterpret_u32_u8;
+    /// Load 8 bytes of data to one `int32x2_t` register.
+    fn vld1_s32() -> int32x2_t => vreinterpret_s32_u8;
+    /// Load 8 bytes of data to one `float32x2_t` register.
+    fn vld1_f32() -> float32x2_t => vreinterpret_f32_u8;
+    /// Load 8 bytes of data to one `uint64x1_t` register.
+    fn vld1_u64() -> uint64x1_t => vreinterpret_u64_u8;
+    /// Load 8 bytes of data to one `int64x1_t` register.
+    fn vld1_s64() -> int64x1_t => vreinterpret_s64_u8;
+    /// Load 8 bytes of data to one `float64x1_t` register.
+    fn vld1_f64() -> float64x1_t => vreinterpret_f64_u8;
+}
+
+vld1_bytes! {
+    unsafe: load;
+    bound: Is128BitsReadable;
+    via: vld1q_u8;
+
+    /// Load 16 bytes of data to one `uint8x16_t` register.
+    fn vld1q_u8() -> uint8x16_t => core::convert::identity;
+    /// Load 16 bytes of data to one `int8x16_t` register.
+    fn vld1q_s8() -> int8x16_t => vreinterpretq_s8_u8;
+    /// Load 16 bytes of data to one `uint16x8_t` register.
+    fn vld1q_u16() -> uint16x8_t => vreinterpretq_u16_u8;
+    /// Load 16 bytes of data to one `int16x8_t` register.
+    fn vld1q_s16() -> int16x8_t => vreinterpretq_s16_u8;
+    /// Load 16 bytes of data to one `uint32x4_t` register.
+    fn vld1q_u32() -> uint32x4_t => vreinterpretq_u32_u8;
+    /// Load 16 bytes of data to one `int32x4_t` register.
+    fn vld1q_s32() -> int32x4_t => vreinterpretq_s32_u8;
+    /// Load 16 bytes of data to one `float32x4_t` register.
+    fn vld1q_f32() -> float32x4_t => vreinterpretq_f32_u8;
+    /// Load 16 bytes of data to one `uint64x2_t` register.
+    fn vld1q_u64() -> uint64x2_t => vreinterpretq_u64_u8;
+    /// Load 16 bytes of data to one `int64x2_t` register.
+    fn vld1q_s64() -> int64x2_t => vreinterpretq_s64_u8;
+    /// Load 16 bytes of data to one `float64x2_t` register.
+    fn vld1q_f64() -> float64x2_t => vreinterpretq_f64_u8;
+}
+
+vld1_bytes! {
+    unsafe: store;
+    bound: Is64BitsUnaligned;
+    via: vst1_u8;
+
+    /// Store 8 bytes of data from one `uint8x8_t` register.
+    fn vst1_u8(val: uint8x8_t) => core::convert::identity;
+    /// Store 8 bytes of data from one `int8x8_t` register.
+    fn vst1_s8(val: int8x8_t) => vreinterpret_u8_s8;
+    /// Store 8 bytes of data from one `uint16x4_t` register.
+    fn vst1_u16(val: uint16x4_t) => vreinterpret_u8_u16;
+    /// Store 8 bytes of data from one `int16x4_t` register.
+    fn vst1_s16(val: int16x4_t) => vreinterpret_u8_s16;
+    /// Store 8 bytes of data from one `uint32x2_t` register.
+    fn vst1_u32(val: uint32x2_t) => vreinterpret_u8_u32;
+    /// Store 8 bytes of data from one `int32x2_t` register.
+    fn vst1_s32(val: int32x2_t) => vreinterpret_u8_s32;
+    /// Store 8 bytes of data from one `float32x2_t` register.
+    fn vst1_f32(val: float32x2_t) => vreinterpret_u8_f32;
+    /// Store 8 bytes of data from one `uint64x1_t` register.
+    fn vst1_u64(val: uint64x1_t) => vreinterpret_u8_u64;
+    /// Store 8 bytes of data from one `int64x1_t` register.
+    fn vst1_s64(val: int64x1_t) => vreinterpret_u8_s64;
+    /// Store 8 bytes of data from one `float64x1_t` register.
+    fn vst1_f64(val: float64x1_t) => vreinterpret_u8_f64;
+}
+
+vld1_bytes! {
+    unsafe: store;
+    bound: Is128BitsUnaligned;
+    via: vst1q_u8;
+
+    /// Store 16 bytes of data from one `uint8x16_t` register.
+    fn vst1q_u8(val: uint8x16_t) => core::convert::identity;
+    /// Store 16 bytes of data from one `int8x16_t` register.
+    fn vst1q_s8(val: int8x16_t) => vreinterpretq_u8_s8;
+    /// Store 16 bytes of data from one `uint16x8_t` register.
+    fn vst1q_u16(val: uint16x8_t) => vreinterpretq_u8_u16;
+    /// Store 16 bytes of data from one `int16x8_t` register.
+    fn vst1q_s16(val: int16x8_t) => vreinterpretq_u8_s16;
+    /// Store 16 bytes of data from one `uint32x4_t` register.
+    fn vst1q_u32(val: uint32x4_t) => vreinterpretq_u8_u32;
+    /// Store 16 bytes of data from one `int32x4_t` register.
+    fn vst1q_s32(val: int32x4_t) => vreinterpretq_u8_s32;
+    /// Store 16 bytes of data from one `float32x4_t` register.
+    fn vst1q_f32(val: float32x4_t) => vreinterpretq_u8_f32;
+    /// Store 16 bytes of data from one `uint64x2_t` register.
+    fn vst1q_u64(val: uint64x2_t) => vreinterpretq_u8_u64;
+    /// Store 16 bytes of data from one `int64x2_t` register.
+    fn vst1q_s64(val: int64x2_t) => vreinterpretq_u8_s64;
+    /// Store 16 bytes of data from one `float64x2_t` register.
+    fn vst1q_f64(val: float64x2_t) => vreinterpretq_u8_f64;
+}
+
+// The tests compare against the element-typed wrappers, whose lanes only match on little-endian
+// targets, see the module documentation.
+#[cfg(all(test, target_endian = "little"))]
+mod tests {
+    use crate::Register;
+
+    // Loads from a misaligned byte buffer must match the element-typed array of the same data, and
+    // storing the register must reproduce the byte buffer.
+    macro_rules! test_vld1_vst1_bytes {
+        ($(#[$attr:meta])* fn $testname:ident, $load:ident, $store:ident, $base:ty, $n:literal) => {
+            #[test]
+            #[cfg(target_feature = "neon")]
+            $(#[$attr])*
+            fn $testname() {
+                #[target_feature(enable = "neon")]
+                fn test() {
+                    const BYTES: usize = $n * size_of::<$base>();
+
+                    let source: [$base; $n] = core::array::from_fn(|i| (i + 1) as $base);
+                    let mut buffer = [0u8; BYTES + 1];
+                    for (chunk, val) in buffer[1..].chunks_exact_mut(size_of::<$base>()).zip(source) {
+                        chunk.copy_from_slice(&val.to_ne_bytes());
+                    }
+
+                    // Try to unalign, even if our stack happens to be aligned.
+                    let bytes: &[u8; BYTES] = buffer[1..].try_into().unwrap();
+                    let val = super::$load(bytes);
+
+                    let lanes: [$base; $n] = val.to_array();
+                    assert_eq!(lanes, source);
+
+                    let mut into = [0u8; BYTES];
+                    super::$store(&mut into, val);
+                    assert_eq!(into, buffer[1..]);
+                }
+
+                unsafe { test() }
+            }
+        };
+    }
+
+    test_vld1_vst1_bytes!(fn test_vld1_u8, vld1_u8, vst1_u8, u8, 8);
+    test_vld1_vst1_bytes!(fn test_vld1_s8, vld1_s8, vst1_s8, i8, 8);
+    test_vld1_vst1_bytes!(fn test_vld1_u16, vld1_u16, vst1_u16, u16, 4);
+    test_vld1_vst1_bytes!(fn test_vld1_s16, vld1_s16, vst1_s16, i16, 4);
+    test_vld1_vst1_bytes!(fn test_vld1_u32, vld1_u32, vst1_u32, u32, 2);
+    test_vld1_vst1_bytes!(fn test_vld1_s32, vld1_s32, vst1_s32, i32, 2);
+    test_vld1_vst1_bytes!(fn test_vld1_f32, vld1_f32, vst1_f32, f32, 2);
+    test_vld1_vst1_bytes!(fn test_vld1_u64, vld1_u64, vst1_u64, u64, 1);
+    test_vld1_vst1_bytes!(fn test_vld1_s64, vld1_s64, vst1_s64, i64, 1);
+    test_vld1_vst1_bytes!(fn test_vld1_f64, vld1_f64, vst1_f64, f64, 1);
+
+    test_vld1_vst1_bytes!(fn test_vld1q_u8, vld1q_u8, vst1q_u8, u8, 16);
+    test_vld1_vst1_bytes!(fn test_vld1q_s8, vld1q_s8, vst1q_s8, i8, 16);
+    test_vld1_vst1_bytes!(fn test_vld1q_u16, vld1q_u16, vst1q_u16, u16, 8);
+    test_vld1_vst1_bytes!(fn test_vld1q_s16, vld1q_s16, vst1q_s16, i16, 8);
+    test_vld1_vst1_bytes!(fn test_vld1q_u32, vld1q_u32, vst1q_u32, u32, 4);
+    test_vld1_vst1_bytes!(fn test_vld1q_s32, vld1q_s32, vst1q_s32, i32, 4);
+    test_vld1_vst1_bytes!(fn test_vld1q_f32, vld1q_f32, vst1q_f32, f32, 4);
+    test_vld1_vst1_bytes!(fn test_vld1q_u64, vld1q_u64, vst1q_u64, u64, 2);
+    test_vld1_vst1_bytes!(fn test_vld1q_s64, vld1q_s64, vst1q_s64, i64, 2);
+    test_vld1_vst1_bytes!(fn test_vld1q_f64, vld1q_f64, vst1q_f64, f64, 2);
+
+    #[test]
+    #[cfg(target_feature = "neon")]
+    fn test_vld1q_f32_from_u32() {
+        #[target_feature(enable = "neon")]
+        fn test() {
+            let bits = [1.0f32, -2.0, 0.5, 8.0].map(f32::to_bits);
+            let val = super::vld1q_f32(&bits);
+            let expected = crate::aarch64::vld1q_f32(&[1.0, -2.0, 0.5, 8.0]);
+
+            let val: [u32; 4] = val.to_array();
+            let expected: [u32; 4] = expected.to_array();
+            assert_eq!(val, expected);
+        }
+
+        unsafe { test() }
+    }
+}
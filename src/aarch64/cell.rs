@@ -0,0 +1,519 @@
+//! Functions generic over [`Cell`] array types.
+//!
+//! These functions enable loading and storing of `&Cell<[T; N]>` and
+//! `&[Cell<T>; N]`, shared mutable container types which permit mutability
+//! even in the presence of aliasing.
+//!
+//! This allows for operating on overlapping slices similar to how one can use
+//! `std::arch` intrinsics with raw pointers. The element type of the cells
+//! matches the element type of the non-`Cell` wrappers in the parent module,
+//! so the alignment caveats described there apply unchanged.
+//!
+//! ```rust
+//! # #[cfg(target_feature = "neon")]
+//! # unsafe { slide_right() }
+//! use core::cell::Cell;
+//!
+//! use safe_unaligned_simd::aarch64::cell;
+//!
+//! #[target_feature(enable = "neon")]
+//! fn slide_right() {
+//!    let mut a = [0u16, 1, 2, 3, 4, 5, 6, 7, 8];
+//!    let val = Cell::from_mut(&mut a[..]).as_slice_of_cells();
+//!
+//!    let load: &[_; 8] = val[..8].try_into().unwrap();
+//!    let store: &[_; 8] = val[1..].try_into().unwrap();
+//!
+//!    let r = cell::vld1q_u16(load);
+//!    cell::vst1q_u16(store, r);
+//!
+//!    assert_eq!(a, [0, 0, 1, 2, 3, 4, 5, 6, 7]);
+//! }
+//! ```
+use core::arch::aarch64::{self as arch, *};
+use core::cell::Cell;
+
+// Internal module for sealing the `Cell` trait.
+mod private {
+    pub trait Sealed<T> {}
+}
+
+/// A trait that marks a cell-like type as having the memory layout of `T`.
+///
+/// This is implemented for `Cell<T>` as well as `[Cell<E>; N]` where `T` is
+/// `[E; N]`.
+pub trait IsCellOf<T>: private::Sealed<T> {}
+
+impl<T> private::Sealed<T> for Cell<T> {}
+impl<T> IsCellOf<T> for Cell<T> {}
+
+impl<T, const N: usize> private::Sealed<[T; N]> for [Cell<T>; N] {}
+impl<T, const N: usize> IsCellOf<[T; N]> for [Cell<T>; N] {}
+
+vld_n_replicate_k! {
+    unsafe: load_cell;
+    // Loads full registers, so 8 bytes per register
+    size: assert_size_8bytes;
+
+    /// Load an array of 8 `u8` values to one 8-byte register.
+    fn vld1_u8(_: &[u8; 8][..1] as [u8; 8]) -> uint8x8_t;
+    /// Load an array of 8 `i8` values to one 8-byte register.
+    fn vld1_s8(_: &[i8; 8][..1] as [i8; 8]) -> int8x8_t;
+    /// Load an array of 4 `u16` values to one 8-byte register.
+    fn vld1_u16(_: &[u16; 4][..1] as [u16; 4]) -> uint16x4_t;
+    /// Load an array of 4 `i16` values to one 8-byte register.
+    fn vld1_s16(_: &[i16; 4][..1] as [i16; 4]) -> int16x4_t;
+    /// Load an array of 2 `u32` values to one 8-byte register.
+    fn vld1_u32(_: &[u32; 2][..1] as [u32; 2]) -> uint32x2_t;
+    /// Load an array of 2 `i32` values to one 8-byte register.
+    fn vld1_s32(_: &[i32; 2][..1] as [i32; 2]) -> int32x2_t;
+    /// Load an array of 2 `f32` values to one 8-byte register.
+    fn vld1_f32(_: &[f32; 2][..1] as [f32; 2]) -> float32x2_t;
+    /// Load one `u64` value to one 8-byte register.
+    fn vld1_u64(_: &[u64; 1][..1] as u64) -> uint64x1_t;
+    /// Load one `i64` value to one 8-byte register.
+    fn vld1_s64(_: &[i64; 1][..1] as i64) -> int64x1_t;
+    /// Load one `f64` value to one 8-byte register.
+    fn vld1_f64(_: &[f64; 1][..1] as f64) -> float64x1_t;
+}
+
+vld_n_replicate_k! {
+    unsafe: load_cell;
+    // Loads full registers, so 16 bytes per register
+    size: assert_size_16bytes;
+
+    /// Load an array of 16 `u8` values to one 16-byte register.
+    fn vld1q_u8(_: &[u8; 16][..1] as [u8; 16]) -> uint8x16_t;
+    /// Load an array of 16 `i8` values to one 16-byte register.
+    fn vld1q_s8(_: &[i8; 16][..1] as [i8; 16]) -> int8x16_t;
+    /// Load an array of 8 `u16` values to one 16-byte register.
+    fn vld1q_u16(_: &[u16; 8][..1] as [u16; 8]) -> uint16x8_t;
+    /// Load an array of 8 `i16` values to one 16-byte register.
+    fn vld1q_s16(_: &[i16; 8][..1] as [i16; 8]) -> int16x8_t;
+    /// Load an array of 4 `u32` values to one 16-byte register.
+    fn vld1q_u32(_: &[u32; 4][..1] as [u32; 4]) -> uint32x4_t;
+    /// Load an array of 4 `i32` values to one 16-byte register.
+    fn vld1q_s32(_: &[i32; 4][..1] as [i32; 4]) -> int32x4_t;
+    /// Load an array of 4 `f32` values to one 16-byte register.
+    fn vld1q_f32(_: &[f32; 4][..1] as [f32; 4]) -> float32x4_t;
+    /// Load an array of 2 `u64` values to one 16-byte register.
+    fn vld1q_u64(_: &[u64; 2][..1] as [u64; 2]) -> uint64x2_t;
+    /// Load an array of 2 `i64` values to one 16-byte register.
+    fn vld1q_s64(_: &[i64; 2][..1] as [i64; 2]) -> int64x2_t;
+    /// Load an array of 2 `f64` values to one 16-byte register.
+    fn vld1q_f64(_: &[f64; 2][..1] as [f64; 2]) -> float64x2_t;
+
+    /// Load two arrays of 16 `u8` values to two 16-byte registers.
+    fn vld1q_u8_x2(_: &[u8; 16][..2] as [[u8; 16]; 2]) -> uint8x16x2_t;
+    /// Load two arrays of 16 `i8` values to two 16-byte registers.
+    fn vld1q_s8_x2(_: &[i8; 16][..2] as [[i8; 16]; 2]) -> int8x16x2_t;
+    /// Load two arrays of 8 `u16` values to two 16-byte registers.
+    fn vld1q_u16_x2(_: &[u16; 8][..2] as [[u16; 8]; 2]) -> uint16x8x2_t;
+    /// Load two arrays of 8 `i16` values to two 16-byte registers.
+    fn vld1q_s16_x2(_: &[i16; 8][..2] as [[i16; 8]; 2]) -> int16x8x2_t;
+    /// Load two arrays of 4 `u32` values to two 16-byte registers.
+    fn vld1q_u32_x2(_: &[u32; 4][..2] as [[u32; 4]; 2]) -> uint32x4x2_t;
+    /// Load two arrays of 4 `i32` values to two 16-byte registers.
+    fn vld1q_s32_x2(_: &[i32; 4][..2] as [[i32; 4]; 2]) -> int32x4x2_t;
+    /// Load two arrays of 4 `f32` values to two 16-byte registers.
+    fn vld1q_f32_x2(_: &[f32; 4][..2] as [[f32; 4]; 2]) -> float32x4x2_t;
+    /// Load two arrays of 2 `u64` values to two 16-byte registers.
+    fn vld1q_u64_x2(_: &[u64; 2][..2] as [[u64; 2]; 2]) -> uint64x2x2_t;
+    /// Load two arrays of 2 `i64` values to two 16-byte registers.
+    fn vld1q_s64_x2(_: &[i64; 2][..2] as [[i64; 2]; 2]) -> int64x2x2_t;
+    /// Load two arrays of 2 `f64` values to two 16-byte registers.
+    fn vld1q_f64_x2(_: &[f64; 2][..2] as [[f64; 2]; 2]) -> float64x2x2_t;
+
+    /// Load three arrays of 16 `u8` values to three 16-byte registers.
+    fn vld1q_u8_x3(_: &[u8; 16][..3] as [[u8; 16]; 3]) -> uint8x16x3_t;
+    /// Load three arrays of 16 `i8` values to three 16-byte registers.
+    fn vld1q_s8_x3(_: &[i8; 16][..3] as [[i8; 16]; 3]) -> int8x16x3_t;
+    /// Load three arrays of 8 `u16` values to three 16-byte registers.
+    fn vld1q_u16_x3(_: &[u16; 8][..3] as [[u16; 8]; 3]) -> uint16x8x3_t;
+    /// Load three arrays of 8 `i16` values to three 16-byte registers.
+    fn vld1q_s16_x3(_: &[i16; 8][..3] as [[i16; 8]; 3]) -> int16x8x3_t;
+    /// Load three arrays of 4 `u32` values to three 16-byte registers.
+    fn vld1q_u32_x3(_: &[u32; 4][..3] as [[u32; 4]; 3]) -> uint32x4x3_t;
+    /// Load three arrays of 4 `i32` values to three 16-byte registers.
+    fn vld1q_s32_x3(_: &[i32; 4][..3] as [[i32; 4]; 3]) -> int32x4x3_t;
+    /// Load three arrays of 4 `f32` values to three 16-byte registers.
+    fn vld1q_f32_x3(_: &[f32; 4][..3] as [[f32; 4]; 3]) -> float32x4x3_t;
+    /// Load three arrays of 2 `u64` values to three 16-byte registers.
+    fn vld1q_u64_x3(_: &[u64; 2][..3] as [[u64; 2]; 3]) -> uint64x2x3_t;
+    /// Load three arrays of 2 `i64` values to three 16-byte registers.
+    fn vld1q_s64_x3(_: &[i64; 2][..3] as [[i64; 2]; 3]) -> int64x2x3_t;
+    /// Load three arrays of 2 `f64` values to three 16-byte registers.
+    fn vld1q_f64_x3(_: &[f64; 2][..3] as [[f64; 2]; 3]) -> float64x2x3_t;
+
+    /// Load four arrays of 16 `u8` values to four 16-byte registers.
+    fn vld1q_u8_x4(_: &[u8; 16][..4] as [[u8; 16]; 4]) -> uint8x16x4_t;
+    /// Load four arrays of 16 `i8` values to four 16-byte registers.
+    fn vld1q_s8_x4(_: &[i8; 16][..4] as [[i8; 16]; 4]) -> int8x16x4_t;
+    /// Load four arrays of 8 `u16` values to four 16-byte registers.
+    fn vld1q_u16_x4(_: &[u16; 8][..4] as [[u16; 8]; 4]) -> uint16x8x4_t;
+    /// Load four arrays of 8 `i16` values to four 16-byte registers.
+    fn vld1q_s16_x4(_: &[i16; 8][..4] as [[i16; 8]; 4]) -> int16x8x4_t;
+    /// Load four arrays of 4 `u32` values to four 16-byte registers.
+    fn vld1q_u32_x4(_: &[u32; 4][..4] as [[u32; 4]; 4]) -> uint32x4x4_t;
+    /// Load four arrays of 4 `i32` values to four 16-byte registers.
+    fn vld1q_s32_x4(_: &[i32; 4][..4] as [[i32; 4]; 4]) -> int32x4x4_t;
+    /// Load four arrays of 4 `f32` values to four 16-byte registers.
+    fn vld1q_f32_x4(_: &[f32; 4][..4] as [[f32; 4]; 4]) -> float32x4x4_t;
+    /// Load four arrays of 2 `u64` values to four 16-byte registers.
+    fn vld1q_u64_x4(_: &[u64; 2][..4] as [[u64; 2]; 4]) -> uint64x2x4_t;
+    /// Load four arrays of 2 `i64` values to four 16-byte registers.
+    fn vld1q_s64_x4(_: &[i64; 2][..4] as [[i64; 2]; 4]) -> int64x2x4_t;
+    /// Load four arrays of 2 `f64` values to four 16-byte registers.
+    fn vld1q_f64_x4(_: &[f64; 2][..4] as [[f64; 2]; 4]) -> float64x2x4_t;
+}
+
+vld_n_replicate_k! {
+    unsafe: load_cell;
+    // Loads full registers, so 16 bytes per register
+    size: assert_size_16bytes;
+
+    /// Load `u8` data to two registers, with de-interleaving.
+    fn vld2q_u8(_: &[u8; 16][..2] as [u8; 32]) -> uint8x16x2_t;
+    /// Load `i8` data to two registers, with de-interleaving.
+    fn vld2q_s8(_: &[i8; 16][..2] as [i8; 32]) -> int8x16x2_t;
+    /// Load `u16` data to two registers, with de-interleaving.
+    fn vld2q_u16(_: &[u16; 8][..2] as [u16; 16]) -> uint16x8x2_t;
+    /// Load `i16` data to two registers, with de-interleaving.
+    fn vld2q_s16(_: &[i16; 8][..2] as [i16; 16]) -> int16x8x2_t;
+    /// Load `u32` data to two registers, with de-interleaving.
+    fn vld2q_u32(_: &[u32; 4][..2] as [u32; 8]) -> uint32x4x2_t;
+    /// Load `i32` data to two registers, with de-interleaving.
+    fn vld2q_s32(_: &[i32; 4][..2] as [i32; 8]) -> int32x4x2_t;
+    /// Load `f32` data to two registers, with de-interleaving.
+    fn vld2q_f32(_: &[f32; 4][..2] as [f32; 8]) -> float32x4x2_t;
+    /// Load `u64` data to two registers, with de-interleaving.
+    fn vld2q_u64(_: &[u64; 2][..2] as [u64; 4]) -> uint64x2x2_t;
+    /// Load `i64` data to two registers, with de-interleaving.
+    fn vld2q_s64(_: &[i64; 2][..2] as [i64; 4]) -> int64x2x2_t;
+    /// Load `f64` data to two registers, with de-interleaving.
+    fn vld2q_f64(_: &[f64; 2][..2] as [f64; 4]) -> float64x2x2_t;
+
+    /// Load `u8` data to three registers, with de-interleaving.
+    fn vld3q_u8(_: &[u8; 16][..3] as [u8; 48]) -> uint8x16x3_t;
+    /// Load `i8` data to three registers, with de-interleaving.
+    fn vld3q_s8(_: &[i8; 16][..3] as [i8; 48]) -> int8x16x3_t;
+    /// Load `u16` data to three registers, with de-interleaving.
+    fn vld3q_u16(_: &[u16; 8][..3] as [u16; 24]) -> uint16x8x3_t;
+    /// Load `i16` data to three registers, with de-interleaving.
+    fn vld3q_s16(_: &[i16; 8][..3] as [i16; 24]) -> int16x8x3_t;
+    /// Load `u32` data to three registers, with de-interleaving.
+    fn vld3q_u32(_: &[u32; 4][..3] as [u32; 12]) -> uint32x4x3_t;
+    /// Load `i32` data to three registers, with de-interleaving.
+    fn vld3q_s32(_: &[i32; 4][..3] as [i32; 12]) -> int32x4x3_t;
+    /// Load `f32` data to three registers, with de-interleaving.
+    fn vld3q_f32(_: &[f32; 4][..3] as [f32; 12]) -> float32x4x3_t;
+    /// Load `u64` data to three registers, with de-interleaving.
+    fn vld3q_u64(_: &[u64; 2][..3] as [u64; 6]) -> uint64x2x3_t;
+    /// Load `i64` data to three registers, with de-interleaving.
+    fn vld3q_s64(_: &[i64; 2][..3] as [i64; 6]) -> int64x2x3_t;
+    /// Load `f64` data to three registers, with de-interleaving.
+    fn vld3q_f64(_: &[f64; 2][..3] as [f64; 6]) -> float64x2x3_t;
+
+    /// Load `u8` data to four registers, with de-interleaving.
+    fn vld4q_u8(_: &[u8; 16][..4] as [u8; 64]) -> uint8x16x4_t;
+    /// Load `i8` data to four registers, with de-interleaving.
+    fn vld4q_s8(_: &[i8; 16][..4] as [i8; 64]) -> int8x16x4_t;
+    /// Load `u16` data to four registers, with de-interleaving.
+    fn vld4q_u16(_: &[u16; 8][..4] as [u16; 32]) -> uint16x8x4_t;
+    /// Load `i16` data to four registers, with de-interleaving.
+    fn vld4q_s16(_: &[i16; 8][..4] as [i16; 32]) -> int16x8x4_t;
+    /// Load `u32` data to four registers, with de-interleaving.
+    fn vld4q_u32(_: &[u32; 4][..4] as [u32; 16]) -> uint32x4x4_t;
+    /// Load `i32` data to four registers, with de-interleaving.
+    fn vld4q_s32(_: &[i32; 4][..4] as [i32; 16]) -> int32x4x4_t;
+    /// Load `f32` data to four registers, with de-interleaving.
+    fn vld4q_f32(_: &[f32; 4][..4] as [f32; 16]) -> float32x4x4_t;
+    /// Load `u64` data to four registers, with de-interleaving.
+    fn vld4q_u64(_: &[u64; 2][..4] as [u64; 8]) -> uint64x2x4_t;
+    /// Load `i64` data to four registers, with de-interleaving.
+    fn vld4q_s64(_: &[i64; 2][..4] as [i64; 8]) -> int64x2x4_t;
+    /// Load `f64` data to four registers, with de-interleaving.
+    fn vld4q_f64(_: &[f64; 2][..4] as [f64; 8]) -> float64x2x4_t;
+}
+
+vld_n_replicate_k! {
+    unsafe: store_cell;
+    // Stores full registers, so 8 bytes per register
+    size: assert_size_8bytes;
+
+    /// Store an array of 8 `u8` values from one 8-byte register.
+    fn vst1_u8(_: &[u8; 8][..1] as [u8; 8]) -> uint8x8_t;
+    /// Store an array of 8 `i8` values from one 8-byte register.
+    fn vst1_s8(_: &[i8; 8][..1] as [i8; 8]) -> int8x8_t;
+    /// Store an array of 4 `u16` values from one 8-byte register.
+    fn vst1_u16(_: &[u16; 4][..1] as [u16; 4]) -> uint16x4_t;
+    /// Store an array of 4 `i16` values from one 8-byte register.
+    fn vst1_s16(_: &[i16; 4][..1] as [i16; 4]) -> int16x4_t;
+    /// Store an array of 2 `u32` values from one 8-byte register.
+    fn vst1_u32(_: &[u32; 2][..1] as [u32; 2]) -> uint32x2_t;
+    /// Store an array of 2 `i32` values from one 8-byte register.
+    fn vst1_s32(_: &[i32; 2][..1] as [i32; 2]) -> int32x2_t;
+    /// Store an array of 2 `f32` values from one 8-byte register.
+    fn vst1_f32(_: &[f32; 2][..1] as [f32; 2]) -> float32x2_t;
+    /// Store one `u64` value from one 8-byte register.
+    fn vst1_u64(_: &[u64; 1][..1] as u64) -> uint64x1_t;
+    /// Store one `i64` value from one 8-byte register.
+    fn vst1_s64(_: &[i64; 1][..1] as i64) -> int64x1_t;
+    /// Store one `f64` value from one 8-byte register.
+    fn vst1_f64(_: &[f64; 1][..1] as f64) -> float64x1_t;
+}
+
+vld_n_replicate_k! {
+    unsafe: store_cell;
+    // Stores full registers, so 16 bytes per register
+    size: assert_size_16bytes;
+
+    /// Store an array of 16 `u8` values from one 16-byte register.
+    fn vst1q_u8(_: &[u8; 16][..1] as [u8; 16]) -> uint8x16_t;
+    /// Store an array of 16 `i8` values from one 16-byte register.
+    fn vst1q_s8(_: &[i8; 16][..1] as [i8; 16]) -> int8x16_t;
+    /// Store an array of 8 `u16` values from one 16-byte register.
+    fn vst1q_u16(_: &[u16; 8][..1] as [u16; 8]) -> uint16x8_t;
+    /// Store an array of 8 `i16` values from one 16-byte register.
+    fn vst1q_s16(_: &[i16; 8][..1] as [i16; 8]) -> int16x8_t;
+    /// Store an array of 4 `u32` values from one 16-byte register.
+    fn vst1q_u32(_: &[u32; 4][..1] as [u32; 4]) -> uint32x4_t;
+    /// Store an array of 4 `i32` values from one 16-byte register.
+    fn vst1q_s32(_: &[i32; 4][..1] as [i32; 4]) -> int32x4_t;
+    /// Store an array of 4 `f32` values from one 16-byte register.
+    fn vst1q_f32(_: &[f32; 4][..1] as [f32; 4]) -> float32x4_t;
+    /// Store an array of 2 `u64` values from one 16-byte register.
+    fn vst1q_u64(_: &[u64; 2][..1] as [u64; 2]) -> uint64x2_t;
+    /// Store an array of 2 `i64` values from one 16-byte register.
+    fn vst1q_s64(_: &[i64; 2][..1] as [i64; 2]) -> int64x2_t;
+    /// Store an array of 2 `f64` values from one 16-byte register.
+    fn vst1q_f64(_: &[f64; 2][..1] as [f64; 2]) -> float64x2_t;
+
+    /// Store two arrays of 16 `u8` values from two 16-byte registers.
+    fn vst1q_u8_x2(_: &[u8; 16][..2] as [[u8; 16]; 2]) -> uint8x16x2_t;
+    /// Store two arrays of 16 `i8` values from two 16-byte registers.
+    fn vst1q_s8_x2(_: &[i8; 16][..2] as [[i8; 16]; 2]) -> int8x16x2_t;
+    /// Store two arrays of 8 `u16` values from two 16-byte registers.
+    fn vst1q_u16_x2(_: &[u16; 8][..2] as [[u16; 8]; 2]) -> uint16x8x2_t;
+    /// Store two arrays of 8 `i16` values from two 16-byte registers.
+    fn vst1q_s16_x2(_: &[i16; 8][..2] as [[i16; 8]; 2]) -> int16x8x2_t;
+    /// Store two arrays of 4 `u32` values from two 16-byte registers.
+    fn vst1q_u32_x2(_: &[u32; 4][..2] as [[u32; 4]; 2]) -> uint32x4x2_t;
+    /// Store two arrays of 4 `i32` values from two 16-byte registers.
+    fn vst1q_s32_x2(_: &[i32; 4][..2] as [[i32; 4]; 2]) -> int32x4x2_t;
+    /// Store two arrays of 4 `f32` values from two 16-byte registers.
+    fn vst1q_f32_x2(_: &[f32; 4][..2] as [[f32; 4]; 2]) -> float32x4x2_t;
+    /// Store two arrays of 2 `u64` values from two 16-byte registers.
+    fn vst1q_u64_x2(_: &[u64; 2][..2] as [[u64; 2]; 2]) -> uint64x2x2_t;
+    /// Store two arrays of 2 `i64` values from two 16-byte registers.
+    fn vst1q_s64_x2(_: &[i64; 2][..2] as [[i64; 2]; 2]) -> int64x2x2_t;
+    /// Store two arrays of 2 `f64` values from two 16-byte registers.
+    fn vst1q_f64_x2(_: &[f64; 2][..2] as [[f64; 2]; 2]) -> float64x2x2_t;
+
+    /// Store three arrays of 16 `u8` values from three 16-byte registers.
+    fn vst1q_u8_x3(_: &[u8; 16][..3] as [[u8; 16]; 3]) -> uint8x16x3_t;
+    /// Store three arrays of 16 `i8` values from three 16-byte registers.
+    fn vst1q_s8_x3(_: &[i8; 16][..3] as [[i8; 16]; 3]) -> int8x16x3_t;
+    /// Store three arrays of 8 `u16` values from three 16-byte registers.
+    fn vst1q_u16_x3(_: &[u16; 8][..3] as [[u16; 8]; 3]) -> uint16x8x3_t;
+    /// Store three arrays of 8 `i16` values from three 16-byte registers.
+    fn vst1q_s16_x3(_: &[i16; 8][..3] as [[i16; 8]; 3]) -> int16x8x3_t;
+    /// Store three arrays of 4 `u32` values from three 16-byte registers.
+    fn vst1q_u32_x3(_: &[u32; 4][..3] as [[u32; 4]; 3]) -> uint32x4x3_t;
+    /// Store three arrays of 4 `i32` values from three 16-byte registers.
+    fn vst1q_s32_x3(_: &[i32; 4][..3] as [[i32; 4]; 3]) -> int32x4x3_t;
+    /// Store three arrays of 4 `f32` values from three 16-byte registers.
+    fn vst1q_f32_x3(_: &[f32; 4][..3] as [[f32; 4]; 3]) -> float32x4x3_t;
+    /// Store three arrays of 2 `u64` values from three 16-byte registers.
+    fn vst1q_u64_x3(_: &[u64; 2][..3] as [[u64; 2]; 3]) -> uint64x2x3_t;
+    /// Store three arrays of 2 `i64` values from three 16-byte registers.
+    fn vst1q_s64_x3(_: &[i64; 2][..3] as [[i64; 2]; 3]) -> int64x2x3_t;
+    /// Store three arrays of 2 `f64` values from three 16-byte registers.
+    fn vst1q_f64_x3(_: &[f64; 2][..3] as [[f64; 2]; 3]) -> float64x2x3_t;
+
+    /// Store four arrays of 16 `u8` values from four 16-byte registers.
+    fn vst1q_u8_x4(_: &[u8; 16][..4] as [[u8; 16]; 4]) -> uint8x16x4_t;
+    /// Store four arrays of 16 `i8` values from four 16-byte registers.
+    fn vst1q_s8_x4(_: &[i8; 16][..4] as [[i8; 16]; 4]) -> int8x16x4_t;
+    /// Store four arrays of 8 `u16` values from four 16-byte registers.
+    fn vst1q_u16_x4(_: &[u16; 8][..4] as [[u16; 8]; 4]) -> uint16x8x4_t;
+    /// Store four arrays of 8 `i16` values from four 16-byte registers.
+    fn vst1q_s16_x4(_: &[i16; 8][..4] as [[i16; 8]; 4]) -> int16x8x4_t;
+    /// Store four arrays of 4 `u32` values from four 16-byte registers.
+    fn vst1q_u32_x4(_: &[u32; 4][..4] as [[u32; 4]; 4]) -> uint32x4x4_t;
+    /// Store four arrays of 4 `i32` values from four 16-byte registers.
+    fn vst1q_s32_x4(_: &[i32; 4][..4] as [[i32; 4]; 4]) -> int32x4x4_t;
+    /// Store four arrays of 4 `f32` values from four 16-byte registers.
+    fn vst1q_f32_x4(_: &[f32; 4][..4] as [[f32; 4]; 4]) -> float32x4x4_t;
+    /// Store four arrays of 2 `u64` values from four 16-byte registers.
+    fn vst1q_u64_x4(_: &[u64; 2][..4] as [[u64; 2]; 4]) -> uint64x2x4_t;
+    /// Store four arrays of 2 `i64` values from four 16-byte registers.
+    fn vst1q_s64_x4(_: &[i64; 2][..4] as [[i64; 2]; 4]) -> int64x2x4_t;
+    /// Store four arrays of 2 `f64` values from four 16-byte registers.
+    fn vst1q_f64_x4(_: &[f64; 2][..4] as [[f64; 2]; 4]) -> float64x2x4_t;
+}
+
+vld_n_replicate_k! {
+    unsafe: store_cell;
+    // Stores full registers, so 16 bytes per register
+    size: assert_size_16bytes;
+
+    /// Store `u8` data from two registers, with interleaving.
+    fn vst2q_u8(_: &[u8; 16][..2] as [u8; 32]) -> uint8x16x2_t;
+    /// Store `i8` data from two registers, with interleaving.
+    fn vst2q_s8(_: &[i8; 16][..2] as [i8; 32]) -> int8x16x2_t;
+    /// Store `u16` data from two registers, with interleaving.
+    fn vst2q_u16(_: &[u16; 8][..2] as [u16; 16]) -> uint16x8x2_t;
+    /// Store `i16` data from two registers, with interleaving.
+    fn vst2q_s16(_: &[i16; 8][..2] as [i16; 16]) -> int16x8x2_t;
+    /// Store `u32` data from two registers, with interleaving.
+    fn vst2q_u32(_: &[u32; 4][..2] as [u32; 8]) -> uint32x4x2_t;
+    /// Store `i32` data from two registers, with interleaving.
+    fn vst2q_s32(_: &[i32; 4][..2] as [i32; 8]) -> int32x4x2_t;
+    /// Store `f32` data from two registers, with interleaving.
+    fn vst2q_f32(_: &[f32; 4][..2] as [f32; 8]) -> float32x4x2_t;
+    /// Store `u64` data from two registers, with interleaving.
+    fn vst2q_u64(_: &[u64; 2][..2] as [u64; 4]) -> uint64x2x2_t;
+    /// Store `i64` data from two registers, with interleaving.
+    fn vst2q_s64(_: &[i64; 2][..2] as [i64; 4]) -> int64x2x2_t;
+    /// Store `f64` data from two registers, with interleaving.
+    fn vst2q_f64(_: &[f64; 2][..2] as [f64; 4]) -> float64x2x2_t;
+
+    /// Store `u8` data from three registers, with interleaving.
+    fn vst3q_u8(_: &[u8; 16][..3] as [u8; 48]) -> uint8x16x3_t;
+    /// Store `i8` data from three registers, with interleaving.
+    fn vst3q_s8(_: &[i8; 16][..3] as [i8; 48]) -> int8x16x3_t;
+    /// Store `u16` data from three registers, with interleaving.
+    fn vst3q_u16(_: &[u16; 8][..3] as [u16; 24]) -> uint16x8x3_t;
+    /// Store `i16` data from three registers, with interleaving.
+    fn vst3q_s16(_: &[i16; 8][..3] as [i16; 24]) -> int16x8x3_t;
+    /// Store `u32` data from three registers, with interleaving.
+    fn vst3q_u32(_: &[u32; 4][..3] as [u32; 12]) -> uint32x4x3_t;
+    /// Store `i32` data from three registers, with interleaving.
+    fn vst3q_s32(_: &[i32; 4][..3] as [i32; 12]) -> int32x4x3_t;
+    /// Store `f32` data from three registers, with interleaving.
+    fn vst3q_f32(_: &[f32; 4][..3] as [f32; 12]) -> float32x4x3_t;
+    /// Store `u64` data from three registers, with interleaving.
+    fn vst3q_u64(_: &[u64; 2][..3] as [u64; 6]) -> uint64x2x3_t;
+    /// Store `i64` data from three registers, with interleaving.
+    fn vst3q_s64(_: &[i64; 2][..3] as [i64; 6]) -> int64x2x3_t;
+    /// Store `f64` data from three registers, with interleaving.
+    fn vst3q_f64(_: &[f64; 2][..3] as [f64; 6]) -> float64x2x3_t;
+
+    /// Store `u8` data from four registers, with interleaving.
+    fn vst4q_u8(_: &[u8; 16][..4] as [u8; 64]) -> uint8x16x4_t;
+    /// Store `i8` data from four registers, with interleaving.
+    fn vst4q_s8(_: &[i8; 16][..4] as [i8; 64]) -> int8x16x4_t;
+    /// Store `u16` data from four registers, with interleaving.
+    fn vst4q_u16(_: &[u16; 8][..4] as [u16; 32]) -> uint16x8x4_t;
+    /// Store `i16` data from four registers, with interleaving.
+    fn vst4q_s16(_: &[i16; 8][..4] as [i16; 32]) -> int16x8x4_t;
+    /// Store `u32` data from four registers, with interleaving.
+    fn vst4q_u32(_: &[u32; 4][..4] as [u32; 16]) -> uint32x4x4_t;
+    /// Store `i32` data from four registers, with interleaving.
+    fn vst4q_s32(_: &[i32; 4][..4] as [i32; 16]) -> int32x4x4_t;
+    /// Store `f32` data from four registers, with interleaving.
+    fn vst4q_f32(_: &[f32; 4][..4] as [f32; 16]) -> float32x4x4_t;
+    /// Store `u64` data from four registers, with interleaving.
+    fn vst4q_u64(_: &[u64; 2][..4] as [u64; 8]) -> uint64x2x4_t;
+    /// Store `i64` data from four registers, with interleaving.
+    fn vst4q_s64(_: &[i64; 2][..4] as [i64; 8]) -> int64x2x4_t;
+    /// Store `f64` data from four registers, with interleaving.
+    fn vst4q_f64(_: &[f64; 2][..4] as [f64; 8]) -> float64x2x4_t;
+}
+
+#[cfg(test)]
+mod tests {
+    use core::cell::Cell;
+
+    use crate::Register;
+
+    // Load from one window of a `Cell` slice and store into an overlapping window of the same
+    // slice. The result must match the equivalent `copy_within` on plain memory.
+    macro_rules! test_vld1_vst1_overlap {
+        ($(#[$attr:meta])* fn $testname:ident, $load:ident, $store:ident, $base:ty, $n:literal) => {
+            #[test]
+            #[cfg(target_feature = "neon")]
+            $(#[$attr])*
+            fn $testname() {
+                const LOAD_INDEX: usize = 1;
+                const STORE_INDEX: usize = 3;
+
+                #[target_feature(enable = "neon")]
+                fn test() {
+                    let mut a: [$base; $n + STORE_INDEX] = core::array::from_fn(|i| i as $base);
+
+                    let mut result = a;
+                    result.copy_within(LOAD_INDEX..LOAD_INDEX + $n, STORE_INDEX);
+
+                    let val = Cell::from_mut(&mut a[..]).as_slice_of_cells();
+                    let load: &[_; $n] = val[LOAD_INDEX..][..$n].try_into().unwrap();
+                    let store: &[_; $n] = val[STORE_INDEX..][..$n].try_into().unwrap();
+
+                    let r = super::$load(load);
+                    super::$store(store, r);
+
+                    assert_eq!(a, result);
+                }
+
+                unsafe { test() }
+            }
+        };
+    }
+
+    test_vld1_vst1_overlap!(fn test_vld1_u8, vld1_u8, vst1_u8, u8, 8);
+    test_vld1_vst1_overlap!(fn test_vld1_u16, vld1_u16, vst1_u16, u16, 4);
+    test_vld1_vst1_overlap!(fn test_vld1_f32, vld1_f32, vst1_f32, f32, 2);
+
+    test_vld1_vst1_overlap!(fn test_vld1q_u8, vld1q_u8, vst1q_u8, u8, 16);
+    test_vld1_vst1_overlap!(fn test_vld1q_s8, vld1q_s8, vst1q_s8, i8, 16);
+    test_vld1_vst1_overlap!(fn test_vld1q_u16, vld1q_u16, vst1q_u16, u16, 8);
+    test_vld1_vst1_overlap!(fn test_vld1q_s16, vld1q_s16, vst1q_s16, i16, 8);
+    test_vld1_vst1_overlap!(fn test_vld1q_u32, vld1q_u32, vst1q_u32, u32, 4);
+    test_vld1_vst1_overlap!(fn test_vld1q_s32, vld1q_s32, vst1q_s32, i32, 4);
+    test_vld1_vst1_overlap!(fn test_vld1q_f32, vld1q_f32, vst1q_f32, f32, 4);
+    test_vld1_vst1_overlap!(fn test_vld1q_u64, vld1q_u64, vst1q_u64, u64, 2);
+    test_vld1_vst1_overlap!(fn test_vld1q_s64, vld1q_s64, vst1q_s64, i64, 2);
+    test_vld1_vst1_overlap!(fn test_vld1q_f64, vld1q_f64, vst1q_f64, f64, 2);
+
+    test_vld1_vst1_overlap!(#[cfg_attr(miri, ignore)] fn test_vld3q_u8, vld3q_u8, vst3q_u8, u8, 48);
+    test_vld1_vst1_overlap!(#[cfg_attr(miri, ignore)] fn test_vld4q_f32, vld4q_f32, vst4q_f32, f32, 16);
+
+    #[test]
+    #[cfg(target_feature = "neon")]
+    #[cfg_attr(miri, ignore)]
+    fn test_vld1q_u16_x2_cell_of_array() {
+        #[target_feature(enable = "neon")]
+        fn test() {
+            let mut a: [[u16; 8]; 2] =
+                core::array::from_fn(|i| core::array::from_fn(|j| (i * 8 + j) as u16));
+            let expected = a;
+
+            let cell = Cell::from_mut(&mut a);
+            let r = super::vld1q_u16_x2(cell);
+
+            let mut into = [[0u16; 8]; 2];
+            super::vst1q_u16_x2(Cell::from_mut(&mut into), r);
+
+            assert_eq!(into, expected);
+        }
+
+        unsafe { test() }
+    }
+
+    #[test]
+    #[cfg(target_feature = "neon")]
+    fn test_vld2q_u8_deinterleave() {
+        #[target_feature(enable = "neon")]
+        fn test() {
+            let mut a: [u8; 32] = core::array::from_fn(|i| i as u8);
+            let r = super::vld2q_u8(Cell::from_mut(&mut a));
+
+            let [even, odd]: [[u8; 16]; 2] = r.to_array();
+            assert_eq!(even, core::array::from_fn(|i| 2 * i as u8));
+            assert_eq!(odd, core::array::from_fn(|i| 2 * i as u8 + 1));
+        }
+
+        unsafe { test() }
+    }
+}
@@ -0,0 +1,123 @@
+//! Functions for [`Complex`] arrays, enabled by the `num-complex` feature.
+//!
+//! `Complex<T>` is `repr(C)` with the real part followed by the imaginary
+//! part, so `[Complex<f32>; 4]` has the layout of `[f32; 8]`. The `vld2q`
+//! loads de-interleave such an array into one register of real parts and one
+//! register of imaginary parts, and the `vst2q` stores interleave them again.
+//!
+//! ```rust
+//! # #[cfg(target_feature = "neon")]
+//! # unsafe { conjugate() }
+//! use core::arch::aarch64::{float32x4x2_t, vnegq_f32};
+//! use num_complex::Complex;
+//! use safe_unaligned_simd::aarch64::complex;
+//!
+//! #[target_feature(enable = "neon")]
+//! fn conjugate() {
+//!     let mut a = [Complex::new(1.0f32, 2.0); 4];
+//!
+//!     let float32x4x2_t(re, im) = complex::vld2q_f32(&a);
+//!     complex::vst2q_f32(&mut a, float32x4x2_t(re, vnegq_f32(im)));
+//!
+//!     assert_eq!(a, [Complex::new(1.0, -2.0); 4]);
+//! }
+//! ```
+use core::arch::aarch64::{self as arch, *};
+
+use num_complex::Complex;
+
+vld_n_replicate_k! {
+    unsafe: load;
+    // Loads full registers, so 16 bytes per register
+    size: assert_size_16bytes;
+
+    /// Load an array of 2 `Complex<f32>` values to one 16-byte register.
+    fn vld1q_f32(_: &[f32; 4][..1] as [Complex<f32>; 2]) -> float32x4_t;
+    /// Load one `Complex<f64>` value to one 16-byte register.
+    fn vld1q_f64(_: &[f64; 2][..1] as Complex<f64>) -> float64x2_t;
+    /// Load `Complex<f32>` data to two registers, with de-interleaving into
+    /// real and imaginary parts.
+    fn vld2q_f32(_: &[f32; 4][..2] as [Complex<f32>; 4]) -> float32x4x2_t;
+    /// Load `Complex<f64>` data to two registers, with de-interleaving into
+    /// real and imaginary parts.
+    fn vld2q_f64(_: &[f64; 2][..2] as [Complex<f64>; 2]) -> float64x2x2_t;
+}
+
+vld_n_replicate_k! {
+    unsafe: store;
+    // Stores full registers, so 16 bytes per register
+    size: assert_size_16bytes;
+
+    /// Store an array of 2 `Complex<f32>` values from one 16-byte register.
+    fn vst1q_f32(_: &[f32; 4][..1] as [Complex<f32>; 2]) -> float32x4_t;
+    /// Store one `Complex<f64>` value from one 16-byte register.
+    fn vst1q_f64(_: &[f64; 2][..1] as Complex<f64>) -> float64x2_t;
+    /// Store `Complex<f32>` data from two registers, interleaving real and
+    /// imaginary parts.
+    fn vst2q_f32(_: &[f32; 4][..2] as [Complex<f32>; 4]) -> float32x4x2_t;
+    /// Store `Complex<f64>` data from two registers, interleaving real and
+    /// imaginary parts.
+    fn vst2q_f64(_: &[f64; 2][..2] as [Complex<f64>; 2]) -> float64x2x2_t;
+}
+
+#[cfg(test)]
+mod tests {
+    use num_complex::Complex;
+
+    use crate::Register;
+
+    #[test]
+    #[cfg(target_feature = "neon")]
+    fn test_vld1q_vst1q_f32() {
+        #[target_feature(enable = "neon")]
+        fn test() {
+            let a = [Complex::new(1.0f32, 2.0), Complex::new(3.0, 4.0)];
+            let mut b = [Complex::new(0.0, 0.0); 2];
+
+            super::vst1q_f32(&mut b, super::vld1q_f32(&a));
+
+            assert_eq!(a, b);
+        }
+
+        unsafe { test() }
+    }
+
+    #[test]
+    #[cfg(target_feature = "neon")]
+    #[cfg_attr(miri, ignore)]
+    fn test_vld2q_f32_split() {
+        #[target_feature(enable = "neon")]
+        fn test() {
+            let a: [Complex<f32>; 4] =
+                core::array::from_fn(|i| Complex::new(i as f32, -(i as f32)));
+            let r = super::vld2q_f32(&a);
+
+            let [re, im]: [[f32; 4]; 2] = r.to_array();
+            assert_eq!(re, a.map(|c| c.re));
+            assert_eq!(im, a.map(|c| c.im));
+
+            let mut b = [Complex::new(0.0, 0.0); 4];
+            super::vst2q_f32(&mut b, r);
+            assert_eq!(a, b);
+        }
+
+        unsafe { test() }
+    }
+
+    #[test]
+    #[cfg(target_feature = "neon")]
+    #[cfg_attr(miri, ignore)]
+    fn test_vld2q_f64_split() {
+        #[target_feature(enable = "neon")]
+        fn test() {
+            let a = [Complex::new(1.0f64, 2.0), Complex::new(3.0, 4.0)];
+            let r = super::vld2q_f64(&a);
+
+            let [re, im]: [[f64; 2]; 2] = r.to_array();
+            assert_eq!(re, [1.0, 3.0]);
+            assert_eq!(im, [2.0, 4.0]);
+        }
+
+        unsafe { test() }
+    }
+}
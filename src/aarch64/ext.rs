@@ -0,0 +1,149 @@
+//! Extension traits with method syntax for the byte-compatible loads and stores.
+//!
+//! Each trait is implemented for every type of the bound it is named after, so generic code
+//! bounded by [`Is128BitsUnaligned`] can call `data.vst1q(neon, v)` without naming the wrapper.
+//! The methods access memory through the [`bytes`](super::bytes) wrappers, or the `u8` wrappers
+//! of [`cell`](super::cell) for the `Cell` types, and use the `u8` registers, which the
+//! `vreinterpret` intrinsics convert to the other element types. They take the [`Neon`] token in
+//! place of a `#[target_feature]` attribute, which trait methods cannot carry safely.
+//!
+//! ```rust
+//! # #[cfg(target_feature = "neon")]
+//! # {
+//! use safe_unaligned_simd::aarch64::{ext::*, token::Neon, Is128BitsUnaligned};
+//!
+//! fn copy<T: Is128BitsUnaligned>(neon: Neon, from: &T, into: &mut T) {
+//!     into.vst1q(neon, from.vld1q(neon));
+//! }
+//!
+//! let mut out = [0u16; 8];
+//! copy(Neon::new(), &[1, 2, 3, 4, 5, 6, 7, 8], &mut out);
+//! assert_eq!(out, [1, 2, 3, 4, 5, 6, 7, 8]);
+//! # }
+//! ```
+use core::arch::aarch64::*;
+use core::cell::Cell;
+use core::ptr;
+
+use super::token::Neon;
+use super::{
+    Is64BitsReadable, Is64BitsUnaligned, Is64CellUnaligned, Is128BitsReadable, Is128BitsUnaligned,
+    Is128CellUnaligned, UnalignedCell,
+};
+
+ext_trait! {
+    /// Loads from [`Is64BitsReadable`] types.
+    pub trait Is64BitsReadableExt: Is64BitsReadable {
+        /// Calls [`bytes::vld1_u8`](super::bytes::vld1_u8) on `self`.
+        fn vld1(&self, token: Neon) -> uint8x8_t = super::bytes::vld1_u8;
+    }
+}
+
+ext_trait! {
+    /// Stores into [`Is64BitsUnaligned`] types.
+    pub trait Is64BitsUnalignedExt: Is64BitsUnaligned {
+        /// Calls [`bytes::vst1_u8`](super::bytes::vst1_u8) on `self`.
+        fn vst1(&mut self, token: Neon, val: uint8x8_t) = super::bytes::vst1_u8;
+    }
+}
+
+ext_trait! {
+    /// Loads from [`Is128BitsReadable`] types.
+    pub trait Is128BitsReadableExt: Is128BitsReadable {
+        /// Calls [`bytes::vld1q_u8`](super::bytes::vld1q_u8) on `self`.
+        fn vld1q(&self, token: Neon) -> uint8x16_t = super::bytes::vld1q_u8;
+    }
+}
+
+ext_trait! {
+    /// Stores into [`Is128BitsUnaligned`] types.
+    pub trait Is128BitsUnalignedExt: Is128BitsUnaligned {
+        /// Calls [`bytes::vst1q_u8`](super::bytes::vst1q_u8) on `self`.
+        fn vst1q(&mut self, token: Neon, val: uint8x16_t) = super::bytes::vst1q_u8;
+    }
+}
+
+ext_trait! {
+    /// Loads from and stores into [`Is64CellUnaligned`] types.
+    pub trait Is64CellUnalignedExt: Is64CellUnaligned {
+        /// Calls [`cell::vld1_u8`](super::cell::vld1_u8) on `self`.
+        fn vld1(&self, token: Neon) -> uint8x8_t = vld1_cell;
+        /// Calls [`cell::vst1_u8`](super::cell::vst1_u8) on `self`.
+        fn vst1(&self, token: Neon, val: uint8x8_t) = vst1_cell;
+    }
+}
+
+ext_trait! {
+    /// Loads from and stores into [`Is128CellUnaligned`] types.
+    pub trait Is128CellUnalignedExt: Is128CellUnaligned {
+        /// Calls [`cell::vld1q_u8`](super::cell::vld1q_u8) on `self`.
+        fn vld1q(&self, token: Neon) -> uint8x16_t = vld1q_cell;
+        /// Calls [`cell::vst1q_u8`](super::cell::vst1q_u8) on `self`.
+        fn vst1q(&self, token: Neon, val: uint8x16_t) = vst1q_cell;
+    }
+}
+
+/// Views a cell type of `BYTES` bytes as a cell of the byte array.
+fn as_byte_cell<T: UnalignedCell<BYTES>, const BYTES: usize>(cell: &T) -> &Cell<[u8; BYTES]> {
+    // Safety: `UnalignedCell<BYTES>` types are `BYTES` bytes large, every bit pattern of them is
+    // valid, and all of their bytes are inside an `UnsafeCell`. `Cell<[u8; BYTES]>` has the same
+    // size with an alignment of one, so the reference stays valid for shared mutation.
+    unsafe { &*ptr::from_ref(cell).cast() }
+}
+
+#[target_feature(enable = "neon")]
+fn vld1_cell<T: Is64CellUnaligned>(from: &T) -> uint8x8_t {
+    super::cell::vld1_u8(as_byte_cell(from))
+}
+
+#[target_feature(enable = "neon")]
+fn vst1_cell<T: Is64CellUnaligned>(into: &T, val: uint8x8_t) {
+    super::cell::vst1_u8(as_byte_cell(into), val)
+}
+
+#[target_feature(enable = "neon")]
+fn vld1q_cell<T: Is128CellUnaligned>(from: &T) -> uint8x16_t {
+    super::cell::vld1q_u8(as_byte_cell(from))
+}
+
+#[target_feature(enable = "neon")]
+fn vst1q_cell<T: Is128CellUnaligned>(into: &T, val: uint8x16_t) {
+    super::cell::vst1q_u8(as_byte_cell(into), val)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bits_ext() {
+        let Some(neon) = Neon::try_new() else {
+            return;
+        };
+
+        let a = [1u32, 2, 3, 4];
+        let mut b = [0u8; 16];
+        b.vst1q(neon, a.vld1q(neon));
+        assert_eq!(b, a.map(u32::to_ne_bytes).as_flattened());
+
+        let mut c = [0u16; 4];
+        c.vst1(neon, [5u16, 6, 7, 8].vld1(neon));
+        assert_eq!(c, [5, 6, 7, 8]);
+    }
+
+    #[test]
+    fn test_cell_ext() {
+        let Some(neon) = Neon::try_new() else {
+            return;
+        };
+
+        let a = Cell::new([0u8; 16]);
+        Is128CellUnalignedExt::vst1q(&a, neon, [3u32; 4].vld1q(neon));
+        assert_eq!(a.get(), [3u32; 4].map(u32::to_ne_bytes).as_flattened());
+
+        let b = [const { Cell::new(0u16) }; 4];
+        let c = Cell::new([9u16, 8, 7, 6]);
+        Is64CellUnalignedExt::vst1(&b, neon, Is64CellUnalignedExt::vld1(&c, neon));
+        assert_eq!(b.map(Cell::into_inner), [9, 8, 7, 6]);
+    }
+}
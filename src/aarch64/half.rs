@@ -0,0 +1,178 @@
+//! Functions generic over arrays of the [`half`](https://docs.rs/half) crate's
+//! types, enabled by the `half` feature.
+//!
+//! These are the `u16` wrappers of the parent module accepting arrays of
+//! [`f16`](struct@f16) and [`bf16`] in place of `u16` arrays. The values are loaded as
+//! their bit representation, ready for `vreinterpretq_f16_u16` on toolchains
+//! with `float16x8_t` support or for widening to `f32` by integer shifts.
+//!
+//! ```rust
+//! # #[cfg(target_feature = "neon")]
+//! # unsafe { round_trip() }
+//! use half::f16;
+//! use safe_unaligned_simd::aarch64::half;
+//!
+//! #[target_feature(enable = "neon")]
+//! fn round_trip() {
+//!     let a = [f16::ONE; 8];
+//!     let mut b = [f16::ZERO; 8];
+//!
+//!     let r = half::vld1q_u16(&a);
+//!     half::vst1q_u16(&mut b, r);
+//!
+//!     assert_eq!(a, b);
+//! }
+//! ```
+use core::arch::aarch64::{self as arch, *};
+
+use ::half::{bf16, f16};
+
+// Internal module for sealing the `half` trait.
+mod private {
+    pub trait Sealed<T> {}
+}
+
+/// A trait that marks a `half` array type as having the memory layout of `T`.
+///
+/// This is implemented for `[f16; N]` and `[bf16; N]` where `T` is `[u16; N]`,
+/// and for nested arrays `[[f16; M]; N]` and `[[bf16; M]; N]` where `T` is
+/// `[[u16; M]; N]`.
+pub trait IsHalfOf<T>: private::Sealed<T> {}
+
+impl<const N: usize> private::Sealed<[u16; N]> for [f16; N] {}
+impl<const N: usize> IsHalfOf<[u16; N]> for [f16; N] {}
+impl<const N: usize> private::Sealed<[u16; N]> for [bf16; N] {}
+impl<const N: usize> IsHalfOf<[u16; N]> for [bf16; N] {}
+
+impl<const M: usize, const N: usize> private::Sealed<[[u16; M]; N]> for [[f16; M]; N] {}
+impl<const M: usize, const N: usize> IsHalfOf<[[u16; M]; N]> for [[f16; M]; N] {}
+impl<const M: usize, const N: usize> private::Sealed<[[u16; M]; N]> for [[bf16; M]; N] {}
+impl<const M: usize, const N: usize> IsHalfOf<[[u16; M]; N]> for [[bf16; M]; N] {}
+
+vld_n_replicate_k! {
+    unsafe: load_half;
+    // Loads half registers, so 8 bytes per register
+    size: assert_size_8bytes;
+
+    /// Load an array of 4 half-precision values to one 8-byte register.
+    fn vld1_u16(_: &[u16; 4][..1] as [u16; 4]) -> uint16x4_t;
+    /// Load two arrays of 4 half-precision values to two 8-byte registers.
+    fn vld1_u16_x2(_: &[u16; 4][..2] as [[u16; 4]; 2]) -> uint16x4x2_t;
+    /// Load three arrays of 4 half-precision values to three 8-byte registers.
+    fn vld1_u16_x3(_: &[u16; 4][..3] as [[u16; 4]; 3]) -> uint16x4x3_t;
+    /// Load four arrays of 4 half-precision values to four 8-byte registers.
+    fn vld1_u16_x4(_: &[u16; 4][..4] as [[u16; 4]; 4]) -> uint16x4x4_t;
+}
+
+vld_n_replicate_k! {
+    unsafe: load_half;
+    // Loads full registers, so 16 bytes per register
+    size: assert_size_16bytes;
+
+    /// Load an array of 8 half-precision values to one 16-byte register.
+    fn vld1q_u16(_: &[u16; 8][..1] as [u16; 8]) -> uint16x8_t;
+    /// Load two arrays of 8 half-precision values to two 16-byte registers.
+    fn vld1q_u16_x2(_: &[u16; 8][..2] as [[u16; 8]; 2]) -> uint16x8x2_t;
+    /// Load three arrays of 8 half-precision values to three 16-byte registers.
+    fn vld1q_u16_x3(_: &[u16; 8][..3] as [[u16; 8]; 3]) -> uint16x8x3_t;
+    /// Load four arrays of 8 half-precision values to four 16-byte registers.
+    fn vld1q_u16_x4(_: &[u16; 8][..4] as [[u16; 8]; 4]) -> uint16x8x4_t;
+    /// Load half-precision data to two registers, with de-interleaving.
+    fn vld2q_u16(_: &[u16; 8][..2] as [u16; 16]) -> uint16x8x2_t;
+    /// Load half-precision data to three registers, with de-interleaving.
+    fn vld3q_u16(_: &[u16; 8][..3] as [u16; 24]) -> uint16x8x3_t;
+    /// Load half-precision data to four registers, with de-interleaving.
+    fn vld4q_u16(_: &[u16; 8][..4] as [u16; 32]) -> uint16x8x4_t;
+}
+
+vld_n_replicate_k! {
+    unsafe: store_half;
+    // Stores half registers, so 8 bytes per register
+    size: assert_size_8bytes;
+
+    /// Store an array of 4 half-precision values from one 8-byte register.
+    fn vst1_u16(_: &[u16; 4][..1] as [u16; 4]) -> uint16x4_t;
+    /// Store two arrays of 4 half-precision values from two 8-byte registers.
+    fn vst1_u16_x2(_: &[u16; 4][..2] as [[u16; 4]; 2]) -> uint16x4x2_t;
+    /// Store three arrays of 4 half-precision values from three 8-byte registers.
+    fn vst1_u16_x3(_: &[u16; 4][..3] as [[u16; 4]; 3]) -> uint16x4x3_t;
+    /// Store four arrays of 4 half-precision values from four 8-byte registers.
+    fn vst1_u16_x4(_: &[u16; 4][..4] as [[u16; 4]; 4]) -> uint16x4x4_t;
+}
+
+vld_n_replicate_k! {
+    unsafe: store_half;
+    // Stores full registers, so 16 bytes per register
+    size: assert_size_16bytes;
+
+    /// Store an array of 8 half-precision values from one 16-byte register.
+    fn vst1q_u16(_: &[u16; 8][..1] as [u16; 8]) -> uint16x8_t;
+    /// Store two arrays of 8 half-precision values from two 16-byte registers.
+    fn vst1q_u16_x2(_: &[u16; 8][..2] as [[u16; 8]; 2]) -> uint16x8x2_t;
+    /// Store three arrays of 8 half-precision values from three 16-byte registers.
+    fn vst1q_u16_x3(_: &[u16; 8][..3] as [[u16; 8]; 3]) -> uint16x8x3_t;
+    /// Store four arrays of 8 half-precision values from four 16-byte registers.
+    fn vst1q_u16_x4(_: &[u16; 8][..4] as [[u16; 8]; 4]) -> uint16x8x4_t;
+    /// Store half-precision data from two registers, with interleaving.
+    fn vst2q_u16(_: &[u16; 8][..2] as [u16; 16]) -> uint16x8x2_t;
+    /// Store half-precision data from three registers, with interleaving.
+    fn vst3q_u16(_: &[u16; 8][..3] as [u16; 24]) -> uint16x8x3_t;
+    /// Store half-precision data from four registers, with interleaving.
+    fn vst4q_u16(_: &[u16; 8][..4] as [u16; 32]) -> uint16x8x4_t;
+}
+
+#[cfg(test)]
+mod tests {
+    use ::half::{bf16, f16};
+
+    use crate::Register;
+
+    #[test]
+    #[cfg(target_feature = "neon")]
+    fn test_vld1q_vst1q_f16() {
+        #[target_feature(enable = "neon")]
+        fn test() {
+            let a: [f16; 8] = core::array::from_fn(|i| f16::from_f32(i as f32));
+            let mut b = [f16::ZERO; 8];
+
+            let r = super::vld1q_u16(&a);
+            super::vst1q_u16(&mut b, r);
+
+            assert_eq!(a, b);
+        }
+
+        unsafe { test() }
+    }
+
+    #[test]
+    #[cfg(target_feature = "neon")]
+    fn test_vld1_u16_bf16_bits() {
+        #[target_feature(enable = "neon")]
+        fn test() {
+            let a: [bf16; 4] = core::array::from_fn(|i| bf16::from_f32(i as f32));
+            let r = super::vld1_u16(&a);
+
+            let bits: [u16; 4] = r.to_array();
+            assert_eq!(bits, a.map(bf16::to_bits));
+        }
+
+        unsafe { test() }
+    }
+
+    #[test]
+    #[cfg(target_feature = "neon")]
+    #[cfg_attr(miri, ignore)]
+    fn test_vld2q_f16_deinterleave() {
+        #[target_feature(enable = "neon")]
+        fn test() {
+            let a: [f16; 16] = core::array::from_fn(|i| f16::from_f32(i as f32));
+            let r = super::vld2q_u16(&a);
+
+            let [even, odd]: [[u16; 8]; 2] = r.to_array();
+            assert_eq!(even, core::array::from_fn(|i| a[2 * i].to_bits()));
+            assert_eq!(odd, core::array::from_fn(|i| a[2 * i + 1].to_bits()));
+        }
+
+        unsafe { test() }
+    }
+}
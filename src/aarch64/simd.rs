@@ -0,0 +1,109 @@
+//! Functions returning and accepting [`Simd`] vectors, enabled by the `nightly` feature.
+//!
+//! These wrappers load or store one 8-byte or 16-byte register and convert
+//! between the register and the portable vector, so `core::simd` code does not
+//! need to transmute at the boundary. They are generic over the lane type and
+//! access memory through the byte wrappers of the [`bytes`](super::bytes)
+//! module, so the `SCTLR.A` caveat of the [parent module](super) does not
+//! apply.
+//!
+//! ```rust
+//! #![feature(portable_simd)]
+//! # #[cfg(target_feature = "neon")]
+//! # unsafe { scale() }
+//! use core::simd::Simd;
+//!
+//! use safe_unaligned_simd::aarch64::simd;
+//!
+//! #[target_feature(enable = "neon")]
+//! fn scale() {
+//!     let mut a = [1u16, 2, 3, 4, 5, 6, 7, 8];
+//!
+//!     let v = simd::vld1q(&a) * Simd::splat(3);
+//!     simd::vst1q(&mut a, v);
+//!
+//!     assert_eq!(a, [3, 6, 9, 12, 15, 18, 21, 24]);
+//! }
+//! ```
+use core::simd::{Simd, SimdElement};
+
+use super::{Is64BitsReadable, Is64BitsUnaligned, Is128BitsReadable, Is128BitsUnaligned, bytes};
+use crate::common_simd::{from_register, to_register};
+
+/// Load an array of any lane type into an 8-byte vector.
+#[inline]
+#[target_feature(enable = "neon")]
+pub fn vld1<T: SimdElement, const N: usize>(from: &[T; N]) -> Simd<T, N>
+where
+    [T; N]: Is64BitsReadable,
+{
+    from_register(bytes::vld1_u8(from))
+}
+
+/// Store an 8-byte vector of any lane type into an array.
+#[inline]
+#[target_feature(enable = "neon")]
+pub fn vst1<T: SimdElement, const N: usize>(into: &mut [T; N], val: Simd<T, N>)
+where
+    [T; N]: Is64BitsUnaligned,
+{
+    bytes::vst1_u8(into, to_register(val))
+}
+
+/// Load an array of any lane type into a 16-byte vector.
+#[inline]
+#[target_feature(enable = "neon")]
+pub fn vld1q<T: SimdElement, const N: usize>(from: &[T; N]) -> Simd<T, N>
+where
+    [T; N]: Is128BitsReadable,
+{
+    from_register(bytes::vld1q_u8(from))
+}
+
+/// Store a 16-byte vector of any lane type into an array.
+#[inline]
+#[target_feature(enable = "neon")]
+pub fn vst1q<T: SimdElement, const N: usize>(into: &mut [T; N], val: Simd<T, N>)
+where
+    [T; N]: Is128BitsUnaligned,
+{
+    bytes::vst1q_u8(into, to_register(val))
+}
+
+#[cfg(test)]
+mod tests {
+    use core::simd::Simd;
+
+    #[test]
+    #[cfg(target_feature = "neon")]
+    fn test_vld1_vst1() {
+        #[target_feature(enable = "neon")]
+        fn test() {
+            let mut a = [1.0f32, 2.0];
+            let v = super::vld1(&a);
+            assert_eq!(v, Simd::from_array(a));
+
+            super::vst1(&mut a, v * Simd::splat(2.0));
+            assert_eq!(a, [2.0, 4.0]);
+        }
+
+        unsafe { test() }
+    }
+
+    #[test]
+    #[cfg(target_feature = "neon")]
+    fn test_vld1q_vst1q() {
+        #[target_feature(enable = "neon")]
+        fn test() {
+            let a: [u8; 16] = core::array::from_fn(|i| i as u8);
+            assert_eq!(super::vld1q(&a), Simd::from_array(a));
+
+            let mut b = [1i64, -2];
+            let v = super::vld1q(&b);
+            super::vst1q(&mut b, -v);
+            assert_eq!(b, [-1, 2]);
+        }
+
+        unsafe { test() }
+    }
+}
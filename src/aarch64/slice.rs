@@ -0,0 +1,940 @@
+//! Slice-taking companions of the `vld1`, `vld1q` and `vldNq` loads and stores.
+//!
+//! For each of these wrappers of the parent module this module provides three
+//! entry points that access the start of a slice instead of an array
+//! reference:
+//!
+//! - `try_*` returns `None` if the slice is too short,
+//! - `*_slice` panics if the slice is too short,
+//! - `*_at` accesses `slice[idx..]` and panics if that is too short.
+//!
+//! The slices have the element type of the register, so the alignment caveat
+//! of the [parent module](super) applies unchanged. The length check is a
+//! single comparison.
+//!
+//! The `chunks_*` functions iterate over the registers loaded from
+//! consecutive chunks of a slice and expose the remainder, like
+//! [`slice::chunks_exact`](prim@slice#method.chunks_exact). The registers of
+//! two to four vectors are loaded with `vldNq` and so deinterleaved, e.g.
+//! `chunks_uint8x16x3` splits RGB pixels into their channels. The `_mut`
+//! counterparts yield a [`ChunkMut`] per chunk, which is loaded from and
+//! stored back in place.
+//!
+//! The `*_partial` functions load the first elements of a slice that may be
+//! shorter than a register and fill the remaining lanes with zero, or with the
+//! lanes of another register for the `*_partial_fill` variants. The stores
+//! write only as many elements as the slice holds. Slices of fewer elements
+//! are read and written with a few overlapping scalar accesses, as NEON has no
+//! masked loads.
+//!
+//! For idempotent kernels the fastest way to handle the tail is often to
+//! process one final window that ends at the end of the slice and overlaps
+//! the previous register. The `for_each_overlapping_*` and
+//! `map_overlapping_*` functions drive such kernels.
+//!
+//! ```rust
+//! # #[cfg(target_feature = "neon")]
+//! # unsafe { sum_pairs() }
+//! use core::arch::aarch64::vaddq_u16;
+//!
+//! use safe_unaligned_simd::aarch64::slice;
+//!
+//! #[target_feature(enable = "neon")]
+//! fn sum_pairs() {
+//!     let data: Vec<u16> = (0..20).collect();
+//!     let mut out = [0u16; 8];
+//!
+//!     let a = slice::vld1q_u16_slice(&data);
+//!     let b = slice::vld1q_u16_at(&data, 8);
+//!     slice::vst1q_u16_slice(&mut out, vaddq_u16(a, b));
+//!
+//!     assert_eq!(out, [8, 10, 12, 14, 16, 18, 20, 22]);
+//!     assert!(slice::try_vld1q_u16(&data[16..]).is_none());
+//! }
+//! ```
+//!
+//! ```rust
+//! # #[cfg(target_feature = "neon")]
+//! # unsafe { swap_red_blue() }
+//! use core::arch::aarch64::uint8x16x3_t;
+//!
+//! use safe_unaligned_simd::aarch64::slice;
+//!
+//! #[target_feature(enable = "neon")]
+//! fn swap_red_blue() {
+//!     let mut rgb: Vec<u8> = [1, 2, 3].repeat(20);
+//!
+//!     let mut chunks = slice::chunks_uint8x16x3_mut(&mut rgb);
+//!     for mut chunk in &mut chunks {
+//!         chunk.update(|uint8x16x3_t(r, g, b)| uint8x16x3_t(b, g, r));
+//!     }
+//!     for pixel in chunks.into_remainder().chunks_exact_mut(3) {
+//!         pixel.swap(0, 2);
+//!     }
+//!
+//!     assert_eq!(rgb, [3, 2, 1].repeat(20));
+//! }
+//! ```
+use core::arch::aarch64::*;
+use core::cell::Cell;
+
+pub use crate::common_slice::{ChunkMut, Chunks, ChunksMut};
+use crate::common_slice::{
+    LoadChunk, StoreChunk, as_bytes, as_bytes_mut, chunk, chunk_mut, read_partial, too_short,
+    write_partial,
+};
+
+// Generates the three slice entry points for one wrapper of the parent module. The basic
+// structure is:
+//
+//   load fn vld1q_u8(&[u8; 16]) -> uint8x16_t => try_vld1q_u8, vld1q_u8_slice, vld1q_u8_at;
+//
+// where `&[u8; 16]` is the array taken by the parent wrapper and the names are those of the
+// `try_`, panicking and offset variants. Wrappers of a single element take `&u64` and the like
+// instead, which is written as `&[u64; 1] as u64`.
+macro_rules! slice_wrappers {
+    ($(
+        $kind:ident fn $intrinsic:ident $args:tt $(-> $ret:ty)? => $try:ident, $slice:ident, $at:ident;
+    )*) => {
+        $(
+            slice_wrappers!(@ $kind $intrinsic $args $(-> $ret)? => $try, $slice, $at);
+        )*
+    };
+
+    (@ load $intrinsic:ident (&[$elem:ty; $n:literal] $(as $single:ty)?) -> $ret:ty
+        => $try:ident, $slice:ident, $at:ident
+    ) => {
+        #[doc = concat!("Loads from the start of `from` with [`", stringify!($intrinsic), "`](super::", stringify!($intrinsic), ").")]
+        ///
+        #[doc = concat!("Returns `None` if the slice has fewer than ", stringify!($n), " elements.")]
+        #[inline]
+        #[target_feature(enable = "neon")]
+        pub fn $try(from: &[$elem]) -> Option<$ret> {
+            let chunk = from.first_chunk::<$n>()?;
+            Some(super::$intrinsic(slice_wrappers!(@ chunk chunk $(as $single)?)))
+        }
+
+        #[doc = concat!("Loads from the start of `from` with [`", stringify!($intrinsic), "`](super::", stringify!($intrinsic), ").")]
+        ///
+        /// # Panics
+        ///
+        #[doc = concat!("Panics if the slice has fewer than ", stringify!($n), " elements.")]
+        #[inline]
+        #[track_caller]
+        #[target_feature(enable = "neon")]
+        pub fn $slice(from: &[$elem]) -> $ret {
+            match from.first_chunk::<$n>() {
+                Some(chunk) => super::$intrinsic(slice_wrappers!(@ chunk chunk $(as $single)?)),
+                None => too_short($n, from.len()),
+            }
+        }
+
+        #[doc = concat!("Loads from `from[idx..]` with [`", stringify!($intrinsic), "`](super::", stringify!($intrinsic), ").")]
+        ///
+        /// # Panics
+        ///
+        #[doc = concat!("Panics if `from[idx..]` has fewer than ", stringify!($n), " elements.")]
+        #[inline]
+        #[track_caller]
+        #[target_feature(enable = "neon")]
+        pub fn $at(from: &[$elem], idx: usize) -> $ret {
+            match from.get(idx..).and_then(<[$elem]>::first_chunk::<$n>) {
+                Some(chunk) => super::$intrinsic(slice_wrappers!(@ chunk chunk $(as $single)?)),
+                None => too_short(idx.saturating_add($n), from.len()),
+            }
+        }
+    };
+
+    (@ store $intrinsic:ident (&mut [$elem:ty; $n:literal] $(as $single:ty)?, $val:ty)
+        => $try:ident, $slice:ident, $at:ident
+    ) => {
+        #[doc = concat!("Stores to the start of `into` with [`", stringify!($intrinsic), "`](super::", stringify!($intrinsic), ").")]
+        ///
+        #[doc = concat!("Returns `None` without storing if the slice has fewer than ", stringify!($n), " elements.")]
+        #[inline]
+        #[target_feature(enable = "neon")]
+        pub fn $try(into: &mut [$elem], val: $val) -> Option<()> {
+            let chunk = into.first_chunk_mut::<$n>()?;
+            Some(super::$intrinsic(slice_wrappers!(@ chunk_mut chunk $(as $single)?), val))
+        }
+
+        #[doc = concat!("Stores to the start of `into` with [`", stringify!($intrinsic), "`](super::", stringify!($intrinsic), ").")]
+        ///
+        /// # Panics
+        ///
+        #[doc = concat!("Panics if the slice has fewer than ", stringify!($n), " elements.")]
+        #[inline]
+        #[track_caller]
+        #[target_feature(enable = "neon")]
+        pub fn $slice(into: &mut [$elem], val: $val) {
+            let len = into.len();
+            match into.first_chunk_mut::<$n>() {
+                Some(chunk) => super::$intrinsic(slice_wrappers!(@ chunk_mut chunk $(as $single)?), val),
+                None => too_short($n, len),
+            }
+        }
+
+        #[doc = concat!("Stores to `into[idx..]` with [`", stringify!($intrinsic), "`](super::", stringify!($intrinsic), ").")]
+        ///
+        /// # Panics
+        ///
+        #[doc = concat!("Panics if `into[idx..]` has fewer than ", stringify!($n), " elements.")]
+        #[inline]
+        #[track_caller]
+        #[target_feature(enable = "neon")]
+        pub fn $at(into: &mut [$elem], idx: usize, val: $val) {
+            let len = into.len();
+            match into.get_mut(idx..).and_then(<[$elem]>::first_chunk_mut::<$n>) {
+                Some(chunk) => super::$intrinsic(slice_wrappers!(@ chunk_mut chunk $(as $single)?), val),
+                None => too_short(idx.saturating_add($n), len),
+            }
+        }
+    };
+
+    // Single-element wrappers take a reference to the element rather than the array.
+    (@ chunk $chunk:ident) => { $chunk };
+    (@ chunk $chunk:ident as $single:ty) => { &$chunk[0] };
+    (@ chunk_mut $chunk:ident) => { $chunk };
+    (@ chunk_mut $chunk:ident as $single:ty) => { &mut $chunk[0] };
+}
+
+slice_wrappers! {
+    load fn vld1_u8(&[u8; 8]) -> uint8x8_t
+        => try_vld1_u8, vld1_u8_slice, vld1_u8_at;
+    load fn vld1_s8(&[i8; 8]) -> int8x8_t
+        => try_vld1_s8, vld1_s8_slice, vld1_s8_at;
+    load fn vld1_u16(&[u16; 4]) -> uint16x4_t
+        => try_vld1_u16, vld1_u16_slice, vld1_u16_at;
+    load fn vld1_s16(&[i16; 4]) -> int16x4_t
+        => try_vld1_s16, vld1_s16_slice, vld1_s16_at;
+    load fn vld1_u32(&[u32; 2]) -> uint32x2_t
+        => try_vld1_u32, vld1_u32_slice, vld1_u32_at;
+    load fn vld1_s32(&[i32; 2]) -> int32x2_t
+        => try_vld1_s32, vld1_s32_slice, vld1_s32_at;
+    load fn vld1_f32(&[f32; 2]) -> float32x2_t
+        => try_vld1_f32, vld1_f32_slice, vld1_f32_at;
+    load fn vld1_u64(&[u64; 1] as u64) -> uint64x1_t
+        => try_vld1_u64, vld1_u64_slice, vld1_u64_at;
+    load fn vld1_s64(&[i64; 1] as i64) -> int64x1_t
+        => try_vld1_s64, vld1_s64_slice, vld1_s64_at;
+    load fn vld1_f64(&[f64; 1] as f64) -> float64x1_t
+        => try_vld1_f64, vld1_f64_slice, vld1_f64_at;
+}
+
+slice_wrappers! {
+    load fn vld1q_u8(&[u8; 16]) -> uint8x16_t
+        => try_vld1q_u8, vld1q_u8_slice, vld1q_u8_at;
+    load fn vld1q_s8(&[i8; 16]) -> int8x16_t
+        => try_vld1q_s8, vld1q_s8_slice, vld1q_s8_at;
+    load fn vld1q_u16(&[u16; 8]) -> uint16x8_t
+        => try_vld1q_u16, vld1q_u16_slice, vld1q_u16_at;
+    load fn vld1q_s16(&[i16; 8]) -> int16x8_t
+        => try_vld1q_s16, vld1q_s16_slice, vld1q_s16_at;
+    load fn vld1q_u32(&[u32; 4]) -> uint32x4_t
+        => try_vld1q_u32, vld1q_u32_slice, vld1q_u32_at;
+    load fn vld1q_s32(&[i32; 4]) -> int32x4_t
+        => try_vld1q_s32, vld1q_s32_slice, vld1q_s32_at;
+    load fn vld1q_f32(&[f32; 4]) -> float32x4_t
+        => try_vld1q_f32, vld1q_f32_slice, vld1q_f32_at;
+    load fn vld1q_u64(&[u64; 2]) -> uint64x2_t
+        => try_vld1q_u64, vld1q_u64_slice, vld1q_u64_at;
+    load fn vld1q_s64(&[i64; 2]) -> int64x2_t
+        => try_vld1q_s64, vld1q_s64_slice, vld1q_s64_at;
+    load fn vld1q_f64(&[f64; 2]) -> float64x2_t
+        => try_vld1q_f64, vld1q_f64_slice, vld1q_f64_at;
+}
+
+slice_wrappers! {
+    load fn vld2q_u8(&[u8; 32]) -> uint8x16x2_t
+        => try_vld2q_u8, vld2q_u8_slice, vld2q_u8_at;
+    load fn vld2q_s8(&[i8; 32]) -> int8x16x2_t
+        => try_vld2q_s8, vld2q_s8_slice, vld2q_s8_at;
+    load fn vld2q_u16(&[u16; 16]) -> uint16x8x2_t
+        => try_vld2q_u16, vld2q_u16_slice, vld2q_u16_at;
+    load fn vld2q_s16(&[i16; 16]) -> int16x8x2_t
+        => try_vld2q_s16, vld2q_s16_slice, vld2q_s16_at;
+    load fn vld2q_u32(&[u32; 8]) -> uint32x4x2_t
+        => try_vld2q_u32, vld2q_u32_slice, vld2q_u32_at;
+    load fn vld2q_s32(&[i32; 8]) -> int32x4x2_t
+        => try_vld2q_s32, vld2q_s32_slice, vld2q_s32_at;
+    load fn vld2q_f32(&[f32; 8]) -> float32x4x2_t
+        => try_vld2q_f32, vld2q_f32_slice, vld2q_f32_at;
+    load fn vld2q_u64(&[u64; 4]) -> uint64x2x2_t
+        => try_vld2q_u64, vld2q_u64_slice, vld2q_u64_at;
+    load fn vld2q_s64(&[i64; 4]) -> int64x2x2_t
+        => try_vld2q_s64, vld2q_s64_slice, vld2q_s64_at;
+    load fn vld2q_f64(&[f64; 4]) -> float64x2x2_t
+        => try_vld2q_f64, vld2q_f64_slice, vld2q_f64_at;
+}
+
+slice_wrappers! {
+    load fn vld3q_u8(&[u8; 48]) -> uint8x16x3_t
+        => try_vld3q_u8, vld3q_u8_slice, vld3q_u8_at;
+    load fn vld3q_s8(&[i8; 48]) -> int8x16x3_t
+        => try_vld3q_s8, vld3q_s8_slice, vld3q_s8_at;
+    load fn vld3q_u16(&[u16; 24]) -> uint16x8x3_t
+        => try_vld3q_u16, vld3q_u16_slice, vld3q_u16_at;
+    load fn vld3q_s16(&[i16; 24]) -> int16x8x3_t
+        => try_vld3q_s16, vld3q_s16_slice, vld3q_s16_at;
+    load fn vld3q_u32(&[u32; 12]) -> uint32x4x3_t
+        => try_vld3q_u32, vld3q_u32_slice, vld3q_u32_at;
+    load fn vld3q_s32(&[i32; 12]) -> int32x4x3_t
+        => try_vld3q_s32, vld3q_s32_slice, vld3q_s32_at;
+    load fn vld3q_f32(&[f32; 12]) -> float32x4x3_t
+        => try_vld3q_f32, vld3q_f32_slice, vld3q_f32_at;
+    load fn vld3q_u64(&[u64; 6]) -> uint64x2x3_t
+        => try_vld3q_u64, vld3q_u64_slice, vld3q_u64_at;
+    load fn vld3q_s64(&[i64; 6]) -> int64x2x3_t
+        => try_vld3q_s64, vld3q_s64_slice, vld3q_s64_at;
+    load fn vld3q_f64(&[f64; 6]) -> float64x2x3_t
+        => try_vld3q_f64, vld3q_f64_slice, vld3q_f64_at;
+}
+
+slice_wrappers! {
+    load fn vld4q_u8(&[u8; 64]) -> uint8x16x4_t
+        => try_vld4q_u8, vld4q_u8_slice, vld4q_u8_at;
+    load fn vld4q_s8(&[i8; 64]) -> int8x16x4_t
+        => try_vld4q_s8, vld4q_s8_slice, vld4q_s8_at;
+    load fn vld4q_u16(&[u16; 32]) -> uint16x8x4_t
+        => try_vld4q_u16, vld4q_u16_slice, vld4q_u16_at;
+    load fn vld4q_s16(&[i16; 32]) -> int16x8x4_t
+        => try_vld4q_s16, vld4q_s16_slice, vld4q_s16_at;
+    load fn vld4q_u32(&[u32; 16]) -> uint32x4x4_t
+        => try_vld4q_u32, vld4q_u32_slice, vld4q_u32_at;
+    load fn vld4q_s32(&[i32; 16]) -> int32x4x4_t
+        => try_vld4q_s32, vld4q_s32_slice, vld4q_s32_at;
+    load fn vld4q_f32(&[f32; 16]) -> float32x4x4_t
+        => try_vld4q_f32, vld4q_f32_slice, vld4q_f32_at;
+    load fn vld4q_u64(&[u64; 8]) -> uint64x2x4_t
+        => try_vld4q_u64, vld4q_u64_slice, vld4q_u64_at;
+    load fn vld4q_s64(&[i64; 8]) -> int64x2x4_t
+        => try_vld4q_s64, vld4q_s64_slice, vld4q_s64_at;
+    load fn vld4q_f64(&[f64; 8]) -> float64x2x4_t
+        => try_vld4q_f64, vld4q_f64_slice, vld4q_f64_at;
+}
+
+slice_wrappers! {
+    store fn vst1_u8(&mut [u8; 8], uint8x8_t)
+        => try_vst1_u8, vst1_u8_slice, vst1_u8_at;
+    store fn vst1_s8(&mut [i8; 8], int8x8_t)
+        => try_vst1_s8, vst1_s8_slice, vst1_s8_at;
+    store fn vst1_u16(&mut [u16; 4], uint16x4_t)
+        => try_vst1_u16, vst1_u16_slice, vst1_u16_at;
+    store fn vst1_s16(&mut [i16; 4], int16x4_t)
+        => try_vst1_s16, vst1_s16_slice, vst1_s16_at;
+    store fn vst1_u32(&mut [u32; 2], uint32x2_t)
+        => try_vst1_u32, vst1_u32_slice, vst1_u32_at;
+    store fn vst1_s32(&mut [i32; 2], int32x2_t)
+        => try_vst1_s32, vst1_s32_slice, vst1_s32_at;
+    store fn vst1_f32(&mut [f32; 2], float32x2_t)
+        => try_vst1_f32, vst1_f32_slice, vst1_f32_at;
+    store fn vst1_u64(&mut [u64; 1] as u64, uint64x1_t)
+        => try_vst1_u64, vst1_u64_slice, vst1_u64_at;
+    store fn vst1_s64(&mut [i64; 1] as i64, int64x1_t)
+        => try_vst1_s64, vst1_s64_slice, vst1_s64_at;
+    store fn vst1_f64(&mut [f64; 1] as f64, float64x1_t)
+        => try_vst1_f64, vst1_f64_slice, vst1_f64_at;
+}
+
+slice_wrappers! {
+    store fn vst1q_u8(&mut [u8; 16], uint8x16_t)
+        => try_vst1q_u8, vst1q_u8_slice, vst1q_u8_at;
+    store fn vst1q_s8(&mut [i8; 16], int8x16_t)
+        => try_vst1q_s8, vst1q_s8_slice, vst1q_s8_at;
+    store fn vst1q_u16(&mut [u16; 8], uint16x8_t)
+        => try_vst1q_u16, vst1q_u16_slice, vst1q_u16_at;
+    store fn vst1q_s16(&mut [i16; 8], int16x8_t)
+        => try_vst1q_s16, vst1q_s16_slice, vst1q_s16_at;
+    store fn vst1q_u32(&mut [u32; 4], uint32x4_t)
+        => try_vst1q_u32, vst1q_u32_slice, vst1q_u32_at;
+    store fn vst1q_s32(&mut [i32; 4], int32x4_t)
+        => try_vst1q_s32, vst1q_s32_slice, vst1q_s32_at;
+    store fn vst1q_f32(&mut [f32; 4], float32x4_t)
+        => try_vst1q_f32, vst1q_f32_slice, vst1q_f32_at;
+    store fn vst1q_u64(&mut [u64; 2], uint64x2_t)
+        => try_vst1q_u64, vst1q_u64_slice, vst1q_u64_at;
+    store fn vst1q_s64(&mut [i64; 2], int64x2_t)
+        => try_vst1q_s64, vst1q_s64_slice, vst1q_s64_at;
+    store fn vst1q_f64(&mut [f64; 2], float64x2_t)
+        => try_vst1q_f64, vst1q_f64_slice, vst1q_f64_at;
+}
+
+slice_wrappers! {
+    store fn vst2q_u8(&mut [u8; 32], uint8x16x2_t)
+        => try_vst2q_u8, vst2q_u8_slice, vst2q_u8_at;
+    store fn vst2q_s8(&mut [i8; 32], int8x16x2_t)
+        => try_vst2q_s8, vst2q_s8_slice, vst2q_s8_at;
+    store fn vst2q_u16(&mut [u16; 16], uint16x8x2_t)
+        => try_vst2q_u16, vst2q_u16_slice, vst2q_u16_at;
+    store fn vst2q_s16(&mut [i16; 16], int16x8x2_t)
+        => try_vst2q_s16, vst2q_s16_slice, vst2q_s16_at;
+    store fn vst2q_u32(&mut [u32; 8], uint32x4x2_t)
+        => try_vst2q_u32, vst2q_u32_slice, vst2q_u32_at;
+    store fn vst2q_s32(&mut [i32; 8], int32x4x2_t)
+        => try_vst2q_s32, vst2q_s32_slice, vst2q_s32_at;
+    store fn vst2q_f32(&mut [f32; 8], float32x4x2_t)
+        => try_vst2q_f32, vst2q_f32_slice, vst2q_f32_at;
+    store fn vst2q_u64(&mut [u64; 4], uint64x2x2_t)
+        => try_vst2q_u64, vst2q_u64_slice, vst2q_u64_at;
+    store fn vst2q_s64(&mut [i64; 4], int64x2x2_t)
+        => try_vst2q_s64, vst2q_s64_slice, vst2q_s64_at;
+    store fn vst2q_f64(&mut [f64; 4], float64x2x2_t)
+        => try_vst2q_f64, vst2q_f64_slice, vst2q_f64_at;
+}
+
+slice_wrappers! {
+    store fn vst3q_u8(&mut [u8; 48], uint8x16x3_t)
+        => try_vst3q_u8, vst3q_u8_slice, vst3q_u8_at;
+    store fn vst3q_s8(&mut [i8; 48], int8x16x3_t)
+        => try_vst3q_s8, vst3q_s8_slice, vst3q_s8_at;
+    store fn vst3q_u16(&mut [u16; 24], uint16x8x3_t)
+        => try_vst3q_u16, vst3q_u16_slice, vst3q_u16_at;
+    store fn vst3q_s16(&mut [i16; 24], int16x8x3_t)
+        => try_vst3q_s16, vst3q_s16_slice, vst3q_s16_at;
+    store fn vst3q_u32(&mut [u32; 12], uint32x4x3_t)
+        => try_vst3q_u32, vst3q_u32_slice, vst3q_u32_at;
+    store fn vst3q_s32(&mut [i32; 12], int32x4x3_t)
+        => try_vst3q_s32, vst3q_s32_slice, vst3q_s32_at;
+    store fn vst3q_f32(&mut [f32; 12], float32x4x3_t)
+        => try_vst3q_f32, vst3q_f32_slice, vst3q_f32_at;
+    store fn vst3q_u64(&mut [u64; 6], uint64x2x3_t)
+        => try_vst3q_u64, vst3q_u64_slice, vst3q_u64_at;
+    store fn vst3q_s64(&mut [i64; 6], int64x2x3_t)
+        => try_vst3q_s64, vst3q_s64_slice, vst3q_s64_at;
+    store fn vst3q_f64(&mut [f64; 6], float64x2x3_t)
+        => try_vst3q_f64, vst3q_f64_slice, vst3q_f64_at;
+}
+
+slice_wrappers! {
+    store fn vst4q_u8(&mut [u8; 64], uint8x16x4_t)
+        => try_vst4q_u8, vst4q_u8_slice, vst4q_u8_at;
+    store fn vst4q_s8(&mut [i8; 64], int8x16x4_t)
+        => try_vst4q_s8, vst4q_s8_slice, vst4q_s8_at;
+    store fn vst4q_u16(&mut [u16; 32], uint16x8x4_t)
+        => try_vst4q_u16, vst4q_u16_slice, vst4q_u16_at;
+    store fn vst4q_s16(&mut [i16; 32], int16x8x4_t)
+        => try_vst4q_s16, vst4q_s16_slice, vst4q_s16_at;
+    store fn vst4q_u32(&mut [u32; 16], uint32x4x4_t)
+        => try_vst4q_u32, vst4q_u32_slice, vst4q_u32_at;
+    store fn vst4q_s32(&mut [i32; 16], int32x4x4_t)
+        => try_vst4q_s32, vst4q_s32_slice, vst4q_s32_at;
+    store fn vst4q_f32(&mut [f32; 16], float32x4x4_t)
+        => try_vst4q_f32, vst4q_f32_slice, vst4q_f32_at;
+    store fn vst4q_u64(&mut [u64; 8], uint64x2x4_t)
+        => try_vst4q_u64, vst4q_u64_slice, vst4q_u64_at;
+    store fn vst4q_s64(&mut [i64; 8], int64x2x4_t)
+        => try_vst4q_s64, vst4q_s64_slice, vst4q_s64_at;
+    store fn vst4q_f64(&mut [f64; 8], float64x2x4_t)
+        => try_vst4q_f64, vst4q_f64_slice, vst4q_f64_at;
+}
+
+// Generates the chunk iterators for one register. The basic structure is:
+//
+//   uint8x16x3_t from [u8; 48] by vld3q_u8, vst3q_u8 => chunks_uint8x16x3, chunks_uint8x16x3_mut;
+//
+// where the loads and stores of the parent module move the register from and to chunks of the
+// array type.
+macro_rules! chunk_iterators {
+    ($(
+        $reg:ident from [$elem:ty; $n:literal] by $load:ident, $store:ident
+            => $chunks:ident, $chunks_mut:ident;
+    )*) => {
+        $(
+            impl LoadChunk<$elem> for $reg {
+                const LEN: usize = $n;
+
+                #[inline(always)]
+                unsafe fn load(from: &[$elem]) -> Self {
+                    unsafe { super::$load(chunk::<_, $n>(from)) }
+                }
+            }
+
+            impl StoreChunk<$elem> for $reg {
+                #[inline(always)]
+                unsafe fn store(into: &mut [$elem], val: Self) {
+                    unsafe { super::$store(chunk_mut::<_, $n>(into), val) }
+                }
+            }
+
+            #[doc = concat!("Iterates over the `", stringify!($reg), "` registers loaded from chunks of ", stringify!($n), " elements of `from` with [`", stringify!($load), "`](super::", stringify!($load), ").")]
+            ///
+            /// The elements that do not fill a register are available from
+            /// [`Chunks::remainder`].
+            #[inline]
+            #[target_feature(enable = "neon")]
+            pub fn $chunks(from: &[$elem]) -> Chunks<'_, $elem, $reg> {
+                // Safety: The target feature is enabled.
+                unsafe { Chunks::new(from) }
+            }
+
+            #[doc = concat!("Iterates over chunks of ", stringify!($n), " elements of `into` that a `", stringify!($reg), "` register is loaded from and stored to with [`", stringify!($load), "`](super::", stringify!($load), ") and [`", stringify!($store), "`](super::", stringify!($store), ").")]
+            ///
+            /// The elements that do not fill a register are available from
+            /// [`ChunksMut::into_remainder`].
+            #[inline]
+            #[target_feature(enable = "neon")]
+            pub fn $chunks_mut(into: &mut [$elem]) -> ChunksMut<'_, $elem, $reg> {
+                // Safety: The target feature is enabled.
+                unsafe { ChunksMut::new(into) }
+            }
+        )*
+    };
+}
+
+chunk_iterators! {
+    uint8x16_t from [u8; 16] by vld1q_u8, vst1q_u8
+        => chunks_uint8x16, chunks_uint8x16_mut;
+    int8x16_t from [i8; 16] by vld1q_s8, vst1q_s8
+        => chunks_int8x16, chunks_int8x16_mut;
+    uint16x8_t from [u16; 8] by vld1q_u16, vst1q_u16
+        => chunks_uint16x8, chunks_uint16x8_mut;
+    int16x8_t from [i16; 8] by vld1q_s16, vst1q_s16
+        => chunks_int16x8, chunks_int16x8_mut;
+    uint32x4_t from [u32; 4] by vld1q_u32, vst1q_u32
+        => chunks_uint32x4, chunks_uint32x4_mut;
+    int32x4_t from [i32; 4] by vld1q_s32, vst1q_s32
+        => chunks_int32x4, chunks_int32x4_mut;
+    float32x4_t from [f32; 4] by vld1q_f32, vst1q_f32
+        => chunks_float32x4, chunks_float32x4_mut;
+    uint64x2_t from [u64; 2] by vld1q_u64, vst1q_u64
+        => chunks_uint64x2, chunks_uint64x2_mut;
+    int64x2_t from [i64; 2] by vld1q_s64, vst1q_s64
+        => chunks_int64x2, chunks_int64x2_mut;
+    float64x2_t from [f64; 2] by vld1q_f64, vst1q_f64
+        => chunks_float64x2, chunks_float64x2_mut;
+}
+
+chunk_iterators! {
+    uint8x16x2_t from [u8; 32] by vld2q_u8, vst2q_u8
+        => chunks_uint8x16x2, chunks_uint8x16x2_mut;
+    int8x16x2_t from [i8; 32] by vld2q_s8, vst2q_s8
+        => chunks_int8x16x2, chunks_int8x16x2_mut;
+    uint16x8x2_t from [u16; 16] by vld2q_u16, vst2q_u16
+        => chunks_uint16x8x2, chunks_uint16x8x2_mut;
+    int16x8x2_t from [i16; 16] by vld2q_s16, vst2q_s16
+        => chunks_int16x8x2, chunks_int16x8x2_mut;
+    uint32x4x2_t from [u32; 8] by vld2q_u32, vst2q_u32
+        => chunks_uint32x4x2, chunks_uint32x4x2_mut;
+    int32x4x2_t from [i32; 8] by vld2q_s32, vst2q_s32
+        => chunks_int32x4x2, chunks_int32x4x2_mut;
+    float32x4x2_t from [f32; 8] by vld2q_f32, vst2q_f32
+        => chunks_float32x4x2, chunks_float32x4x2_mut;
+    uint64x2x2_t from [u64; 4] by vld2q_u64, vst2q_u64
+        => chunks_uint64x2x2, chunks_uint64x2x2_mut;
+    int64x2x2_t from [i64; 4] by vld2q_s64, vst2q_s64
+        => chunks_int64x2x2, chunks_int64x2x2_mut;
+    float64x2x2_t from [f64; 4] by vld2q_f64, vst2q_f64
+        => chunks_float64x2x2, chunks_float64x2x2_mut;
+}
+
+chunk_iterators! {
+    uint8x16x3_t from [u8; 48] by vld3q_u8, vst3q_u8
+        => chunks_uint8x16x3, chunks_uint8x16x3_mut;
+    int8x16x3_t from [i8; 48] by vld3q_s8, vst3q_s8
+        => chunks_int8x16x3, chunks_int8x16x3_mut;
+    uint16x8x3_t from [u16; 24] by vld3q_u16, vst3q_u16
+        => chunks_uint16x8x3, chunks_uint16x8x3_mut;
+    int16x8x3_t from [i16; 24] by vld3q_s16, vst3q_s16
+        => chunks_int16x8x3, chunks_int16x8x3_mut;
+    uint32x4x3_t from [u32; 12] by vld3q_u32, vst3q_u32
+        => chunks_uint32x4x3, chunks_uint32x4x3_mut;
+    int32x4x3_t from [i32; 12] by vld3q_s32, vst3q_s32
+        => chunks_int32x4x3, chunks_int32x4x3_mut;
+    float32x4x3_t from [f32; 12] by vld3q_f32, vst3q_f32
+        => chunks_float32x4x3, chunks_float32x4x3_mut;
+    uint64x2x3_t from [u64; 6] by vld3q_u64, vst3q_u64
+        => chunks_uint64x2x3, chunks_uint64x2x3_mut;
+    int64x2x3_t from [i64; 6] by vld3q_s64, vst3q_s64
+        => chunks_int64x2x3, chunks_int64x2x3_mut;
+    float64x2x3_t from [f64; 6] by vld3q_f64, vst3q_f64
+        => chunks_float64x2x3, chunks_float64x2x3_mut;
+}
+
+chunk_iterators! {
+    uint8x16x4_t from [u8; 64] by vld4q_u8, vst4q_u8
+        => chunks_uint8x16x4, chunks_uint8x16x4_mut;
+    int8x16x4_t from [i8; 64] by vld4q_s8, vst4q_s8
+        => chunks_int8x16x4, chunks_int8x16x4_mut;
+    uint16x8x4_t from [u16; 32] by vld4q_u16, vst4q_u16
+        => chunks_uint16x8x4, chunks_uint16x8x4_mut;
+    int16x8x4_t from [i16; 32] by vld4q_s16, vst4q_s16
+        => chunks_int16x8x4, chunks_int16x8x4_mut;
+    uint32x4x4_t from [u32; 16] by vld4q_u32, vst4q_u32
+        => chunks_uint32x4x4, chunks_uint32x4x4_mut;
+    int32x4x4_t from [i32; 16] by vld4q_s32, vst4q_s32
+        => chunks_int32x4x4, chunks_int32x4x4_mut;
+    float32x4x4_t from [f32; 16] by vld4q_f32, vst4q_f32
+        => chunks_float32x4x4, chunks_float32x4x4_mut;
+    uint64x2x4_t from [u64; 8] by vld4q_u64, vst4q_u64
+        => chunks_uint64x2x4, chunks_uint64x2x4_mut;
+    int64x2x4_t from [i64; 8] by vld4q_s64, vst4q_s64
+        => chunks_int64x2x4, chunks_int64x2x4_mut;
+    float64x2x4_t from [f64; 8] by vld4q_f64, vst4q_f64
+        => chunks_float64x2x4, chunks_float64x2x4_mut;
+}
+
+/// Loads the first 16 bytes of `from`, filling the lanes past its end with zero.
+///
+/// Slices of 16 bytes are loaded with [`vld1q_u8`](super::vld1q_u8), shorter ones with a few
+/// overlapping scalar loads.
+#[inline]
+#[target_feature(enable = "neon")]
+pub fn vld1q_u8_partial(from: &[u8]) -> uint8x16_t {
+    match from.first_chunk::<16>() {
+        Some(from) => super::vld1q_u8(from),
+        None => vreinterpretq_u8_u64(super::vld1q_u64(&read_partial(from))),
+    }
+}
+
+/// Loads the first 16 bytes of `from`, taking the lanes past its end from `fill`.
+///
+/// See [`vld1q_u8_partial`].
+#[inline]
+#[target_feature(enable = "neon")]
+pub fn vld1q_u8_partial_fill(from: &[u8], fill: uint8x16_t) -> uint8x16_t {
+    let len = from.len().min(16) as u8;
+    let lanes = super::vld1q_u8(&[0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15]);
+    vbslq_u8(
+        vcltq_u8(lanes, vdupq_n_u8(len)),
+        vld1q_u8_partial(from),
+        fill,
+    )
+}
+
+/// Stores the low lanes of `val` to `into`, as many as it holds up to 16.
+///
+/// Slices of 16 bytes are stored to with [`vst1q_u8`](super::vst1q_u8), shorter ones with a few
+/// overlapping scalar stores.
+#[inline]
+#[target_feature(enable = "neon")]
+pub fn vst1q_u8_partial(into: &mut [u8], val: uint8x16_t) {
+    if let Some(into) = into.first_chunk_mut::<16>() {
+        return super::vst1q_u8(into, val);
+    }
+
+    let mut words = [0u64; 2];
+    super::vst1q_u64(&mut words, vreinterpretq_u64_u8(val));
+    write_partial(into, words);
+}
+
+// Generates the partial loads and stores of the other element types on top of the `u8` ones. The
+// basic structure is:
+//
+//   uint16x8_t from [u16; 8] by vreinterpretq_u16_u8, vreinterpretq_u8_u16
+//       => vld1q_u16_partial, vld1q_u16_partial_fill, vst1q_u16_partial;
+//
+// where the reinterpretations convert from and to `uint8x16_t`.
+macro_rules! partial_wrappers {
+    ($(
+        $reg:ident from [$elem:ty; $n:literal] by $from_u8:ident, $to_u8:ident
+            => $load:ident, $load_fill:ident, $store:ident;
+    )*) => {
+        $(
+            #[doc = concat!("Loads the first ", stringify!($n), " elements of `from`, filling the lanes past its end with zero.")]
+            ///
+            /// See [`vld1q_u8_partial`].
+            #[inline]
+            #[target_feature(enable = "neon")]
+            pub fn $load(from: &[$elem]) -> $reg {
+                $from_u8(vld1q_u8_partial(as_bytes(from)))
+            }
+
+            #[doc = concat!("Loads the first ", stringify!($n), " elements of `from`, taking the lanes past its end from `fill`.")]
+            ///
+            /// See [`vld1q_u8_partial_fill`].
+            #[inline]
+            #[target_feature(enable = "neon")]
+            pub fn $load_fill(from: &[$elem], fill: $reg) -> $reg {
+                $from_u8(vld1q_u8_partial_fill(as_bytes(from), $to_u8(fill)))
+            }
+
+            #[doc = concat!("Stores the low lanes of `val` to `into`, as many as it holds up to ", stringify!($n), ".")]
+            ///
+            /// See [`vst1q_u8_partial`].
+            #[inline]
+            #[target_feature(enable = "neon")]
+            pub fn $store(into: &mut [$elem], val: $reg) {
+                vst1q_u8_partial(as_bytes_mut(into), $to_u8(val))
+            }
+        )*
+    };
+}
+
+partial_wrappers! {
+    int8x16_t from [i8; 16] by vreinterpretq_s8_u8, vreinterpretq_u8_s8
+        => vld1q_s8_partial, vld1q_s8_partial_fill, vst1q_s8_partial;
+    uint16x8_t from [u16; 8] by vreinterpretq_u16_u8, vreinterpretq_u8_u16
+        => vld1q_u16_partial, vld1q_u16_partial_fill, vst1q_u16_partial;
+    int16x8_t from [i16; 8] by vreinterpretq_s16_u8, vreinterpretq_u8_s16
+        => vld1q_s16_partial, vld1q_s16_partial_fill, vst1q_s16_partial;
+    uint32x4_t from [u32; 4] by vreinterpretq_u32_u8, vreinterpretq_u8_u32
+        => vld1q_u32_partial, vld1q_u32_partial_fill, vst1q_u32_partial;
+    int32x4_t from [i32; 4] by vreinterpretq_s32_u8, vreinterpretq_u8_s32
+        => vld1q_s32_partial, vld1q_s32_partial_fill, vst1q_s32_partial;
+    float32x4_t from [f32; 4] by vreinterpretq_f32_u8, vreinterpretq_u8_f32
+        => vld1q_f32_partial, vld1q_f32_partial_fill, vst1q_f32_partial;
+    uint64x2_t from [u64; 2] by vreinterpretq_u64_u8, vreinterpretq_u8_u64
+        => vld1q_u64_partial, vld1q_u64_partial_fill, vst1q_u64_partial;
+    int64x2_t from [i64; 2] by vreinterpretq_s64_u8, vreinterpretq_u8_s64
+        => vld1q_s64_partial, vld1q_s64_partial_fill, vst1q_s64_partial;
+    float64x2_t from [f64; 2] by vreinterpretq_f64_u8, vreinterpretq_u8_f64
+        => vld1q_f64_partial, vld1q_f64_partial_fill, vst1q_f64_partial;
+}
+
+// Generates the drivers with an overlapping final window for one register. The basic structure is:
+//
+//   uint8x16_t from [u8; 16] by chunks_uint8x16, vld1q_u8, vst1q_u8
+//       => for_each_overlapping_uint8x16, map_overlapping_uint8x16;
+//
+// where the chunk iterator and the loads of the parent module read the windows, and the loads and
+// stores of the `cell` module update them in place.
+macro_rules! overlapping_drivers {
+    ($(
+        $reg:ident from [$elem:ty; $n:literal] by $chunks:ident, $load:ident, $store:ident
+            => $for_each:ident, $map:ident;
+    )*) => {
+        $(
+            #[doc = concat!("Calls `f` with each `", stringify!($reg), "` of `from` and one final window that ends at the end of the slice.")]
+            ///
+            /// The final window overlaps the previous one if the slice does
+            /// not divide into whole registers, so the kernel must be
+            /// idempotent, such as a reduction with `orr`, `and`, `min` or
+            /// `max`. `f` also receives the offset of each window.
+            ///
+            /// Returns `false` without calling `f` if the slice is shorter
+            /// than a register.
+            #[inline]
+            #[target_feature(enable = "neon")]
+            pub fn $for_each(from: &[$elem], mut f: impl FnMut(usize, $reg)) -> bool {
+                let Some(start) = from.len().checked_sub($n) else {
+                    return false;
+                };
+
+                let mut chunks = $chunks(from);
+                for (i, val) in (&mut chunks).enumerate() {
+                    f(i * $n, val);
+                }
+                if !chunks.remainder().is_empty() {
+                    f(start, super::$load(chunk::<_, $n>(&from[start..])));
+                }
+                true
+            }
+
+            #[doc = concat!("Replaces each `", stringify!($reg), "` of `into` with the result of `f`, including one final window that ends at the end of the slice.")]
+            ///
+            /// The final window overlaps the previous one if the slice does
+            /// not divide into whole registers, and the overlap is processed
+            /// again after it was stored, so the kernel must be idempotent,
+            /// such as case conversion. The windows are accessed through
+            #[doc = concat!("[`cell::", stringify!($load), "`](super::cell::", stringify!($load), ") and [`cell::", stringify!($store), "`](super::cell::", stringify!($store), ").")]
+            ///
+            /// Returns `false` without calling `f` if the slice is shorter
+            /// than a register.
+            #[inline]
+            #[target_feature(enable = "neon")]
+            pub fn $map(into: &mut [$elem], mut f: impl FnMut($reg) -> $reg) -> bool {
+                let cells = Cell::from_mut(into).as_slice_of_cells();
+                let Some(last) = cells.last_chunk::<$n>() else {
+                    return false;
+                };
+
+                let (chunks, rest) = cells.as_chunks::<$n>();
+                for chunk in chunks {
+                    super::cell::$store(chunk, f(super::cell::$load(chunk)));
+                }
+                if !rest.is_empty() {
+                    super::cell::$store(last, f(super::cell::$load(last)));
+                }
+                true
+            }
+        )*
+    };
+}
+
+overlapping_drivers! {
+    uint8x16_t from [u8; 16] by chunks_uint8x16, vld1q_u8, vst1q_u8
+        => for_each_overlapping_uint8x16, map_overlapping_uint8x16;
+    int8x16_t from [i8; 16] by chunks_int8x16, vld1q_s8, vst1q_s8
+        => for_each_overlapping_int8x16, map_overlapping_int8x16;
+    uint16x8_t from [u16; 8] by chunks_uint16x8, vld1q_u16, vst1q_u16
+        => for_each_overlapping_uint16x8, map_overlapping_uint16x8;
+    int16x8_t from [i16; 8] by chunks_int16x8, vld1q_s16, vst1q_s16
+        => for_each_overlapping_int16x8, map_overlapping_int16x8;
+    uint32x4_t from [u32; 4] by chunks_uint32x4, vld1q_u32, vst1q_u32
+        => for_each_overlapping_uint32x4, map_overlapping_uint32x4;
+    int32x4_t from [i32; 4] by chunks_int32x4, vld1q_s32, vst1q_s32
+        => for_each_overlapping_int32x4, map_overlapping_int32x4;
+    float32x4_t from [f32; 4] by chunks_float32x4, vld1q_f32, vst1q_f32
+        => for_each_overlapping_float32x4, map_overlapping_float32x4;
+    uint64x2_t from [u64; 2] by chunks_uint64x2, vld1q_u64, vst1q_u64
+        => for_each_overlapping_uint64x2, map_overlapping_uint64x2;
+    int64x2_t from [i64; 2] by chunks_int64x2, vld1q_s64, vst1q_s64
+        => for_each_overlapping_int64x2, map_overlapping_int64x2;
+    float64x2_t from [f64; 2] by chunks_float64x2, vld1q_f64, vst1q_f64
+        => for_each_overlapping_float64x2, map_overlapping_float64x2;
+}
+
+#[cfg(test)]
+mod tests {
+    use core::arch::aarch64::{
+        vandq_u8, vcgeq_u8, vcleq_u8, vdupq_n_f32, vdupq_n_u8, vmulq_n_f32, vsubq_u8,
+    };
+
+    #[test]
+    #[cfg(target_feature = "neon")]
+    fn test_vld1q_vst1q_u32() {
+        #[target_feature(enable = "neon")]
+        fn test() {
+            let a: [u32; 6] = core::array::from_fn(|i| i as u32);
+            let mut b = [0u32; 6];
+
+            assert!(super::try_vld1q_u32(&a[3..]).is_none());
+            super::vst1q_u32_at(&mut b, 2, super::vld1q_u32_slice(&a));
+            assert_eq!(b, [0, 0, 0, 1, 2, 3]);
+        }
+
+        unsafe { test() }
+    }
+
+    #[test]
+    #[cfg(target_feature = "neon")]
+    fn test_vld1_f64() {
+        #[target_feature(enable = "neon")]
+        fn test() {
+            let a = [1.0f64, 2.0];
+            let mut b = [0.0f64; 2];
+
+            super::vst1_f64_slice(&mut b, super::vld1_f64_at(&a, 1));
+            assert_eq!(b, [2.0, 0.0]);
+            assert!(super::try_vld1_f64(&a[2..]).is_none());
+        }
+
+        unsafe { test() }
+    }
+
+    #[test]
+    #[cfg(target_feature = "neon")]
+    fn test_chunks_uint8x16x3() {
+        #[target_feature(enable = "neon")]
+        fn test() {
+            let a: [u8; 100] = core::array::from_fn(|i| i as u8);
+
+            let chunks = super::chunks_uint8x16x3(&a);
+            assert_eq!(chunks.len(), 2);
+            assert_eq!(chunks.remainder(), &a[96..]);
+
+            let mut b = [0u8; 96];
+            for (v, mut out) in chunks.zip(super::chunks_uint8x16x3_mut(&mut b)) {
+                out.store(v);
+            }
+            assert_eq!(b, a[..96]);
+        }
+
+        unsafe { test() }
+    }
+
+    #[test]
+    #[cfg(target_feature = "neon")]
+    fn test_chunks_float32x4_mut() {
+        #[target_feature(enable = "neon")]
+        fn test() {
+            let mut a: [f32; 10] = core::array::from_fn(|i| i as f32);
+
+            let mut chunks = super::chunks_float32x4_mut(&mut a);
+            for mut chunk in &mut chunks {
+                chunk.update(|v| vmulq_n_f32(v, 2.0));
+            }
+            assert_eq!(chunks.into_remainder(), [8.0, 9.0]);
+            assert_eq!(a, [0.0, 2.0, 4.0, 6.0, 8.0, 10.0, 12.0, 14.0, 8.0, 9.0]);
+        }
+
+        unsafe { test() }
+    }
+
+    #[test]
+    #[cfg(target_feature = "neon")]
+    fn test_vld1q_vst1q_u8_partial() {
+        #[target_feature(enable = "neon")]
+        fn test() {
+            let a: [u8; 17] = core::array::from_fn(|i| i as u8 + 1);
+            let fill = vdupq_n_u8(0xff);
+
+            for n in 0..=17 {
+                let len = n.min(16);
+                let mut expected = [0u8; 16];
+                expected[..len].copy_from_slice(&a[..len]);
+
+                let mut b = [0u8; 16];
+                super::vst1q_u8_slice(&mut b, super::vld1q_u8_partial(&a[..n]));
+                assert_eq!(b, expected, "{n} bytes");
+
+                expected[len..].fill(0xff);
+                let v = super::vld1q_u8_partial_fill(&a[..n], fill);
+                super::vst1q_u8_slice(&mut b, v);
+                assert_eq!(b, expected, "{n} bytes");
+
+                let mut c = [0u8; 17];
+                super::vst1q_u8_partial(&mut c[..n], v);
+                assert_eq!(c[..len], a[..len], "{n} bytes");
+                assert!(c[len..].iter().all(|&b| b == 0), "{n} bytes");
+            }
+        }
+
+        unsafe { test() }
+    }
+
+    #[test]
+    #[cfg(target_feature = "neon")]
+    fn test_vld1q_vst1q_f32_partial() {
+        #[target_feature(enable = "neon")]
+        fn test() {
+            let mut b = [0.0f32; 4];
+            let v = super::vld1q_f32_partial_fill(&[1.0], vdupq_n_f32(-1.0));
+            super::vst1q_f32_partial(&mut b[..3], v);
+            assert_eq!(b, [1.0, -1.0, -1.0, 0.0]);
+        }
+
+        unsafe { test() }
+    }
+
+    #[test]
+    #[cfg(target_feature = "neon")]
+    fn test_overlapping_uint8x16() {
+        #[target_feature(enable = "neon")]
+        fn test() {
+            let mut text = *b"the quick brown fox jumps over the lazy dog";
+
+            assert!(super::map_overlapping_uint8x16(&mut text, |v| {
+                let lower = vandq_u8(vcgeq_u8(v, vdupq_n_u8(b'a')), vcleq_u8(v, vdupq_n_u8(b'z')));
+                vsubq_u8(v, vandq_u8(lower, vdupq_n_u8(0x20)))
+            }));
+            assert_eq!(text, *b"THE QUICK BROWN FOX JUMPS OVER THE LAZY DOG");
+
+            let mut offsets = [0; 3];
+            let mut calls = 0;
+            assert!(super::for_each_overlapping_uint8x16(&text, |offset, _| {
+                offsets[calls] = offset;
+                calls += 1;
+            }));
+            assert_eq!(offsets, [0, 16, 27]);
+            assert!(!super::for_each_overlapping_uint8x16(
+                &text[..15],
+                |_, _| unreachable!()
+            ));
+        }
+
+        unsafe { test() }
+    }
+
+    #[test]
+    #[cfg(target_feature = "neon")]
+    #[should_panic = "range end index 9 out of range for slice of length 8"]
+    fn test_vld1q_u16_at_panics() {
+        #[target_feature(enable = "neon")]
+        fn test() {
+            super::vld1q_u16_at(&[0; 8], 1);
+        }
+
+        unsafe { test() }
+    }
+}
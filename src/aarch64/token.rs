@@ -0,0 +1,51 @@
+//! Capability token for the `neon` target feature.
+//!
+//! A [`Neon`] token can only be obtained when `neon` is available, so its methods call the
+//! wrappers of the parent module without `unsafe`. The `neon` feature is enabled by default on
+//! `aarch64` targets, where [`Neon::new`] creates the token at compile time.
+//!
+//! ```rust
+//! # #[cfg(target_feature = "neon")]
+//! # {
+//! use safe_unaligned_simd::aarch64::token::Neon;
+//!
+//! let neon = Neon::new();
+//! let mut out = [0u8; 16];
+//! let v = neon.vld1q_u8(&[1; 16]);
+//! neon.vst1q_u8(&mut out, v);
+//! assert_eq!(out, [1; 16]);
+//! # }
+//! ```
+token! {
+    /// Proof that the `neon` target feature is available.
+    pub struct Neon: "neon";
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    #[cfg(target_feature = "neon")]
+    fn test_neon_token() {
+        use super::Neon;
+
+        let neon = Neon::new();
+        assert_eq!(Neon::try_new(), Some(neon));
+
+        let a: [u8; 48] = core::array::from_fn(|i| i as u8);
+        let mut b = [0u8; 48];
+        neon.vst3q_u8(&mut b, neon.vld3q_u8(&a));
+        assert_eq!(a, b);
+
+        let mut c = [0.0f32; 4];
+        neon.vst1q_f32(&mut c, neon.vld1q_dup_f32(&1.5));
+        assert_eq!(c, [1.5; 4]);
+
+        let mut d = [0i16; 8];
+        let mut e = [0i16; 8];
+        neon.vst1q_s16(&mut d, neon.vld1q_dup_s16(&-3));
+        // Safety: the token proves that `neon` is available.
+        unsafe { crate::aarch64::vst1q_s16(&mut e, crate::aarch64::vld1q_dup_s16(&-3)) };
+        assert_eq!(d, e);
+        assert_eq!(d, [-3; 8]);
+    }
+}
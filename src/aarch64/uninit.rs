@@ -0,0 +1,336 @@
+//! Store wrappers that write into [`MaybeUninit`] destinations.
+//!
+//! Each function performs the store of the same name in the parent module and
+//! returns the now initialized reference, so output buffers do not need to be
+//! zeroed before they are written. Use [`array_mut`] to pass arrays of
+//! uninitialized elements and [`chunks_mut`] to store into the spare capacity
+//! of a vector.
+//!
+//! ```rust
+//! # #[cfg(target_feature = "neon")]
+//! # unsafe { encode() }
+//! use core::arch::aarch64::vdupq_n_u8;
+//! use core::mem::MaybeUninit;
+//!
+//! use safe_unaligned_simd::aarch64::uninit;
+//!
+//! #[target_feature(enable = "neon")]
+//! fn encode() {
+//!     let mut out = [MaybeUninit::<u8>::uninit(); 16];
+//!
+//!     let init = uninit::vst1q_u8(uninit::array_mut(&mut out), vdupq_n_u8(7));
+//!
+//!     assert_eq!(*init, [7u8; 16]);
+//! }
+//! ```
+//!
+//! [`MaybeUninit`]: core::mem::MaybeUninit
+use core::arch::aarch64::{self as arch, *};
+
+pub use crate::common_uninit::{array_mut, chunks_mut};
+
+vld_n_replicate_k! {
+    unsafe: store_uninit;
+    // Stores full registers, so 8 bytes per register
+    size: assert_size_8bytes;
+
+    /// Store an array of 8 `u8` values from one 8-byte register.
+    fn vst1_u8(_: &[u8; 8][..1] as [u8; 8]) -> uint8x8_t;
+    /// Store an array of 8 `i8` values from one 8-byte register.
+    fn vst1_s8(_: &[i8; 8][..1] as [i8; 8]) -> int8x8_t;
+    /// Store an array of 4 `u16` values from one 8-byte register.
+    fn vst1_u16(_: &[u16; 4][..1] as [u16; 4]) -> uint16x4_t;
+    /// Store an array of 4 `i16` values from one 8-byte register.
+    fn vst1_s16(_: &[i16; 4][..1] as [i16; 4]) -> int16x4_t;
+    /// Store an array of 2 `u32` values from one 8-byte register.
+    fn vst1_u32(_: &[u32; 2][..1] as [u32; 2]) -> uint32x2_t;
+    /// Store an array of 2 `i32` values from one 8-byte register.
+    fn vst1_s32(_: &[i32; 2][..1] as [i32; 2]) -> int32x2_t;
+    /// Store an array of 2 `f32` values from one 8-byte register.
+    fn vst1_f32(_: &[f32; 2][..1] as [f32; 2]) -> float32x2_t;
+    /// Store one `u64` value from one 8-byte register.
+    fn vst1_u64(_: &[u64; 1][..1] as u64) -> uint64x1_t;
+    /// Store one `i64` value from one 8-byte register.
+    fn vst1_s64(_: &[i64; 1][..1] as i64) -> int64x1_t;
+    /// Store one `f64` value from one 8-byte register.
+    fn vst1_f64(_: &[f64; 1][..1] as f64) -> float64x1_t;
+
+    /// Store arrays of 8 `u8` values from two 8-byte registers.
+    fn vst1_u8_x2(_: &[u8; 8][..2] as [[u8; 8]; 2]) -> uint8x8x2_t;
+    /// Store arrays of 8 `i8` values from two 8-byte registers.
+    fn vst1_s8_x2(_: &[i8; 8][..2] as [[i8; 8]; 2]) -> int8x8x2_t;
+    /// Store arrays of 4 `u16` values from two 8-byte registers.
+    fn vst1_u16_x2(_: &[u16; 4][..2] as [[u16; 4]; 2]) -> uint16x4x2_t;
+    /// Store arrays of 4 `i16` values from two 8-byte registers.
+    fn vst1_s16_x2(_: &[i16; 4][..2] as [[i16; 4]; 2]) -> int16x4x2_t;
+    /// Store arrays of 2 `u32` values from two 8-byte registers.
+    fn vst1_u32_x2(_: &[u32; 2][..2] as [[u32; 2]; 2]) -> uint32x2x2_t;
+    /// Store arrays of 2 `i32` values from two 8-byte registers.
+    fn vst1_s32_x2(_: &[i32; 2][..2] as [[i32; 2]; 2]) -> int32x2x2_t;
+    /// Store arrays of 2 `f32` values from two 8-byte registers.
+    fn vst1_f32_x2(_: &[f32; 2][..2] as [[f32; 2]; 2]) -> float32x2x2_t;
+    /// Store two `u64` values from two 8-byte registers.
+    fn vst1_u64_x2(_: &[u64; 1][..2] as [u64; 2]) -> uint64x1x2_t;
+    /// Store two `i64` values from two 8-byte registers.
+    fn vst1_s64_x2(_: &[i64; 1][..2] as [i64; 2]) -> int64x1x2_t;
+    /// Store two `f64` values from two 8-byte registers.
+    fn vst1_f64_x2(_: &[f64; 1][..2] as [f64; 2]) -> float64x1x2_t;
+
+    /// Store arrays of 8 `u8` values from three 8-byte registers.
+    fn vst1_u8_x3(_: &[u8; 8][..3] as [[u8; 8]; 3]) -> uint8x8x3_t;
+    /// Store arrays of 8 `i8` values from three 8-byte registers.
+    fn vst1_s8_x3(_: &[i8; 8][..3] as [[i8; 8]; 3]) -> int8x8x3_t;
+    /// Store arrays of 4 `u16` values from three 8-byte registers.
+    fn vst1_u16_x3(_: &[u16; 4][..3] as [[u16; 4]; 3]) -> uint16x4x3_t;
+    /// Store arrays of 4 `i16` values from three 8-byte registers.
+    fn vst1_s16_x3(_: &[i16; 4][..3] as [[i16; 4]; 3]) -> int16x4x3_t;
+    /// Store arrays of 2 `u32` values from three 8-byte registers.
+    fn vst1_u32_x3(_: &[u32; 2][..3] as [[u32; 2]; 3]) -> uint32x2x3_t;
+    /// Store arrays of 2 `i32` values from three 8-byte registers.
+    fn vst1_s32_x3(_: &[i32; 2][..3] as [[i32; 2]; 3]) -> int32x2x3_t;
+    /// Store arrays of 2 `f32` values from three 8-byte registers.
+    fn vst1_f32_x3(_: &[f32; 2][..3] as [[f32; 2]; 3]) -> float32x2x3_t;
+    /// Store two `u64` values from three 8-byte registers.
+    fn vst1_u64_x3(_: &[u64; 1][..3] as [u64; 3]) -> uint64x1x3_t;
+    /// Store two `i64` values from three 8-byte registers.
+    fn vst1_s64_x3(_: &[i64; 1][..3] as [i64; 3]) -> int64x1x3_t;
+    /// Store two `f64` values from three 8-byte registers.
+    fn vst1_f64_x3(_: &[f64; 1][..3] as [f64; 3]) -> float64x1x3_t;
+
+    /// Store arrays of 8 `u8` values from four 8-byte registers.
+    fn vst1_u8_x4(_: &[u8; 8][..4] as [[u8; 8]; 4]) -> uint8x8x4_t;
+    /// Store arrays of 8 `i8` values from four 8-byte registers.
+    fn vst1_s8_x4(_: &[i8; 8][..4] as [[i8; 8]; 4]) -> int8x8x4_t;
+    /// Store arrays of 4 `u16` values from four 8-byte registers.
+    fn vst1_u16_x4(_: &[u16; 4][..4] as [[u16; 4]; 4]) -> uint16x4x4_t;
+    /// Store arrays of 4 `i16` values from four 8-byte registers.
+    fn vst1_s16_x4(_: &[i16; 4][..4] as [[i16; 4]; 4]) -> int16x4x4_t;
+    /// Store arrays of 2 `u32` values from four 8-byte registers.
+    fn vst1_u32_x4(_: &[u32; 2][..4] as [[u32; 2]; 4]) -> uint32x2x4_t;
+    /// Store arrays of 2 `i32` values from four 8-byte registers.
+    fn vst1_s32_x4(_: &[i32; 2][..4] as [[i32; 2]; 4]) -> int32x2x4_t;
+    /// Store arrays of 2 `f32` values from four 8-byte registers.
+    fn vst1_f32_x4(_: &[f32; 2][..4] as [[f32; 2]; 4]) -> float32x2x4_t;
+    /// Store two `u64` values from four 8-byte registers.
+    fn vst1_u64_x4(_: &[u64; 1][..4] as [u64; 4]) -> uint64x1x4_t;
+    /// Store two `i64` values from four 8-byte registers.
+    fn vst1_s64_x4(_: &[i64; 1][..4] as [i64; 4]) -> int64x1x4_t;
+    /// Store two `f64` values from four 8-byte registers.
+    fn vst1_f64_x4(_: &[f64; 1][..4] as [f64; 4]) -> float64x1x4_t;
+}
+
+vld_n_replicate_k! {
+    unsafe: store_uninit;
+    // Stores full registers, so 16 bytes per register
+    size: assert_size_16bytes;
+
+    /// Store an array of 16 `u8` values to one 16-byte register.
+    fn vst1q_u8(_: &[u8; 16][..1] as [u8; 16]) -> uint8x16_t;
+    /// Store an array of 16 `i8` values to one 16-byte register.
+    fn vst1q_s8(_: &[i8; 16][..1] as [i8; 16]) -> int8x16_t;
+    /// Store an array of 8 `u16` values to one 16-byte register.
+    fn vst1q_u16(_: &[u16; 8][..1] as [u16; 8]) -> uint16x8_t;
+    /// Store an array of 8 `i16` values to one 16-byte register.
+    fn vst1q_s16(_: &[i16; 8][..1] as [i16; 8]) -> int16x8_t;
+    /// Store an array of 4 `u32` values to one 16-byte register.
+    fn vst1q_u32(_: &[u32; 4][..1] as [u32; 4]) -> uint32x4_t;
+    /// Store an array of 4 `i32` values to one 16-byte register.
+    fn vst1q_s32(_: &[i32; 4][..1] as [i32; 4]) -> int32x4_t;
+    /// Store an array of 4 `f32` values to one 16-byte register.
+    fn vst1q_f32(_: &[f32; 4][..1] as [f32; 4]) -> float32x4_t;
+    /// Store an array of 2 `u64` value to one 16-byte register.
+    fn vst1q_u64(_: &[u64; 2][..1] as [u64; 2]) -> uint64x2_t;
+    /// Store an array of 2 `i64` value to one 16-byte register.
+    fn vst1q_s64(_: &[i64; 2][..1] as [i64; 2]) -> int64x2_t;
+    /// Store an array of 2 `f64` value to one 16-byte register.
+    fn vst1q_f64(_: &[f64; 2][..1] as [f64; 2]) -> float64x2_t;
+
+    /// Store two arrays of 16 `u8` values from two 16-byte registers.
+    fn vst1q_u8_x2(_: &[u8; 16][..2] as [[u8; 16]; 2]) -> uint8x16x2_t;
+    /// Store two arrays of 16 `i8` values from two 16-byte registers.
+    fn vst1q_s8_x2(_: &[i8; 16][..2] as [[i8; 16]; 2]) -> int8x16x2_t;
+    /// Store two arrays of 8 `u16` values from two 16-byte registers.
+    fn vst1q_u16_x2(_: &[u16; 8][..2] as [[u16; 8]; 2]) -> uint16x8x2_t;
+    /// Store two arrays of 8 `i16` values from two 16-byte registers.
+    fn vst1q_s16_x2(_: &[i16; 8][..2] as [[i16; 8]; 2]) -> int16x8x2_t;
+    /// Store two arrays of 4 `u32` values from two 16-byte registers.
+    fn vst1q_u32_x2(_: &[u32; 4][..2] as [[u32; 4]; 2]) -> uint32x4x2_t;
+    /// Store two arrays of 4 `i32` values from two 16-byte registers.
+    fn vst1q_s32_x2(_: &[i32; 4][..2] as [[i32; 4]; 2]) -> int32x4x2_t;
+    /// Store two arrays of 4 `f32` values from two 16-byte registers.
+    fn vst1q_f32_x2(_: &[f32; 4][..2] as [[f32; 4]; 2]) -> float32x4x2_t;
+    /// Store two arrays of 2 `u64` value from two 16-byte registers.
+    fn vst1q_u64_x2(_: &[u64; 2][..2] as [[u64; 2]; 2]) -> uint64x2x2_t;
+    /// Store two arrays of 2 `i64` value from two 16-byte registers.
+    fn vst1q_s64_x2(_: &[i64; 2][..2] as [[i64; 2]; 2]) -> int64x2x2_t;
+    /// Store two arrays of 2 `f64` value from two 16-byte registers.
+    fn vst1q_f64_x2(_: &[f64; 2][..2] as [[f64; 2]; 2]) -> float64x2x2_t;
+
+    /// Store three arrays of 16 `u8` values from three16-byte registers.
+    fn vst1q_u8_x3(_: &[u8; 16][..3] as [[u8; 16]; 3]) -> uint8x16x3_t;
+    /// Store three arrays of 16 `i8` values from three16-byte registers.
+    fn vst1q_s8_x3(_: &[i8; 16][..3] as [[i8; 16]; 3]) -> int8x16x3_t;
+    /// Store three arrays of 8 `u16` values from three16-byte registers.
+    fn vst1q_u16_x3(_: &[u16; 8][..3] as [[u16; 8]; 3]) -> uint16x8x3_t;
+    /// Store three arrays of 8 `i16` values from three16-byte registers.
+    fn vst1q_s16_x3(_: &[i16; 8][..3] as [[i16; 8]; 3]) -> int16x8x3_t;
+    /// Store three arrays of 4 `u32` values from three16-byte registers.
+    fn vst1q_u32_x3(_: &[u32; 4][..3] as [[u32; 4]; 3]) -> uint32x4x3_t;
+    /// Store three arrays of 4 `i32` values from three16-byte registers.
+    fn vst1q_s32_x3(_: &[i32; 4][..3] as [[i32; 4]; 3]) -> int32x4x3_t;
+    /// Store three arrays of 4 `f32` values from three16-byte registers.
+    fn vst1q_f32_x3(_: &[f32; 4][..3] as [[f32; 4]; 3]) -> float32x4x3_t;
+    /// Store three arrays of 2 `u64` value from three16-byte registers.
+    fn vst1q_u64_x3(_: &[u64; 2][..3] as [[u64; 2]; 3]) -> uint64x2x3_t;
+    /// Store three arrays of 2 `i64` value from three16-byte registers.
+    fn vst1q_s64_x3(_: &[i64; 2][..3] as [[i64; 2]; 3]) -> int64x2x3_t;
+    /// Store three arrays of 2 `f64` value from three16-byte registers.
+    fn vst1q_f64_x3(_: &[f64; 2][..3] as [[f64; 2]; 3]) -> float64x2x3_t;
+
+    /// Store four arrays of 16 `u8` values from four 16-byte registers.
+    fn vst1q_u8_x4(_: &[u8; 16][..4] as [[u8; 16]; 4]) -> uint8x16x4_t;
+    /// Store four arrays of 16 `i8` values from four 16-byte registers.
+    fn vst1q_s8_x4(_: &[i8; 16][..4] as [[i8; 16]; 4]) -> int8x16x4_t;
+    /// Store four arrays of 8 `u16` values from four 16-byte registers.
+    fn vst1q_u16_x4(_: &[u16; 8][..4] as [[u16; 8]; 4]) -> uint16x8x4_t;
+    /// Store four arrays of 8 `i16` values from four 16-byte registers.
+    fn vst1q_s16_x4(_: &[i16; 8][..4] as [[i16; 8]; 4]) -> int16x8x4_t;
+    /// Store four arrays of 4 `u32` values from four 16-byte registers.
+    fn vst1q_u32_x4(_: &[u32; 4][..4] as [[u32; 4]; 4]) -> uint32x4x4_t;
+    /// Store four arrays of 4 `i32` values from four 16-byte registers.
+    fn vst1q_s32_x4(_: &[i32; 4][..4] as [[i32; 4]; 4]) -> int32x4x4_t;
+    /// Store four arrays of 4 `f32` values from four 16-byte registers.
+    fn vst1q_f32_x4(_: &[f32; 4][..4] as [[f32; 4]; 4]) -> float32x4x4_t;
+    /// Store four arrays of 2 `u64` value from four 16-byte registers.
+    fn vst1q_u64_x4(_: &[u64; 2][..4] as [[u64; 2]; 4]) -> uint64x2x4_t;
+    /// Store four arrays of 2 `i64` value from four 16-byte registers.
+    fn vst1q_s64_x4(_: &[i64; 2][..4] as [[i64; 2]; 4]) -> int64x2x4_t;
+    /// Store four arrays of 2 `f64` value from four 16-byte registers.
+    fn vst1q_f64_x4(_: &[f64; 2][..4] as [[f64; 2]; 4]) -> float64x2x4_t;
+}
+
+vld_n_replicate_k! {
+    unsafe: store_uninit;
+    // Stores full registers, so 16 bytes per register
+    size: assert_size_16bytes;
+
+    /// Store `u8` data from two registers, with interleaving.
+    fn vst2q_u8(_: &[u8; 16][..2] as [u8; 32]) -> uint8x16x2_t;
+    /// Store `i8` data from two registers, with interleaving.
+    fn vst2q_s8(_: &[i8; 16][..2] as [i8; 32]) -> int8x16x2_t;
+    /// Store `u16` data from two registers, with interleaving.
+    fn vst2q_u16(_: &[u16; 8][..2] as [u16; 16]) -> uint16x8x2_t;
+    /// Store `i16` data from two registers, with interleaving.
+    fn vst2q_s16(_: &[i16; 8][..2] as [i16; 16]) -> int16x8x2_t;
+    /// Store `u32` data from two registers, with interleaving.
+    fn vst2q_u32(_: &[u32; 4][..2] as [u32; 8]) -> uint32x4x2_t;
+    /// Store `i32` data from two registers, with interleaving.
+    fn vst2q_s32(_: &[i32; 4][..2] as [i32; 8]) -> int32x4x2_t;
+    /// Store `f32` data from two registers, with interleaving.
+    fn vst2q_f32(_: &[f32; 4][..2] as [f32; 8]) -> float32x4x2_t;
+    /// Store `u64` data from two registers, with interleaving.
+    fn vst2q_u64(_: &[u64; 2][..2] as [u64; 4]) -> uint64x2x2_t;
+    /// Store `i64` data from two registers, with interleaving.
+    fn vst2q_s64(_: &[i64; 2][..2] as [i64; 4]) -> int64x2x2_t;
+    /// Store `f64` data from two registers, with interleaving.
+    fn vst2q_f64(_: &[f64; 2][..2] as [f64; 4]) -> float64x2x2_t;
+
+    /// Store `u8` data from three registers, with interleaving.
+    fn vst3q_u8(_: &[u8; 16][..3] as [u8; 48]) -> uint8x16x3_t;
+    /// Store `i8` data from three registers, with interleaving.
+    fn vst3q_s8(_: &[i8; 16][..3] as [i8; 48]) -> int8x16x3_t;
+    /// Store `u16` data from three registers, with interleaving.
+    fn vst3q_u16(_: &[u16; 8][..3] as [u16; 24]) -> uint16x8x3_t;
+    /// Store `i16` data from three registers, with interleaving.
+    fn vst3q_s16(_: &[i16; 8][..3] as [i16; 24]) -> int16x8x3_t;
+    /// Store `u32` data from three registers, with interleaving.
+    fn vst3q_u32(_: &[u32; 4][..3] as [u32; 12]) -> uint32x4x3_t;
+    /// Store `i32` data from three registers, with interleaving.
+    fn vst3q_s32(_: &[i32; 4][..3] as [i32; 12]) -> int32x4x3_t;
+    /// Store `f32` data from three registers, with interleaving.
+    fn vst3q_f32(_: &[f32; 4][..3] as [f32; 12]) -> float32x4x3_t;
+    /// Store `u64` data from three registers, with interleaving.
+    fn vst3q_u64(_: &[u64; 2][..3] as [u64; 6]) -> uint64x2x3_t;
+    /// Store `i64` data from three registers, with interleaving.
+    fn vst3q_s64(_: &[i64; 2][..3] as [i64; 6]) -> int64x2x3_t;
+    /// Store `f64` data from three registers, with interleaving.
+    fn vst3q_f64(_: &[f64; 2][..3] as [f64; 6]) -> float64x2x3_t;
+
+    /// Store `u8` data from four registers, with interleaving.
+    fn vst4q_u8(_: &[u8; 16][..4] as [u8; 64]) -> uint8x16x4_t;
+    /// Store `i8` data from four registers, with interleaving.
+    fn vst4q_s8(_: &[i8; 16][..4] as [i8; 64]) -> int8x16x4_t;
+    /// Store `u16` data from four registers, with interleaving.
+    fn vst4q_u16(_: &[u16; 8][..4] as [u16; 32]) -> uint16x8x4_t;
+    /// Store `i16` data from four registers, with interleaving.
+    fn vst4q_s16(_: &[i16; 8][..4] as [i16; 32]) -> int16x8x4_t;
+    /// Store `u32` data from four registers, with interleaving.
+    fn vst4q_u32(_: &[u32; 4][..4] as [u32; 16]) -> uint32x4x4_t;
+    /// Store `i32` data from four registers, with interleaving.
+    fn vst4q_s32(_: &[i32; 4][..4] as [i32; 16]) -> int32x4x4_t;
+    /// Store `f32` data from four registers, with interleaving.
+    fn vst4q_f32(_: &[f32; 4][..4] as [f32; 16]) -> float32x4x4_t;
+    /// Store `u64` data from four registers, with interleaving.
+    fn vst4q_u64(_: &[u64; 2][..4] as [u64; 8]) -> uint64x2x4_t;
+    /// Store `i64` data from four registers, with interleaving.
+    fn vst4q_s64(_: &[i64; 2][..4] as [i64; 8]) -> int64x2x4_t;
+    /// Store `f64` data from four registers, with interleaving.
+    fn vst4q_f64(_: &[f64; 2][..4] as [f64; 8]) -> float64x2x4_t;
+}
+
+#[cfg(test)]
+mod tests {
+    use core::mem::MaybeUninit;
+
+    // Load with the wrapper of the parent module and store the registers into uninitialized
+    // memory. The initialized result must match the source.
+    macro_rules! test_vst_uninit {
+        ($(#[$attr:meta])* fn $testname:ident, $load:ident, $store:ident, $ty:ty) => {
+            #[test]
+            #[cfg(target_feature = "neon")]
+            $(#[$attr])*
+            fn $testname() {
+                #[target_feature(enable = "neon")]
+                fn test() {
+                    let bytes: [u8; size_of::<$ty>()] = core::array::from_fn(|i| i as u8);
+                    let a: $ty = unsafe { core::mem::transmute_copy(&bytes) };
+
+                    let mut b = MaybeUninit::<$ty>::uninit();
+                    let r = super::$store(&mut b, crate::aarch64::$load(&a));
+
+                    assert_eq!(*r, a);
+                }
+
+                unsafe { test() }
+            }
+        };
+    }
+
+    test_vst_uninit!(fn test_vst1_u8, vld1_u8, vst1_u8, [u8; 8]);
+    test_vst_uninit!(fn test_vst1_f64, vld1_f64, vst1_f64, f64);
+    test_vst_uninit!(fn test_vst1_s16_x3, vld1_s16_x3, vst1_s16_x3, [[i16; 4]; 3]);
+    test_vst_uninit!(fn test_vst1q_u32, vld1q_u32, vst1q_u32, [u32; 4]);
+    test_vst_uninit!(fn test_vst1q_f32_x4, vld1q_f32_x4, vst1q_f32_x4, [[f32; 4]; 4]);
+    test_vst_uninit!(fn test_vst2q_u8, vld2q_u8, vst2q_u8, [u8; 32]);
+    test_vst_uninit!(fn test_vst4q_s64, vld4q_s64, vst4q_s64, [i64; 8]);
+
+    #[test]
+    #[cfg(target_feature = "neon")]
+    fn test_spare_capacity() {
+        #[target_feature(enable = "neon")]
+        fn test(out: &mut Vec<u8>) {
+            let (chunks, _) = super::chunks_mut::<u8, 16>(out.spare_capacity_mut());
+
+            for (i, chunk) in chunks[..2].iter_mut().enumerate() {
+                super::vst1q_u8(chunk, super::vdupq_n_u8(i as u8));
+            }
+
+            unsafe { out.set_len(32) };
+        }
+
+        let mut out = Vec::with_capacity(40);
+        unsafe { test(&mut out) };
+        assert_eq!(out[..16], [0; 16]);
+        assert_eq!(out[16..], [1; 16]);
+    }
+}
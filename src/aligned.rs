@@ -0,0 +1,552 @@
+//! Owned buffers with a minimum alignment, enabled by the `alloc` feature.
+//!
+//! [`AlignedVec<T, ALIGN>`](AlignedVec) is a growable vector and
+//! [`AlignedBox<[T], ALIGN>`](AlignedBox) a fixed-size slice whose first
+//! element is aligned to `ALIGN` bytes. Their `aligned_chunks` views split the
+//! elements into arrays whose size is a multiple of `ALIGN`, so every chunk is
+//! aligned as well. The chunks can be passed to the unaligned wrappers of this
+//! crate, and their alignment can be relied on by aligned and streaming
+//! loads and stores.
+//!
+//! ```rust
+//! use safe_unaligned_simd::aligned::AlignedVec;
+//!
+//! let mut scratch = AlignedVec::<f32, 32>::new();
+//! scratch.resize(44, 1.0);
+//!
+//! let (chunks, rest): (&[[f32; 8]], &[f32]) = scratch.aligned_chunks();
+//! assert_eq!(chunks.len(), 5);
+//! assert_eq!(rest.len(), 4);
+//! assert!(chunks.iter().all(|c| c.as_ptr().addr() % 32 == 0));
+//! # #[cfg(target_arch = "x86_64")]
+//! # if is_x86_feature_detected!("avx") {
+//! # use safe_unaligned_simd::x86_64::_mm256_loadu_ps;
+//! // Each chunk can be loaded, and a 32-byte aligned load would be valid as well.
+//! let v = unsafe { _mm256_loadu_ps(&chunks[0]) };
+//! # }
+//! ```
+use alloc::alloc::{Layout, alloc, dealloc, handle_alloc_error, realloc};
+use core::fmt;
+use core::marker::PhantomData;
+use core::mem::MaybeUninit;
+use core::ops::{Deref, DerefMut};
+use core::ptr::{self, NonNull};
+
+/// A contiguous growable array of `T` whose buffer is aligned to `ALIGN` bytes.
+///
+/// The alignment is the larger of `ALIGN` and the alignment of `T`. `ALIGN`
+/// must be a power of two and `T` must not be zero-sized, which is checked at
+/// compile time. Like [`Vec`](alloc::vec::Vec) it dereferences to a slice.
+pub struct AlignedVec<T: Copy, const ALIGN: usize> {
+    ptr: NonNull<T>,
+    len: usize,
+    capacity: usize,
+    marker: PhantomData<T>,
+}
+
+/// A fixed-size slice of `T` on the heap whose first element is aligned to `ALIGN` bytes.
+///
+/// This is created from an [`AlignedVec`] or with [`from_slice`] and
+/// [`repeat`], and dereferences to the slice.
+///
+/// [`from_slice`]: AlignedBox::from_slice
+/// [`repeat`]: AlignedBox::repeat
+pub struct AlignedBox<T: ?Sized, const ALIGN: usize> {
+    ptr: NonNull<T>,
+    marker: PhantomData<T>,
+}
+
+// Safety: Both types own their elements like `Vec` and `Box` do.
+unsafe impl<T: Copy + Send, const ALIGN: usize> Send for AlignedVec<T, ALIGN> {}
+unsafe impl<T: Copy + Sync, const ALIGN: usize> Sync for AlignedVec<T, ALIGN> {}
+unsafe impl<T: ?Sized + Send, const ALIGN: usize> Send for AlignedBox<T, ALIGN> {}
+unsafe impl<T: ?Sized + Sync, const ALIGN: usize> Sync for AlignedBox<T, ALIGN> {}
+
+/// The layout of a buffer of `capacity` elements.
+#[inline]
+fn buffer_layout<T, const ALIGN: usize>(capacity: usize) -> Layout {
+    const {
+        assert!(ALIGN.is_power_of_two(), "alignment must be a power of two");
+        assert!(size_of::<T>() != 0, "element type must not be zero-sized");
+    };
+    match Layout::array::<T>(capacity).and_then(|layout| layout.align_to(ALIGN)) {
+        Ok(layout) => layout,
+        Err(_) => capacity_overflow(),
+    }
+}
+
+/// A well-aligned pointer for empty buffers.
+#[inline]
+fn dangling<T, const ALIGN: usize>() -> NonNull<T> {
+    let align = buffer_layout::<T, ALIGN>(0).align();
+    // Safety: The alignment of a layout is never zero.
+    unsafe { NonNull::new_unchecked(ptr::without_provenance_mut(align)) }
+}
+
+#[cold]
+#[inline(never)]
+#[track_caller]
+fn capacity_overflow() -> ! {
+    panic!("capacity overflow")
+}
+
+/// Splits a slice that starts at an `ALIGN`-aligned address into aligned arrays and the rest.
+#[inline]
+fn aligned_chunks<T, const N: usize, const ALIGN: usize>(slice: &[T]) -> (&[[T; N]], &[T]) {
+    const {
+        assert!(
+            N != 0 && (N * size_of::<T>()).is_multiple_of(ALIGN),
+            "chunk size must be a multiple of the alignment"
+        )
+    };
+    slice.as_chunks()
+}
+
+/// Like [`aligned_chunks`] for mutable slices.
+#[inline]
+fn aligned_chunks_mut<T, const N: usize, const ALIGN: usize>(
+    slice: &mut [T],
+) -> (&mut [[T; N]], &mut [T]) {
+    const {
+        assert!(
+            N != 0 && (N * size_of::<T>()).is_multiple_of(ALIGN),
+            "chunk size must be a multiple of the alignment"
+        )
+    };
+    slice.as_chunks_mut()
+}
+
+impl<T: Copy, const ALIGN: usize> AlignedVec<T, ALIGN> {
+    /// Creates an empty vector without allocating.
+    #[inline]
+    pub fn new() -> Self {
+        AlignedVec {
+            ptr: dangling::<T, ALIGN>(),
+            len: 0,
+            capacity: 0,
+            marker: PhantomData,
+        }
+    }
+
+    /// Creates an empty vector with space for at least `capacity` elements.
+    #[inline]
+    pub fn with_capacity(capacity: usize) -> Self {
+        let mut vec = Self::new();
+        vec.reserve(capacity);
+        vec
+    }
+
+    /// Creates a vector holding a copy of `slice`.
+    #[inline]
+    pub fn from_slice(slice: &[T]) -> Self {
+        let mut vec = Self::with_capacity(slice.len());
+        vec.extend_from_slice(slice);
+        vec
+    }
+
+    /// Returns the number of elements in the vector.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the vector contains no elements.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the number of elements the vector can hold without reallocating.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Reserves capacity for at least `additional` more elements.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new capacity overflows.
+    pub fn reserve(&mut self, additional: usize) {
+        let Some(needed) = self.len.checked_add(additional) else {
+            capacity_overflow()
+        };
+        if needed <= self.capacity {
+            return;
+        }
+
+        let capacity = needed.max(self.capacity.saturating_mul(2)).max(4);
+        let layout = buffer_layout::<T, ALIGN>(capacity);
+        let ptr = if self.capacity == 0 {
+            // Safety: The layout has a non-zero size as `T` is not zero-sized.
+            unsafe { alloc(layout) }
+        } else {
+            let old = buffer_layout::<T, ALIGN>(self.capacity);
+            // Safety: The buffer was allocated with `old`, which has the same alignment.
+            unsafe { realloc(self.ptr.as_ptr().cast(), old, layout.size()) }
+        };
+
+        let Some(ptr) = NonNull::new(ptr.cast()) else {
+            handle_alloc_error(layout)
+        };
+        self.ptr = ptr;
+        self.capacity = capacity;
+    }
+
+    /// Appends an element to the back of the vector.
+    #[inline]
+    pub fn push(&mut self, value: T) {
+        if self.len == self.capacity {
+            self.reserve(1);
+        }
+        // Safety: The capacity exceeds the length.
+        unsafe { self.ptr.add(self.len).write(value) };
+        self.len += 1;
+    }
+
+    /// Removes the last element and returns it, or `None` if the vector is empty.
+    #[inline]
+    pub fn pop(&mut self) -> Option<T> {
+        self.len = self.len.checked_sub(1)?;
+        // Safety: The element at the old length was initialized.
+        Some(unsafe { self.ptr.add(self.len).read() })
+    }
+
+    /// Appends all elements of a slice.
+    #[inline]
+    pub fn extend_from_slice(&mut self, other: &[T]) {
+        self.reserve(other.len());
+        let spare = &mut self.spare_capacity_mut()[..other.len()];
+        for (dst, &src) in spare.iter_mut().zip(other) {
+            dst.write(src);
+        }
+        self.len += other.len();
+    }
+
+    /// Resizes the vector to `new_len` elements, filling new elements with `value`.
+    #[inline]
+    pub fn resize(&mut self, new_len: usize, value: T) {
+        if let Some(additional) = new_len.checked_sub(self.len) {
+            self.reserve(additional);
+            for dst in &mut self.spare_capacity_mut()[..additional] {
+                dst.write(value);
+            }
+        }
+        self.len = new_len;
+    }
+
+    /// Shortens the vector to `len` elements, doing nothing if it is already shorter.
+    #[inline]
+    pub fn truncate(&mut self, len: usize) {
+        self.len = self.len.min(len);
+    }
+
+    /// Removes all elements, keeping the capacity.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.len = 0;
+    }
+
+    /// Sets the length of the vector.
+    ///
+    /// # Safety
+    ///
+    /// `new_len` must not exceed the capacity and the elements up to
+    /// `new_len` must be initialized.
+    #[inline]
+    pub unsafe fn set_len(&mut self, new_len: usize) {
+        self.len = new_len;
+    }
+
+    /// Returns the spare capacity after the elements as uninitialized elements.
+    ///
+    /// Combined with the `uninit` modules the spare capacity can be written
+    /// with stores before extending the length with [`set_len`](Self::set_len).
+    #[inline]
+    pub fn spare_capacity_mut(&mut self) -> &mut [MaybeUninit<T>] {
+        // Safety: The buffer holds `capacity` elements, of which those after the length are not
+        // borrowed elsewhere.
+        unsafe {
+            let spare = self.ptr.add(self.len).cast::<MaybeUninit<T>>();
+            core::slice::from_raw_parts_mut(spare.as_ptr(), self.capacity - self.len)
+        }
+    }
+
+    /// Returns the elements as a slice.
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        // Safety: The first `len` elements are initialized.
+        unsafe { core::slice::from_raw_parts(self.ptr.as_ptr(), self.len) }
+    }
+
+    /// Returns the elements as a mutable slice.
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        // Safety: The first `len` elements are initialized.
+        unsafe { core::slice::from_raw_parts_mut(self.ptr.as_ptr(), self.len) }
+    }
+
+    /// Splits the elements into arrays of `N` elements, each aligned to
+    /// `ALIGN` bytes, and the remaining elements.
+    ///
+    /// The size of `[T; N]` must be a multiple of `ALIGN`, which is checked
+    /// at compile time.
+    #[inline]
+    pub fn aligned_chunks<const N: usize>(&self) -> (&[[T; N]], &[T]) {
+        aligned_chunks::<T, N, ALIGN>(self.as_slice())
+    }
+
+    /// Splits the elements into mutable arrays of `N` elements, each aligned
+    /// to `ALIGN` bytes, and the remaining elements.
+    ///
+    /// See [`aligned_chunks`](Self::aligned_chunks).
+    #[inline]
+    pub fn aligned_chunks_mut<const N: usize>(&mut self) -> (&mut [[T; N]], &mut [T]) {
+        aligned_chunks_mut::<T, N, ALIGN>(self.as_mut_slice())
+    }
+
+    /// Converts the vector into an [`AlignedBox`], dropping any excess capacity.
+    pub fn into_boxed_slice(self) -> AlignedBox<[T], ALIGN> {
+        let this = core::mem::ManuallyDrop::new(self);
+        let ptr = if this.len == 0 {
+            if this.capacity != 0 {
+                let layout = buffer_layout::<T, ALIGN>(this.capacity);
+                // Safety: The buffer was allocated with this layout.
+                unsafe { dealloc(this.ptr.as_ptr().cast(), layout) };
+            }
+            dangling::<T, ALIGN>()
+        } else if this.len == this.capacity {
+            this.ptr
+        } else {
+            let old = buffer_layout::<T, ALIGN>(this.capacity);
+            let layout = buffer_layout::<T, ALIGN>(this.len);
+            // Safety: The buffer was allocated with `old`, which has the same alignment.
+            let ptr = unsafe { realloc(this.ptr.as_ptr().cast(), old, layout.size()) };
+            match NonNull::new(ptr.cast()) {
+                Some(ptr) => ptr,
+                None => handle_alloc_error(layout),
+            }
+        };
+
+        AlignedBox {
+            ptr: NonNull::slice_from_raw_parts(ptr, this.len),
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<T: Copy, const ALIGN: usize> Drop for AlignedVec<T, ALIGN> {
+    fn drop(&mut self) {
+        if self.capacity != 0 {
+            let layout = buffer_layout::<T, ALIGN>(self.capacity);
+            // Safety: The buffer was allocated with this layout, and `T: Copy` needs no drop.
+            unsafe { dealloc(self.ptr.as_ptr().cast(), layout) };
+        }
+    }
+}
+
+impl<T: Copy, const ALIGN: usize> Default for AlignedVec<T, ALIGN> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Copy, const ALIGN: usize> Clone for AlignedVec<T, ALIGN> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self::from_slice(self)
+    }
+}
+
+impl<T: Copy, const ALIGN: usize> Deref for AlignedVec<T, ALIGN> {
+    type Target = [T];
+
+    #[inline]
+    fn deref(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+impl<T: Copy, const ALIGN: usize> DerefMut for AlignedVec<T, ALIGN> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut [T] {
+        self.as_mut_slice()
+    }
+}
+
+impl<T: Copy + fmt::Debug, const ALIGN: usize> fmt::Debug for AlignedVec<T, ALIGN> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_slice(), f)
+    }
+}
+
+impl<T: Copy, const ALIGN: usize> Extend<T> for AlignedVec<T, ALIGN> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+        self.reserve(iter.size_hint().0);
+        for value in iter {
+            self.push(value);
+        }
+    }
+}
+
+impl<T: Copy, const ALIGN: usize> FromIterator<T> for AlignedVec<T, ALIGN> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut vec = Self::new();
+        vec.extend(iter);
+        vec
+    }
+}
+
+impl<T: Copy, const ALIGN: usize> AlignedBox<[T], ALIGN> {
+    /// Creates a boxed slice holding a copy of `slice`.
+    #[inline]
+    pub fn from_slice(slice: &[T]) -> Self {
+        AlignedVec::from_slice(slice).into_boxed_slice()
+    }
+
+    /// Creates a boxed slice of `len` copies of `value`.
+    #[inline]
+    pub fn repeat(value: T, len: usize) -> Self {
+        let mut vec = AlignedVec::with_capacity(len);
+        vec.resize(len, value);
+        vec.into_boxed_slice()
+    }
+
+    /// Splits the elements into arrays of `N` elements, each aligned to
+    /// `ALIGN` bytes, and the remaining elements.
+    ///
+    /// See [`AlignedVec::aligned_chunks`].
+    #[inline]
+    pub fn aligned_chunks<const N: usize>(&self) -> (&[[T; N]], &[T]) {
+        aligned_chunks::<T, N, ALIGN>(self)
+    }
+
+    /// Splits the elements into mutable arrays of `N` elements, each aligned
+    /// to `ALIGN` bytes, and the remaining elements.
+    ///
+    /// See [`AlignedVec::aligned_chunks`].
+    #[inline]
+    pub fn aligned_chunks_mut<const N: usize>(&mut self) -> (&mut [[T; N]], &mut [T]) {
+        aligned_chunks_mut::<T, N, ALIGN>(self)
+    }
+}
+
+impl<T: ?Sized, const ALIGN: usize> Drop for AlignedBox<T, ALIGN> {
+    fn drop(&mut self) {
+        // Safety: The box owns the value, which is not used after this.
+        unsafe {
+            let layout = Layout::for_value(self.ptr.as_ref());
+            ptr::drop_in_place(self.ptr.as_ptr());
+            if layout.size() != 0 {
+                // The allocation was made with the same size and the alignment raised to `ALIGN`.
+                let layout = layout.align_to(ALIGN).unwrap_unchecked();
+                dealloc(self.ptr.as_ptr().cast(), layout);
+            }
+        }
+    }
+}
+
+impl<T: Copy, const ALIGN: usize> From<AlignedVec<T, ALIGN>> for AlignedBox<[T], ALIGN> {
+    #[inline]
+    fn from(vec: AlignedVec<T, ALIGN>) -> Self {
+        vec.into_boxed_slice()
+    }
+}
+
+impl<T: Copy, const ALIGN: usize> Clone for AlignedBox<[T], ALIGN> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self::from_slice(self)
+    }
+}
+
+impl<T: ?Sized, const ALIGN: usize> Deref for AlignedBox<T, ALIGN> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &T {
+        // Safety: The box owns the initialized value.
+        unsafe { self.ptr.as_ref() }
+    }
+}
+
+impl<T: ?Sized, const ALIGN: usize> DerefMut for AlignedBox<T, ALIGN> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut T {
+        // Safety: The box owns the initialized value.
+        unsafe { self.ptr.as_mut() }
+    }
+}
+
+impl<T: ?Sized + fmt::Debug, const ALIGN: usize> fmt::Debug for AlignedBox<T, ALIGN> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&**self, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AlignedBox, AlignedVec};
+
+    #[test]
+    fn test_aligned_vec_growth() {
+        let mut vec = AlignedVec::<u8, 4096>::new();
+        assert!(vec.is_empty());
+        assert_eq!(vec.as_ptr().addr() % 4096, 0);
+
+        for i in 0..100 {
+            vec.push(i);
+            assert_eq!(vec.as_ptr().addr() % 4096, 0);
+        }
+        assert_eq!(vec.len(), 100);
+        assert!(vec.iter().copied().eq(0..100));
+
+        vec.extend_from_slice(&[1, 2, 3]);
+        assert_eq!(vec[100..], [1, 2, 3]);
+        assert_eq!(vec.pop(), Some(3));
+
+        vec.truncate(2);
+        assert_eq!(*vec, [0, 1]);
+        vec.resize(4, 9);
+        assert_eq!(*vec, [0, 1, 9, 9]);
+
+        vec.clear();
+        assert_eq!(vec.pop(), None);
+    }
+
+    #[test]
+    fn test_aligned_chunks() {
+        let mut vec: AlignedVec<u32, 32> = (0..20).collect();
+
+        let (chunks, rest) = vec.aligned_chunks::<8>();
+        assert_eq!(chunks.len(), 2);
+        assert_eq!(rest, [16, 17, 18, 19]);
+        assert!(chunks.iter().all(|c| c.as_ptr().addr() % 32 == 0));
+
+        let (chunks, _) = vec.aligned_chunks_mut::<16>();
+        chunks[0] = [0; 16];
+        assert_eq!(vec[..17], [[0; 16].as_slice(), &[16]].concat());
+    }
+
+    #[test]
+    fn test_aligned_box() {
+        let mut vec = AlignedVec::<f64, 64>::with_capacity(100);
+        vec.extend([1.0, 2.0, 3.0]);
+
+        let boxed = vec.into_boxed_slice();
+        assert_eq!(*boxed, [1.0, 2.0, 3.0]);
+        assert_eq!(boxed.as_ptr().addr() % 64, 0);
+
+        let mut boxed = AlignedBox::<[u16], 128>::repeat(7, 130);
+        assert_eq!(boxed.as_ptr().addr() % 128, 0);
+        let (chunks, rest) = boxed.aligned_chunks_mut::<64>();
+        assert_eq!((chunks.len(), rest.len()), (2, 2));
+        assert!(boxed.iter().all(|&x| x == 7));
+
+        let empty = AlignedBox::<[u8], 16>::from_slice(&[]);
+        assert!(empty.is_empty());
+        assert_eq!(empty.clone().as_ptr().addr() % 16, 0);
+    }
+}
@@ -0,0 +1,254 @@
+//! Integration with [`bytemuck`], enabled by the `bytemuck` feature.
+//!
+//! [`impl_unaligned_bits_pod!`](crate::impl_unaligned_bits_pod) implements
+//! [`UnalignedBits`](crate::UnalignedBits) for types that already implement
+//! [`Pod`], or the load-only [`ReadableBits`](crate::ReadableBits) for
+//! [`NoUninit`] types, and [`chunks`] / [`chunks_mut`] split slices into the
+//! fixed-size arrays taken by the load and store functions.
+//!
+//! The traits cannot be implemented for every `Pod` type at once. A blanket
+//! impl over `T: Pod` would overlap with the impls for arrays, `Wrapping`,
+//! `Saturating` and `Cell`, since `bytemuck` could implement `Pod` for any of
+//! them, and it cannot name the register width of `T`. Each type is therefore
+//! listed once with its width in the macro.
+//!
+//! ```rust
+//! use safe_unaligned_simd::bytemuck::chunks;
+//!
+//! let data = [0u8; 70];
+//! let (blocks, rest): (&[[u8; 32]], &[u8]) = chunks(&data);
+//! assert_eq!(blocks.len(), 2);
+//! assert_eq!(rest.len(), 6);
+//! ```
+use ::bytemuck::{AnyBitPattern, NoUninit, Pod};
+
+/// Splits a slice into chunks of type `C` and the remaining elements.
+///
+/// `C` is typically an array such as `[u8; 32]` or `[f32; 8]`. Its size must be
+/// a multiple of the size of `T` and its alignment must not exceed the
+/// alignment of `T`, which is checked at compile time.
+#[inline]
+pub fn chunks<C: AnyBitPattern, T: NoUninit>(slice: &[T]) -> (&[C], &[T]) {
+    let per_chunk = const { elements_per_chunk::<C, T>() };
+    let (head, tail) = slice.split_at(slice.len() - slice.len() % per_chunk);
+    (::bytemuck::cast_slice(head), tail)
+}
+
+/// Splits a mutable slice into chunks of type `C` and the remaining elements.
+///
+/// See [`chunks`] for the requirements on `C`.
+#[inline]
+pub fn chunks_mut<C: Pod, T: Pod>(slice: &mut [T]) -> (&mut [C], &mut [T]) {
+    let per_chunk = const { elements_per_chunk::<C, T>() };
+    let len = slice.len();
+    let (head, tail) = slice.split_at_mut(len - len % per_chunk);
+    (::bytemuck::cast_slice_mut(head), tail)
+}
+
+const fn elements_per_chunk<C, T>() -> usize {
+    assert!(size_of::<T>() != 0, "element type must not be zero-sized");
+    assert!(
+        size_of::<C>() != 0 && size_of::<C>().is_multiple_of(size_of::<T>()),
+        "chunk size must be a multiple of the element size",
+    );
+    assert!(
+        align_of::<C>() <= align_of::<T>(),
+        "chunk alignment must not exceed the element alignment",
+    );
+    size_of::<C>() / size_of::<T>()
+}
+
+/// Implements [`UnalignedBits`](crate::UnalignedBits) for types implementing
+/// [`Pod`].
+///
+/// `Pod` already guarantees that the type has no padding and is valid for any
+/// bit pattern, so only the size is checked against `Bits`. With a leading
+/// `readable:`, the macro instead implements the load-only
+/// [`ReadableBits`](crate::ReadableBits) for types implementing [`NoUninit`],
+/// which may have invalid bit patterns.
+///
+/// ```rust
+/// # #[cfg(target_arch = "x86_64")]
+/// # {
+/// use safe_unaligned_simd::impl_unaligned_bits_pod;
+///
+/// #[derive(Clone, Copy)]
+/// #[repr(C)]
+/// struct Rgba {
+///     r: u8,
+///     g: u8,
+///     b: u8,
+///     a: u8,
+/// }
+///
+/// // SAFETY: `Rgba` is `repr(C)` with only `u8` fields.
+/// unsafe impl bytemuck::Zeroable for Rgba {}
+/// unsafe impl bytemuck::Pod for Rgba {}
+///
+/// impl_unaligned_bits_pod! {
+///     Rgba as i32,
+/// }
+///
+/// #[target_feature(enable = "sse2")]
+/// fn load_pixels(pixels: &[Rgba; 4]) -> core::arch::x86_64::__m128i {
+///     safe_unaligned_simd::x86_64::_mm_loadu_si128(pixels)
+/// }
+///
+/// #[derive(Clone, Copy)]
+/// #[repr(u8)]
+/// enum Channel {
+///     Red,
+///     Green,
+///     Blue,
+/// }
+///
+/// // SAFETY: `Channel` is a fieldless `repr(u8)` enum.
+/// unsafe impl bytemuck::NoUninit for Channel {}
+///
+/// impl_unaligned_bits_pod! {
+///     readable: Channel as i8,
+/// }
+///
+/// #[target_feature(enable = "sse2")]
+/// fn load_channels(channels: &[Channel; 16]) -> core::arch::x86_64::__m128i {
+///     safe_unaligned_simd::x86_64::_mm_loadu_si128(channels)
+/// }
+/// # }
+/// ```
+#[macro_export]
+macro_rules! impl_unaligned_bits_pod {
+    (readable: $($ty:ty as $bits:ty),* $(,)?) => {
+        $(
+            const _: () = {
+                fn no_uninit<T: $crate::__private::NoUninit>() {}
+
+                #[allow(dead_code)]
+                fn is_no_uninit() {
+                    no_uninit::<$ty>()
+                }
+
+                assert!(
+                    size_of::<$ty>() == size_of::<$bits>(),
+                    "type size does not match `Bits`",
+                );
+            };
+
+            // SAFETY: `NoUninit` types have no padding and no interior mutability. The size is
+            // checked above.
+            unsafe impl $crate::ReadableBits for $ty {
+                type Bits = $bits;
+            }
+        )*
+    };
+
+    ($($ty:ty as $bits:ty),* $(,)?) => {
+        $(
+            const _: () = {
+                fn pod<T: $crate::__private::Pod>() {}
+
+                #[allow(dead_code)]
+                fn is_pod() {
+                    pod::<$ty>()
+                }
+
+                assert!(
+                    size_of::<$ty>() == size_of::<$bits>(),
+                    "type size does not match `Bits`",
+                );
+            };
+
+            // SAFETY: `Pod` types have no padding, no interior mutability and are valid for any
+            // bit pattern. The size is checked above.
+            unsafe impl $crate::UnalignedBits for $ty {
+                type Bits = $bits;
+            }
+        )*
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{chunks, chunks_mut};
+
+    #[test]
+    fn chunks_split_remainder() {
+        let data: [u8; 70] = core::array::from_fn(|i| i as u8);
+        let (blocks, rest): (&[[u8; 32]], _) = chunks(&data);
+
+        assert_eq!(blocks.len(), 2);
+        assert_eq!(blocks[1][0], 32);
+        assert_eq!(rest, &data[64..]);
+    }
+
+    #[test]
+    fn chunks_mut_write_through() {
+        let mut data = [0u32; 10];
+        let (blocks, rest): (&mut [[u32; 4]], _) = chunks_mut(&mut data);
+
+        assert_eq!(blocks.len(), 2);
+        assert_eq!(rest.len(), 2);
+        blocks[1] = [1, 2, 3, 4];
+        rest[0] = 5;
+
+        assert_eq!(data, [0, 0, 0, 0, 1, 2, 3, 4, 5, 0]);
+    }
+
+    #[cfg(any(
+        target_arch = "x86",
+        target_arch = "x86_64",
+        target_arch = "wasm32",
+        target_arch = "aarch64",
+        target_arch = "arm64ec",
+    ))]
+    #[test]
+    fn pod_types_implement_unaligned_traits() {
+        use crate::common_traits::{Is64BitsUnaligned, Is128BitsUnaligned};
+
+        #[derive(Clone, Copy)]
+        #[repr(C)]
+        struct Rgba {
+            r: u8,
+            g: u8,
+            b: u8,
+            a: u8,
+        }
+
+        // SAFETY: `Rgba` is `repr(C)` with only `u8` fields.
+        unsafe impl ::bytemuck::Zeroable for Rgba {}
+        // SAFETY: See above.
+        unsafe impl ::bytemuck::Pod for Rgba {}
+
+        crate::impl_unaligned_bits_pod!(Rgba as i32);
+
+        fn assert_is_64_bits_unaligned<T: Is64BitsUnaligned>() {}
+        fn assert_is_128_bits_unaligned<T: Is128BitsUnaligned>() {}
+
+        assert_is_64_bits_unaligned::<[Rgba; 2]>();
+        assert_is_128_bits_unaligned::<[Rgba; 4]>();
+    }
+
+    #[test]
+    fn no_uninit_types_implement_readable_traits() {
+        use crate::common_traits::{Is32BitsReadable, Is128BitsReadable};
+
+        #[allow(dead_code)]
+        #[derive(Clone, Copy)]
+        #[repr(u8)]
+        enum Channel {
+            Red,
+            Green,
+            Blue,
+        }
+
+        // SAFETY: `Channel` is a fieldless `repr(u8)` enum.
+        unsafe impl ::bytemuck::NoUninit for Channel {}
+
+        crate::impl_unaligned_bits_pod!(readable: Channel as i8);
+
+        fn assert_is_32_bits_readable<T: Is32BitsReadable>() {}
+        fn assert_is_128_bits_readable<T: Is128BitsReadable>() {}
+
+        assert_is_32_bits_readable::<[Channel; 4]>();
+        assert_is_128_bits_readable::<[Channel; 16]>();
+    }
+}
@@ -0,0 +1,225 @@
+//! By-value conversions between registers and arrays of the same size.
+//!
+//! Every register type of the supported platforms implements [`Register<BYTES>`](Register) for its
+//! size, including the multi-register tuples of `aarch64` such as `float32x4x3_t`. The conversions
+//! are bounded by the [`Unaligned`] and [`Readable`] traits, so a register can become any array
+//! whose bit patterns are all valid, and any readable array can become a register.
+use core::fmt;
+
+use crate::common_traits::{Readable, Unaligned};
+
+// Internal module for sealing the `Register` trait.
+mod private {
+    pub trait Sealed {}
+}
+
+/// A register type of `BYTES` bytes.
+///
+/// The methods reinterpret the bits of the register without any target feature, which makes them
+/// usable for debugging and tests regardless of the enabled features.
+///
+/// ```rust
+/// # #[cfg(target_arch = "x86_64")]
+/// # {
+/// use core::arch::x86_64::__m256i;
+///
+/// use safe_unaligned_simd::Register;
+///
+/// let v = __m256i::from_array([7u16; 16]);
+/// let bytes: [u8; 32] = v.to_array();
+/// assert_eq!(bytes, [7u16; 16].map(u16::to_ne_bytes).as_flattened());
+/// assert_eq!(format!("{:?}", v.lanes::<[u32; 8]>()), format!("{:?}", [0x0007_0007u32; 8]));
+/// # }
+/// ```
+pub trait Register<const BYTES: usize>: Copy + private::Sealed {
+    /// Reinterprets the register as an array of the same size.
+    #[inline]
+    fn to_array<T: Unaligned<BYTES>>(self) -> T {
+        // Safety: `Self` and `T` are `BYTES` bytes large and every bit pattern is valid for `T`.
+        unsafe { core::mem::transmute_copy(&self) }
+    }
+
+    /// Reinterprets an array of the same size as a register.
+    #[inline]
+    fn from_array<T: Readable<BYTES>>(array: T) -> Self {
+        // Safety: `Self` and `T` are `BYTES` bytes large, `T` has no padding bytes, and every bit
+        // pattern is valid for a register.
+        unsafe { core::mem::transmute_copy(&array) }
+    }
+
+    /// Returns a formatter of the register as lanes of an array of the same size.
+    ///
+    /// The formatter uses the [`Debug`](fmt::Debug) implementation of the array, including its
+    /// `{:#?}` and `{:x?}` variants.
+    #[inline]
+    fn lanes<T: Unaligned<BYTES> + fmt::Debug>(self) -> Lanes<T> {
+        Lanes(self.to_array())
+    }
+}
+
+/// Formats the lanes of a register, returned by [`Register::lanes`].
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Lanes<T>(T);
+
+impl<T> Lanes<T> {
+    /// Returns the array of lanes.
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Lanes<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+macro_rules! impl_register {
+    ($(impl Register<$bytes:literal> for { $($ty:ty),* $(,)? })*) => {
+        $($(
+            const _: () = const { assert!(size_of::<$ty>() == $bytes) };
+
+            impl private::Sealed for $ty {}
+            impl Register<$bytes> for $ty {}
+        )*)*
+    };
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod x86 {
+    #[cfg(target_arch = "x86")]
+    use core::arch::x86::*;
+    #[cfg(target_arch = "x86_64")]
+    use core::arch::x86_64::*;
+
+    use super::{Register, private};
+
+    impl_register! {
+        impl Register<16> for { __m128, __m128d, __m128i }
+        impl Register<32> for { __m256, __m256d, __m256i }
+        impl Register<64> for { __m512, __m512d, __m512i }
+    }
+}
+
+#[cfg(any(target_arch = "aarch64", target_arch = "arm64ec"))]
+mod aarch64 {
+    use core::arch::aarch64::*;
+
+    use super::{Register, private};
+
+    impl_register! {
+        impl Register<8> for {
+            uint8x8_t, int8x8_t, uint16x4_t, int16x4_t, uint32x2_t, int32x2_t, float32x2_t,
+            uint64x1_t, int64x1_t, float64x1_t,
+        }
+        impl Register<16> for {
+            uint8x16_t, int8x16_t, uint16x8_t, int16x8_t, uint32x4_t, int32x4_t, float32x4_t,
+            uint64x2_t, int64x2_t, float64x2_t,
+            uint8x8x2_t, int8x8x2_t, uint16x4x2_t, int16x4x2_t, uint32x2x2_t, int32x2x2_t,
+            float32x2x2_t, uint64x1x2_t, int64x1x2_t, float64x1x2_t,
+        }
+        impl Register<24> for {
+            uint8x8x3_t, int8x8x3_t, uint16x4x3_t, int16x4x3_t, uint32x2x3_t, int32x2x3_t,
+            float32x2x3_t, uint64x1x3_t, int64x1x3_t, float64x1x3_t,
+        }
+        impl Register<32> for {
+            uint8x16x2_t, int8x16x2_t, uint16x8x2_t, int16x8x2_t, uint32x4x2_t, int32x4x2_t,
+            float32x4x2_t, uint64x2x2_t, int64x2x2_t, float64x2x2_t,
+            uint8x8x4_t, int8x8x4_t, uint16x4x4_t, int16x4x4_t, uint32x2x4_t, int32x2x4_t,
+            float32x2x4_t, uint64x1x4_t, int64x1x4_t, float64x1x4_t,
+        }
+        impl Register<48> for {
+            uint8x16x3_t, int8x16x3_t, uint16x8x3_t, int16x8x3_t, uint32x4x3_t, int32x4x3_t,
+            float32x4x3_t, uint64x2x3_t, int64x2x3_t, float64x2x3_t,
+        }
+        impl Register<64> for {
+            uint8x16x4_t, int8x16x4_t, uint16x8x4_t, int16x8x4_t, uint32x4x4_t, int32x4x4_t,
+            float32x4x4_t, uint64x2x4_t, int64x2x4_t, float64x2x4_t,
+        }
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+mod wasm32 {
+    use core::arch::wasm32::v128;
+
+    use super::{Register, private};
+
+    impl_register! {
+        impl Register<16> for { v128 }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    fn test_x86_register() {
+        #[cfg(target_arch = "x86")]
+        use core::arch::x86::*;
+        #[cfg(target_arch = "x86_64")]
+        use core::arch::x86_64::*;
+
+        let v = __m256i::from_array([1u16, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16]);
+        let halves: [[u16; 8]; 2] = v.to_array();
+        assert_eq!(
+            halves,
+            [[1, 2, 3, 4, 5, 6, 7, 8], [9, 10, 11, 12, 13, 14, 15, 16]]
+        );
+
+        let f = __m128::from_array([true; 16]);
+        assert_eq!(f.to_array::<[u8; 16]>(), [1; 16]);
+
+        let d = __m512d::from_array([1.5f64; 8]);
+        assert_eq!(d.to_array::<[f64; 8]>(), [1.5; 8]);
+    }
+
+    #[test]
+    #[cfg(any(target_arch = "aarch64", target_arch = "arm64ec"))]
+    fn test_aarch64_register() {
+        use core::arch::aarch64::*;
+
+        let rgb = [
+            [1.0f32, 2.0, 3.0, 4.0],
+            [5.0, 6.0, 7.0, 8.0],
+            [9.0, 10.0, 11.0, 12.0],
+        ];
+        let v = float32x4x3_t::from_array(rgb);
+        assert_eq!(v.to_array::<[[f32; 4]; 3]>(), rgb);
+
+        let d = uint8x8x3_t::from_array([3u64; 3]);
+        assert_eq!(d.to_array::<[u8; 24]>()[16], 3);
+    }
+
+    #[test]
+    #[cfg(target_arch = "wasm32")]
+    fn test_wasm32_register() {
+        use core::arch::wasm32::v128;
+
+        let v = v128::from_array([-1i8; 16]);
+        assert_eq!(v.to_array::<[u32; 4]>(), [u32::MAX; 4]);
+    }
+
+    #[test]
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    fn test_lanes() {
+        #[cfg(target_arch = "x86")]
+        use core::arch::x86::__m128i;
+        #[cfg(target_arch = "x86_64")]
+        use core::arch::x86_64::__m128i;
+
+        let v = __m128i::from_array([0x0102_0304u32, 5, 6, 7]);
+        assert_eq!(
+            format!("{:?}", v.lanes::<[u32; 4]>()),
+            "[16909060, 5, 6, 7]"
+        );
+        assert_eq!(
+            format!("{:x?}", v.lanes::<[u32; 4]>()),
+            "[1020304, 5, 6, 7]"
+        );
+        assert_eq!(v.lanes::<[u64; 2]>().into_inner(), v.to_array::<[u64; 2]>());
+    }
+}
@@ -0,0 +1,20 @@
+//! Conversions between platform registers and `core::simd` vectors, shared by the `simd` modules.
+use core::simd::{Simd, SimdElement};
+
+/// Reinterprets a register as a vector of the same size.
+#[inline(always)]
+pub(crate) fn from_register<R: Copy, T: SimdElement, const N: usize>(reg: R) -> Simd<T, N> {
+    const { assert!(size_of::<R>() == size_of::<Simd<T, N>>()) };
+    // Safety: Both types have the same size by the assertion above. Registers are valid for any
+    // bit pattern and callers only instantiate this with elements that are as well.
+    unsafe { core::mem::transmute_copy(&reg) }
+}
+
+/// Reinterprets a vector as a register of the same size.
+#[inline(always)]
+pub(crate) fn to_register<R: Copy, T: SimdElement, const N: usize>(vec: Simd<T, N>) -> R {
+    const { assert!(size_of::<R>() == size_of::<Simd<T, N>>()) };
+    // Safety: Both types have the same size by the assertion above and registers are valid for
+    // any bit pattern.
+    unsafe { core::mem::transmute_copy(&vec) }
+}
@@ -0,0 +1,411 @@
+//! Byte views of slices and the chunk iterators for the `slice` modules.
+// The byte views are only needed by the element-generic wrappers of x86 and wasm32.
+#![allow(unused)]
+use core::iter::FusedIterator;
+use core::marker::PhantomData;
+use core::mem::size_of_val;
+
+use crate::common_traits::{ReadableElement, UnalignedElement};
+
+/// Views the first `N` bytes of a slice as a byte array, if the slice is long enough.
+#[inline(always)]
+pub(crate) fn first_bytes<T: ReadableElement, const N: usize>(slice: &[T]) -> Option<&[u8; N]> {
+    if size_of_val(slice) < N {
+        return None;
+    }
+
+    // Safety: The slice covers at least `N` bytes, which are initialized and not padding by the
+    // `ReadableElement` bound. Byte arrays have no alignment requirement.
+    Some(unsafe { &*slice.as_ptr().cast::<[u8; N]>() })
+}
+
+/// Views the first `N` bytes of a mutable slice as a byte array, if the slice is long enough.
+#[inline(always)]
+pub(crate) fn first_bytes_mut<T: UnalignedElement, const N: usize>(
+    slice: &mut [T],
+) -> Option<&mut [u8; N]> {
+    if size_of_val(slice) < N {
+        return None;
+    }
+
+    // Safety: As above, and every bit pattern written through the bytes is a valid `T` by the
+    // `UnalignedElement` bound.
+    Some(unsafe { &mut *slice.as_mut_ptr().cast::<[u8; N]>() })
+}
+
+/// Panics like slice indexing for a slice with fewer than `needed` elements.
+#[cold]
+#[inline(never)]
+#[track_caller]
+pub(crate) fn too_short(needed: usize, len: usize) -> ! {
+    panic!("range end index {needed} out of range for slice of length {len}")
+}
+
+/// Panics for a slice covering fewer than `needed` bytes.
+#[cold]
+#[inline(never)]
+#[track_caller]
+pub(crate) fn too_short_bytes(needed: usize, len: usize) -> ! {
+    panic!("access of {needed} bytes out of range for slice of {len} bytes")
+}
+
+/// Returns the first `N` elements of a slice, panicking like slice indexing if it is too short.
+#[inline(always)]
+#[track_caller]
+pub(crate) fn chunk<T, const N: usize>(slice: &[T]) -> &[T; N] {
+    match slice.first_chunk() {
+        Some(chunk) => chunk,
+        None => too_short(N, slice.len()),
+    }
+}
+
+/// Returns the first `N` elements of a mutable slice, panicking like slice indexing if it is too
+/// short.
+#[inline(always)]
+#[track_caller]
+pub(crate) fn chunk_mut<T, const N: usize>(slice: &mut [T]) -> &mut [T; N] {
+    let len = slice.len();
+    match slice.first_chunk_mut() {
+        Some(chunk) => chunk,
+        None => too_short(N, len),
+    }
+}
+
+/// Views the first `N` bytes of a slice as a byte array, panicking if it is too short.
+#[inline(always)]
+#[track_caller]
+pub(crate) fn bytes<T: ReadableElement, const N: usize>(slice: &[T]) -> &[u8; N] {
+    match first_bytes(slice) {
+        Some(bytes) => bytes,
+        None => too_short_bytes(N, size_of_val(slice)),
+    }
+}
+
+/// Views the first `N` bytes of a mutable slice as a byte array, panicking if it is too short.
+#[inline(always)]
+#[track_caller]
+pub(crate) fn bytes_mut<T: UnalignedElement, const N: usize>(slice: &mut [T]) -> &mut [u8; N] {
+    let len = size_of_val(slice);
+    match first_bytes_mut(slice) {
+        Some(bytes) => bytes,
+        None => too_short_bytes(N, len),
+    }
+}
+
+/// The number of `T` elements in a register of `bytes` bytes.
+///
+/// Fails to compile, when used in a constant, for elements that do not evenly divide the register.
+pub(crate) const fn elements<T>(bytes: usize) -> usize {
+    assert!(
+        size_of::<T>() != 0 && bytes.is_multiple_of(size_of::<T>()),
+        "the element size must divide the register size"
+    );
+    bytes / size_of::<T>()
+}
+
+/// Views a slice as its bytes.
+#[inline(always)]
+pub(crate) fn as_bytes<T: ReadableElement>(slice: &[T]) -> &[u8] {
+    // Safety: As in `first_bytes`, for all bytes of the slice.
+    unsafe { core::slice::from_raw_parts(slice.as_ptr().cast(), size_of_val(slice)) }
+}
+
+/// Views a mutable slice as its bytes.
+#[inline(always)]
+pub(crate) fn as_bytes_mut<T: UnalignedElement>(slice: &mut [T]) -> &mut [u8] {
+    let len = size_of_val(slice);
+    // Safety: As in `first_bytes_mut`, for all bytes of the slice.
+    unsafe { core::slice::from_raw_parts_mut(slice.as_mut_ptr().cast(), len) }
+}
+
+/// Reads up to 16 bytes from the start of `bytes` as two little-endian words, filling the missing
+/// bytes with zero.
+///
+/// Tails are read with at most two overlapping loads per word instead of a byte loop.
+#[inline(always)]
+pub(crate) fn read_partial(bytes: &[u8]) -> [u64; 2] {
+    match bytes.split_at_checked(8) {
+        Some((lo, hi)) => [read_partial_u64(lo), read_partial_u64(hi)],
+        None => [read_partial_u64(bytes), 0],
+    }
+}
+
+/// Writes the little-endian bytes of two words to the start of `bytes`, at most 16 bytes.
+#[inline(always)]
+pub(crate) fn write_partial(bytes: &mut [u8], [lo, hi]: [u64; 2]) {
+    match bytes.split_at_mut_checked(8) {
+        Some((lo_bytes, hi_bytes)) => {
+            write_partial_u64(lo_bytes, lo);
+            write_partial_u64(hi_bytes, hi);
+        }
+        None => write_partial_u64(bytes, lo),
+    }
+}
+
+/// Reads up to 8 bytes as a little-endian word, filling the missing bytes with zero.
+#[inline(always)]
+fn read_partial_u64(bytes: &[u8]) -> u64 {
+    let n = bytes.len();
+    if let Some(word) = bytes.first_chunk::<8>() {
+        u64::from_le_bytes(*word)
+    } else if let (Some(lo), Some(hi)) = (bytes.first_chunk::<4>(), bytes.last_chunk::<4>()) {
+        // The two halves overlap for fewer than 8 bytes, in which case the shared bytes agree.
+        let lo = u64::from(u32::from_le_bytes(*lo));
+        let hi = u64::from(u32::from_le_bytes(*hi));
+        lo | hi << (8 * (n - 4))
+    } else if let Some(&first) = bytes.first() {
+        // One to three bytes: the first, middle and last byte cover all of them.
+        let mid = n / 2;
+        u64::from(first)
+            | u64::from(bytes[mid]) << (8 * mid)
+            | u64::from(bytes[n - 1]) << (8 * (n - 1))
+    } else {
+        0
+    }
+}
+
+/// Writes the first `min(bytes.len(), 8)` little-endian bytes of a word.
+#[inline(always)]
+fn write_partial_u64(bytes: &mut [u8], word: u64) {
+    let n = bytes.len();
+    let word = word.to_le_bytes();
+    if let Some(all) = bytes.first_chunk_mut::<8>() {
+        *all = word;
+    } else if n >= 4 {
+        bytes[..4].copy_from_slice(&word[..4]);
+        bytes[n - 4..].copy_from_slice(&word[n - 4..n]);
+    } else if n >= 2 {
+        bytes[..2].copy_from_slice(&word[..2]);
+        bytes[n - 2..].copy_from_slice(&word[n - 2..n]);
+    } else if let Some(first) = bytes.first_mut() {
+        *first = word[0];
+    }
+}
+
+/// A register that is loaded from chunks of `LEN` elements of `T`.
+///
+/// Implemented by the `slice` module of each platform for the registers of its `chunks_*`
+/// functions.
+pub trait LoadChunk<T>: Sized {
+    /// The number of elements in one chunk.
+    const LEN: usize;
+
+    /// Loads the register from the first `LEN` elements of `chunk`.
+    ///
+    /// # Safety
+    ///
+    /// The target features of the load must be available.
+    unsafe fn load(chunk: &[T]) -> Self;
+}
+
+/// A register that is also stored to chunks of `LEN` elements of `T`.
+pub trait StoreChunk<T>: LoadChunk<T> {
+    /// Stores the register to the first `LEN` elements of `chunk`.
+    ///
+    /// # Safety
+    ///
+    /// The target features of the store must be available.
+    unsafe fn store(chunk: &mut [T], v: Self);
+}
+
+/// An iterator over the registers loaded from consecutive chunks of a slice.
+///
+/// This is created by the `chunks_*` functions of the `slice` modules. Like
+/// [`ChunksExact`](core::slice::ChunksExact) it skips the elements at the end
+/// that do not fill a whole register, which are available from
+/// [`remainder`](Self::remainder).
+#[derive(Debug)]
+pub struct Chunks<'a, T, R> {
+    chunks: &'a [T],
+    remainder: &'a [T],
+    register: PhantomData<fn() -> R>,
+}
+
+impl<'a, T, R: LoadChunk<T>> Chunks<'a, T, R> {
+    /// # Safety
+    ///
+    /// The target features of the register must be available.
+    #[inline(always)]
+    pub(crate) unsafe fn new(slice: &'a [T]) -> Self {
+        let (chunks, remainder) = slice.split_at(slice.len() - slice.len() % R::LEN);
+        Chunks {
+            chunks,
+            remainder,
+            register: PhantomData,
+        }
+    }
+}
+
+impl<T, R> Clone for Chunks<'_, T, R> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Chunks { ..*self }
+    }
+}
+
+impl<'a, T, R> Chunks<'a, T, R> {
+    /// Returns the elements at the end of the slice that do not fill a register.
+    #[inline]
+    pub fn remainder(&self) -> &'a [T] {
+        self.remainder
+    }
+}
+
+impl<T, R: LoadChunk<T>> Iterator for Chunks<'_, T, R> {
+    type Item = R;
+
+    #[inline]
+    fn next(&mut self) -> Option<R> {
+        let (chunk, rest) = self.chunks.split_at_checked(R::LEN)?;
+        self.chunks = rest;
+        // Safety: `Chunks` is only constructed where the target features are available.
+        Some(unsafe { R::load(chunk) })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.chunks.len() / R::LEN;
+        (len, Some(len))
+    }
+}
+
+impl<T, R: LoadChunk<T>> ExactSizeIterator for Chunks<'_, T, R> {}
+
+impl<T, R: LoadChunk<T>> FusedIterator for Chunks<'_, T, R> {}
+
+/// An iterator over the chunks of a mutable slice that a register is loaded from and stored to.
+///
+/// This is created by the `chunks_*_mut` functions of the `slice` modules and
+/// yields a [`ChunkMut`] for each chunk. The elements at the end that do not
+/// fill a whole register are available from
+/// [`into_remainder`](Self::into_remainder).
+#[derive(Debug)]
+pub struct ChunksMut<'a, T, R> {
+    chunks: &'a mut [T],
+    remainder: &'a mut [T],
+    register: PhantomData<fn() -> R>,
+}
+
+impl<'a, T, R: StoreChunk<T>> ChunksMut<'a, T, R> {
+    /// # Safety
+    ///
+    /// The target features of the register must be available.
+    #[inline(always)]
+    pub(crate) unsafe fn new(slice: &'a mut [T]) -> Self {
+        let len = slice.len();
+        let (chunks, remainder) = slice.split_at_mut(len - len % R::LEN);
+        ChunksMut {
+            chunks,
+            remainder,
+            register: PhantomData,
+        }
+    }
+}
+
+impl<'a, T, R> ChunksMut<'a, T, R> {
+    /// Returns the elements at the end of the slice that do not fill a register.
+    #[inline]
+    pub fn into_remainder(self) -> &'a mut [T] {
+        self.remainder
+    }
+}
+
+impl<'a, T, R: StoreChunk<T>> Iterator for ChunksMut<'a, T, R> {
+    type Item = ChunkMut<'a, T, R>;
+
+    #[inline]
+    fn next(&mut self) -> Option<ChunkMut<'a, T, R>> {
+        let (chunk, rest) = core::mem::take(&mut self.chunks).split_at_mut_checked(R::LEN)?;
+        self.chunks = rest;
+        Some(ChunkMut {
+            chunk,
+            register: PhantomData,
+        })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.chunks.len() / R::LEN;
+        (len, Some(len))
+    }
+}
+
+impl<T, R: StoreChunk<T>> ExactSizeIterator for ChunksMut<'_, T, R> {}
+
+impl<T, R: StoreChunk<T>> FusedIterator for ChunksMut<'_, T, R> {}
+
+/// One chunk of a mutable slice, yielded by [`ChunksMut`].
+///
+/// The chunk holds exactly one register. It can be loaded, and the result of
+/// the computation stored back in place.
+#[derive(Debug)]
+pub struct ChunkMut<'a, T, R> {
+    chunk: &'a mut [T],
+    register: PhantomData<fn() -> R>,
+}
+
+impl<T, R: StoreChunk<T>> ChunkMut<'_, T, R> {
+    /// Loads the register from the chunk.
+    #[inline]
+    pub fn load(&self) -> R {
+        // Safety: `ChunkMut` is only constructed by `ChunksMut`, whose construction requires the
+        // target features.
+        unsafe { R::load(self.chunk) }
+    }
+
+    /// Stores a register to the chunk.
+    #[inline]
+    pub fn store(&mut self, v: R) {
+        // Safety: As in `load`.
+        unsafe { R::store(self.chunk, v) }
+    }
+
+    /// Loads the register, applies `f` and stores the result back to the chunk.
+    #[inline]
+    pub fn update(&mut self, f: impl FnOnce(R) -> R) {
+        let v = f(self.load());
+        self.store(v);
+    }
+
+    /// Returns the elements of the chunk.
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        self.chunk
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{read_partial, write_partial};
+
+    #[test]
+    fn test_read_partial() {
+        let bytes: [u8; 17] = core::array::from_fn(|i| i as u8 + 1);
+
+        for n in 0..=17 {
+            let mut expected = [0u8; 16];
+            let len = n.min(16);
+            expected[..len].copy_from_slice(&bytes[..len]);
+
+            let [lo, hi] = read_partial(&bytes[..n]);
+            assert_eq!(lo.to_le_bytes(), expected[..8], "{n} bytes");
+            assert_eq!(hi.to_le_bytes(), expected[8..], "{n} bytes");
+        }
+    }
+
+    #[test]
+    fn test_write_partial() {
+        let lo = u64::from_le_bytes(core::array::from_fn(|i| i as u8 + 1));
+        let hi = u64::from_le_bytes(core::array::from_fn(|i| i as u8 + 9));
+
+        for n in 0..=17 {
+            let mut bytes = [0xffu8; 17];
+            write_partial(&mut bytes[..n], [lo, hi]);
+
+            for (i, &b) in bytes.iter().enumerate() {
+                let expected = if i < n.min(16) { i as u8 + 1 } else { 0xff };
+                assert_eq!(b, expected, "{n} bytes at {i}");
+            }
+        }
+    }
+}
@@ -0,0 +1,469 @@
+//! Macros defining the capability tokens of the `token` modules.
+//!
+//! A token is a zero-sized value that can only be obtained once its target features are known to
+//! be available, either by runtime detection or because they are enabled at compile time. Holding
+//! one therefore proves that the `#[target_feature]` wrappers of that feature set may be called,
+//! and its methods do so without `unsafe` at the call site.
+
+/// Evaluates to whether the target feature is available on the running CPU.
+///
+/// With the `std` feature this uses the runtime detection macros of `std::arch` where available.
+/// Otherwise only features enabled at compile time are considered.
+macro_rules! detect {
+    ($feature:tt) => {{
+        #[cfg(all(feature = "std", any(target_arch = "x86", target_arch = "x86_64")))]
+        let detected = ::std::arch::is_x86_feature_detected!($feature);
+        #[cfg(all(feature = "std", target_arch = "aarch64"))]
+        let detected = ::std::arch::is_aarch64_feature_detected!($feature);
+        #[cfg(not(all(
+            feature = "std",
+            any(target_arch = "x86", target_arch = "x86_64", target_arch = "aarch64")
+        )))]
+        let detected = cfg!(target_feature = $feature);
+        detected
+    }};
+}
+
+/// Declares a token type for a set of target features.
+///
+/// The feature list must be complete, including the features of the parent token that the new
+/// token dereferences to.
+macro_rules! token {
+    (
+        $(#[$meta:meta])*
+        pub struct $name:ident $(($parent:ident))?: $($feature:tt),+;
+    ) => {
+        $(#[$meta])*
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+        pub struct $name(());
+
+        impl $name {
+            /// Returns the token if the running CPU supports its target features.
+            ///
+            /// Without the `std` feature only target features enabled at compile time are
+            /// detected.
+            #[inline]
+            pub fn try_new() -> Option<Self> {
+                if $(detect!($feature))&&+ {
+                    Some(Self(()))
+                } else {
+                    None
+                }
+            }
+
+            /// Returns the token in builds that enable its target features at compile time.
+            #[inline]
+            #[cfg(all($(target_feature = $feature),+))]
+            pub const fn new() -> Self {
+                Self(())
+            }
+
+            /// Returns the token without checking for the target features.
+            ///
+            /// # Safety
+            ///
+            /// The running CPU must support all target features of the token. This is the case
+            /// within a function that enables them with `#[target_feature]`.
+            #[inline]
+            pub const unsafe fn new_unchecked() -> Self {
+                Self(())
+            }
+        }
+
+        #[cfg(all($(target_feature = $feature),+))]
+        impl Default for $name {
+            #[inline]
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+
+        $(
+            impl ::core::ops::Deref for $name {
+                type Target = $parent;
+
+                #[inline]
+                fn deref(&self) -> &$parent {
+                    // Every feature of the parent is also a feature of this token.
+                    const PARENT: $parent = $parent(());
+                    &PARENT
+                }
+            }
+
+            impl From<$name> for $parent {
+                #[inline]
+                fn from(_: $name) -> $parent {
+                    $parent(())
+                }
+            }
+        )?
+    };
+}
+
+/// Defines the wrappers of a target feature module together with the methods of its token.
+///
+/// Each wrapper is written as a plain function whose documentation is followed by
+/// `#[method = name]`, which names the token method calling it. Deriving both from the same item
+/// keeps the signatures of the wrappers and the methods in sync. Wrappers with a lane index are
+/// declared in a separate invocation, as all their generic parameters are `<const L: usize, T>`.
+#[cfg(any(target_arch = "x86", target_arch = "x86_64", target_arch = "wasm32"))]
+macro_rules! token_wrappers {
+    (
+        impl $token:path;
+
+        $(
+            $(#[doc = $doc:expr])*
+            #[method = $method:ident]
+            $(#[$meta:meta])*
+            pub fn $wrapper:ident<const $lane:ident: usize, $param:ident: $bound:path>
+                ($($arg:ident: $ty:ty),* $(,)?) $(-> $ret:ty)? $body:block
+        )*
+    ) => {
+        token_wrappers! {
+            @emit $token;
+            $(
+                [$(#[doc = $doc])* $(#[$meta])*] $method $wrapper
+                [const $lane: usize, $param: $bound] [$lane, $param]
+                ($($arg: $ty),*) [$($ret)?] $body
+            )*
+        }
+    };
+
+    (
+        impl $token:path;
+
+        $(
+            $(#[doc = $doc:expr])*
+            #[method = $method:ident]
+            $(#[$meta:meta])*
+            pub fn $wrapper:ident $(<$($param:ident: $bound:path),+>)?
+                ($($arg:ident: $ty:ty),* $(,)?) $(-> $ret:ty)? $body:block
+        )*
+    ) => {
+        token_wrappers! {
+            @emit $token;
+            $(
+                [$(#[doc = $doc])* $(#[$meta])*] $method $wrapper
+                [$($($param: $bound),+)?] [$($($param),+)?]
+                ($($arg: $ty),*) [$($ret)?] $body
+            )*
+        }
+    };
+
+    (
+        @emit $token:path;
+        $(
+            [$($attr:tt)*] $method:ident $wrapper:ident [$($generics:tt)*] [$($turbofish:tt)*]
+            ($($arg:ident: $ty:ty),*) [$($ret:ty)?] $body:block
+        )*
+    ) => {
+        $(
+            $($attr)*
+            pub fn $wrapper<$($generics)*>($($arg: $ty),*) $(-> $ret)? $body
+        )*
+
+        impl $token {
+            $(
+                #[doc = concat!(
+                    "Calls [`", stringify!($wrapper), "`] with the target features of the token.",
+                )]
+                #[inline]
+                pub fn $method<$($generics)*>(self, $($arg: $ty),*) $(-> $ret)? {
+                    // Safety: the token exists, so the target features of the wrapper are
+                    // available.
+                    #[allow(unused_unsafe)]
+                    unsafe {
+                        $wrapper::<$($turbofish)*>($($arg),*)
+                    }
+                }
+            )*
+        }
+    };
+}
+
+/// Declares an extension trait of the `ext` modules, implemented for every type of its bound.
+///
+/// Each method is written as `fn method(&self, token: Token, args) -> Ret = wrapper;`, with
+/// `&mut self` for stores, and passes `self` as the memory operand of the wrapper. The token
+/// argument proves that the target features of the wrapper are available.
+macro_rules! ext_trait {
+    (
+        $(#[$meta:meta])*
+        pub trait $name:ident: $bound:ident {
+            $(
+                $(#[$method_meta:meta])*
+                fn $method:ident($($sig:tt)*) $(-> $ret:ty)? = $wrapper:path;
+            )*
+        }
+    ) => {
+        $(#[$meta])*
+        pub trait $name: $bound + Sized {
+            $(
+                ext_trait!(
+                    @method $(#[$method_meta])* fn $method($($sig)*) $(-> $ret)? = $wrapper
+                );
+            )*
+        }
+
+        impl<T: $bound> $name for T {}
+    };
+
+    (
+        @method $(#[$meta:meta])*
+        fn $method:ident(&self, $token:ident: $tok:ty $(, $arg:ident: $ty:ty)*) $(-> $ret:ty)?
+            = $wrapper:path
+    ) => {
+        $(#[$meta])*
+        #[inline]
+        fn $method(&self, $token: $tok $(, $arg: $ty)*) $(-> $ret)? {
+            let _ = $token;
+            // Safety: the token exists, so the target features of the wrapper are available.
+            #[allow(unused_unsafe)]
+            unsafe {
+                $wrapper(self $(, $arg)*)
+            }
+        }
+    };
+
+    (
+        @method $(#[$meta:meta])*
+        fn $method:ident(&mut self, $token:ident: $tok:ty $(, $arg:ident: $ty:ty)*)
+            $(-> $ret:ty)? = $wrapper:path
+    ) => {
+        $(#[$meta])*
+        #[inline]
+        fn $method(&mut self, $token: $tok $(, $arg: $ty)*) $(-> $ret)? {
+            let _ = $token;
+            // Safety: the token exists, so the target features of the wrapper are available.
+            #[allow(unused_unsafe)]
+            unsafe {
+                $wrapper(self $(, $arg)*)
+            }
+        }
+    };
+}
+
+/// Compiles a function body once per capability token and dispatches to the best variant at
+/// runtime.
+///
+/// The body is compiled into a `#[target_feature]` function for each listed token of the current
+/// architecture, where the named binding holds the token. On the first call the tokens are
+/// detected in the listed order, so wider feature sets should come first, and the choice is
+/// cached for later calls. The fallback block runs if no token is available.
+///
+/// The tokens are named by their type, e.g. `Sse`, `Sse2`, `Avx`, `Avx512f`, `Avx512Bw`,
+/// `Avx512Vbmi2`, `Neon` or `Simd128`, and tokens of other architectures are skipped. Each body
+/// must compile for every listed token of an architecture, and bodies listing tokens of several
+/// architectures can access memory through the [`portable`](crate::portable) module. Tokens of wider feature sets
+/// dereference to narrower ones, so a body written against the methods of `Sse2` also compiles
+/// for `Avx`, where it benefits from the VEX encoding. Without the `std` feature only target
+/// features enabled at compile time are detected.
+///
+/// The function may not be generic and its arguments must be plain identifiers.
+///
+/// ```rust
+/// # #[cfg(target_arch = "x86_64")]
+/// # {
+/// use core::arch::x86_64::{_mm_add_epi8, _mm_set1_epi8};
+/// use safe_unaligned_simd::multiversion;
+///
+/// multiversion! {
+///     /// Adds one to every byte.
+///     pub fn add_one(data: &mut [u8; 16]) {
+///         match token {
+///             Avx | Sse2 => {
+///                 let v = token.mm_loadu_si128(data);
+///                 token.mm_storeu_si128(data, _mm_add_epi8(v, _mm_set1_epi8(1)));
+///             }
+///             _ => {
+///                 for byte in data {
+///                     *byte = byte.wrapping_add(1);
+///                 }
+///             }
+///         }
+///     }
+/// }
+///
+/// let mut data = [0u8; 16];
+/// add_one(&mut data);
+/// assert_eq!(data, [1; 16]);
+/// # }
+/// ```
+#[macro_export]
+macro_rules! multiversion {
+    (
+        $(#[$meta:meta])*
+        $vis:vis fn $name:ident($($arg:ident: $ty:ty),* $(,)?) $(-> $ret:ty)? {
+            match $token:ident {
+                $($tok:ident)|+ => $body:block $(,)?
+                _ => $fallback:block $(,)?
+            }
+        }
+    ) => {
+        $(#[$meta])*
+        $vis fn $name($($arg: $ty),*) $(-> $ret)? {
+            static SELECTED: ::core::sync::atomic::AtomicUsize =
+                ::core::sync::atomic::AtomicUsize::new(usize::MAX);
+
+            let selected = SELECTED.load(::core::sync::atomic::Ordering::Relaxed);
+            #[allow(unused_mut)]
+            let mut index = 0;
+            $crate::multiversion!(
+                @variants [$($tok)+] [index selected SELECTED $token]
+                { ($($arg: $ty),*) $(-> $ret)? $body }
+            );
+
+            if selected == usize::MAX {
+                SELECTED.store(index, ::core::sync::atomic::Ordering::Relaxed);
+            }
+            $fallback
+        }
+    };
+
+    (@variants [] $state:tt $signature:tt) => {};
+    (@variants [$tok:ident $($rest:ident)*] $state:tt $signature:tt) => {
+        $crate::multiversion!(@variant $tok $state $signature);
+        $crate::multiversion!(@variants [$($rest)*] $state $signature);
+    };
+
+    // The target features of each token, which must match its `token!` declaration.
+    (@variant Sse $($rest:tt)*) => {
+        $crate::multiversion!(@arch [x86] "sse" Sse $($rest)*);
+    };
+    (@variant Sse2 $($rest:tt)*) => {
+        $crate::multiversion!(@arch [x86] "sse,sse2" Sse2 $($rest)*);
+    };
+    (@variant Avx $($rest:tt)*) => {
+        $crate::multiversion!(@arch [x86] "sse,sse2,avx" Avx $($rest)*);
+    };
+    (@variant Avx512f $($rest:tt)*) => {
+        $crate::multiversion!(@arch [x86] "sse,sse2,avx,avx512f,avx512vl" Avx512f $($rest)*);
+    };
+    (@variant Avx512Bw $($rest:tt)*) => {
+        $crate::multiversion!(
+            @arch [x86] "sse,sse2,avx,avx512f,avx512vl,avx512bw" Avx512Bw $($rest)*
+        );
+    };
+    (@variant Avx512Vbmi2 $($rest:tt)*) => {
+        $crate::multiversion!(
+            @arch [x86] "sse,sse2,avx,avx512f,avx512vl,avx512bw,avx512vbmi2" Avx512Vbmi2
+            $($rest)*
+        );
+    };
+    (@variant Neon $($rest:tt)*) => {
+        $crate::multiversion!(@arch [aarch64] "neon" Neon $($rest)*);
+    };
+    (@variant Simd128 $($rest:tt)*) => {
+        $crate::multiversion!(@arch [wasm32] "simd128" Simd128 $($rest)*);
+    };
+
+    (@arch [x86] $($rest:tt)*) => {
+        $crate::multiversion!(
+            @compile [any(target_arch = "x86", target_arch = "x86_64")] $($rest)*
+        );
+    };
+    (@arch [aarch64] $($rest:tt)*) => {
+        $crate::multiversion!(
+            @compile [any(target_arch = "aarch64", target_arch = "arm64ec")] $($rest)*
+        );
+    };
+    (@arch [wasm32] $($rest:tt)*) => {
+        $crate::multiversion!(@compile [target_arch = "wasm32"] $($rest)*);
+    };
+
+    (
+        @compile [$($cfg:tt)*] $features:tt $tok:ident
+        [$index:ident $selected:ident $cache:ident $token:ident]
+        { ($($arg:ident: $ty:ty),*) $(-> $ret:ty)? $body:block }
+    ) => {
+        #[cfg($($cfg)*)]
+        {
+            #[inline]
+            #[target_feature(enable = $features)]
+            #[allow(unused_variables)]
+            fn variant($($arg: $ty),*) $(-> $ret)? {
+                // Safety: `variant` is only called once the token has been detected.
+                let $token = unsafe { $crate::__private::token::$tok::new_unchecked() };
+                $body
+            }
+
+            if $selected == $index
+                || ($selected == usize::MAX
+                    && $crate::__private::token::$tok::try_new().is_some())
+            {
+                if $selected == usize::MAX {
+                    $cache.store($index, ::core::sync::atomic::Ordering::Relaxed);
+                }
+                // Safety: the token of the variant has been detected, now or on an earlier call.
+                #[allow(unused_unsafe)]
+                return unsafe { variant($($arg),*) };
+            }
+            $index += 1;
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    multiversion! {
+        fn selected_token(a: u32, b: u32) -> (&'static str, u32) {
+            match token {
+                Avx | Sse2 | Neon | Simd128 => {
+                    (core::any::type_name_of_val(&token), a + b)
+                }
+                _ => {
+                    ("fallback", a + b)
+                }
+            }
+        }
+    }
+
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    multiversion! {
+        fn other_architectures(data: &mut [u8; 16]) -> bool {
+            match token {
+                Neon | Simd128 => {
+                    let _ = (token, data);
+                    true
+                }
+                _ => {
+                    data.fill(1);
+                    false
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_multiversion_dispatch() {
+        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+        let expected = if crate::__private::token::Avx::try_new().is_some() {
+            "Avx"
+        } else {
+            "Sse2"
+        };
+        #[cfg(any(target_arch = "aarch64", target_arch = "arm64ec"))]
+        let expected = "Neon";
+        #[cfg(target_arch = "wasm32")]
+        let expected = if cfg!(target_feature = "simd128") {
+            "Simd128"
+        } else {
+            "fallback"
+        };
+
+        // The second call uses the cached selection.
+        for _ in 0..2 {
+            let (name, sum) = selected_token(1, 2);
+            assert!(name.ends_with(expected), "{name}");
+            assert_eq!(sum, 3);
+        }
+    }
+
+    #[test]
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    fn test_multiversion_fallback() {
+        let mut data = [0u8; 16];
+        assert!(!other_architectures(&mut data));
+        assert_eq!(data, [1; 16]);
+    }
+}
@@ -5,6 +5,22 @@
 //!
 //! These traits provide abstractions over the bit-width that these vector
 //! types' load and store intrinsics operate on.
+//!
+//! The `Is*BitsUnaligned` traits are implemented by types for which every bit
+//! pattern is valid, so they can be both loaded from and stored into. The
+//! `Is*BitsReadable` traits are implemented by every such type plus types
+//! that can only be loaded from, such as `[bool; 16]`.
+//!
+//! All impls are written for the const-generic `Unaligned<BYTES>`,
+//! `Readable<BYTES>` and `UnalignedCell<BYTES>` traits, and the fixed-width
+//! traits are blanket-implemented aliases of them.
+//!
+//! Coverage is structural: the lists name element types, and arrays of them
+//! (including nested arrays), `Wrapping`, `Saturating` and `Cell` qualify when
+//! the total size matches, e.g. `[[u8; 4]; 4]` is 128 bits. With the
+//! `nightly` feature the same holds for `core::simd::Simd` vectors. Arrays of
+//! 24 and 48 bytes, the size of three 64-bit or 128-bit registers, implement
+//! `Unaligned<24>` and `Unaligned<48>` without a fixed-width alias.
 // Different targets require different widths and enabling them at a type level dependent on the OS
 // adds a lot of annotations that must all be tested in every combination. They are a property of
 // the types, after all. Still we have some types that are only available per platform.
@@ -13,124 +29,303 @@
 // Internal module for sealing SIMD traits.
 mod private {
     pub trait Sealed {}
+
+    pub trait BitWidth {}
 }
 
+/// A trait that marks a type as valid for unaligned operations on `BYTES` bytes.
+///
+/// This is implemented for exactly the types implementing the fixed-width
+/// traits, e.g. `Unaligned<16>` for the [`Is128BitsUnaligned`] types, which
+/// are aliases of it. Bounding on `Unaligned<BYTES>` allows code that is
+/// generic over the vector width, and a `T: Unaligned<16>` can be passed to
+/// any wrapper that expects `Is128BitsUnaligned`.
+///
+/// ```rust
+/// # #[cfg(target_arch = "x86_64")]
+/// # {
+/// use core::arch::x86_64::{__m128i, __m256i};
+///
+/// use safe_unaligned_simd::{Unaligned, x86_64};
+///
+/// trait Load<const BYTES: usize> {
+///     type Register;
+///
+///     fn load<T: Unaligned<BYTES>>(data: &T) -> Self::Register;
+/// }
+///
+/// struct Sse2;
+/// struct Avx;
+///
+/// impl Load<16> for Sse2 {
+///     type Register = __m128i;
+///
+///     fn load<T: Unaligned<16>>(data: &T) -> __m128i {
+///         assert!(is_x86_feature_detected!("sse2"));
+///         unsafe { x86_64::_mm_loadu_si128(data) }
+///     }
+/// }
+///
+/// impl Load<32> for Avx {
+///     type Register = __m256i;
+///
+///     fn load<T: Unaligned<32>>(data: &T) -> __m256i {
+///         assert!(is_x86_feature_detected!("avx"));
+///         unsafe { x86_64::_mm256_loadu_si256(data) }
+///     }
+/// }
+///
+/// let _ = Sse2::load(&[0u8; 16]);
+/// # }
+/// ```
+pub trait Unaligned<const BYTES: usize>: Readable<BYTES> {}
+
 /// A trait that marks a type as valid for unaligned operations as an [`i8`].
-pub trait Is8BitsUnaligned: private::Sealed {}
+///
+/// This is an alias of [`Unaligned<1>`](Unaligned).
+pub trait Is8BitsUnaligned: Unaligned<1> {}
+impl<T: Unaligned<1>> Is8BitsUnaligned for T {}
 
 /// A trait that marks a type as valid for unaligned operations as an [`i16`].
-pub trait Is16BitsUnaligned: private::Sealed {}
+///
+/// This is an alias of [`Unaligned<2>`](Unaligned).
+pub trait Is16BitsUnaligned: Unaligned<2> {}
+impl<T: Unaligned<2>> Is16BitsUnaligned for T {}
 
 /// A trait that marks a type as valid for unaligned operations as an [`i32`].
-pub trait Is32BitsUnaligned: private::Sealed {}
+///
+/// This is an alias of [`Unaligned<4>`](Unaligned).
+pub trait Is32BitsUnaligned: Unaligned<4> {}
+impl<T: Unaligned<4>> Is32BitsUnaligned for T {}
 
 /// A trait that marks a type as valid for unaligned operations as an [`i64`].
-pub trait Is64BitsUnaligned: private::Sealed {}
+///
+/// This is an alias of [`Unaligned<8>`](Unaligned).
+pub trait Is64BitsUnaligned: Unaligned<8> {}
+impl<T: Unaligned<8>> Is64BitsUnaligned for T {}
 
 /// A trait that marks a type as valid for unaligned operations as a 128-bit
 /// integer vector type such as [`__m128i`][x86] or [`v128`][wasm32].
 ///
+/// This is an alias of [`Unaligned<16>`](Unaligned).
+///
 /// [x86]: https://doc.rust-lang.org/stable/core/arch/x86/struct.__m128i.html
 /// [wasm32]: https://doc.rust-lang.org/stable/core/arch/wasm32/struct.v128.html
-pub trait Is128BitsUnaligned: private::Sealed {}
+pub trait Is128BitsUnaligned: Unaligned<16> {}
+impl<T: Unaligned<16>> Is128BitsUnaligned for T {}
 
 /// A trait that marks a type as valid for unaligned operations as a 256-bit
 /// integer vector type such as [`__m256i`][x86].
 ///
+/// This is an alias of [`Unaligned<32>`](Unaligned).
+///
 /// [x86]: https://doc.rust-lang.org/stable/core/arch/x86/struct.__m256i.html
-pub trait Is256BitsUnaligned: private::Sealed {}
+pub trait Is256BitsUnaligned: Unaligned<32> {}
+impl<T: Unaligned<32>> Is256BitsUnaligned for T {}
 
 /// A trait that marks a type as valid for unaligned operations as a 512-bit
 /// integer vector type such as [`__m512i`][x86].
 ///
+/// This is an alias of [`Unaligned<64>`](Unaligned).
+///
 /// [x86]: https://doc.rust-lang.org/stable/core/arch/x86/struct.__m512i.html
-pub trait Is512BitsUnaligned: private::Sealed {}
+pub trait Is512BitsUnaligned: Unaligned<64> {}
+impl<T: Unaligned<64>> Is512BitsUnaligned for T {}
+
+//////////////////////////////
+// Start of readable traits //
+//////////////////////////////
+
+/// A trait that marks a type as valid for unaligned loads on `BYTES` bytes.
+///
+/// Every type implementing [`Unaligned<BYTES>`](Unaligned) is readable.
+/// Additionally, types for which not every bit pattern is valid, such as
+/// `bool`, `char` or `NonZero` integers, can be read from but not stored into.
+/// The `Is*BitsReadable` traits are aliases of it.
+pub trait Readable<const BYTES: usize>: private::Sealed {}
+
+/// A trait that marks a type as valid for unaligned loads as an [`i8`].
+///
+/// Every type implementing [`Is8BitsUnaligned`] is readable. Additionally,
+/// types for which not every bit pattern is valid, such as `bool`, `char` or
+/// `NonZero` integers, can be read from but not stored into.
+///
+/// This is an alias of [`Readable<1>`](Readable).
+pub trait Is8BitsReadable: Readable<1> {}
+impl<T: Readable<1>> Is8BitsReadable for T {}
+
+/// A trait that marks a type as valid for unaligned loads as an [`i16`].
+///
+/// Every type implementing [`Is16BitsUnaligned`] is readable. Additionally,
+/// types for which not every bit pattern is valid, such as `bool`, `char` or
+/// `NonZero` integers, can be read from but not stored into.
+///
+/// This is an alias of [`Readable<2>`](Readable).
+pub trait Is16BitsReadable: Readable<2> {}
+impl<T: Readable<2>> Is16BitsReadable for T {}
+
+/// A trait that marks a type as valid for unaligned loads as an [`i32`].
+///
+/// Every type implementing [`Is32BitsUnaligned`] is readable. Additionally,
+/// types for which not every bit pattern is valid, such as `bool`, `char` or
+/// `NonZero` integers, can be read from but not stored into.
+///
+/// This is an alias of [`Readable<4>`](Readable).
+pub trait Is32BitsReadable: Readable<4> {}
+impl<T: Readable<4>> Is32BitsReadable for T {}
+
+/// A trait that marks a type as valid for unaligned loads as an [`i64`].
+///
+/// Every type implementing [`Is64BitsUnaligned`] is readable. Additionally,
+/// types for which not every bit pattern is valid, such as `bool`, `char` or
+/// `NonZero` integers, can be read from but not stored into.
+///
+/// This is an alias of [`Readable<8>`](Readable).
+pub trait Is64BitsReadable: Readable<8> {}
+impl<T: Readable<8>> Is64BitsReadable for T {}
+
+/// A trait that marks a type as valid for unaligned loads as a 128-bit vector.
+///
+/// Every type implementing [`Is128BitsUnaligned`] is readable. Additionally,
+/// types for which not every bit pattern is valid, such as `bool`, `char` or
+/// `NonZero` integers, can be read from but not stored into.
+///
+/// This is an alias of [`Readable<16>`](Readable).
+pub trait Is128BitsReadable: Readable<16> {}
+impl<T: Readable<16>> Is128BitsReadable for T {}
+
+/// A trait that marks a type as valid for unaligned loads as a 256-bit vector.
+///
+/// Every type implementing [`Is256BitsUnaligned`] is readable. Additionally,
+/// types for which not every bit pattern is valid, such as `bool`, `char` or
+/// `NonZero` integers, can be read from but not stored into.
+///
+/// This is an alias of [`Readable<32>`](Readable).
+pub trait Is256BitsReadable: Readable<32> {}
+impl<T: Readable<32>> Is256BitsReadable for T {}
+
+/// A trait that marks a type as valid for unaligned loads as a 512-bit vector.
+///
+/// Every type implementing [`Is512BitsUnaligned`] is readable. Additionally,
+/// types for which not every bit pattern is valid, such as `bool`, `char` or
+/// `NonZero` integers, can be read from but not stored into.
+///
+/// This is an alias of [`Readable<64>`](Readable).
+pub trait Is512BitsReadable: Readable<64> {}
+impl<T: Readable<64>> Is512BitsReadable for T {}
 
 ////////////////////////////
 // Start of `Cell` traits //
 ////////////////////////////
 
-impl<T, const N: usize> private::Sealed for [core::cell::Cell<T>; N] where [T; N]: private::Sealed {}
-impl<T, const N: usize> private::Sealed for core::cell::Cell<[T; N]> where [T; N]: private::Sealed {}
+impl<T: private::Sealed> private::Sealed for core::cell::Cell<T> {}
+
+/// A trait that marks a cell-like type as valid for unaligned operations on
+/// `BYTES` bytes.
+///
+/// This is implemented for `Cell<T>` and `[Cell<E>; N]` where `T` or `[E; N]`
+/// implement [`Unaligned<BYTES>`](Unaligned). The `Is*CellUnaligned` traits are
+/// aliases of it.
+pub trait UnalignedCell<const BYTES: usize>: private::Sealed {}
+
+impl<T: private::Sealed, const N: usize, const BYTES: usize> UnalignedCell<BYTES>
+    for [core::cell::Cell<T>; N]
+where
+    [T; N]: Unaligned<BYTES>,
+{
+}
+impl<T: Unaligned<BYTES>, const BYTES: usize> UnalignedCell<BYTES> for core::cell::Cell<T> {}
 
 /// A trait that marks a cell-like type as valid for unaligned operations as an
 /// [`i8`].
-pub trait Is8CellUnaligned: private::Sealed {}
-
-impl<T, const N: usize> Is8CellUnaligned for [core::cell::Cell<T>; N] where [T; N]: Is8BitsUnaligned {}
-impl<T, const N: usize> Is8CellUnaligned for core::cell::Cell<[T; N]> where [T; N]: Is8BitsUnaligned {}
+///
+/// This is an alias of [`UnalignedCell<1>`](UnalignedCell).
+pub trait Is8CellUnaligned: UnalignedCell<1> {}
+impl<T: UnalignedCell<1>> Is8CellUnaligned for T {}
 
 /// A trait that marks a cell-like type as valid for unaligned operations as an
 /// [`i16`].
-pub trait Is16CellUnaligned: private::Sealed {}
-
-impl<T, const N: usize> Is16CellUnaligned for [core::cell::Cell<T>; N] where
-    [T; N]: Is16BitsUnaligned
-{
-}
-impl<T, const N: usize> Is16CellUnaligned for core::cell::Cell<[T; N]> where
-    [T; N]: Is16BitsUnaligned
-{
-}
+///
+/// This is an alias of [`UnalignedCell<2>`](UnalignedCell).
+pub trait Is16CellUnaligned: UnalignedCell<2> {}
+impl<T: UnalignedCell<2>> Is16CellUnaligned for T {}
 
 /// A trait that marks a cell-like type as valid for unaligned operations as an
 /// [`i32`].
-pub trait Is32CellUnaligned: private::Sealed {}
-
-impl<T, const N: usize> Is32CellUnaligned for [core::cell::Cell<T>; N] where
-    [T; N]: Is32BitsUnaligned
-{
-}
-impl<T, const N: usize> Is32CellUnaligned for core::cell::Cell<[T; N]> where
-    [T; N]: Is32BitsUnaligned
-{
-}
+///
+/// This is an alias of [`UnalignedCell<4>`](UnalignedCell).
+pub trait Is32CellUnaligned: UnalignedCell<4> {}
+impl<T: UnalignedCell<4>> Is32CellUnaligned for T {}
 
 /// A trait that marks a cell-like type as valid for unaligned operations as an
 /// [`i64`].
-pub trait Is64CellUnaligned: private::Sealed {}
-
-impl<T, const N: usize> Is64CellUnaligned for [core::cell::Cell<T>; N] where
-    [T; N]: Is64BitsUnaligned
-{
-}
-impl<T, const N: usize> Is64CellUnaligned for core::cell::Cell<[T; N]> where
-    [T; N]: Is64BitsUnaligned
-{
-}
+///
+/// This is an alias of [`UnalignedCell<8>`](UnalignedCell).
+pub trait Is64CellUnaligned: UnalignedCell<8> {}
+impl<T: UnalignedCell<8>> Is64CellUnaligned for T {}
 
 /// A trait that marks a cell-like type as valid for unaligned operations as a
 /// 128-bit integer vector type such as [`__m128i`][x86] or [`v128`][wasm32].
 ///
+/// This is an alias of [`UnalignedCell<16>`](UnalignedCell).
+///
 /// [x86]: https://doc.rust-lang.org/stable/core/arch/x86/struct.__m128i.html
 /// [wasm32]: https://doc.rust-lang.org/stable/core/arch/wasm32/struct.v128.html
-pub trait Is128CellUnaligned: private::Sealed {}
-
-impl<T, const N: usize> Is128CellUnaligned for [core::cell::Cell<T>; N] where
-    [T; N]: Is128BitsUnaligned
-{
-}
-impl<T, const N: usize> Is128CellUnaligned for core::cell::Cell<[T; N]> where
-    [T; N]: Is128BitsUnaligned
-{
-}
+pub trait Is128CellUnaligned: UnalignedCell<16> {}
+impl<T: UnalignedCell<16>> Is128CellUnaligned for T {}
 
 /// A trait that marks a cell-like type as valid for unaligned operations as a
 /// 256-bit integer vector type such as [`__m256i`][x86].
 ///
+/// This is an alias of [`UnalignedCell<32>`](UnalignedCell).
+///
 /// [x86]: https://doc.rust-lang.org/stable/core/arch/x86/struct.__m256i.html
-pub trait Is256CellUnaligned: private::Sealed {}
+pub trait Is256CellUnaligned: UnalignedCell<32> {}
+impl<T: UnalignedCell<32>> Is256CellUnaligned for T {}
 
-impl<T, const N: usize> Is256CellUnaligned for [core::cell::Cell<T>; N] where
-    [T; N]: Is256BitsUnaligned
-{
-}
-impl<T, const N: usize> Is256CellUnaligned for core::cell::Cell<[T; N]> where
-    [T; N]: Is256BitsUnaligned
-{
-}
+///////////////////////////////////
+// Start of slice element traits //
+///////////////////////////////////
+
+/// A trait that marks a type as valid for unaligned loads from a slice of it.
+///
+/// Slices of these types can be read as bytes: they are initialized and have
+/// no padding. This covers every type implementing one of the
+/// [`Readable`] traits, arrays of them, `Wrapping` and `Saturating`.
+pub trait ReadableElement: private::Sealed {}
+
+/// A trait that marks a type as valid for unaligned stores into a slice of it.
+///
+/// Every bit pattern is a valid value of these types, as for the types
+/// implementing one of the [`Unaligned`] traits.
+pub trait UnalignedElement: ReadableElement {}
+
+impl<T: ReadableElement, const N: usize> ReadableElement for [T; N] {}
+impl<T: UnalignedElement, const N: usize> UnalignedElement for [T; N] {}
+impl<T: ReadableElement> ReadableElement for core::num::Wrapping<T> {}
+impl<T: UnalignedElement> UnalignedElement for core::num::Wrapping<T> {}
+impl<T: ReadableElement> ReadableElement for core::num::Saturating<T> {}
+impl<T: UnalignedElement> UnalignedElement for core::num::Saturating<T> {}
 
 macro_rules! impl_N_bits_traits {
+    (
+        impl $trait:path, $readable:path [$target:ty] for {
+            $($(#[$cfg_attr:meta])* $source:ty,)*
+        }
+    ) => {
+        impl_N_bits_traits! {
+            impl $trait [$target] for {
+                $($(#[$cfg_attr])* $source,)*
+            }
+        }
+
+        $(
+            $(#[$cfg_attr])*
+            impl $readable for $source {}
+            $(#[$cfg_attr])*
+            impl UnalignedElement for $source {}
+        )*
+    };
     (
         impl $trait:path [$target:ty] for {
             $($(#[$cfg_attr:meta])* $source:ty,)*
@@ -145,106 +340,79 @@ macro_rules! impl_N_bits_traits {
             impl private::Sealed for $source {}
             $(#[$cfg_attr])*
             impl $trait for $source {}
+            $(#[$cfg_attr])*
+            impl ReadableElement for $source {}
         )*
     };
 }
 
 impl_N_bits_traits! {
-    impl Is8BitsUnaligned [i8] for {
-        [u8; 1],
-        [i8; 1],
+    impl Unaligned<1>, Readable<1> [i8] for {
         u8,
         i8,
+        Option<core::num::NonZero<u8>>,
+        Option<core::num::NonZero<i8>>,
     }
 }
 
 impl_N_bits_traits! {
-    impl Is8CellUnaligned [i8] for {
-        core::cell::Cell<u8>,
-        core::cell::Cell<i8>,
-    }
-}
-
-impl_N_bits_traits! {
-    impl Is16BitsUnaligned [i16] for {
-        [u8; 2],
-        [i8; 2],
-        [u16; 1],
-        [i16; 1],
+    impl Unaligned<2>, Readable<2> [i16] for {
         u16,
         i16,
+        #[cfg(feature = "half")] half::f16,
+        #[cfg(feature = "half")] half::bf16,
+        Option<core::num::NonZero<u16>>,
+        Option<core::num::NonZero<i16>>,
     }
 }
 
 impl_N_bits_traits! {
-    impl Is16CellUnaligned [i16] for {
-        core::cell::Cell<u16>,
-        core::cell::Cell<i16>,
-    }
-}
-
-impl_N_bits_traits! {
-    impl Is32BitsUnaligned [i32] for {
-        [u8; 4],
-        [i8; 4],
-        [u16; 2],
-        [i16; 2],
-        [u32; 1],
-        [i32; 1],
-        [f32; 1],
+    impl Unaligned<4>, Readable<4> [i32] for {
         u32,
         i32,
         f32,
+        Option<core::num::NonZero<u32>>,
+        Option<core::num::NonZero<i32>>,
     }
 }
 
 impl_N_bits_traits! {
-    impl Is32CellUnaligned [i32] for {
-        core::cell::Cell<u32>,
-        core::cell::Cell<i32>,
-        core::cell::Cell<f32>,
-    }
-}
-
-impl_N_bits_traits! {
-    impl Is64BitsUnaligned [i64] for {
-        [u8; 8],
-        [i8; 8],
-        [u16; 4],
-        [i16; 4],
-        [u32; 2],
-        [i32; 2],
-        [f32; 2],
-        [u64; 1],
-        [i64; 1],
-        [f64; 1],
+    impl Unaligned<8>, Readable<8> [i64] for {
         u64,
         i64,
         f64,
+        #[cfg(feature = "num-complex")] num_complex::Complex<f32>,
+        Option<core::num::NonZero<u64>>,
+        Option<core::num::NonZero<i64>>,
+        #[cfg(any(target_arch = "aarch64", target_arch = "arm64ec"))] core::arch::aarch64::uint8x8_t,
+        #[cfg(any(target_arch = "aarch64", target_arch = "arm64ec"))] core::arch::aarch64::int8x8_t,
+        #[cfg(any(target_arch = "aarch64", target_arch = "arm64ec"))] core::arch::aarch64::uint16x4_t,
+        #[cfg(any(target_arch = "aarch64", target_arch = "arm64ec"))] core::arch::aarch64::int16x4_t,
+        #[cfg(any(target_arch = "aarch64", target_arch = "arm64ec"))] core::arch::aarch64::uint32x2_t,
+        #[cfg(any(target_arch = "aarch64", target_arch = "arm64ec"))] core::arch::aarch64::int32x2_t,
+        #[cfg(any(target_arch = "aarch64", target_arch = "arm64ec"))] core::arch::aarch64::float32x2_t,
+        #[cfg(any(target_arch = "aarch64", target_arch = "arm64ec"))] core::arch::aarch64::uint64x1_t,
+        #[cfg(any(target_arch = "aarch64", target_arch = "arm64ec"))] core::arch::aarch64::int64x1_t,
+        #[cfg(any(target_arch = "aarch64", target_arch = "arm64ec"))] core::arch::aarch64::float64x1_t,
     }
 }
 
 impl_N_bits_traits! {
-    impl Is64CellUnaligned [i64] for {
-        core::cell::Cell<u64>,
-        core::cell::Cell<i64>,
-        core::cell::Cell<f64>,
-    }
-}
-
-impl_N_bits_traits! {
-    impl Is128BitsUnaligned [i128] for {
-        [u8; 16],
-        [i8; 16],
-        [u16; 8],
-        [i16; 8],
-        [u32; 4],
-        [i32; 4],
-        [f32; 4],
-        [u64; 2],
-        [i64; 2],
-        [f64; 2],
+    impl Unaligned<16>, Readable<16> [i128] for {
+        u128,
+        i128,
+        #[cfg(feature = "num-complex")] num_complex::Complex<f64>,
         #[cfg(target_arch = "wasm32")] core::arch::wasm32::v128,
+        #[cfg(any(target_arch = "aarch64", target_arch = "arm64ec"))] core::arch::aarch64::uint8x16_t,
+        #[cfg(any(target_arch = "aarch64", target_arch = "arm64ec"))] core::arch::aarch64::int8x16_t,
+        #[cfg(any(target_arch = "aarch64", target_arch = "arm64ec"))] core::arch::aarch64::uint16x8_t,
+        #[cfg(any(target_arch = "aarch64", target_arch = "arm64ec"))] core::arch::aarch64::int16x8_t,
+        #[cfg(any(target_arch = "aarch64", target_arch = "arm64ec"))] core::arch::aarch64::uint32x4_t,
+        #[cfg(any(target_arch = "aarch64", target_arch = "arm64ec"))] core::arch::aarch64::int32x4_t,
+        #[cfg(any(target_arch = "aarch64", target_arch = "arm64ec"))] core::arch::aarch64::float32x4_t,
+        #[cfg(any(target_arch = "aarch64", target_arch = "arm64ec"))] core::arch::aarch64::uint64x2_t,
+        #[cfg(any(target_arch = "aarch64", target_arch = "arm64ec"))] core::arch::aarch64::int64x2_t,
+        #[cfg(any(target_arch = "aarch64", target_arch = "arm64ec"))] core::arch::aarch64::float64x2_t,
         #[cfg(target_arch = "x86")] core::arch::x86::__m128,
         #[cfg(target_arch = "x86")] core::arch::x86::__m128d,
         #[cfg(target_arch = "x86")] core::arch::x86::__m128i,
@@ -255,23 +423,7 @@ impl_N_bits_traits! {
 }
 
 impl_N_bits_traits! {
-    impl Is256BitsUnaligned [[i128; 2]] for {
-        [u8; 32],
-        [i8; 32],
-        [u16; 16],
-        [i16; 16],
-        [u32; 8],
-        [i32; 8],
-        [f32; 8],
-        [u64; 4],
-        [i64; 4],
-        [f64; 4],
-        #[cfg(target_arch = "x86")] [core::arch::x86::__m128; 2],
-        #[cfg(target_arch = "x86")] [core::arch::x86::__m128d; 2],
-        #[cfg(target_arch = "x86")] [core::arch::x86::__m128i; 2],
-        #[cfg(target_arch = "x86_64")] [core::arch::x86_64::__m128; 2],
-        #[cfg(target_arch = "x86_64")] [core::arch::x86_64::__m128d; 2],
-        #[cfg(target_arch = "x86_64")] [core::arch::x86_64::__m128i; 2],
+    impl Unaligned<32>, Readable<32> [[i128; 2]] for {
         #[cfg(target_arch = "x86")] core::arch::x86::__m256,
         #[cfg(target_arch = "x86")] core::arch::x86::__m256d,
         #[cfg(target_arch = "x86")] core::arch::x86::__m256i,
@@ -282,29 +434,7 @@ impl_N_bits_traits! {
 }
 
 impl_N_bits_traits! {
-    impl Is512BitsUnaligned [[i128; 4]] for {
-        [u8; 64],
-        [i8; 64],
-        [u16; 32],
-        [i16; 32],
-        [u32; 16],
-        [i32; 16],
-        [f32; 16],
-        [u64; 8],
-        [i64; 8],
-        [f64; 8],
-        #[cfg(target_arch = "x86")] [core::arch::x86::__m128; 4],
-        #[cfg(target_arch = "x86")] [core::arch::x86::__m128d; 4],
-        #[cfg(target_arch = "x86")] [core::arch::x86::__m128i; 4],
-        #[cfg(target_arch = "x86_64")] [core::arch::x86_64::__m128; 4],
-        #[cfg(target_arch = "x86_64")] [core::arch::x86_64::__m128d; 4],
-        #[cfg(target_arch = "x86_64")] [core::arch::x86_64::__m128i; 4],
-        #[cfg(target_arch = "x86")] [core::arch::x86::__m256; 2],
-        #[cfg(target_arch = "x86")] [core::arch::x86::__m256d; 2],
-        #[cfg(target_arch = "x86")] [core::arch::x86::__m256i; 2],
-        #[cfg(target_arch = "x86_64")] [core::arch::x86_64::__m256; 2],
-        #[cfg(target_arch = "x86_64")] [core::arch::x86_64::__m256d; 2],
-        #[cfg(target_arch = "x86_64")] [core::arch::x86_64::__m256i; 2],
+    impl Unaligned<64>, Readable<64> [[i128; 4]] for {
         #[cfg(target_arch = "x86")] core::arch::x86::__m512,
         #[cfg(target_arch = "x86")] core::arch::x86::__m512d,
         #[cfg(target_arch = "x86")] core::arch::x86::__m512i,
@@ -314,6 +444,459 @@ impl_N_bits_traits! {
     }
 }
 
+// Types with invalid bit patterns only implement the readable traits.
+impl_N_bits_traits! {
+    impl Readable<1> [i8] for {
+        bool,
+        core::num::NonZero<u8>,
+        core::num::NonZero<i8>,
+        core::cmp::Ordering,
+    }
+}
+
+impl_N_bits_traits! {
+    impl Readable<2> [i16] for {
+        core::num::NonZero<u16>,
+        core::num::NonZero<i16>,
+    }
+}
+
+impl_N_bits_traits! {
+    impl Readable<4> [i32] for {
+        char,
+        core::num::NonZero<u32>,
+        core::num::NonZero<i32>,
+    }
+}
+
+impl_N_bits_traits! {
+    impl Readable<8> [i64] for {
+        core::num::NonZero<u64>,
+        core::num::NonZero<i64>,
+    }
+}
+
+///////////////////////////////
+// Start of structural impls //
+///////////////////////////////
+
+// The lists above only contain element types. Arrays of any element, including nested arrays, and
+// the transparent `Wrapping` / `Saturating` wrappers qualify through these impls when their total
+// size matches.
+impl<T: private::Sealed, const N: usize> private::Sealed for [T; N] {}
+impl<T: private::Sealed> private::Sealed for core::num::Wrapping<T> {}
+impl<T: private::Sealed> private::Sealed for core::num::Saturating<T> {}
+
+// Vectors of `core::simd` have the size of the array of their lanes for the power-of-two lane
+// counts listed below.
+#[cfg(feature = "nightly")]
+impl<T: private::Sealed + core::simd::SimdElement, const N: usize> private::Sealed
+    for core::simd::Simd<T, N>
+{
+}
+
+impl<T: Unaligned<BYTES>, const BYTES: usize> Unaligned<BYTES> for core::num::Wrapping<T> {}
+impl<T: Unaligned<BYTES>, const BYTES: usize> Unaligned<BYTES> for core::num::Saturating<T> {}
+impl<T: Readable<BYTES>, const BYTES: usize> Readable<BYTES> for core::num::Wrapping<T> {}
+impl<T: Readable<BYTES>, const BYTES: usize> Readable<BYTES> for core::num::Saturating<T> {}
+
+macro_rules! impl_N_bits_structural {
+    (
+        impl $trait:ident<$bytes:literal> for {
+            $([$elem:ident<$elem_bytes:literal>; $n:literal],)*
+        }
+    ) => {
+        $(
+            impl<T: $elem<$elem_bytes>> $trait<$bytes> for [T; $n] {}
+            #[cfg(feature = "nightly")]
+            impl<T: $elem<$elem_bytes> + core::simd::SimdElement> $trait<$bytes>
+                for core::simd::Simd<T, $n>
+            {
+            }
+        )*
+    };
+    // Sizes of multiple registers, such as the 48 bytes of `float32x4x3_t`, only cover arrays.
+    // Vectors of three lanes are padded to the next power of two.
+    (
+        impl $trait:ident<$bytes:literal> for arrays {
+            $([$elem:ident<$elem_bytes:literal>; $n:literal],)*
+        }
+    ) => {
+        $(
+            impl<T: $elem<$elem_bytes>> $trait<$bytes> for [T; $n] {}
+        )*
+    };
+}
+
+impl_N_bits_structural! {
+    impl Unaligned<1> for {
+        [Unaligned<1>; 1],
+    }
+}
+
+impl_N_bits_structural! {
+    impl Unaligned<2> for {
+        [Unaligned<1>; 2],
+        [Unaligned<2>; 1],
+    }
+}
+
+impl_N_bits_structural! {
+    impl Unaligned<4> for {
+        [Unaligned<1>; 4],
+        [Unaligned<2>; 2],
+        [Unaligned<4>; 1],
+    }
+}
+
+impl_N_bits_structural! {
+    impl Unaligned<8> for {
+        [Unaligned<1>; 8],
+        [Unaligned<2>; 4],
+        [Unaligned<4>; 2],
+        [Unaligned<8>; 1],
+    }
+}
+
+impl_N_bits_structural! {
+    impl Unaligned<16> for {
+        [Unaligned<1>; 16],
+        [Unaligned<2>; 8],
+        [Unaligned<4>; 4],
+        [Unaligned<8>; 2],
+        [Unaligned<16>; 1],
+    }
+}
+
+impl_N_bits_structural! {
+    impl Unaligned<24> for arrays {
+        [Unaligned<1>; 24],
+        [Unaligned<2>; 12],
+        [Unaligned<4>; 6],
+        [Unaligned<8>; 3],
+        [Unaligned<24>; 1],
+    }
+}
+
+impl_N_bits_structural! {
+    impl Unaligned<32> for {
+        [Unaligned<1>; 32],
+        [Unaligned<2>; 16],
+        [Unaligned<4>; 8],
+        [Unaligned<8>; 4],
+        [Unaligned<16>; 2],
+        [Unaligned<32>; 1],
+    }
+}
+
+impl_N_bits_structural! {
+    impl Unaligned<48> for arrays {
+        [Unaligned<1>; 48],
+        [Unaligned<2>; 24],
+        [Unaligned<4>; 12],
+        [Unaligned<8>; 6],
+        [Unaligned<16>; 3],
+        [Unaligned<48>; 1],
+    }
+}
+
+impl_N_bits_structural! {
+    impl Unaligned<64> for {
+        [Unaligned<1>; 64],
+        [Unaligned<2>; 32],
+        [Unaligned<4>; 16],
+        [Unaligned<8>; 8],
+        [Unaligned<16>; 4],
+        [Unaligned<32>; 2],
+        [Unaligned<64>; 1],
+    }
+}
+
+impl_N_bits_structural! {
+    impl Readable<1> for {
+        [Readable<1>; 1],
+    }
+}
+
+impl_N_bits_structural! {
+    impl Readable<2> for {
+        [Readable<1>; 2],
+        [Readable<2>; 1],
+    }
+}
+
+impl_N_bits_structural! {
+    impl Readable<4> for {
+        [Readable<1>; 4],
+        [Readable<2>; 2],
+        [Readable<4>; 1],
+    }
+}
+
+impl_N_bits_structural! {
+    impl Readable<8> for {
+        [Readable<1>; 8],
+        [Readable<2>; 4],
+        [Readable<4>; 2],
+        [Readable<8>; 1],
+    }
+}
+
+impl_N_bits_structural! {
+    impl Readable<16> for {
+        [Readable<1>; 16],
+        [Readable<2>; 8],
+        [Readable<4>; 4],
+        [Readable<8>; 2],
+        [Readable<16>; 1],
+    }
+}
+
+impl_N_bits_structural! {
+    impl Readable<24> for arrays {
+        [Readable<1>; 24],
+        [Readable<2>; 12],
+        [Readable<4>; 6],
+        [Readable<8>; 3],
+        [Readable<24>; 1],
+    }
+}
+
+impl_N_bits_structural! {
+    impl Readable<32> for {
+        [Readable<1>; 32],
+        [Readable<2>; 16],
+        [Readable<4>; 8],
+        [Readable<8>; 4],
+        [Readable<16>; 2],
+        [Readable<32>; 1],
+    }
+}
+
+impl_N_bits_structural! {
+    impl Readable<48> for arrays {
+        [Readable<1>; 48],
+        [Readable<2>; 24],
+        [Readable<4>; 12],
+        [Readable<8>; 6],
+        [Readable<16>; 3],
+        [Readable<48>; 1],
+    }
+}
+
+impl_N_bits_structural! {
+    impl Readable<64> for {
+        [Readable<1>; 64],
+        [Readable<2>; 32],
+        [Readable<4>; 16],
+        [Readable<8>; 8],
+        [Readable<16>; 4],
+        [Readable<32>; 2],
+        [Readable<64>; 1],
+    }
+}
+
+///////////////////////////////
+// Start of user type traits //
+///////////////////////////////
+
+/// A trait for user types that are valid for unaligned operations as
+/// [`Self::Bits`](UnalignedBits::Bits).
+///
+/// Implementing this trait for `T` implements the matching `Is*BitsUnaligned`
+/// trait for `T` and for arrays of `T`, e.g. `Is128BitsUnaligned` for
+/// `[Rgba; 4]` when `Rgba` is 32 bits wide, as well as the `Is*CellUnaligned`
+/// traits for the corresponding `Cell` types.
+///
+/// Prefer the [`impl_unaligned_bits!`](crate::impl_unaligned_bits) macro,
+/// which checks these requirements at compile time.
+///
+/// # Safety
+///
+/// - `size_of::<Self>()` must equal `size_of::<Self::Bits>()`.
+/// - `Self` must not contain padding bytes.
+/// - Every bit pattern must be a valid value of `Self`.
+/// - `Self` must not contain any `UnsafeCell`.
+pub unsafe trait UnalignedBits: Copy + 'static {
+    /// The integer type with the same size as `Self`, one of `i8`, `i16`,
+    /// `i32`, `i64`, `i128`, `[i128; 2]` or `[i128; 4]`.
+    type Bits: private::BitWidth;
+}
+
+/// A trait for user types that are valid for unaligned loads as
+/// [`Self::Bits`](ReadableBits::Bits).
+///
+/// This is the load-only counterpart of [`UnalignedBits`], for types such as
+/// fieldless enums for which not every bit pattern is valid. Implementing it
+/// for `T` implements the matching `Is*BitsReadable` trait for `T` and for
+/// arrays of `T`. Every [`UnalignedBits`] type implements it.
+///
+/// # Safety
+///
+/// - `size_of::<Self>()` must equal `size_of::<Self::Bits>()`.
+/// - `Self` must not contain padding bytes.
+/// - `Self` must not contain any `UnsafeCell`.
+pub unsafe trait ReadableBits: Copy + 'static {
+    /// The integer type with the same size as `Self`, see
+    /// [`UnalignedBits::Bits`].
+    type Bits: private::BitWidth;
+}
+
+// SAFETY: The requirements of `ReadableBits` are a subset of those of `UnalignedBits`.
+unsafe impl<T: UnalignedBits> ReadableBits for T {
+    type Bits = <T as UnalignedBits>::Bits;
+}
+
+impl private::BitWidth for i8 {}
+impl private::BitWidth for i16 {}
+impl private::BitWidth for i32 {}
+impl private::BitWidth for i64 {}
+impl private::BitWidth for i128 {}
+impl private::BitWidth for [i128; 2] {}
+impl private::BitWidth for [i128; 4] {}
+
+impl<T: ReadableBits> private::Sealed for T {}
+impl<T: ReadableBits> ReadableElement for T {}
+impl<T: UnalignedBits> UnalignedElement for T {}
+
+macro_rules! impl_user_bits_traits {
+    ($(impl $trait:ty, $readable:ty [$bits:ty];)*) => {
+        $(
+            impl<T: UnalignedBits<Bits = $bits>> $trait for T {}
+            impl<T: ReadableBits<Bits = $bits>> $readable for T {}
+        )*
+    };
+}
+
+impl_user_bits_traits! {
+    impl Unaligned<1>, Readable<1> [i8];
+    impl Unaligned<2>, Readable<2> [i16];
+    impl Unaligned<4>, Readable<4> [i32];
+    impl Unaligned<8>, Readable<8> [i64];
+    impl Unaligned<16>, Readable<16> [i128];
+    impl Unaligned<32>, Readable<32> [[i128; 2]];
+    impl Unaligned<64>, Readable<64> [[i128; 4]];
+}
+
+/// Types that are valid for any bit pattern, allowed as fields of types passed
+/// to [`impl_unaligned_bits!`](crate::impl_unaligned_bits).
+///
+/// # Safety
+///
+/// Every bit pattern must be a valid value of `Self` and `Self` must not
+/// contain padding bytes or any `UnsafeCell`.
+#[doc(hidden)]
+pub unsafe trait AnyBitPattern: Copy + 'static {}
+
+macro_rules! impl_any_bit_pattern {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            // SAFETY: Primitive integers and floats are valid for any bit pattern.
+            unsafe impl AnyBitPattern for $ty {}
+        )*
+    };
+}
+
+impl_any_bit_pattern!(
+    u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, usize, isize, f32, f64
+);
+
+// SAFETY: Arrays have no padding between elements and inherit the validity of `T`.
+unsafe impl<T: AnyBitPattern, const N: usize> AnyBitPattern for [T; N] {}
+// SAFETY: Guaranteed by the safety contract of `UnalignedBits`.
+unsafe impl<T: UnalignedBits> AnyBitPattern for T {}
+
+/// Used by [`impl_unaligned_bits!`](crate::impl_unaligned_bits) to require
+/// that each field type is valid for any bit pattern. Never called.
+#[doc(hidden)]
+pub fn field<T: AnyBitPattern>() -> T {
+    unreachable!()
+}
+
+/// Implements [`UnalignedBits`] for a struct after checking at compile time
+/// that its fields cover it without padding and are valid for any bit pattern.
+///
+/// All fields must be listed with their types, using `0`, `1`, ... as field
+/// names for tuple structs. The size of the struct must match `Bits`, which is
+/// one of `i8`, `i16`, `i32`, `i64`, `i128`, `[i128; 2]` or `[i128; 4]`.
+///
+/// ```rust
+/// use safe_unaligned_simd::impl_unaligned_bits;
+///
+/// #[derive(Clone, Copy)]
+/// #[repr(C)]
+/// struct Rgba {
+///     r: u8,
+///     g: u8,
+///     b: u8,
+///     a: u8,
+/// }
+///
+/// #[derive(Clone, Copy)]
+/// #[repr(transparent)]
+/// struct Sample(f32);
+///
+/// impl_unaligned_bits! {
+///     Rgba as i32 { r: u8, g: u8, b: u8, a: u8 }
+///     Sample as i32 { 0: f32 }
+/// }
+///
+/// # #[cfg(target_arch = "x86_64")]
+/// #[target_feature(enable = "sse2")]
+/// fn load_pixels(pixels: &[Rgba; 4]) -> core::arch::x86_64::__m128i {
+///     safe_unaligned_simd::x86_64::_mm_loadu_si128(pixels)
+/// }
+/// ```
+///
+/// Types with padding are rejected:
+///
+/// ```rust,compile_fail
+/// use safe_unaligned_simd::impl_unaligned_bits;
+///
+/// #[derive(Clone, Copy)]
+/// #[repr(C)]
+/// struct Padded {
+///     a: u8,
+///     b: u16,
+/// }
+///
+/// impl_unaligned_bits! {
+///     Padded as i32 { a: u8, b: u16 }
+/// }
+/// ```
+#[macro_export]
+macro_rules! impl_unaligned_bits {
+    ($(
+        $ty:ident as $bits:ty { $($field:tt: $field_ty:ty),* $(,)? }
+    )*) => {
+        $(
+            const _: () = {
+                // Lists every field exactly once with its type, and requires each field type to
+                // be valid for any bit pattern.
+                #[allow(dead_code)]
+                fn fields() -> $ty {
+                    $ty { $($field: $crate::__private::field::<$field_ty>(),)* }
+                }
+
+                assert!(
+                    0 $(+ size_of::<$field_ty>())* == size_of::<$ty>(),
+                    "type contains padding bytes",
+                );
+                assert!(
+                    size_of::<$ty>() == size_of::<$bits>(),
+                    "type size does not match `Bits`",
+                );
+            };
+
+            // SAFETY: The fields are exhaustive, valid for any bit pattern, and cover the type
+            // without padding, as checked above. Their types contain no `UnsafeCell`.
+            unsafe impl $crate::UnalignedBits for $ty {
+                type Bits = $bits;
+            }
+        )*
+    };
+}
+
 #[cfg(target_arch = "x86")]
 use core::arch::x86::{__m128i, __m256i, __m512i};
 #[cfg(target_arch = "x86_64")]
@@ -330,10 +913,37 @@ const _: () = assert!(size_of::<[i128; 4]>() == size_of::<__m512i>());
 
 #[cfg(test)]
 mod tests {
-    use super::Is8CellUnaligned;
+    use super::{
+        Is8CellUnaligned, Is32CellUnaligned, Is64BitsUnaligned, Is128BitsReadable,
+        Is128BitsUnaligned, Is128CellUnaligned, Readable, Unaligned, UnalignedCell,
+    };
     use core::cell::Cell;
+    use core::num::{NonZero, Wrapping};
+
+    #[derive(Clone, Copy)]
+    #[repr(C)]
+    struct Rgba {
+        r: u8,
+        g: u8,
+        b: u8,
+        a: u8,
+    }
+
+    #[derive(Clone, Copy)]
+    #[repr(transparent)]
+    struct Sample(f64);
+
+    crate::impl_unaligned_bits! {
+        Rgba as i32 { r: u8, g: u8, b: u8, a: u8 }
+        Sample as i64 { 0: f64 }
+    }
 
     fn assert_is_8_cell_unaligned<T: Is8CellUnaligned>() {}
+    fn assert_is_64_bits_unaligned<T: Is64BitsUnaligned>() {}
+    fn assert_is_128_bits_readable<T: Is128BitsReadable>() {}
+    fn assert_is_128_bits_unaligned<T: Is128BitsUnaligned>() {}
+    fn assert_is_32_cell_unaligned<T: Is32CellUnaligned>() {}
+    fn assert_is_128_cell_unaligned<T: Is128CellUnaligned>() {}
 
     #[test]
     fn is_8_cell_unaligned_covers_8_bit_cells() {
@@ -344,4 +954,94 @@ mod tests {
         assert_is_8_cell_unaligned::<Cell<[u8; 1]>>();
         assert_is_8_cell_unaligned::<Cell<[i8; 1]>>();
     }
+
+    #[test]
+    fn is_128_bits_readable_covers_writable_and_load_only_types() {
+        assert_is_128_bits_readable::<[u8; 16]>();
+        assert_is_128_bits_readable::<[bool; 16]>();
+        assert_is_128_bits_readable::<[char; 4]>();
+        assert_is_128_bits_readable::<[NonZero<u32>; 4]>();
+        assert_is_128_bits_readable::<[core::cmp::Ordering; 16]>();
+    }
+
+    #[test]
+    fn is_128_bits_unaligned_is_structural() {
+        use core::num::Saturating;
+
+        assert_is_128_bits_unaligned::<u128>();
+        assert_is_128_bits_unaligned::<[i128; 1]>();
+        assert_is_128_bits_unaligned::<[[u8; 4]; 4]>();
+        assert_is_128_bits_unaligned::<[[[u8; 2]; 2]; 4]>();
+        assert_is_128_bits_unaligned::<[Wrapping<u32>; 4]>();
+        assert_is_128_bits_unaligned::<Wrapping<[u32; 4]>>();
+        assert_is_128_bits_unaligned::<[Saturating<i16>; 8]>();
+        assert_is_128_bits_readable::<[[bool; 8]; 2]>();
+        assert_is_128_cell_unaligned::<Cell<[[u8; 4]; 4]>>();
+        assert_is_128_cell_unaligned::<[Cell<[u8; 4]>; 4]>();
+    }
+
+    #[test]
+    fn is_64_bits_unaligned_covers_option_non_zero() {
+        assert_is_64_bits_unaligned::<Option<NonZero<u64>>>();
+        assert_is_64_bits_unaligned::<[Option<NonZero<u16>>; 4]>();
+    }
+
+    #[test]
+    #[cfg(feature = "half")]
+    fn half_arrays_implement_unaligned_traits() {
+        use half::{bf16, f16};
+
+        assert_is_128_bits_unaligned::<[f16; 8]>();
+        assert_is_128_bits_unaligned::<[bf16; 8]>();
+        assert_is_128_cell_unaligned::<[Cell<f16>; 8]>();
+        assert_is_128_cell_unaligned::<Cell<[bf16; 8]>>();
+    }
+
+    #[test]
+    #[cfg(feature = "num-complex")]
+    fn complex_arrays_implement_unaligned_traits() {
+        use num_complex::Complex;
+
+        assert_is_64_bits_unaligned::<Complex<f32>>();
+        assert_is_128_bits_unaligned::<[Complex<f32>; 2]>();
+        assert_is_128_bits_unaligned::<Complex<f64>>();
+        assert_is_128_cell_unaligned::<[Cell<Complex<f64>>; 1]>();
+    }
+
+    #[test]
+    fn user_types_implement_unaligned_traits() {
+        assert_is_128_bits_unaligned::<[Rgba; 4]>();
+        assert_is_128_bits_unaligned::<[Sample; 2]>();
+        assert_is_128_bits_readable::<[Rgba; 4]>();
+        assert_is_64_bits_unaligned::<Sample>();
+        assert_is_32_cell_unaligned::<Cell<Rgba>>();
+        assert_is_128_cell_unaligned::<[Cell<Rgba>; 4]>();
+        assert_is_128_cell_unaligned::<Cell<[Sample; 2]>>();
+    }
+
+    // Width-generic code only names the const parameter and reaches the fixed-width aliases.
+    fn assert_generic_width<const BYTES: usize, T: Unaligned<BYTES>>() {
+        fn assert_readable<const BYTES: usize, T: Readable<BYTES>>() {}
+        fn assert_cell<const BYTES: usize, T: UnalignedCell<BYTES>>() {}
+
+        assert_readable::<BYTES, T>();
+        assert_cell::<BYTES, Cell<T>>();
+    }
+
+    fn assert_128_from_generic<T: Unaligned<16>>() {
+        assert_is_128_bits_unaligned::<T>();
+        assert_is_128_bits_readable::<T>();
+        assert_is_128_cell_unaligned::<Cell<T>>();
+    }
+
+    #[test]
+    fn const_generic_traits_match_fixed_width_traits() {
+        assert_generic_width::<1, u8>();
+        assert_generic_width::<16, [u32; 4]>();
+        assert_generic_width::<32, [[u8; 16]; 2]>();
+        assert_generic_width::<64, [f64; 8]>();
+        assert_generic_width::<16, [Rgba; 4]>();
+        assert_128_from_generic::<[u16; 8]>();
+        assert_128_from_generic::<Wrapping<[Sample; 2]>>();
+    }
 }
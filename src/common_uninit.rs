@@ -0,0 +1,92 @@
+//! Conversions between `MaybeUninit` arrays and slices, shared by the `uninit` modules.
+use core::mem::MaybeUninit;
+
+/// Views an array of uninitialized elements as an uninitialized array.
+///
+/// This turns a `&mut [MaybeUninit<u8>; 16]` into the `&mut MaybeUninit<[u8; 16]>` that the store
+/// wrappers accept.
+#[inline]
+pub fn array_mut<T, const N: usize>(array: &mut [MaybeUninit<T>; N]) -> &mut MaybeUninit<[T; N]> {
+    // Safety: `[MaybeUninit<T>; N]` and `MaybeUninit<[T; N]>` have the same layout and both are
+    // valid for any bit pattern, including uninitialized memory.
+    unsafe { &mut *core::ptr::from_mut(array).cast() }
+}
+
+/// Splits a slice of uninitialized elements into uninitialized arrays of `N` elements and the
+/// remainder.
+///
+/// This is intended for the spare capacity of a vector. After storing into the first `k` chunks
+/// the first `k * N` elements are initialized and the length of the vector may be extended.
+///
+/// ```rust
+/// # #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+/// # mod arch {
+/// # #[cfg(target_arch = "x86")]
+/// # pub use safe_unaligned_simd::x86::uninit;
+/// # #[cfg(target_arch = "x86_64")]
+/// # pub use safe_unaligned_simd::x86_64::uninit;
+/// # }
+/// # #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+/// # {
+/// let mut out: Vec<u8> = Vec::with_capacity(64);
+///
+/// let (chunks, _) = arch::uninit::chunks_mut::<u8, 16>(out.spare_capacity_mut());
+/// for chunk in chunks.iter_mut() {
+///     chunk.write([0xab; 16]);
+/// }
+///
+/// let initialized = chunks.len() * 16;
+/// // Safety: every chunk was written above.
+/// unsafe { out.set_len(initialized) };
+/// assert!(out.iter().all(|&b| b == 0xab));
+/// # }
+/// ```
+///
+/// # Panics
+///
+/// Panics if `N` is zero.
+#[inline]
+pub fn chunks_mut<T, const N: usize>(
+    slice: &mut [MaybeUninit<T>],
+) -> (&mut [MaybeUninit<[T; N]>], &mut [MaybeUninit<T>]) {
+    let (chunks, rest) = slice.as_chunks_mut::<N>();
+    let len = chunks.len();
+    let ptr = chunks.as_mut_ptr().cast::<MaybeUninit<[T; N]>>();
+    // Safety: as in `array_mut`, each `[MaybeUninit<T>; N]` has the layout of
+    // `MaybeUninit<[T; N]>` and the pointer covers exactly `len` of them.
+    let chunks = unsafe { core::slice::from_raw_parts_mut(ptr, len) };
+    (chunks, rest)
+}
+
+#[cfg(test)]
+mod tests {
+    use core::mem::MaybeUninit;
+
+    use super::{array_mut, chunks_mut};
+
+    #[test]
+    fn test_array_mut() {
+        let mut array = [MaybeUninit::<u8>::uninit(); 4];
+        let init = array_mut(&mut array).write([1, 2, 3, 4]);
+        assert_eq!(*init, [1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn test_chunks_mut() {
+        let mut out: Vec<u16> = Vec::with_capacity(11);
+        let spare = out.spare_capacity_mut();
+        let capacity = spare.len();
+
+        let (chunks, rest) = chunks_mut::<u16, 4>(spare);
+        assert_eq!(chunks.len(), capacity / 4);
+        assert_eq!(rest.len(), capacity % 4);
+
+        for (i, chunk) in chunks.iter_mut().enumerate() {
+            chunk.write([i as u16; 4]);
+        }
+
+        let len = chunks.len() * 4;
+        unsafe { out.set_len(len) };
+        assert!(out.chunks(4).enumerate().all(|(i, c)| c == [i as u16; 4]));
+    }
+}
@@ -19,10 +19,83 @@
 //!
 //! See [the `std::arch` module documentation][stdarch] for a full explanation and [the `rustc` 1.87 release notes][rustc-1.87] for a simple example of runtime feature detection with fallback.
 //!
+//! The `token` module of each platform provides zero-sized tokens whose
+//! constructors check for the target features, e.g. `x86_64::token::Avx` or
+//! `aarch64::token::Neon`. Their methods call the wrappers without `unsafe`.
+//! The [`multiversion!`] macro compiles a function once per token and calls
+//! the variant of the best token detected at runtime. The `ext` modules
+//! provide the loads and stores as token-gated methods of the memory types,
+//! e.g. `data.loadu_si128(sse2)` for any `T: Is128BitsReadable`.
+//!
 //! [rustc-1.86]: https://blog.rust-lang.org/2025/04/03/Rust-1.86.0/#allow-safe-functions-to-be-marked-with-the-target-feature-attribute
 //! [rustc-1.87]: https://blog.rust-lang.org/2025/05/15/Rust-1.87.0/#safe-architecture-intrinsics
 //! [stdarch]: https://doc.rust-lang.org/stable/std/arch/index.html#overview
 //!
+//! ## User types
+//!
+//! Plain-data structs can be passed to the integer vector loads and stores by
+//! implementing [`UnalignedBits`], preferably via the [`impl_unaligned_bits!`]
+//! macro which checks the layout at compile time.
+//!
+//! ## Uninitialized destinations
+//!
+//! The `uninit` module of each platform provides the full-register stores for
+//! `&mut MaybeUninit<T>` destinations, returning the initialized `&mut T`. Its
+//! `chunks_mut` helper splits the spare capacity of a vector into arrays.
+//!
+//! ## Slices
+//!
+//! The `slice` module of each platform provides `try_*`, `*_slice` and `*_at`
+//! companions of the full-register loads and stores that take slices and
+//! check their length with a single comparison. The integer variants accept
+//! slices of any [`ReadableElement`] or [`UnalignedElement`] type. Its
+//! `chunks_*` functions iterate over the registers of a whole slice and
+//! expose the remainder, with `_mut` counterparts that store back in place.
+//! The `*_partial` functions load and store such remainders, filling the lanes
+//! past the end of the slice with zero or the lanes of a fill register. For
+//! idempotent kernels, the `for_each_overlapping_*` and `map_overlapping_*`
+//! drivers instead process a final window that overlaps the previous register.
+//!
+//! ## Portable loads and stores
+//!
+//! The [`portable`] module provides `load128`, `store128`, `load64_zero`,
+//! splatting and extending loads with the same names on every platform. They
+//! return the native 128-bit register, such as `__m128i` on `x86_64`.
+//!
+//! ## Registers as arrays
+//!
+//! The [`Register`] trait converts register types by value to and from arrays
+//! of the same size, e.g. `__m256i` and `[u16; 16]` or `float32x4x3_t` and
+//! `[[f32; 4]; 3]`, and formats their lanes with [`Register::lanes`].
+//!
+//! ## Interleaved loads and stores
+//!
+//! The `x86::interleave` module de-interleaves arrays of two, three or four
+//! registers, such as `&[u8; 48] -> [__m128i; 3]` for RGB pixels, and stores
+//! them back, with the same lanes as `vld3q_u8` and `vst3q_u8` on `aarch64`.
+//!
+//! ## Optional features
+//!
+//! - `std` - The token constructors detect target features at runtime.
+//! - `alloc` - The `aligned` module provides `AlignedVec` and `AlignedBox`,
+//!   heap buffers with a minimum alignment whose chunk views are aligned.
+//! - `bytemuck` - The `bytemuck` module implements [`UnalignedBits`] for
+//!   `bytemuck::Pod` types, [`ReadableBits`] for `bytemuck::NoUninit` types,
+//!   and casts slices into fixed-size chunks.
+//! - `zerocopy` - The `zerocopy` module implements [`UnalignedBits`] for
+//!   `zerocopy::FromBytes + IntoBytes + Immutable` types, [`ReadableBits`] for
+//!   `IntoBytes + Immutable` types, and casts byte slices into fixed-size
+//!   chunks.
+//! - `half` - Arrays of `half::f16` and `half::bf16` implement the unaligned
+//!   traits, and on `aarch64` the `half` module provides the `u16` wrappers
+//!   for them.
+//! - `num-complex` - Arrays of `num_complex::Complex` implement the unaligned
+//!   traits, and the `complex` modules on `x86` and `aarch64` provide float
+//!   loads and stores for them.
+//! - `nightly` - Requires a nightly compiler. `core::simd::Simd` vectors
+//!   implement the unaligned traits, and the `simd` modules on each platform
+//!   provide loads and stores that return and accept `Simd` directly.
+//!
 //! ## Supported target architectures
 //!
 //! ### `x86` / `x86_64`
@@ -37,14 +110,23 @@
 //! ### `aarch64`, `arm64ec`
 //! - `neon`
 //!
+//! The `bytes` module provides `vld1`/`vst1` variants that are generic over
+//! byte-compatible types, such as loading a `float32x4_t` from a `&[u8; 16]`.
+//!
+//! The `cell` module provides 16-byte register loads and stores for `Cell`
+//! array types, like on `x86`.
+//!
 //! Intrinsics that load / store individual lanes are not designed yet.
 //!
 //! ### `wasm32`
 //! - `simd128`
 //!
+//! Like on `x86`, the `cell` module provides variants that are generic over
+//! `Cell` array types.
+//!
 //! ## A note on creating mutable array references from slices
 //!
-//! **_tl;dr:_ Use [`as_mut_array`][as_mut_array] to avoid this bug, stable since `1.93`.**
+//! **_tl;dr:_ Use [`as_mut_array`][as_mut_array] to avoid this bug, stable since `1.93`, or the `slice` modules.**
 //!
 //! Beware of accidentally creating mutable references to temporary arrays.
 //!
@@ -70,10 +152,136 @@
 //! [as_mut_array]: https://doc.rust-lang.org/1.93.0/std/primitive.slice.html#method.as_mut_array
 #![forbid(missing_docs, non_ascii_idents)]
 #![cfg_attr(not(test), no_std)]
+#![cfg_attr(feature = "nightly", feature(portable_simd))]
 
-#[cfg(any(target_arch = "x86", target_arch = "x86_64", target_arch = "wasm32",))]
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "std")]
+extern crate std;
+
+#[cfg(any(
+    target_arch = "x86",
+    target_arch = "x86_64",
+    target_arch = "wasm32",
+    target_arch = "aarch64",
+    target_arch = "arm64ec",
+))]
 mod common_traits;
 
+#[cfg(any(
+    target_arch = "x86",
+    target_arch = "x86_64",
+    target_arch = "wasm32",
+    target_arch = "aarch64",
+    target_arch = "arm64ec",
+))]
+pub use common_traits::{
+    Readable, ReadableBits, ReadableElement, Unaligned, UnalignedBits, UnalignedCell,
+    UnalignedElement,
+};
+
+#[cfg(any(
+    target_arch = "x86",
+    target_arch = "x86_64",
+    target_arch = "wasm32",
+    target_arch = "aarch64",
+    target_arch = "arm64ec",
+))]
+mod common_register;
+
+#[cfg(any(
+    target_arch = "x86",
+    target_arch = "x86_64",
+    target_arch = "wasm32",
+    target_arch = "aarch64",
+    target_arch = "arm64ec",
+))]
+pub use common_register::{Lanes, Register};
+
+#[cfg(any(
+    target_arch = "x86",
+    target_arch = "x86_64",
+    target_arch = "wasm32",
+    target_arch = "aarch64",
+    target_arch = "arm64ec",
+))]
+mod common_uninit;
+
+#[cfg(any(
+    target_arch = "x86",
+    target_arch = "x86_64",
+    target_arch = "wasm32",
+    target_arch = "aarch64",
+    target_arch = "arm64ec",
+))]
+mod common_slice;
+
+#[cfg(any(
+    target_arch = "x86",
+    target_arch = "x86_64",
+    target_arch = "wasm32",
+    target_arch = "aarch64",
+    target_arch = "arm64ec",
+))]
+#[macro_use]
+mod common_token;
+
+#[cfg(all(
+    feature = "nightly",
+    any(
+        target_arch = "x86",
+        target_arch = "x86_64",
+        target_arch = "wasm32",
+        target_arch = "aarch64",
+        target_arch = "arm64ec",
+    )
+))]
+mod common_simd;
+
+#[cfg(any(
+    target_arch = "x86",
+    target_arch = "x86_64",
+    target_arch = "wasm32",
+    target_arch = "aarch64",
+    target_arch = "arm64ec",
+))]
+#[doc(hidden)]
+pub mod __private {
+    pub use crate::common_traits::{AnyBitPattern, field};
+
+    #[cfg(feature = "bytemuck")]
+    pub use ::bytemuck::{NoUninit, Pod};
+
+    #[cfg(feature = "zerocopy")]
+    pub use ::zerocopy::{FromBytes, Immutable, IntoBytes};
+
+    #[cfg(any(target_arch = "aarch64", target_arch = "arm64ec"))]
+    pub use crate::aarch64::token;
+    #[cfg(target_arch = "wasm32")]
+    pub use crate::wasm32::token;
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    pub use crate::x86::token;
+}
+
+#[cfg(feature = "alloc")]
+pub mod aligned;
+
+#[cfg(feature = "bytemuck")]
+pub mod bytemuck;
+
+#[cfg(feature = "zerocopy")]
+pub mod zerocopy;
+
+#[cfg(any(
+    target_arch = "x86",
+    target_arch = "x86_64",
+    target_arch = "wasm32",
+    target_arch = "aarch64",
+    target_arch = "arm64ec",
+))]
+pub mod portable;
+
 #[cfg(any(target_arch = "aarch64", target_arch = "arm64ec"))]
 pub mod aarch64;
 
@@ -0,0 +1,361 @@
+//! Loads and stores of 128-bit registers with the same names on every platform.
+//!
+//! The functions here accept the shared [`Is128BitsReadable`], [`Is64BitsReadable`] and similar
+//! traits and return the native 128-bit register of the target, the [`Vec128`] alias. Each one
+//! requires the baseline vector feature of its platform: `sse2` on `x86` and `x86_64`, `neon` on
+//! `aarch64` and `simd128` on `wasm32`. Operations other than memory accesses still use the
+//! intrinsics of the platform.
+//!
+//! | Function             | `x86` / `x86_64`            | `aarch64`                 | `wasm32`                 |
+//! |----------------------|-----------------------------|---------------------------|--------------------------|
+//! | [`load128`]          | `_mm_loadu_si128`           | `vld1q_u8`                | `v128_load`              |
+//! | [`store128`]         | `_mm_storeu_si128`          | `vst1q_u8`                | `v128_store`             |
+//! | [`load64_zero`]      | `_mm_loadu_si64`            | `vld1_u8` + `vcombine_u8` | `v128_load64_zero`       |
+//! | [`splat_load32`]     | `_mm_set1_epi32`            | `vdupq_n_u32`             | `v128_load32_splat`      |
+//! | [`load_extend_u8x8`] | `_mm_unpacklo_epi8` of zero | `vmovl_u8`                | `u16x8_load_extend_u8x8` |
+//!
+//! ```rust
+//! # #[cfg(any(target_arch = "x86_64", target_feature = "neon", target_feature = "simd128"))]
+//! # {
+//! use safe_unaligned_simd::portable;
+//!
+//! #[cfg_attr(target_arch = "x86_64", target_feature(enable = "sse2"))]
+//! #[cfg_attr(target_arch = "aarch64", target_feature(enable = "neon"))]
+//! #[cfg_attr(target_arch = "wasm32", target_feature(enable = "simd128"))]
+//! fn copy_widened(bytes: &[u8; 8], out: &mut [u16; 8]) {
+//!     let v = portable::load_extend_u8x8(bytes);
+//!     portable::store128(out, v);
+//! }
+//!
+//! let mut out = [0u16; 8];
+//! # #[allow(unused_unsafe)]
+//! unsafe { copy_widened(&[1, 2, 3, 4, 5, 6, 7, 255], &mut out) };
+//! assert_eq!(out, [1, 2, 3, 4, 5, 6, 7, 255]);
+//! # }
+//! ```
+#![allow(
+    // Boilerplate that would repeat each function description for little benefit
+    clippy::missing_safety_doc
+)]
+
+#[cfg(target_arch = "x86")]
+use core::arch::x86::{self as arch, __m128i};
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::{self as arch, __m128i};
+
+#[cfg(any(target_arch = "aarch64", target_arch = "arm64ec"))]
+use core::arch::aarch64::{self as arch, uint8x16_t};
+
+#[cfg(target_arch = "wasm32")]
+use core::arch::wasm32::v128;
+
+#[cfg(not(target_arch = "wasm32"))]
+use crate::common_traits::Readable;
+use crate::common_traits::{
+    Is8BitsReadable, Is16BitsReadable, Is32BitsReadable, Is64BitsReadable, Is128BitsReadable,
+    Is128BitsUnaligned,
+};
+
+/// The native 128-bit register of the target.
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub type Vec128 = __m128i;
+
+/// The native 128-bit register of the target.
+#[cfg(any(target_arch = "aarch64", target_arch = "arm64ec"))]
+pub type Vec128 = uint8x16_t;
+
+/// The native 128-bit register of the target.
+#[cfg(target_arch = "wasm32")]
+pub type Vec128 = v128;
+
+/// Reads the bytes of a readable value.
+#[cfg(not(target_arch = "wasm32"))]
+#[inline(always)]
+fn to_bytes<T: Readable<N>, const N: usize>(value: &T) -> [u8; N] {
+    const { assert!(size_of::<T>() == N) };
+    // Safety: `T` is `N` bytes large, as checked above, and readable types have no uninitialized
+    // bytes. The byte array has no alignment requirement.
+    unsafe { core::ptr::from_ref(value).cast::<[u8; N]>().read() }
+}
+
+// Generates one function per entry with the baseline vector feature of each platform enabled. The
+// basic structure is:
+//
+//   fn load128<T: Is128BitsReadable>(mem_addr: &T) -> Vec128 {
+//       x86: crate::x86::_mm_loadu_si128(mem_addr),
+//       aarch64: crate::aarch64::bytes::vld1q_u8(mem_addr),
+//       wasm32: crate::wasm32::v128_load(mem_addr),
+//   }
+//
+// where each platform names the expression that implements the function on it.
+macro_rules! portable {
+    ($(
+        $(#[$meta:meta])*
+        pub fn $name:ident<$t:ident: $bound:ident>($($arg:ident: $ty:ty),*) $(-> $ret:ty)? {
+            x86: $x86:expr,
+            aarch64: $aarch64:expr,
+            wasm32: $wasm32:expr $(,)?
+        }
+    )*) => {
+        $(
+            $(#[$meta])*
+            #[inline]
+            #[cfg_attr(
+                any(target_arch = "x86", target_arch = "x86_64"),
+                target_feature(enable = "sse2")
+            )]
+            #[cfg_attr(
+                any(target_arch = "aarch64", target_arch = "arm64ec"),
+                target_feature(enable = "neon")
+            )]
+            #[cfg_attr(target_arch = "wasm32", target_feature(enable = "simd128"))]
+            pub fn $name<$t: $bound>($($arg: $ty),*) $(-> $ret)? {
+                #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+                {
+                    $x86
+                }
+                #[cfg(any(target_arch = "aarch64", target_arch = "arm64ec"))]
+                {
+                    $aarch64
+                }
+                #[cfg(target_arch = "wasm32")]
+                {
+                    $wasm32
+                }
+            }
+        )*
+    };
+}
+
+portable! {
+    /// Loads 16 bytes into a register.
+    pub fn load128<T: Is128BitsReadable>(mem_addr: &T) -> Vec128 {
+        x86: crate::x86::_mm_loadu_si128(mem_addr),
+        aarch64: crate::aarch64::bytes::vld1q_u8(mem_addr),
+        wasm32: crate::wasm32::v128_load(mem_addr),
+    }
+
+    /// Stores the 16 bytes of a register.
+    pub fn store128<T: Is128BitsUnaligned>(mem_addr: &mut T, a: Vec128) {
+        x86: crate::x86::_mm_storeu_si128(mem_addr, a),
+        aarch64: crate::aarch64::bytes::vst1q_u8(mem_addr, a),
+        wasm32: crate::wasm32::v128_store(mem_addr, a),
+    }
+
+    /// Loads 8 bytes into the low half of a register and zeroes the high half.
+    pub fn load64_zero<T: Is64BitsReadable>(mem_addr: &T) -> Vec128 {
+        x86: crate::x86::_mm_loadu_si64(mem_addr),
+        aarch64: arch::vcombine_u8(crate::aarch64::bytes::vld1_u8(mem_addr), arch::vdup_n_u8(0)),
+        wasm32: crate::wasm32::v128_load64_zero(mem_addr),
+    }
+
+    /// Loads one byte into every 8-bit lane of a register.
+    pub fn splat_load8<T: Is8BitsReadable>(mem_addr: &T) -> Vec128 {
+        x86: arch::_mm_set1_epi8(i8::from_ne_bytes(to_bytes(mem_addr))),
+        aarch64: arch::vdupq_n_u8(u8::from_ne_bytes(to_bytes(mem_addr))),
+        wasm32: crate::wasm32::v128_load8_splat(mem_addr),
+    }
+
+    /// Loads 2 bytes into every 16-bit lane of a register.
+    pub fn splat_load16<T: Is16BitsReadable>(mem_addr: &T) -> Vec128 {
+        x86: arch::_mm_set1_epi16(i16::from_ne_bytes(to_bytes(mem_addr))),
+        aarch64: {
+            let v = arch::vdupq_n_u16(u16::from_ne_bytes(to_bytes(mem_addr)));
+            arch::vreinterpretq_u8_u16(v)
+        },
+        wasm32: crate::wasm32::v128_load16_splat(mem_addr),
+    }
+
+    /// Loads 4 bytes into every 32-bit lane of a register.
+    pub fn splat_load32<T: Is32BitsReadable>(mem_addr: &T) -> Vec128 {
+        x86: arch::_mm_set1_epi32(i32::from_ne_bytes(to_bytes(mem_addr))),
+        aarch64: {
+            let v = arch::vdupq_n_u32(u32::from_ne_bytes(to_bytes(mem_addr)));
+            arch::vreinterpretq_u8_u32(v)
+        },
+        wasm32: crate::wasm32::v128_load32_splat(mem_addr),
+    }
+
+    /// Loads 8 bytes into both 64-bit lanes of a register.
+    pub fn splat_load64<T: Is64BitsReadable>(mem_addr: &T) -> Vec128 {
+        x86: {
+            let v = crate::x86::_mm_loadu_si64(mem_addr);
+            arch::_mm_unpacklo_epi64(v, v)
+        },
+        aarch64: {
+            let v = arch::vdupq_n_u64(u64::from_ne_bytes(to_bytes(mem_addr)));
+            arch::vreinterpretq_u8_u64(v)
+        },
+        wasm32: crate::wasm32::v128_load64_splat(mem_addr),
+    }
+
+    /// Loads eight 8-bit integers and zero extends each one to a 16-bit lane.
+    pub fn load_extend_u8x8<T: Is64BitsReadable>(mem_addr: &T) -> Vec128 {
+        x86: {
+            let v = crate::x86::_mm_loadu_si64(mem_addr);
+            arch::_mm_unpacklo_epi8(v, arch::_mm_setzero_si128())
+        },
+        aarch64: {
+            let v = arch::vmovl_u8(crate::aarch64::bytes::vld1_u8(mem_addr));
+            arch::vreinterpretq_u8_u16(v)
+        },
+        wasm32: crate::wasm32::u16x8_load_extend_u8x8(mem_addr),
+    }
+
+    /// Loads eight 8-bit integers and sign extends each one to a 16-bit lane.
+    pub fn load_extend_i8x8<T: Is64BitsReadable>(mem_addr: &T) -> Vec128 {
+        x86: {
+            let v = crate::x86::_mm_loadu_si64(mem_addr);
+            arch::_mm_srai_epi16::<8>(arch::_mm_unpacklo_epi8(v, v))
+        },
+        aarch64: {
+            let v = arch::vmovl_s8(crate::aarch64::bytes::vld1_s8(mem_addr));
+            arch::vreinterpretq_u8_s16(v)
+        },
+        wasm32: crate::wasm32::i16x8_load_extend_i8x8(mem_addr),
+    }
+
+    /// Loads four 16-bit integers and zero extends each one to a 32-bit lane.
+    pub fn load_extend_u16x4<T: Is64BitsReadable>(mem_addr: &T) -> Vec128 {
+        x86: {
+            let v = crate::x86::_mm_loadu_si64(mem_addr);
+            arch::_mm_unpacklo_epi16(v, arch::_mm_setzero_si128())
+        },
+        aarch64: {
+            let v = arch::vmovl_u16(crate::aarch64::bytes::vld1_u16(mem_addr));
+            arch::vreinterpretq_u8_u32(v)
+        },
+        wasm32: crate::wasm32::u32x4_load_extend_u16x4(mem_addr),
+    }
+
+    /// Loads four 16-bit integers and sign extends each one to a 32-bit lane.
+    pub fn load_extend_i16x4<T: Is64BitsReadable>(mem_addr: &T) -> Vec128 {
+        x86: {
+            let v = crate::x86::_mm_loadu_si64(mem_addr);
+            arch::_mm_srai_epi32::<16>(arch::_mm_unpacklo_epi16(v, v))
+        },
+        aarch64: {
+            let v = arch::vmovl_s16(crate::aarch64::bytes::vld1_s16(mem_addr));
+            arch::vreinterpretq_u8_s32(v)
+        },
+        wasm32: crate::wasm32::i32x4_load_extend_i16x4(mem_addr),
+    }
+
+    /// Loads two 32-bit integers and zero extends each one to a 64-bit lane.
+    pub fn load_extend_u32x2<T: Is64BitsReadable>(mem_addr: &T) -> Vec128 {
+        x86: {
+            let v = crate::x86::_mm_loadu_si64(mem_addr);
+            arch::_mm_unpacklo_epi32(v, arch::_mm_setzero_si128())
+        },
+        aarch64: {
+            let v = arch::vmovl_u32(crate::aarch64::bytes::vld1_u32(mem_addr));
+            arch::vreinterpretq_u8_u64(v)
+        },
+        wasm32: crate::wasm32::u64x2_load_extend_u32x2(mem_addr),
+    }
+
+    /// Loads two 32-bit integers and sign extends each one to a 64-bit lane.
+    pub fn load_extend_i32x2<T: Is64BitsReadable>(mem_addr: &T) -> Vec128 {
+        x86: {
+            let v = crate::x86::_mm_loadu_si64(mem_addr);
+            // The sign of each lane, interleaved as the high halves of the 64-bit lanes.
+            let sign = arch::_mm_srai_epi32::<31>(v);
+            arch::_mm_unpacklo_epi32(v, sign)
+        },
+        aarch64: {
+            let v = arch::vmovl_s32(crate::aarch64::bytes::vld1_s32(mem_addr));
+            arch::vreinterpretq_u8_s64(v)
+        },
+        wasm32: crate::wasm32::i64x2_load_extend_i32x2(mem_addr),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    // Every test enables the baseline vector feature of the platform, which is part of the
+    // `x86_64` and `aarch64` targets and must be enabled for `wasm32`.
+    macro_rules! test_portable {
+        ($(fn $name:ident() $body:block)*) => {
+            $(
+                #[test]
+                #[cfg_attr(all(target_arch = "wasm32", not(target_feature = "simd128")), ignore)]
+                fn $name() {
+                    #[cfg_attr(
+                        any(target_arch = "x86", target_arch = "x86_64"),
+                        target_feature(enable = "sse2")
+                    )]
+                    #[cfg_attr(
+                        any(target_arch = "aarch64", target_arch = "arm64ec"),
+                        target_feature(enable = "neon")
+                    )]
+                    #[cfg_attr(target_arch = "wasm32", target_feature(enable = "simd128"))]
+                    fn test() $body
+
+                    #[allow(unused_unsafe)]
+                    unsafe {
+                        test()
+                    }
+                }
+            )*
+        };
+    }
+
+    test_portable! {
+        fn test_load_store128() {
+            let a = [1u32, 2, 3, u32::MAX];
+            let mut b = [0u8; 16];
+            super::store128(&mut b, super::load128(&a));
+            assert_eq!(b, *a.map(u32::to_ne_bytes).as_flattened());
+        }
+
+        fn test_load64_zero() {
+            let mut b = [0xffu8; 16];
+            super::store128(&mut b, super::load64_zero(&[1u8, 2, 3, 4, 5, 6, 7, 8]));
+            assert_eq!(b, [1, 2, 3, 4, 5, 6, 7, 8, 0, 0, 0, 0, 0, 0, 0, 0]);
+        }
+
+        fn test_splat_loads() {
+            let mut b = [0u8; 16];
+            super::store128(&mut b, super::splat_load8(&0xab_u8));
+            assert_eq!(b, [0xab; 16]);
+
+            let mut b = [0u16; 8];
+            super::store128(&mut b, super::splat_load16(&[1u8, 2]));
+            assert_eq!(b, [u16::from_ne_bytes([1, 2]); 8]);
+
+            let mut b = [0i32; 4];
+            super::store128(&mut b, super::splat_load32(&-7i32));
+            assert_eq!(b, [-7; 4]);
+
+            let mut b = [0u32; 4];
+            super::store128(&mut b, super::splat_load64(&[5u32, 6]));
+            assert_eq!(b, [5, 6, 5, 6]);
+        }
+
+        fn test_load_extend() {
+            let mut b = [0u16; 8];
+            super::store128(&mut b, super::load_extend_u8x8(&[0u8, 1, 2, 3, 4, 5, 127, 255]));
+            assert_eq!(b, [0, 1, 2, 3, 4, 5, 127, 255]);
+
+            let mut b = [0i16; 8];
+            super::store128(&mut b, super::load_extend_i8x8(&[0i8, 1, -1, 3, -4, 5, 127, -128]));
+            assert_eq!(b, [0, 1, -1, 3, -4, 5, 127, -128]);
+
+            let mut b = [0u32; 4];
+            super::store128(&mut b, super::load_extend_u16x4(&[0u16, 1, 0x8000, u16::MAX]));
+            assert_eq!(b, [0, 1, 0x8000, 0xffff]);
+
+            let mut b = [0i32; 4];
+            super::store128(&mut b, super::load_extend_i16x4(&[0i16, -1, i16::MIN, i16::MAX]));
+            assert_eq!(b, [0, -1, -32768, 32767]);
+
+            let mut b = [0u64; 2];
+            super::store128(&mut b, super::load_extend_u32x2(&[u32::MAX, 1]));
+            assert_eq!(b, [0xffff_ffff, 1]);
+
+            let mut b = [0i64; 2];
+            super::store128(&mut b, super::load_extend_i32x2(&[i32::MIN, 7]));
+            assert_eq!(b, [-2147483648, 7]);
+        }
+    }
+}
@@ -2,174 +2,336 @@
 use core::arch::wasm32::{self as arch, v128};
 use core::ptr;
 
+pub mod cell;
+pub mod ext;
+#[cfg(feature = "nightly")]
+pub mod simd;
+pub mod slice;
+pub mod token;
+pub mod uninit;
+
 pub use crate::common_traits::{
     Is8BitsUnaligned as Is1ByteUnaligned, Is16BitsUnaligned as Is2BytesUnaligned,
     Is32BitsUnaligned as Is4BytesUnaligned, Is64BitsUnaligned as Is8BytesUnaligned,
     Is128BitsUnaligned as Is16BytesUnaligned,
 };
 
-/// Loads eight 8-bit integers and sign extends each one to a 16-bit lane.
-///
-/// Safe wrapper around [`arch::i16x8_load_extend_i8x8`].
-#[inline]
-#[target_feature(enable = "simd128")]
-pub fn i16x8_load_extend_i8x8<T: Is8BytesUnaligned>(t: &T) -> v128 {
-    unsafe { arch::i16x8_load_extend_i8x8(ptr::from_ref(t).cast()) }
-}
+pub use crate::common_traits::{
+    Is8BitsReadable as Is1ByteReadable, Is16BitsReadable as Is2BytesReadable,
+    Is32BitsReadable as Is4BytesReadable, Is64BitsReadable as Is8BytesReadable,
+    Is128BitsReadable as Is16BytesReadable,
+};
 
-/// Loads eight 8-bit integers and zero extends each one to a 16-bit lane.
-///
-/// Safe wrapper around [`arch::i16x8_load_extend_u8x8`].
-#[inline]
-#[target_feature(enable = "simd128")]
-pub fn i16x8_load_extend_u8x8<T: Is8BytesUnaligned>(t: &T) -> v128 {
-    unsafe { arch::i16x8_load_extend_u8x8(ptr::from_ref(t).cast()) }
-}
+pub use crate::common_traits::{
+    Is8CellUnaligned as Is1ByteCellUnaligned, Is16CellUnaligned as Is2BytesCellUnaligned,
+    Is32CellUnaligned as Is4BytesCellUnaligned, Is64CellUnaligned as Is8BytesCellUnaligned,
+    Is128CellUnaligned as Is16BytesCellUnaligned,
+};
 
-/// Loads four 16-bit integers and sign extends each one to a 32-bit lane.
-///
-/// Safe wrapper around [`arch::i32x4_load_extend_i16x4`].
-#[inline]
-#[target_feature(enable = "simd128")]
-pub fn i32x4_load_extend_i16x4<T: Is8BytesUnaligned>(t: &T) -> v128 {
-    unsafe { arch::i32x4_load_extend_i16x4(ptr::from_ref(t).cast()) }
-}
+pub use crate::common_traits::{
+    Readable, ReadableElement, Unaligned, UnalignedCell, UnalignedElement,
+};
 
-/// Loads four 16-bit integers and zero extends each one to a 32-bit lane.
-///
-/// Safe wrapper around [`arch::i32x4_load_extend_u16x4`].
-#[inline]
-#[target_feature(enable = "simd128")]
-pub fn i32x4_load_extend_u16x4<T: Is8BytesUnaligned>(t: &T) -> v128 {
-    unsafe { arch::i32x4_load_extend_u16x4(ptr::from_ref(t).cast()) }
-}
+token_wrappers! {
+    impl token::Simd128;
+
+    /// Loads eight 8-bit integers and sign extends each one to a 16-bit lane.
+    ///
+    /// Safe wrapper around [`arch::i16x8_load_extend_i8x8`].
+    #[method = i16x8_load_extend_i8x8]
+    #[inline]
+    #[target_feature(enable = "simd128")]
+    pub fn i16x8_load_extend_i8x8<T: Is8BytesReadable>(t: &T) -> v128 {
+        unsafe { arch::i16x8_load_extend_i8x8(ptr::from_ref(t).cast()) }
+    }
 
-/// Loads two 32-bit integers and sign extends each one to a 64-bit lane.
-///
-/// Safe wrapper around [`arch::i64x2_load_extend_i32x2`].
-#[inline]
-#[target_feature(enable = "simd128")]
-pub fn i64x2_load_extend_i32x2<T: Is8BytesUnaligned>(t: &T) -> v128 {
-    unsafe { arch::i64x2_load_extend_i32x2(ptr::from_ref(t).cast()) }
-}
+    /// Loads eight 8-bit integers and zero extends each one to a 16-bit lane.
+    ///
+    /// Safe wrapper around [`arch::i16x8_load_extend_u8x8`].
+    #[method = i16x8_load_extend_u8x8]
+    #[inline]
+    #[target_feature(enable = "simd128")]
+    pub fn i16x8_load_extend_u8x8<T: Is8BytesReadable>(t: &T) -> v128 {
+        unsafe { arch::i16x8_load_extend_u8x8(ptr::from_ref(t).cast()) }
+    }
 
-/// Loads two 32-bit integers and zero extends each one to a 64-bit lane.
-///
-/// Safe wrapper around [`arch::i64x2_load_extend_u32x2`].
-#[inline]
-#[target_feature(enable = "simd128")]
-pub fn i64x2_load_extend_u32x2<T: Is8BytesUnaligned>(t: &T) -> v128 {
-    unsafe { arch::i64x2_load_extend_u32x2(ptr::from_ref(t).cast()) }
-}
+    /// Loads four 16-bit integers and sign extends each one to a 32-bit lane.
+    ///
+    /// Safe wrapper around [`arch::i32x4_load_extend_i16x4`].
+    #[method = i32x4_load_extend_i16x4]
+    #[inline]
+    #[target_feature(enable = "simd128")]
+    pub fn i32x4_load_extend_i16x4<T: Is8BytesReadable>(t: &T) -> v128 {
+        unsafe { arch::i32x4_load_extend_i16x4(ptr::from_ref(t).cast()) }
+    }
 
-/// Loads eight 8-bit integers and zero extends each one to a 16-bit lane.
-///
-/// Safe wrapper around [`arch::u16x8_load_extend_u8x8`].
-#[inline]
-#[target_feature(enable = "simd128")]
-pub fn u16x8_load_extend_u8x8<T: Is8BytesUnaligned>(t: &T) -> v128 {
-    unsafe { arch::u16x8_load_extend_u8x8(ptr::from_ref(t).cast()) }
-}
+    /// Loads four 16-bit integers and zero extends each one to a 32-bit lane.
+    ///
+    /// Safe wrapper around [`arch::i32x4_load_extend_u16x4`].
+    #[method = i32x4_load_extend_u16x4]
+    #[inline]
+    #[target_feature(enable = "simd128")]
+    pub fn i32x4_load_extend_u16x4<T: Is8BytesReadable>(t: &T) -> v128 {
+        unsafe { arch::i32x4_load_extend_u16x4(ptr::from_ref(t).cast()) }
+    }
 
-/// Loads four 16-bit integers and zero extends each one to a 32-bit lane.
-///
-/// Safe wrapper around [`arch::u32x4_load_extend_u16x4`].
-#[inline]
-#[target_feature(enable = "simd128")]
-pub fn u32x4_load_extend_u16x4<T: Is8BytesUnaligned>(t: &T) -> v128 {
-    unsafe { arch::u32x4_load_extend_u16x4(ptr::from_ref(t).cast()) }
-}
+    /// Loads two 32-bit integers and sign extends each one to a 64-bit lane.
+    ///
+    /// Safe wrapper around [`arch::i64x2_load_extend_i32x2`].
+    #[method = i64x2_load_extend_i32x2]
+    #[inline]
+    #[target_feature(enable = "simd128")]
+    pub fn i64x2_load_extend_i32x2<T: Is8BytesReadable>(t: &T) -> v128 {
+        unsafe { arch::i64x2_load_extend_i32x2(ptr::from_ref(t).cast()) }
+    }
 
-/// Loads two 32-bit integers and zero extends each one to a 64-bit lane.
-///
-/// Safe wrapper around [`arch::u64x2_load_extend_u32x2`].
-#[inline]
-#[target_feature(enable = "simd128")]
-pub fn u64x2_load_extend_u32x2<T: Is8BytesUnaligned>(t: &T) -> v128 {
-    unsafe { arch::u64x2_load_extend_u32x2(ptr::from_ref(t).cast()) }
-}
+    /// Loads two 32-bit integers and zero extends each one to a 64-bit lane.
+    ///
+    /// Safe wrapper around [`arch::i64x2_load_extend_u32x2`].
+    #[method = i64x2_load_extend_u32x2]
+    #[inline]
+    #[target_feature(enable = "simd128")]
+    pub fn i64x2_load_extend_u32x2<T: Is8BytesReadable>(t: &T) -> v128 {
+        unsafe { arch::i64x2_load_extend_u32x2(ptr::from_ref(t).cast()) }
+    }
 
-/// Loads a `v128` vector from the given heap address.
-///
-/// Safe wrapper around [`arch::v128_load`].
-#[inline]
-#[target_feature(enable = "simd128")]
-pub fn v128_load<T: Is16BytesUnaligned>(t: &T) -> v128 {
-    unsafe { arch::v128_load(ptr::from_ref(t).cast()) }
-}
+    /// Loads eight 8-bit integers and zero extends each one to a 16-bit lane.
+    ///
+    /// Safe wrapper around [`arch::u16x8_load_extend_u8x8`].
+    #[method = u16x8_load_extend_u8x8]
+    #[inline]
+    #[target_feature(enable = "simd128")]
+    pub fn u16x8_load_extend_u8x8<T: Is8BytesReadable>(t: &T) -> v128 {
+        unsafe { arch::u16x8_load_extend_u8x8(ptr::from_ref(t).cast()) }
+    }
 
-/// Loads a single element and splats to all lanes of a `v128` vector.
-///
-/// Safe wrapper around [`arch::v128_load8_splat`].
-#[inline]
-#[target_feature(enable = "simd128")]
-pub fn v128_load8_splat<T: Is1ByteUnaligned>(t: &T) -> v128 {
-    unsafe { arch::v128_load8_splat(ptr::from_ref(t).cast()) }
-}
+    /// Loads four 16-bit integers and zero extends each one to a 32-bit lane.
+    ///
+    /// Safe wrapper around [`arch::u32x4_load_extend_u16x4`].
+    #[method = u32x4_load_extend_u16x4]
+    #[inline]
+    #[target_feature(enable = "simd128")]
+    pub fn u32x4_load_extend_u16x4<T: Is8BytesReadable>(t: &T) -> v128 {
+        unsafe { arch::u32x4_load_extend_u16x4(ptr::from_ref(t).cast()) }
+    }
 
-/// Loads a single element and splats to all lanes of a `v128` vector.
-///
-/// Safe wrapper around [`arch::v128_load16_splat`].
-#[inline]
-#[target_feature(enable = "simd128")]
-pub fn v128_load16_splat<T: Is2BytesUnaligned>(t: &T) -> v128 {
-    unsafe { arch::v128_load16_splat(ptr::from_ref(t).cast()) }
-}
+    /// Loads two 32-bit integers and zero extends each one to a 64-bit lane.
+    ///
+    /// Safe wrapper around [`arch::u64x2_load_extend_u32x2`].
+    #[method = u64x2_load_extend_u32x2]
+    #[inline]
+    #[target_feature(enable = "simd128")]
+    pub fn u64x2_load_extend_u32x2<T: Is8BytesReadable>(t: &T) -> v128 {
+        unsafe { arch::u64x2_load_extend_u32x2(ptr::from_ref(t).cast()) }
+    }
 
-/// Loads a single element and splats to all lanes of a `v128` vector.
-///
-/// Safe wrapper around [`arch::v128_load32_splat`].
-#[inline]
-#[target_feature(enable = "simd128")]
-pub fn v128_load32_splat<T: Is4BytesUnaligned>(t: &T) -> v128 {
-    unsafe { arch::v128_load32_splat(ptr::from_ref(t).cast()) }
+    /// Loads a `v128` vector from the given heap address.
+    ///
+    /// Safe wrapper around [`arch::v128_load`].
+    #[method = v128_load]
+    #[inline]
+    #[target_feature(enable = "simd128")]
+    pub fn v128_load<T: Is16BytesReadable>(t: &T) -> v128 {
+        unsafe { arch::v128_load(ptr::from_ref(t).cast()) }
+    }
 }
 
-/// Loads a 32-bit element into the low bits of the vector and sets all other bits to zero.
-///
-/// Safe wrapper around [`arch::v128_load32_zero`].
-#[inline]
-#[target_feature(enable = "simd128")]
-pub fn v128_load32_zero<T: Is4BytesUnaligned>(t: &T) -> v128 {
-    unsafe { arch::v128_load32_zero(ptr::from_ref(t).cast()) }
-}
+token_wrappers! {
+    impl token::Simd128;
+
+    /// Loads a 8-bit value from `t` and sets lane `L` of `v` to that value.
+    ///
+    /// The lane index `L` must be less than 16, which is checked at compile time.
+    ///
+    /// Safe wrapper around [`arch::v128_load8_lane`].
+    #[method = v128_load8_lane]
+    #[inline]
+    #[target_feature(enable = "simd128")]
+    pub fn v128_load8_lane<const L: usize, T: Is1ByteReadable>(v: v128, t: &T) -> v128 {
+        const { assert!(L < 16, "lane index out of bounds") };
+        unsafe { arch::v128_load8_lane::<L>(v, ptr::from_ref(t).cast()) }
+    }
 
-/// Loads a single element and splats to all lanes of a `v128` vector.
-///
-/// Safe wrapper around [`arch::v128_load64_splat`].
-#[inline]
-#[target_feature(enable = "simd128")]
-pub fn v128_load64_splat<T: Is8BytesUnaligned>(t: &T) -> v128 {
-    unsafe { arch::v128_load64_splat(ptr::from_ref(t).cast()) }
+    /// Loads a 16-bit value from `t` and sets lane `L` of `v` to that value.
+    ///
+    /// The lane index `L` must be less than 8, which is checked at compile time.
+    ///
+    /// Safe wrapper around [`arch::v128_load16_lane`].
+    #[method = v128_load16_lane]
+    #[inline]
+    #[target_feature(enable = "simd128")]
+    pub fn v128_load16_lane<const L: usize, T: Is2BytesReadable>(v: v128, t: &T) -> v128 {
+        const { assert!(L < 8, "lane index out of bounds") };
+        unsafe { arch::v128_load16_lane::<L>(v, ptr::from_ref(t).cast()) }
+    }
+
+    /// Loads a 32-bit value from `t` and sets lane `L` of `v` to that value.
+    ///
+    /// The lane index `L` must be less than 4, which is checked at compile time.
+    ///
+    /// Safe wrapper around [`arch::v128_load32_lane`].
+    #[method = v128_load32_lane]
+    #[inline]
+    #[target_feature(enable = "simd128")]
+    pub fn v128_load32_lane<const L: usize, T: Is4BytesReadable>(v: v128, t: &T) -> v128 {
+        const { assert!(L < 4, "lane index out of bounds") };
+        unsafe { arch::v128_load32_lane::<L>(v, ptr::from_ref(t).cast()) }
+    }
+
+    /// Loads a 64-bit value from `t` and sets lane `L` of `v` to that value.
+    ///
+    /// The lane index `L` must be less than 2, which is checked at compile time.
+    ///
+    /// Safe wrapper around [`arch::v128_load64_lane`].
+    #[method = v128_load64_lane]
+    #[inline]
+    #[target_feature(enable = "simd128")]
+    pub fn v128_load64_lane<const L: usize, T: Is8BytesReadable>(v: v128, t: &T) -> v128 {
+        const { assert!(L < 2, "lane index out of bounds") };
+        unsafe { arch::v128_load64_lane::<L>(v, ptr::from_ref(t).cast()) }
+    }
 }
 
-/// Loads a 64-bit element into the low bits of the vector and sets all other bits to zero.
-///
-/// Safe wrapper around [`arch::v128_load64_zero`].
-#[inline]
-#[target_feature(enable = "simd128")]
-pub fn v128_load64_zero<T: Is8BytesUnaligned>(t: &T) -> v128 {
-    unsafe { arch::v128_load64_zero(ptr::from_ref(t).cast()) }
+token_wrappers! {
+    impl token::Simd128;
+
+    /// Loads a single element and splats to all lanes of a `v128` vector.
+    ///
+    /// Safe wrapper around [`arch::v128_load8_splat`].
+    #[method = v128_load8_splat]
+    #[inline]
+    #[target_feature(enable = "simd128")]
+    pub fn v128_load8_splat<T: Is1ByteReadable>(t: &T) -> v128 {
+        unsafe { arch::v128_load8_splat(ptr::from_ref(t).cast()) }
+    }
+
+    /// Loads a single element and splats to all lanes of a `v128` vector.
+    ///
+    /// Safe wrapper around [`arch::v128_load16_splat`].
+    #[method = v128_load16_splat]
+    #[inline]
+    #[target_feature(enable = "simd128")]
+    pub fn v128_load16_splat<T: Is2BytesReadable>(t: &T) -> v128 {
+        unsafe { arch::v128_load16_splat(ptr::from_ref(t).cast()) }
+    }
+
+    /// Loads a single element and splats to all lanes of a `v128` vector.
+    ///
+    /// Safe wrapper around [`arch::v128_load32_splat`].
+    #[method = v128_load32_splat]
+    #[inline]
+    #[target_feature(enable = "simd128")]
+    pub fn v128_load32_splat<T: Is4BytesReadable>(t: &T) -> v128 {
+        unsafe { arch::v128_load32_splat(ptr::from_ref(t).cast()) }
+    }
+
+    /// Loads a 32-bit element into the low bits of the vector and sets all other bits to zero.
+    ///
+    /// Safe wrapper around [`arch::v128_load32_zero`].
+    #[method = v128_load32_zero]
+    #[inline]
+    #[target_feature(enable = "simd128")]
+    pub fn v128_load32_zero<T: Is4BytesReadable>(t: &T) -> v128 {
+        unsafe { arch::v128_load32_zero(ptr::from_ref(t).cast()) }
+    }
+
+    /// Loads a single element and splats to all lanes of a `v128` vector.
+    ///
+    /// Safe wrapper around [`arch::v128_load64_splat`].
+    #[method = v128_load64_splat]
+    #[inline]
+    #[target_feature(enable = "simd128")]
+    pub fn v128_load64_splat<T: Is8BytesReadable>(t: &T) -> v128 {
+        unsafe { arch::v128_load64_splat(ptr::from_ref(t).cast()) }
+    }
+
+    /// Loads a 64-bit element into the low bits of the vector and sets all other bits to zero.
+    ///
+    /// Safe wrapper around [`arch::v128_load64_zero`].
+    #[method = v128_load64_zero]
+    #[inline]
+    #[target_feature(enable = "simd128")]
+    pub fn v128_load64_zero<T: Is8BytesReadable>(t: &T) -> v128 {
+        unsafe { arch::v128_load64_zero(ptr::from_ref(t).cast()) }
+    }
+
+    /// Stores a `v128` vector to the given heap address.
+    ///
+    /// Safe wrapper around [`arch::v128_store`].
+    #[method = v128_store]
+    #[inline]
+    #[target_feature(enable = "simd128")]
+    pub fn v128_store<T: Is16BytesUnaligned>(t: &mut T, v: v128) {
+        unsafe { arch::v128_store(ptr::from_mut(t).cast(), v) }
+    }
 }
 
-/// Stores a `v128` vector to the given heap address.
-///
-/// Safe wrapper around [`arch::v128_store`].
-#[inline]
-#[target_feature(enable = "simd128")]
-pub fn v128_store<T: Is16BytesUnaligned>(t: &mut T, v: v128) {
-    unsafe { arch::v128_store(ptr::from_mut(t).cast(), v) }
+token_wrappers! {
+    impl token::Simd128;
+
+    /// Stores the 8-bit value from lane `L` of `v` into `t`.
+    ///
+    /// The lane index `L` must be less than 16, which is checked at compile time.
+    ///
+    /// Safe wrapper around [`arch::v128_store8_lane`].
+    #[method = v128_store8_lane]
+    #[inline]
+    #[target_feature(enable = "simd128")]
+    pub fn v128_store8_lane<const L: usize, T: Is1ByteUnaligned>(v: v128, t: &mut T) {
+        const { assert!(L < 16, "lane index out of bounds") };
+        unsafe { arch::v128_store8_lane::<L>(v, ptr::from_mut(t).cast()) }
+    }
+
+    /// Stores the 16-bit value from lane `L` of `v` into `t`.
+    ///
+    /// The lane index `L` must be less than 8, which is checked at compile time.
+    ///
+    /// Safe wrapper around [`arch::v128_store16_lane`].
+    #[method = v128_store16_lane]
+    #[inline]
+    #[target_feature(enable = "simd128")]
+    pub fn v128_store16_lane<const L: usize, T: Is2BytesUnaligned>(v: v128, t: &mut T) {
+        const { assert!(L < 8, "lane index out of bounds") };
+        unsafe { arch::v128_store16_lane::<L>(v, ptr::from_mut(t).cast()) }
+    }
+
+    /// Stores the 32-bit value from lane `L` of `v` into `t`.
+    ///
+    /// The lane index `L` must be less than 4, which is checked at compile time.
+    ///
+    /// Safe wrapper around [`arch::v128_store32_lane`].
+    #[method = v128_store32_lane]
+    #[inline]
+    #[target_feature(enable = "simd128")]
+    pub fn v128_store32_lane<const L: usize, T: Is4BytesUnaligned>(v: v128, t: &mut T) {
+        const { assert!(L < 4, "lane index out of bounds") };
+        unsafe { arch::v128_store32_lane::<L>(v, ptr::from_mut(t).cast()) }
+    }
+
+    /// Stores the 64-bit value from lane `L` of `v` into `t`.
+    ///
+    /// The lane index `L` must be less than 2, which is checked at compile time.
+    ///
+    /// Safe wrapper around [`arch::v128_store64_lane`].
+    #[method = v128_store64_lane]
+    #[inline]
+    #[target_feature(enable = "simd128")]
+    pub fn v128_store64_lane<const L: usize, T: Is8BytesUnaligned>(v: v128, t: &mut T) {
+        const { assert!(L < 2, "lane index out of bounds") };
+        unsafe { arch::v128_store64_lane::<L>(v, ptr::from_mut(t).cast()) }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use core::arch::wasm32::{self as arch, v128};
 
+    use crate::Register;
+
     fn assert_v128_bytes<const N: usize>(val: v128, data: &[[u8; N]]) {
-        assert_eq!(
-            unsafe { core::mem::transmute::<_, [u8; 16]>(val) },
-            unsafe { core::slice::from_raw_parts(data.as_ptr() as *const u8, data.len() * N) }
-        );
+        assert_eq!(val.to_array::<[u8; 16]>(), unsafe {
+            core::slice::from_raw_parts(data.as_ptr() as *const u8, data.len() * N)
+        });
     }
 
     #[test]
@@ -304,6 +466,19 @@ mod tests {
         test(a[1..].try_into().unwrap())
     }
 
+    #[test]
+    #[cfg_attr(not(target_feature = "simd128"), ignore)]
+    fn test_v128_load_char() {
+        #[target_feature(enable = "simd128")]
+        fn test(a: &[char; 4]) {
+            let v = super::v128_load(a);
+            let expected = ['a', 'b', 'c', 'd'].map(|c| (c as u32).to_ne_bytes());
+            assert_v128_bytes(v, &expected);
+        }
+
+        test(&['a', 'b', 'c', 'd'])
+    }
+
     #[test]
     #[cfg_attr(not(target_feature = "simd128"), ignore)]
     fn test_v128_load8_splat() {
@@ -464,4 +639,147 @@ mod tests {
 
         test()
     }
+
+    #[test]
+    #[cfg_attr(not(target_feature = "simd128"), ignore)]
+    fn test_v128_load8_lane() {
+        #[target_feature(enable = "simd128")]
+        fn test(a: &u8) {
+            let v = super::v128_load8_lane::<5, _>(arch::u8x16_splat(1), a);
+            let mut expected = [[1u8]; 16];
+            expected[5] = [42];
+            assert_v128_bytes(v, &expected);
+        }
+
+        let a: [u8; 3] = [0, 42, 0];
+        test(&a[1]);
+    }
+
+    #[test]
+    #[cfg_attr(not(target_feature = "simd128"), ignore)]
+    fn test_v128_load16_lane() {
+        #[target_feature(enable = "simd128")]
+        fn test(a: &[u8; 2]) {
+            let v = super::v128_load16_lane::<7, _>(arch::u16x8_splat(1), a);
+            let mut expected = [1u16.to_ne_bytes(); 8];
+            expected[7] = 42u16.to_ne_bytes();
+            assert_v128_bytes(v, &expected);
+        }
+
+        let a: [u8; 3] = [0, 42u16.to_ne_bytes()[0], 42u16.to_ne_bytes()[1]];
+        test(a[1..].try_into().unwrap());
+    }
+
+    #[test]
+    #[cfg_attr(not(target_feature = "simd128"), ignore)]
+    fn test_v128_load32_lane() {
+        #[target_feature(enable = "simd128")]
+        fn test(a: &f32) {
+            let v = super::v128_load32_lane::<0, _>(arch::f32x4_splat(1.0), a);
+            let expected = [
+                2.5f32.to_ne_bytes(),
+                1f32.to_ne_bytes(),
+                1f32.to_ne_bytes(),
+                1f32.to_ne_bytes(),
+            ];
+            assert_v128_bytes(v, &expected);
+        }
+
+        test(&2.5);
+    }
+
+    #[test]
+    #[cfg_attr(not(target_feature = "simd128"), ignore)]
+    fn test_v128_load64_lane() {
+        #[target_feature(enable = "simd128")]
+        fn test(a: &[u32; 2]) {
+            let v = super::v128_load64_lane::<1, _>(arch::u64x2_splat(0), a);
+            let expected = [
+                0u32.to_ne_bytes(),
+                0u32.to_ne_bytes(),
+                1u32.to_ne_bytes(),
+                2u32.to_ne_bytes(),
+            ];
+            assert_v128_bytes(v, &expected);
+        }
+
+        test(&[1, 2]);
+    }
+
+    #[test]
+    #[cfg_attr(not(target_feature = "simd128"), ignore)]
+    fn test_v128_load_lane_readable() {
+        use core::num::NonZeroU32;
+
+        #[target_feature(enable = "simd128")]
+        fn test(a: &bool, b: &NonZeroU32) {
+            let v = super::v128_load8_lane::<3, _>(arch::u8x16_splat(0), a);
+            let mut expected = [[0u8]; 16];
+            expected[3] = [1];
+            assert_v128_bytes(v, &expected);
+
+            let v = super::v128_load32_lane::<1, _>(arch::u32x4_splat(7), b);
+            let mut expected = [7u32.to_ne_bytes(); 4];
+            expected[1] = 42u32.to_ne_bytes();
+            assert_v128_bytes(v, &expected);
+        }
+
+        test(&true, &NonZeroU32::new(42).unwrap());
+    }
+
+    #[test]
+    #[cfg_attr(not(target_feature = "simd128"), ignore)]
+    fn test_v128_store8_lane() {
+        #[target_feature(enable = "simd128")]
+        fn test() {
+            let v = super::v128_load(&core::array::from_fn::<u8, 16, _>(|i| i as u8));
+            let mut into = [0u8; 2];
+            super::v128_store8_lane::<15, _>(v, &mut into[1]);
+            assert_eq!(into, [0, 15]);
+        }
+
+        test()
+    }
+
+    #[test]
+    #[cfg_attr(not(target_feature = "simd128"), ignore)]
+    fn test_v128_store16_lane() {
+        #[target_feature(enable = "simd128")]
+        fn test() {
+            let v = arch::u16x8(0, 1, 2, 3, 4, 5, 6, 7);
+            let mut into = 0i16;
+            super::v128_store16_lane::<3, _>(v, &mut into);
+            assert_eq!(into, 3);
+        }
+
+        test()
+    }
+
+    #[test]
+    #[cfg_attr(not(target_feature = "simd128"), ignore)]
+    fn test_v128_store32_lane() {
+        #[target_feature(enable = "simd128")]
+        fn test() {
+            let v = arch::u32x4(10, 11, 12, 13);
+            let mut into = [0u8; 4];
+            super::v128_store32_lane::<2, _>(v, &mut into);
+            assert_eq!(into, 12u32.to_ne_bytes());
+        }
+
+        test()
+    }
+
+    #[test]
+    #[cfg_attr(not(target_feature = "simd128"), ignore)]
+    fn test_v128_store64_lane() {
+        #[target_feature(enable = "simd128")]
+        fn test() {
+            let v = arch::f64x2(1.0, 2.0);
+            let mut into = [0f64; 1];
+            super::v128_store64_lane::<1, _>(v, &mut into);
+            assert_eq!(into, [2.0]);
+        }
+
+        test()
+    }
 }
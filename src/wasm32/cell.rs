@@ -0,0 +1,330 @@
+//! Functions generic over [`Cell`][Cell] array types.
+//!
+//! These functions enable loading and storing of `&Cell<[T; N]>` and
+//! `&[Cell<T>; N]`, shared mutable container types which permit mutability
+//! even in the presence of aliasing.
+//!
+//! This allows for operating on overlapping slices similar to how one can use
+//! `core::arch` intrinsics with raw pointers.
+//!
+//! [Cell]: core::cell::Cell
+//!
+//! ```rust
+//! # #[cfg(target_feature = "simd128")]
+//! # slide_right();
+//! use core::cell::Cell;
+//!
+//! use safe_unaligned_simd::wasm32::cell;
+//!
+//! #[target_feature(enable = "simd128")]
+//! fn slide_right() {
+//!    let mut a = [0u16, 1, 2, 3, 4, 5, 6, 7, 8];
+//!    let val = Cell::from_mut(&mut a[..]).as_slice_of_cells();
+//!
+//!    let load: &[_; 8] = val[..8].try_into().unwrap();
+//!    let store: &[_; 8] = val[1..].try_into().unwrap();
+//!
+//!    let r = cell::v128_load(load);
+//!    cell::v128_store(store, r);
+//!
+//!    assert_eq!(a, [0, 0, 1, 2, 3, 4, 5, 6, 7]);
+//! }
+//! ```
+use core::arch::wasm32::{self as arch, v128};
+use core::ptr;
+
+use super::{
+    Is1ByteCellUnaligned, Is2BytesCellUnaligned, Is4BytesCellUnaligned, Is8BytesCellUnaligned,
+    Is16BytesCellUnaligned,
+};
+
+/// Loads eight 8-bit integers and sign extends each one to a 16-bit lane.
+///
+/// Safe wrapper around [`arch::i16x8_load_extend_i8x8`].
+#[inline]
+#[target_feature(enable = "simd128")]
+pub fn i16x8_load_extend_i8x8<T: Is8BytesCellUnaligned>(t: &T) -> v128 {
+    unsafe { arch::i16x8_load_extend_i8x8(ptr::from_ref(t).cast()) }
+}
+
+/// Loads eight 8-bit integers and zero extends each one to a 16-bit lane.
+///
+/// Safe wrapper around [`arch::i16x8_load_extend_u8x8`].
+#[inline]
+#[target_feature(enable = "simd128")]
+pub fn i16x8_load_extend_u8x8<T: Is8BytesCellUnaligned>(t: &T) -> v128 {
+    unsafe { arch::i16x8_load_extend_u8x8(ptr::from_ref(t).cast()) }
+}
+
+/// Loads four 16-bit integers and sign extends each one to a 32-bit lane.
+///
+/// Safe wrapper around [`arch::i32x4_load_extend_i16x4`].
+#[inline]
+#[target_feature(enable = "simd128")]
+pub fn i32x4_load_extend_i16x4<T: Is8BytesCellUnaligned>(t: &T) -> v128 {
+    unsafe { arch::i32x4_load_extend_i16x4(ptr::from_ref(t).cast()) }
+}
+
+/// Loads four 16-bit integers and zero extends each one to a 32-bit lane.
+///
+/// Safe wrapper around [`arch::i32x4_load_extend_u16x4`].
+#[inline]
+#[target_feature(enable = "simd128")]
+pub fn i32x4_load_extend_u16x4<T: Is8BytesCellUnaligned>(t: &T) -> v128 {
+    unsafe { arch::i32x4_load_extend_u16x4(ptr::from_ref(t).cast()) }
+}
+
+/// Loads two 32-bit integers and sign extends each one to a 64-bit lane.
+///
+/// Safe wrapper around [`arch::i64x2_load_extend_i32x2`].
+#[inline]
+#[target_feature(enable = "simd128")]
+pub fn i64x2_load_extend_i32x2<T: Is8BytesCellUnaligned>(t: &T) -> v128 {
+    unsafe { arch::i64x2_load_extend_i32x2(ptr::from_ref(t).cast()) }
+}
+
+/// Loads two 32-bit integers and zero extends each one to a 64-bit lane.
+///
+/// Safe wrapper around [`arch::i64x2_load_extend_u32x2`].
+#[inline]
+#[target_feature(enable = "simd128")]
+pub fn i64x2_load_extend_u32x2<T: Is8BytesCellUnaligned>(t: &T) -> v128 {
+    unsafe { arch::i64x2_load_extend_u32x2(ptr::from_ref(t).cast()) }
+}
+
+/// Loads eight 8-bit integers and zero extends each one to a 16-bit lane.
+///
+/// Safe wrapper around [`arch::u16x8_load_extend_u8x8`].
+#[inline]
+#[target_feature(enable = "simd128")]
+pub fn u16x8_load_extend_u8x8<T: Is8BytesCellUnaligned>(t: &T) -> v128 {
+    unsafe { arch::u16x8_load_extend_u8x8(ptr::from_ref(t).cast()) }
+}
+
+/// Loads four 16-bit integers and zero extends each one to a 32-bit lane.
+///
+/// Safe wrapper around [`arch::u32x4_load_extend_u16x4`].
+#[inline]
+#[target_feature(enable = "simd128")]
+pub fn u32x4_load_extend_u16x4<T: Is8BytesCellUnaligned>(t: &T) -> v128 {
+    unsafe { arch::u32x4_load_extend_u16x4(ptr::from_ref(t).cast()) }
+}
+
+/// Loads two 32-bit integers and zero extends each one to a 64-bit lane.
+///
+/// Safe wrapper around [`arch::u64x2_load_extend_u32x2`].
+#[inline]
+#[target_feature(enable = "simd128")]
+pub fn u64x2_load_extend_u32x2<T: Is8BytesCellUnaligned>(t: &T) -> v128 {
+    unsafe { arch::u64x2_load_extend_u32x2(ptr::from_ref(t).cast()) }
+}
+
+/// Loads a `v128` vector from the given heap address.
+///
+/// Safe wrapper around [`arch::v128_load`].
+#[inline]
+#[target_feature(enable = "simd128")]
+pub fn v128_load<T: Is16BytesCellUnaligned>(t: &T) -> v128 {
+    unsafe { arch::v128_load(ptr::from_ref(t).cast()) }
+}
+
+/// Loads a single element and splats to all lanes of a `v128` vector.
+///
+/// Safe wrapper around [`arch::v128_load8_splat`].
+#[inline]
+#[target_feature(enable = "simd128")]
+pub fn v128_load8_splat<T: Is1ByteCellUnaligned>(t: &T) -> v128 {
+    unsafe { arch::v128_load8_splat(ptr::from_ref(t).cast()) }
+}
+
+/// Loads a single element and splats to all lanes of a `v128` vector.
+///
+/// Safe wrapper around [`arch::v128_load16_splat`].
+#[inline]
+#[target_feature(enable = "simd128")]
+pub fn v128_load16_splat<T: Is2BytesCellUnaligned>(t: &T) -> v128 {
+    unsafe { arch::v128_load16_splat(ptr::from_ref(t).cast()) }
+}
+
+/// Loads a single element and splats to all lanes of a `v128` vector.
+///
+/// Safe wrapper around [`arch::v128_load32_splat`].
+#[inline]
+#[target_feature(enable = "simd128")]
+pub fn v128_load32_splat<T: Is4BytesCellUnaligned>(t: &T) -> v128 {
+    unsafe { arch::v128_load32_splat(ptr::from_ref(t).cast()) }
+}
+
+/// Loads a 32-bit element into the low bits of the vector and sets all other bits to zero.
+///
+/// Safe wrapper around [`arch::v128_load32_zero`].
+#[inline]
+#[target_feature(enable = "simd128")]
+pub fn v128_load32_zero<T: Is4BytesCellUnaligned>(t: &T) -> v128 {
+    unsafe { arch::v128_load32_zero(ptr::from_ref(t).cast()) }
+}
+
+/// Loads a single element and splats to all lanes of a `v128` vector.
+///
+/// Safe wrapper around [`arch::v128_load64_splat`].
+#[inline]
+#[target_feature(enable = "simd128")]
+pub fn v128_load64_splat<T: Is8BytesCellUnaligned>(t: &T) -> v128 {
+    unsafe { arch::v128_load64_splat(ptr::from_ref(t).cast()) }
+}
+
+/// Loads a 64-bit element into the low bits of the vector and sets all other bits to zero.
+///
+/// Safe wrapper around [`arch::v128_load64_zero`].
+#[inline]
+#[target_feature(enable = "simd128")]
+pub fn v128_load64_zero<T: Is8BytesCellUnaligned>(t: &T) -> v128 {
+    unsafe { arch::v128_load64_zero(ptr::from_ref(t).cast()) }
+}
+
+/// Stores a `v128` vector to the given heap address.
+///
+/// Safe wrapper around [`arch::v128_store`].
+#[inline]
+#[target_feature(enable = "simd128")]
+pub fn v128_store<T: Is16BytesCellUnaligned>(t: &T, v: v128) {
+    unsafe { arch::v128_store(ptr::from_ref(t).cast_mut().cast(), v) }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::arch::wasm32::{self as arch, v128};
+    use core::cell::Cell;
+
+    use crate::Register;
+
+    fn assert_v128_bytes<const N: usize>(val: v128, data: &[[u8; N]]) {
+        assert_eq!(val.to_array::<[u8; 16]>(), unsafe {
+            core::slice::from_raw_parts(data.as_ptr() as *const u8, data.len() * N)
+        });
+    }
+
+    #[test]
+    #[cfg_attr(not(target_feature = "simd128"), ignore)]
+    fn test_v128_load_store_overlap() {
+        #[target_feature(enable = "simd128")]
+        fn test() {
+            let mut a: [u32; 6] = core::array::from_fn(|i| i as u32);
+            let mut result = a;
+            result.copy_within(0..4, 2);
+
+            let val = Cell::from_mut(&mut a[..]).as_slice_of_cells();
+            let load: &[_; 4] = val[..4].try_into().unwrap();
+            let store: &[_; 4] = val[2..].try_into().unwrap();
+
+            let v = super::v128_load(load);
+            super::v128_store(store, v);
+
+            assert_eq!(a, result);
+        }
+
+        test()
+    }
+
+    #[test]
+    #[cfg_attr(not(target_feature = "simd128"), ignore)]
+    fn test_v128_store_cell_of_array() {
+        #[target_feature(enable = "simd128")]
+        fn test() {
+            let mut into = [42u16; 8];
+            let v = arch::u16x8_splat(1);
+            super::v128_store(Cell::from_mut(&mut into), v);
+            assert_eq!(into, [1u16; 8]);
+        }
+
+        test()
+    }
+
+    #[test]
+    #[cfg_attr(not(target_feature = "simd128"), ignore)]
+    fn test_v128_load8_splat() {
+        #[target_feature(enable = "simd128")]
+        fn test(a: &Cell<u8>) {
+            let v = super::v128_load8_splat(a);
+            assert_v128_bytes(v, &[[42u8]; 16]);
+        }
+
+        test(&Cell::new(42));
+    }
+
+    #[test]
+    #[cfg_attr(not(target_feature = "simd128"), ignore)]
+    fn test_v128_load16_splat() {
+        #[target_feature(enable = "simd128")]
+        fn test(a: &Cell<u16>) {
+            let v = super::v128_load16_splat(a);
+            assert_v128_bytes(v, &[42u16.to_ne_bytes(); 8]);
+        }
+
+        test(&Cell::new(42));
+    }
+
+    #[test]
+    #[cfg_attr(not(target_feature = "simd128"), ignore)]
+    fn test_v128_load32_zero() {
+        #[target_feature(enable = "simd128")]
+        fn test(a: &[Cell<u8>; 4]) {
+            let v = super::v128_load32_zero(a);
+            assert_v128_bytes(v, &[[1, 2, 3, 4], [0; 4], [0; 4], [0; 4]]);
+        }
+
+        let mut a = [1u8, 2, 3, 4];
+        let a = Cell::from_mut(&mut a[..]).as_slice_of_cells();
+        test(a.try_into().unwrap());
+    }
+
+    #[test]
+    #[cfg_attr(not(target_feature = "simd128"), ignore)]
+    fn test_v128_load64_splat() {
+        #[target_feature(enable = "simd128")]
+        fn test(a: &Cell<u64>) {
+            let v = super::v128_load64_splat(a);
+            assert_v128_bytes(v, &[42u64.to_ne_bytes(); 2]);
+        }
+
+        test(&Cell::new(42));
+    }
+
+    #[test]
+    #[cfg_attr(not(target_feature = "simd128"), ignore)]
+    fn test_v128_load64_zero() {
+        #[target_feature(enable = "simd128")]
+        fn test(a: &Cell<[u32; 2]>) {
+            let v = super::v128_load64_zero(a);
+            assert_v128_bytes(v, &[1u32.to_ne_bytes(), 2u32.to_ne_bytes(), [0; 4], [0; 4]]);
+        }
+
+        test(&Cell::new([1, 2]));
+    }
+
+    #[test]
+    #[cfg_attr(not(target_feature = "simd128"), ignore)]
+    fn test_i16x8_load_extend_i8x8() {
+        #[target_feature(enable = "simd128")]
+        fn test(a: &Cell<[i8; 8]>) {
+            let v = super::i16x8_load_extend_i8x8(a);
+            assert_v128_bytes(v, &a.get().map(|i| (i as i16).to_ne_bytes()));
+        }
+
+        test(&Cell::new(core::array::from_fn(|i| -(i as i8))));
+    }
+
+    #[test]
+    #[cfg_attr(not(target_feature = "simd128"), ignore)]
+    fn test_u32x4_load_extend_u16x4() {
+        #[target_feature(enable = "simd128")]
+        fn test(a: &[Cell<u16>; 4]) {
+            let v = super::u32x4_load_extend_u16x4(a);
+            assert_v128_bytes(v, &a.each_ref().map(|i| (i.get() as u32).to_ne_bytes()));
+        }
+
+        let mut a = [u16::MAX, 1, 2, 3];
+        let a = Cell::from_mut(&mut a[..]).as_slice_of_cells();
+        test(a.try_into().unwrap());
+    }
+}
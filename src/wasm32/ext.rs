@@ -0,0 +1,176 @@
+//! Extension traits with method syntax for the unaligned loads and stores.
+//!
+//! Each trait is implemented for every type of the bound it is named after, so generic code
+//! bounded by [`Is16BytesUnaligned`] can call `data.v128_store(simd, v)` without naming the
+//! wrapper. The methods take the [`Simd128`] token, which mirrors the other platforms and makes
+//! code generic over the memory types usable from functions without the `simd128` feature.
+//!
+//! ```rust
+//! # #[cfg(target_feature = "simd128")]
+//! # {
+//! use safe_unaligned_simd::wasm32::{ext::*, token::Simd128, Is16BytesUnaligned};
+//!
+//! fn copy<T: Is16BytesUnaligned>(simd: Simd128, from: &T, into: &mut T) {
+//!     into.v128_store(simd, from.v128_load(simd));
+//! }
+//!
+//! let mut out = [0u16; 8];
+//! copy(Simd128::new(), &[1, 2, 3, 4, 5, 6, 7, 8], &mut out);
+//! assert_eq!(out, [1, 2, 3, 4, 5, 6, 7, 8]);
+//! # }
+//! ```
+use core::arch::wasm32::v128;
+
+use super::token::Simd128;
+use super::{
+    Is1ByteCellUnaligned, Is1ByteReadable, Is2BytesCellUnaligned, Is2BytesReadable,
+    Is4BytesCellUnaligned, Is4BytesReadable, Is8BytesCellUnaligned, Is8BytesReadable,
+    Is16BytesCellUnaligned, Is16BytesReadable, Is16BytesUnaligned,
+};
+
+ext_trait! {
+    /// Loads from [`Is1ByteReadable`] types.
+    pub trait Is1ByteReadableExt: Is1ByteReadable {
+        /// Calls [`v128_load8_splat`](super::v128_load8_splat) on `self`.
+        fn v128_load8_splat(&self, token: Simd128) -> v128 = super::v128_load8_splat;
+    }
+}
+
+ext_trait! {
+    /// Loads from [`Is2BytesReadable`] types.
+    pub trait Is2BytesReadableExt: Is2BytesReadable {
+        /// Calls [`v128_load16_splat`](super::v128_load16_splat) on `self`.
+        fn v128_load16_splat(&self, token: Simd128) -> v128 = super::v128_load16_splat;
+    }
+}
+
+ext_trait! {
+    /// Loads from [`Is4BytesReadable`] types.
+    pub trait Is4BytesReadableExt: Is4BytesReadable {
+        /// Calls [`v128_load32_splat`](super::v128_load32_splat) on `self`.
+        fn v128_load32_splat(&self, token: Simd128) -> v128 = super::v128_load32_splat;
+        /// Calls [`v128_load32_zero`](super::v128_load32_zero) on `self`.
+        fn v128_load32_zero(&self, token: Simd128) -> v128 = super::v128_load32_zero;
+    }
+}
+
+ext_trait! {
+    /// Loads from [`Is8BytesReadable`] types.
+    pub trait Is8BytesReadableExt: Is8BytesReadable {
+        /// Calls [`v128_load64_splat`](super::v128_load64_splat) on `self`.
+        fn v128_load64_splat(&self, token: Simd128) -> v128 = super::v128_load64_splat;
+        /// Calls [`v128_load64_zero`](super::v128_load64_zero) on `self`.
+        fn v128_load64_zero(&self, token: Simd128) -> v128 = super::v128_load64_zero;
+        /// Calls [`i16x8_load_extend_i8x8`](super::i16x8_load_extend_i8x8) on `self`.
+        fn i16x8_load_extend_i8x8(&self, token: Simd128) -> v128 = super::i16x8_load_extend_i8x8;
+        /// Calls [`i16x8_load_extend_u8x8`](super::i16x8_load_extend_u8x8) on `self`.
+        fn i16x8_load_extend_u8x8(&self, token: Simd128) -> v128 = super::i16x8_load_extend_u8x8;
+        /// Calls [`i32x4_load_extend_i16x4`](super::i32x4_load_extend_i16x4) on `self`.
+        fn i32x4_load_extend_i16x4(&self, token: Simd128) -> v128 =
+            super::i32x4_load_extend_i16x4;
+        /// Calls [`i32x4_load_extend_u16x4`](super::i32x4_load_extend_u16x4) on `self`.
+        fn i32x4_load_extend_u16x4(&self, token: Simd128) -> v128 =
+            super::i32x4_load_extend_u16x4;
+        /// Calls [`i64x2_load_extend_i32x2`](super::i64x2_load_extend_i32x2) on `self`.
+        fn i64x2_load_extend_i32x2(&self, token: Simd128) -> v128 =
+            super::i64x2_load_extend_i32x2;
+        /// Calls [`i64x2_load_extend_u32x2`](super::i64x2_load_extend_u32x2) on `self`.
+        fn i64x2_load_extend_u32x2(&self, token: Simd128) -> v128 =
+            super::i64x2_load_extend_u32x2;
+        /// Calls [`u16x8_load_extend_u8x8`](super::u16x8_load_extend_u8x8) on `self`.
+        fn u16x8_load_extend_u8x8(&self, token: Simd128) -> v128 = super::u16x8_load_extend_u8x8;
+        /// Calls [`u32x4_load_extend_u16x4`](super::u32x4_load_extend_u16x4) on `self`.
+        fn u32x4_load_extend_u16x4(&self, token: Simd128) -> v128 =
+            super::u32x4_load_extend_u16x4;
+        /// Calls [`u64x2_load_extend_u32x2`](super::u64x2_load_extend_u32x2) on `self`.
+        fn u64x2_load_extend_u32x2(&self, token: Simd128) -> v128 =
+            super::u64x2_load_extend_u32x2;
+    }
+}
+
+ext_trait! {
+    /// Loads from [`Is16BytesReadable`] types.
+    pub trait Is16BytesReadableExt: Is16BytesReadable {
+        /// Calls [`v128_load`](super::v128_load) on `self`.
+        fn v128_load(&self, token: Simd128) -> v128 = super::v128_load;
+    }
+}
+
+ext_trait! {
+    /// Stores into [`Is16BytesUnaligned`] types.
+    pub trait Is16BytesUnalignedExt: Is16BytesUnaligned {
+        /// Calls [`v128_store`](super::v128_store) on `self`.
+        fn v128_store(&mut self, token: Simd128, v: v128) = super::v128_store;
+    }
+}
+
+ext_trait! {
+    /// Loads from [`Is1ByteCellUnaligned`] types.
+    pub trait Is1ByteCellUnalignedExt: Is1ByteCellUnaligned {
+        /// Calls [`cell::v128_load8_splat`](super::cell::v128_load8_splat) on `self`.
+        fn v128_load8_splat(&self, token: Simd128) -> v128 = super::cell::v128_load8_splat;
+    }
+}
+
+ext_trait! {
+    /// Loads from [`Is2BytesCellUnaligned`] types.
+    pub trait Is2BytesCellUnalignedExt: Is2BytesCellUnaligned {
+        /// Calls [`cell::v128_load16_splat`](super::cell::v128_load16_splat) on `self`.
+        fn v128_load16_splat(&self, token: Simd128) -> v128 = super::cell::v128_load16_splat;
+    }
+}
+
+ext_trait! {
+    /// Loads from [`Is4BytesCellUnaligned`] types.
+    pub trait Is4BytesCellUnalignedExt: Is4BytesCellUnaligned {
+        /// Calls [`cell::v128_load32_splat`](super::cell::v128_load32_splat) on `self`.
+        fn v128_load32_splat(&self, token: Simd128) -> v128 = super::cell::v128_load32_splat;
+        /// Calls [`cell::v128_load32_zero`](super::cell::v128_load32_zero) on `self`.
+        fn v128_load32_zero(&self, token: Simd128) -> v128 = super::cell::v128_load32_zero;
+    }
+}
+
+ext_trait! {
+    /// Loads from [`Is8BytesCellUnaligned`] types.
+    pub trait Is8BytesCellUnalignedExt: Is8BytesCellUnaligned {
+        /// Calls [`cell::v128_load64_splat`](super::cell::v128_load64_splat) on `self`.
+        fn v128_load64_splat(&self, token: Simd128) -> v128 = super::cell::v128_load64_splat;
+        /// Calls [`cell::v128_load64_zero`](super::cell::v128_load64_zero) on `self`.
+        fn v128_load64_zero(&self, token: Simd128) -> v128 = super::cell::v128_load64_zero;
+    }
+}
+
+ext_trait! {
+    /// Loads from and stores into [`Is16BytesCellUnaligned`] types.
+    pub trait Is16BytesCellUnalignedExt: Is16BytesCellUnaligned {
+        /// Calls [`cell::v128_load`](super::cell::v128_load) on `self`.
+        fn v128_load(&self, token: Simd128) -> v128 = super::cell::v128_load;
+        /// Calls [`cell::v128_store`](super::cell::v128_store) on `self`.
+        fn v128_store(&self, token: Simd128, v: v128) = super::cell::v128_store;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    #[cfg(target_feature = "simd128")]
+    fn test_ext() {
+        use core::arch::wasm32::u8x16_extract_lane;
+        use core::cell::Cell;
+
+        use super::*;
+
+        let simd = Simd128::new();
+
+        let mut a = [0u8; 16];
+        a.v128_store(simd, [1u32, 2, 3, 4].v128_load(simd));
+        assert_eq!(a, [1u32, 2, 3, 4].map(u32::to_ne_bytes).as_flattened());
+
+        let v = [7u8, 8].v128_load16_splat(simd);
+        assert_eq!(u8x16_extract_lane::<15>(v), 8);
+
+        let c = Cell::new([0u8; 16]);
+        c.v128_store(simd, [3u8; 16].v128_load(simd));
+        assert_eq!(c.get(), [3; 16]);
+    }
+}
@@ -0,0 +1,77 @@
+//! Functions returning and accepting [`Simd`] vectors, enabled by the `nightly` feature.
+//!
+//! These wrappers perform the load or store of the same name in the parent
+//! module and convert between `v128` and the portable vector, so `core::simd`
+//! code does not need to transmute at the boundary. They are generic over the
+//! lane type.
+//!
+//! ```rust
+//! #![feature(portable_simd)]
+//! # #[cfg(target_feature = "simd128")]
+//! # scale();
+//! use core::simd::Simd;
+//!
+//! use safe_unaligned_simd::wasm32::simd;
+//!
+//! #[target_feature(enable = "simd128")]
+//! fn scale() {
+//!     let mut a = [1u16, 2, 3, 4, 5, 6, 7, 8];
+//!
+//!     let v = simd::v128_load(&a) * Simd::splat(3);
+//!     simd::v128_store(&mut a, v);
+//!
+//!     assert_eq!(a, [3, 6, 9, 12, 15, 18, 21, 24]);
+//! }
+//! ```
+use core::simd::{Simd, SimdElement};
+
+use super::{Is16BytesReadable, Is16BytesUnaligned};
+use crate::common_simd::{from_register, to_register};
+
+/// Loads an array of any lane type into a vector.
+///
+/// See [`v128_load`](super::v128_load).
+#[inline]
+#[target_feature(enable = "simd128")]
+pub fn v128_load<T: SimdElement, const N: usize>(t: &[T; N]) -> Simd<T, N>
+where
+    [T; N]: Is16BytesReadable,
+{
+    from_register(super::v128_load(t))
+}
+
+/// Stores a vector of any lane type into an array.
+///
+/// See [`v128_store`](super::v128_store).
+#[inline]
+#[target_feature(enable = "simd128")]
+pub fn v128_store<T: SimdElement, const N: usize>(t: &mut [T; N], v: Simd<T, N>)
+where
+    [T; N]: Is16BytesUnaligned,
+{
+    super::v128_store(t, to_register(v))
+}
+
+#[cfg(test)]
+mod tests {
+    use core::simd::Simd;
+
+    #[test]
+    #[cfg_attr(not(target_feature = "simd128"), ignore)]
+    fn test_v128_load_store() {
+        #[target_feature(enable = "simd128")]
+        fn test() {
+            let mut a = [1.0f32, 2.0, 3.0, 4.0];
+            let v = super::v128_load(&a);
+            assert_eq!(v, Simd::from_array(a));
+
+            super::v128_store(&mut a, v * Simd::splat(2.0));
+            assert_eq!(a, [2.0, 4.0, 6.0, 8.0]);
+
+            let b: [u8; 16] = core::array::from_fn(|i| i as u8);
+            assert_eq!(super::v128_load(&b), Simd::from_array(b));
+        }
+
+        test()
+    }
+}
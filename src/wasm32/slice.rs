@@ -0,0 +1,398 @@
+//! Slice-taking companions of [`v128_load`](super::v128_load) and
+//! [`v128_store`](super::v128_store).
+//!
+//! Each wrapper comes in three entry points that access the start of a slice
+//! instead of a reference:
+//!
+//! - `try_*` returns `None` if the slice is too short,
+//! - `*_slice` panics if the slice is too short,
+//! - `*_at` accesses `slice[idx..]` and panics if that is too short.
+//!
+//! The wrappers are generic over the element type, bounded by
+//! [`ReadableElement`] for loads and [`UnalignedElement`] for stores, and
+//! check the length of the slice in bytes with a single comparison.
+//!
+//! [`chunks_v128`] iterates over the vectors loaded from consecutive chunks
+//! of a slice and exposes the remainder, like
+//! [`slice::chunks_exact`](prim@slice#method.chunks_exact). Its `_mut`
+//! counterpart yields a [`ChunkMut`] per chunk, which is loaded from and
+//! stored back in place.
+//!
+//! [`v128_load_partial`] loads the first elements of a slice that may be
+//! shorter than a vector and fills the remaining lanes with zero, or with the
+//! lanes of another vector for [`v128_load_partial_fill`].
+//! [`v128_store_partial`] writes only as many bytes as the slice holds.
+//! Slices of fewer bytes are read and written with a few overlapping scalar
+//! accesses, as simd128 has no masked loads.
+//!
+//! For idempotent kernels the fastest way to handle the tail is often to
+//! process one final window that ends at the end of the slice and overlaps
+//! the previous vector, which [`for_each_overlapping_v128`] and
+//! [`map_overlapping_v128`] drive.
+//!
+//! ```rust
+//! # #[cfg(target_feature = "simd128")]
+//! # sum_pairs();
+//! use core::arch::wasm32::u16x8_add;
+//!
+//! use safe_unaligned_simd::wasm32::slice;
+//!
+//! #[target_feature(enable = "simd128")]
+//! fn sum_pairs() {
+//!     let data: Vec<u16> = (0..20).collect();
+//!     let mut out = [0u16; 8];
+//!
+//!     let a = slice::v128_load_slice(&data);
+//!     let b = slice::v128_load_at(&data, 8);
+//!     slice::v128_store_slice(&mut out, u16x8_add(a, b));
+//!
+//!     assert_eq!(out, [8, 10, 12, 14, 16, 18, 20, 22]);
+//!     assert!(slice::try_v128_load(&data[16..]).is_none());
+//! }
+//! ```
+use core::arch::wasm32::{self as arch, v128};
+use core::cell::Cell;
+
+use super::{ReadableElement, UnalignedElement};
+pub use crate::common_slice::{ChunkMut, Chunks, ChunksMut};
+use crate::common_slice::{
+    LoadChunk, StoreChunk, as_bytes, as_bytes_mut, bytes, bytes_mut, elements, first_bytes,
+    first_bytes_mut, read_partial, too_short_bytes, write_partial,
+};
+
+/// Loads a `v128` vector from the start of `t` with [`v128_load`](super::v128_load).
+///
+/// Returns `None` if the slice covers fewer than 16 bytes.
+#[inline]
+#[target_feature(enable = "simd128")]
+pub fn try_v128_load<T: ReadableElement>(t: &[T]) -> Option<v128> {
+    let bytes = first_bytes::<T, 16>(t)?;
+    Some(super::v128_load(bytes))
+}
+
+/// Loads a `v128` vector from the start of `t` with [`v128_load`](super::v128_load).
+///
+/// # Panics
+///
+/// Panics if the slice covers fewer than 16 bytes.
+#[inline]
+#[track_caller]
+#[target_feature(enable = "simd128")]
+pub fn v128_load_slice<T: ReadableElement>(t: &[T]) -> v128 {
+    match first_bytes::<T, 16>(t) {
+        Some(bytes) => super::v128_load(bytes),
+        None => too_short_bytes(16, size_of_val(t)),
+    }
+}
+
+/// Loads a `v128` vector from `t[idx..]` with [`v128_load`](super::v128_load).
+///
+/// # Panics
+///
+/// Panics if `idx` is out of bounds or `t[idx..]` covers fewer than 16 bytes.
+#[inline]
+#[track_caller]
+#[target_feature(enable = "simd128")]
+pub fn v128_load_at<T: ReadableElement>(t: &[T], idx: usize) -> v128 {
+    v128_load_slice(&t[idx..])
+}
+
+/// Stores a `v128` vector to the start of `t` with [`v128_store`](super::v128_store).
+///
+/// Returns `None` without storing if the slice covers fewer than 16 bytes.
+#[inline]
+#[target_feature(enable = "simd128")]
+pub fn try_v128_store<T: UnalignedElement>(t: &mut [T], v: v128) -> Option<()> {
+    let bytes = first_bytes_mut::<T, 16>(t)?;
+    super::v128_store(bytes, v);
+    Some(())
+}
+
+/// Stores a `v128` vector to the start of `t` with [`v128_store`](super::v128_store).
+///
+/// # Panics
+///
+/// Panics if the slice covers fewer than 16 bytes.
+#[inline]
+#[track_caller]
+#[target_feature(enable = "simd128")]
+pub fn v128_store_slice<T: UnalignedElement>(t: &mut [T], v: v128) {
+    let len = size_of_val(t);
+    match first_bytes_mut::<T, 16>(t) {
+        Some(bytes) => super::v128_store(bytes, v),
+        None => too_short_bytes(16, len),
+    }
+}
+
+/// Stores a `v128` vector to `t[idx..]` with [`v128_store`](super::v128_store).
+///
+/// # Panics
+///
+/// Panics if `idx` is out of bounds or `t[idx..]` covers fewer than 16 bytes.
+#[inline]
+#[track_caller]
+#[target_feature(enable = "simd128")]
+pub fn v128_store_at<T: UnalignedElement>(t: &mut [T], idx: usize, v: v128) {
+    v128_store_slice(&mut t[idx..], v)
+}
+
+/// Loads the first 16 bytes of `t`, filling the lanes past its end with zero.
+///
+/// Slices of 16 bytes or more are loaded with [`v128_load`](super::v128_load), shorter ones with
+/// a few overlapping scalar loads.
+#[inline]
+#[target_feature(enable = "simd128")]
+pub fn v128_load_partial<T: ReadableElement>(t: &[T]) -> v128 {
+    let bytes = as_bytes(t);
+    match bytes.first_chunk::<16>() {
+        Some(bytes) => super::v128_load(bytes),
+        None => super::v128_load(&read_partial(bytes)),
+    }
+}
+
+/// Loads the first 16 bytes of `t`, taking the bytes past its end from `fill`.
+///
+/// See [`v128_load_partial`].
+#[inline]
+#[target_feature(enable = "simd128")]
+pub fn v128_load_partial_fill<T: ReadableElement>(t: &[T], fill: v128) -> v128 {
+    let len = size_of_val(t).min(16) as u8;
+    let lanes = arch::u8x16(0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15);
+    let loaded = arch::u8x16_lt(lanes, arch::u8x16_splat(len));
+    arch::v128_bitselect(v128_load_partial(t), fill, loaded)
+}
+
+/// Stores the low bytes of `v` to `t`, as many as it holds up to 16.
+///
+/// Slices of 16 bytes or more are stored to with [`v128_store`](super::v128_store), shorter ones
+/// with a few overlapping scalar stores.
+#[inline]
+#[target_feature(enable = "simd128")]
+pub fn v128_store_partial<T: UnalignedElement>(t: &mut [T], v: v128) {
+    let bytes = as_bytes_mut(t);
+    if let Some(bytes) = bytes.first_chunk_mut::<16>() {
+        return super::v128_store(bytes, v);
+    }
+
+    let mut words = [0u64; 2];
+    super::v128_store(&mut words, v);
+    write_partial(bytes, words);
+}
+
+impl<T: ReadableElement> LoadChunk<T> for v128 {
+    const LEN: usize = elements::<T>(16);
+
+    #[inline(always)]
+    unsafe fn load(t: &[T]) -> Self {
+        super::v128_load(bytes::<T, 16>(t))
+    }
+}
+
+impl<T: UnalignedElement> StoreChunk<T> for v128 {
+    #[inline(always)]
+    unsafe fn store(t: &mut [T], v: Self) {
+        super::v128_store(bytes_mut::<T, 16>(t), v)
+    }
+}
+
+/// Iterates over the `v128` vectors loaded from chunks of 16 bytes of `t` with
+/// [`v128_load`](super::v128_load).
+///
+/// The elements that do not fill a vector are available from
+/// [`Chunks::remainder`]. The size of `T` must divide 16, which is checked at
+/// compile time.
+#[inline]
+#[target_feature(enable = "simd128")]
+pub fn chunks_v128<T: ReadableElement>(t: &[T]) -> Chunks<'_, T, v128> {
+    // Safety: The target feature is enabled.
+    unsafe { Chunks::new(t) }
+}
+
+/// Iterates over chunks of 16 bytes of `t` that a `v128` vector is loaded from
+/// and stored to with [`v128_load`](super::v128_load) and
+/// [`v128_store`](super::v128_store).
+///
+/// The elements that do not fill a vector are available from
+/// [`ChunksMut::into_remainder`]. The size of `T` must divide 16, which is
+/// checked at compile time.
+#[inline]
+#[target_feature(enable = "simd128")]
+pub fn chunks_v128_mut<T: UnalignedElement>(t: &mut [T]) -> ChunksMut<'_, T, v128> {
+    // Safety: The target feature is enabled.
+    unsafe { ChunksMut::new(t) }
+}
+
+/// Calls `f` with each `v128` of `t` and one final window that ends at the end of the slice.
+///
+/// The final window overlaps the previous one if the slice does not divide into whole vectors, so
+/// the kernel must be idempotent, such as a reduction with `or`, `and`, `min` or `max`. `f` also
+/// receives the element offset of each window.
+///
+/// Returns `false` without calling `f` if the slice is shorter than a vector. The size of `T` must
+/// divide 16, which is checked at compile time.
+#[inline]
+#[target_feature(enable = "simd128")]
+pub fn for_each_overlapping_v128<T: ReadableElement>(
+    t: &[T],
+    mut f: impl FnMut(usize, v128),
+) -> bool {
+    let len = <v128 as LoadChunk<T>>::LEN;
+    let Some(start) = t.len().checked_sub(len) else {
+        return false;
+    };
+
+    let mut chunks = chunks_v128(t);
+    for (i, v) in (&mut chunks).enumerate() {
+        f(i * len, v);
+    }
+    if !chunks.remainder().is_empty() {
+        f(start, super::v128_load(bytes::<T, 16>(&t[start..])));
+    }
+    true
+}
+
+/// Replaces each `v128` of `t` with the result of `f`, including one final window that ends at the
+/// end of the slice.
+///
+/// The final window overlaps the previous one if the slice does not divide into whole vectors, and
+/// the overlap is processed again after it was stored, so the kernel must be idempotent, such as
+/// case conversion. The windows are accessed through [`cell::v128_load`](super::cell::v128_load)
+/// and [`cell::v128_store`](super::cell::v128_store).
+///
+/// Returns `false` without calling `f` if the slice is shorter than a vector. The size of `T` must
+/// divide 16, which is checked at compile time.
+#[inline]
+#[target_feature(enable = "simd128")]
+pub fn map_overlapping_v128<T: UnalignedElement>(
+    t: &mut [T],
+    mut f: impl FnMut(v128) -> v128,
+) -> bool {
+    const { elements::<T>(16) };
+    let cells = Cell::from_mut(as_bytes_mut(t)).as_slice_of_cells();
+    let Some(last) = cells.last_chunk::<16>() else {
+        return false;
+    };
+
+    let (chunks, rest) = cells.as_chunks::<16>();
+    for chunk in chunks {
+        super::cell::v128_store(chunk, f(super::cell::v128_load(chunk)));
+    }
+    if !rest.is_empty() {
+        super::cell::v128_store(last, f(super::cell::v128_load(last)));
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use core::arch::wasm32 as arch;
+
+    #[test]
+    #[cfg_attr(not(target_feature = "simd128"), ignore)]
+    fn test_v128_load_store() {
+        #[target_feature(enable = "simd128")]
+        fn test() {
+            let a: [u32; 5] = core::array::from_fn(|i| i as u32);
+            let mut b = [0u8; 20];
+
+            assert!(super::try_v128_load(&a[2..]).is_none());
+            assert!(super::try_v128_store(&mut b[5..], arch::u8x16_splat(0)).is_none());
+
+            super::v128_store_at(&mut b, 4, super::v128_load_at(&a, 1));
+            assert_eq!(b[..4], [0; 4]);
+            assert_eq!(b[4..], [1, 0, 0, 0, 2, 0, 0, 0, 3, 0, 0, 0, 4, 0, 0, 0]);
+        }
+
+        test()
+    }
+
+    #[test]
+    #[cfg_attr(not(target_feature = "simd128"), ignore)]
+    fn test_chunks_v128() {
+        #[target_feature(enable = "simd128")]
+        fn test() {
+            let mut a: [u32; 10] = core::array::from_fn(|i| i as u32);
+
+            let chunks = super::chunks_v128(&a);
+            assert_eq!(chunks.len(), 2);
+            assert_eq!(chunks.remainder(), [8, 9]);
+
+            let mut chunks = super::chunks_v128_mut(&mut a);
+            for mut chunk in &mut chunks {
+                chunk.update(|v| arch::i32x4_add(v, v));
+            }
+            assert_eq!(chunks.into_remainder(), [8, 9]);
+            assert_eq!(a, [0, 2, 4, 6, 8, 10, 12, 14, 8, 9]);
+        }
+
+        test()
+    }
+
+    #[test]
+    #[cfg_attr(not(target_feature = "simd128"), ignore)]
+    fn test_v128_load_store_partial() {
+        #[target_feature(enable = "simd128")]
+        fn test() {
+            let a: [u8; 17] = core::array::from_fn(|i| i as u8 + 1);
+            let fill = arch::u8x16_splat(0xff);
+
+            for n in 0..=17 {
+                let len = n.min(16);
+                let mut expected = [0u8; 16];
+                expected[..len].copy_from_slice(&a[..len]);
+
+                let mut b = [0u8; 16];
+                super::v128_store_slice(&mut b, super::v128_load_partial(&a[..n]));
+                assert_eq!(b, expected, "{n} bytes");
+
+                expected[len..].fill(0xff);
+                let v = super::v128_load_partial_fill(&a[..n], fill);
+                super::v128_store_slice(&mut b, v);
+                assert_eq!(b, expected, "{n} bytes");
+
+                let mut c = [0u8; 17];
+                super::v128_store_partial(&mut c[..n], v);
+                assert_eq!(c[..len], a[..len], "{n} bytes");
+                assert!(c[len..].iter().all(|&b| b == 0), "{n} bytes");
+            }
+        }
+
+        test()
+    }
+
+    #[test]
+    #[cfg_attr(not(target_feature = "simd128"), ignore)]
+    fn test_overlapping_v128() {
+        #[target_feature(enable = "simd128")]
+        fn test() {
+            let mut a = [1u16; 13];
+            assert!(super::map_overlapping_v128(&mut a, |v| arch::u16x8_max(
+                v,
+                arch::u16x8_splat(2)
+            )));
+            assert_eq!(a, [2; 13]);
+
+            let mut offsets = Vec::new();
+            assert!(super::for_each_overlapping_v128(&a, |offset, _| offsets.push(offset)));
+            assert_eq!(offsets, [0, 5]);
+            assert!(!super::for_each_overlapping_v128(
+                &a[..7],
+                |_, _| unreachable!()
+            ));
+        }
+
+        test()
+    }
+
+    #[test]
+    #[cfg_attr(not(target_feature = "simd128"), ignore)]
+    #[should_panic = "access of 16 bytes out of range for slice of 12 bytes"]
+    fn test_v128_load_slice_panics() {
+        #[target_feature(enable = "simd128")]
+        fn test() {
+            super::v128_load_slice(&[0u32; 3]);
+        }
+
+        test()
+    }
+}
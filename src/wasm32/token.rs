@@ -0,0 +1,53 @@
+//! Capability token for the `simd128` target feature.
+//!
+//! A [`Simd128`] token can only be obtained in builds that enable `simd128`, as WebAssembly has
+//! no runtime feature detection. Its methods call the wrappers of the parent module and are
+//! mostly useful for code that is generic over the tokens of several platforms.
+//!
+//! ```rust
+//! # #[cfg(target_feature = "simd128")]
+//! # {
+//! use safe_unaligned_simd::wasm32::token::Simd128;
+//!
+//! let simd = Simd128::new();
+//! let mut out = [0u8; 16];
+//! let v = simd.v128_load(&[1u8; 16]);
+//! simd.v128_store(&mut out, v);
+//! assert_eq!(out, [1; 16]);
+//! # }
+//! ```
+token! {
+    /// Proof that the `simd128` target feature is available.
+    pub struct Simd128: "simd128";
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    #[cfg(target_feature = "simd128")]
+    fn test_simd128_token() {
+        use super::Simd128;
+
+        let simd = Simd128::new();
+        assert_eq!(Simd128::try_new(), Some(simd));
+
+        let a: [u32; 4] = core::array::from_fn(|i| i as u32);
+        let mut b = [0u32; 4];
+        simd.v128_store(&mut b, simd.v128_load(&a));
+        assert_eq!(a, b);
+
+        let mut c = 0u32;
+        simd.v128_store32_lane::<2, _>(simd.v128_load(&a), &mut c);
+        assert_eq!(c, 2);
+
+        let mut d = [0u32; 4];
+        let mut e = [0u32; 4];
+        let v = simd.v128_load32_lane::<1, _>(simd.v128_load(&a), &7u32);
+        simd.v128_store(&mut d, v);
+        // `simd128` is enabled at compile time, so the wrappers can be called directly.
+        let v = crate::wasm32::v128_load32_lane::<1, _>(crate::wasm32::v128_load(&a), &7u32);
+        crate::wasm32::v128_store(&mut e, v);
+        assert_eq!(d, e);
+        assert_eq!(d, [0, 7, 2, 3]);
+    }
+}
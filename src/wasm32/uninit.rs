@@ -0,0 +1,164 @@
+//! Store wrappers that write into [`MaybeUninit`] destinations.
+//!
+//! Each function performs the store of the same name in the parent module and
+//! returns the now initialized reference, so output buffers do not need to be
+//! zeroed before they are written. Use [`array_mut`] to pass arrays of
+//! uninitialized elements and [`chunks_mut`] to store into the spare capacity
+//! of a vector.
+//!
+//! ```rust
+//! # #[cfg(target_feature = "simd128")]
+//! # encode();
+//! use core::arch::wasm32::u8x16_splat;
+//! use core::mem::MaybeUninit;
+//!
+//! use safe_unaligned_simd::wasm32::uninit;
+//!
+//! #[target_feature(enable = "simd128")]
+//! fn encode() {
+//!     let mut out = [MaybeUninit::<u8>::uninit(); 16];
+//!
+//!     let init = uninit::v128_store(uninit::array_mut(&mut out), u8x16_splat(7));
+//!
+//!     assert_eq!(*init, [7u8; 16]);
+//! }
+//! ```
+use core::arch::wasm32::{self as arch, v128};
+use core::mem::MaybeUninit;
+
+use super::{
+    Is1ByteUnaligned, Is2BytesUnaligned, Is4BytesUnaligned, Is8BytesUnaligned, Is16BytesUnaligned,
+};
+
+pub use crate::common_uninit::{array_mut, chunks_mut};
+
+/// Stores a `v128` vector into uninitialized memory.
+///
+/// See [`v128_store`](super::v128_store).
+#[inline]
+#[target_feature(enable = "simd128")]
+pub fn v128_store<T: Is16BytesUnaligned>(t: &mut MaybeUninit<T>, v: v128) -> &mut T {
+    unsafe {
+        arch::v128_store(t.as_mut_ptr().cast(), v);
+        t.assume_init_mut()
+    }
+}
+
+/// Stores the 8-bit value from lane `L` of `v` into uninitialized memory.
+///
+/// See [`v128_store8_lane`](super::v128_store8_lane).
+#[inline]
+#[target_feature(enable = "simd128")]
+pub fn v128_store8_lane<const L: usize, T: Is1ByteUnaligned>(
+    v: v128,
+    t: &mut MaybeUninit<T>,
+) -> &mut T {
+    const { assert!(L < 16, "lane index out of bounds") };
+    unsafe {
+        arch::v128_store8_lane::<L>(v, t.as_mut_ptr().cast());
+        t.assume_init_mut()
+    }
+}
+
+/// Stores the 16-bit value from lane `L` of `v` into uninitialized memory.
+///
+/// See [`v128_store16_lane`](super::v128_store16_lane).
+#[inline]
+#[target_feature(enable = "simd128")]
+pub fn v128_store16_lane<const L: usize, T: Is2BytesUnaligned>(
+    v: v128,
+    t: &mut MaybeUninit<T>,
+) -> &mut T {
+    const { assert!(L < 8, "lane index out of bounds") };
+    unsafe {
+        arch::v128_store16_lane::<L>(v, t.as_mut_ptr().cast());
+        t.assume_init_mut()
+    }
+}
+
+/// Stores the 32-bit value from lane `L` of `v` into uninitialized memory.
+///
+/// See [`v128_store32_lane`](super::v128_store32_lane).
+#[inline]
+#[target_feature(enable = "simd128")]
+pub fn v128_store32_lane<const L: usize, T: Is4BytesUnaligned>(
+    v: v128,
+    t: &mut MaybeUninit<T>,
+) -> &mut T {
+    const { assert!(L < 4, "lane index out of bounds") };
+    unsafe {
+        arch::v128_store32_lane::<L>(v, t.as_mut_ptr().cast());
+        t.assume_init_mut()
+    }
+}
+
+/// Stores the 64-bit value from lane `L` of `v` into uninitialized memory.
+///
+/// See [`v128_store64_lane`](super::v128_store64_lane).
+#[inline]
+#[target_feature(enable = "simd128")]
+pub fn v128_store64_lane<const L: usize, T: Is8BytesUnaligned>(
+    v: v128,
+    t: &mut MaybeUninit<T>,
+) -> &mut T {
+    const { assert!(L < 2, "lane index out of bounds") };
+    unsafe {
+        arch::v128_store64_lane::<L>(v, t.as_mut_ptr().cast());
+        t.assume_init_mut()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::arch::wasm32 as arch;
+    use core::mem::MaybeUninit;
+
+    #[test]
+    #[cfg_attr(not(target_feature = "simd128"), ignore)]
+    fn test_v128_store() {
+        #[target_feature(enable = "simd128")]
+        fn test() {
+            let v = arch::u32x4(0, 1, 2, 3);
+
+            let mut a = [MaybeUninit::<u32>::uninit(); 4];
+            assert_eq!(
+                *super::v128_store(super::array_mut(&mut a), v),
+                [0, 1, 2, 3]
+            );
+
+            let mut a = MaybeUninit::<u32>::uninit();
+            assert_eq!(*super::v128_store32_lane::<2, _>(v, &mut a), 2);
+
+            let mut a = MaybeUninit::<[u16; 4]>::uninit();
+            assert_eq!(*super::v128_store64_lane::<1, _>(v, &mut a), [2, 0, 3, 0]);
+
+            let mut a = MaybeUninit::<u16>::uninit();
+            assert_eq!(*super::v128_store16_lane::<2, _>(v, &mut a), 1);
+
+            let mut a = MaybeUninit::<u8>::uninit();
+            assert_eq!(*super::v128_store8_lane::<12, _>(v, &mut a), 3);
+        }
+
+        test()
+    }
+
+    #[test]
+    #[cfg_attr(not(target_feature = "simd128"), ignore)]
+    fn test_spare_capacity() {
+        #[target_feature(enable = "simd128")]
+        fn test(out: &mut Vec<u8>) {
+            let (chunks, _) = super::chunks_mut::<u8, 16>(out.spare_capacity_mut());
+
+            for (i, chunk) in chunks[..2].iter_mut().enumerate() {
+                super::v128_store(chunk, arch::u8x16_splat(i as u8));
+            }
+
+            unsafe { out.set_len(32) };
+        }
+
+        let mut out = Vec::with_capacity(40);
+        test(&mut out);
+        assert_eq!(out[..16], [0; 16]);
+        assert_eq!(out[16..], [1; 16]);
+    }
+}
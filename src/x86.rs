@@ -30,8 +30,26 @@ pub use self::avx512vbmi2::*;
 
 pub mod cell;
 
+#[cfg(feature = "num-complex")]
+pub mod complex;
+
+pub mod ext;
+
+pub mod interleave;
+
+#[cfg(feature = "nightly")]
+pub mod simd;
+
+pub mod slice;
+
+pub mod token;
+
+pub mod uninit;
+
 pub use crate::common_traits::{
-    Is16BitsUnaligned, Is16CellUnaligned, Is32BitsUnaligned, Is32CellUnaligned, Is64BitsUnaligned,
-    Is64CellUnaligned, Is128BitsUnaligned, Is128CellUnaligned, Is256BitsUnaligned,
-    Is256CellUnaligned, Is512BitsUnaligned,
+    Is16BitsReadable, Is16BitsUnaligned, Is16CellUnaligned, Is32BitsReadable, Is32BitsUnaligned,
+    Is32CellUnaligned, Is64BitsReadable, Is64BitsUnaligned, Is64CellUnaligned, Is128BitsReadable,
+    Is128BitsUnaligned, Is128CellUnaligned, Is256BitsReadable, Is256BitsUnaligned,
+    Is256CellUnaligned, Is512BitsReadable, Is512BitsUnaligned, Readable, ReadableElement,
+    Unaligned, UnalignedCell, UnalignedElement,
 };
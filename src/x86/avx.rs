@@ -5,256 +5,277 @@ use core::arch::x86_64::{self as arch, __m128, __m128d, __m256, __m256d, __m256i
 use core::ptr;
 
 #[cfg(target_arch = "x86")]
-use crate::x86::{Is128BitsUnaligned, Is256BitsUnaligned};
+use crate::x86::{Is128BitsReadable, Is128BitsUnaligned, Is256BitsReadable, Is256BitsUnaligned};
 #[cfg(target_arch = "x86_64")]
-use crate::x86_64::{Is128BitsUnaligned, Is256BitsUnaligned};
-
-/// Broadcasts 128 bits from memory (composed of 2 packed double-precision
-/// (64-bit) floating-point elements) to all elements of the returned vector.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_broadcast_pd)
-///
-/// # Note
-///
-/// If you are targeting `rustc 1.90` or later, [use the `std::arch` intrinsic
-/// directly][arch_link] instead of importing this one.
-///
-/// This function is included for legacy purposes, as it was missed in the
-/// initial safe intrinsics stabilization effort.
-///
-/// [arch_link]: https://doc.rust-lang.org/stable/core/arch/x86/fn._mm256_broadcast_pd.html
-#[inline]
-#[target_feature(enable = "avx")]
-pub fn _mm256_broadcast_pd(mem_addr: &__m128d) -> __m256d {
-    // FIXME: Remove unsafe blocks when MSRV includes the safe version
-    #[allow(unused_unsafe)]
-    unsafe {
-        arch::_mm256_broadcast_pd(mem_addr)
+use crate::x86_64::{Is128BitsReadable, Is128BitsUnaligned, Is256BitsReadable, Is256BitsUnaligned};
+
+token_wrappers! {
+    impl super::token::Avx;
+
+    /// Broadcasts 128 bits from memory (composed of 2 packed double-precision
+    /// (64-bit) floating-point elements) to all elements of the returned vector.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_broadcast_pd)
+    ///
+    /// # Note
+    ///
+    /// If you are targeting `rustc 1.90` or later, [use the `std::arch` intrinsic
+    /// directly][arch_link] instead of importing this one.
+    ///
+    /// This function is included for legacy purposes, as it was missed in the
+    /// initial safe intrinsics stabilization effort.
+    ///
+    /// [arch_link]: https://doc.rust-lang.org/stable/core/arch/x86/fn._mm256_broadcast_pd.html
+    #[method = mm256_broadcast_pd]
+    #[inline]
+    #[target_feature(enable = "avx")]
+    pub fn _mm256_broadcast_pd(mem_addr: &__m128d) -> __m256d {
+        // FIXME: Remove unsafe blocks when MSRV includes the safe version
+        #[allow(unused_unsafe)]
+        unsafe {
+            arch::_mm256_broadcast_pd(mem_addr)
+        }
     }
-}
 
-/// Broadcasts 128 bits from memory (composed of 4 packed single-precision
-/// (32-bit) floating-point elements) to all elements of the returned vector.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_broadcast_ps)
-///
-/// # Note
-///
-/// If you are targeting `rustc 1.90` or later, [use the `std::arch` intrinsic
-/// directly][arch_link] instead of importing this one.
-///
-/// This function is included for legacy purposes, as it was missed in the
-/// initial safe intrinsics stabilization effort.
-///
-/// [arch_link]: https://doc.rust-lang.org/stable/core/arch/x86/fn._mm256_broadcast_ps.html
-#[inline]
-#[target_feature(enable = "avx")]
-pub fn _mm256_broadcast_ps(mem_addr: &__m128) -> __m256 {
-    // FIXME: Remove unsafe blocks when MSRV includes the safe version
-    #[allow(unused_unsafe)]
-    unsafe {
-        arch::_mm256_broadcast_ps(mem_addr)
+    /// Broadcasts 128 bits from memory (composed of 4 packed single-precision
+    /// (32-bit) floating-point elements) to all elements of the returned vector.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_broadcast_ps)
+    ///
+    /// # Note
+    ///
+    /// If you are targeting `rustc 1.90` or later, [use the `std::arch` intrinsic
+    /// directly][arch_link] instead of importing this one.
+    ///
+    /// This function is included for legacy purposes, as it was missed in the
+    /// initial safe intrinsics stabilization effort.
+    ///
+    /// [arch_link]: https://doc.rust-lang.org/stable/core/arch/x86/fn._mm256_broadcast_ps.html
+    #[method = mm256_broadcast_ps]
+    #[inline]
+    #[target_feature(enable = "avx")]
+    pub fn _mm256_broadcast_ps(mem_addr: &__m128) -> __m256 {
+        // FIXME: Remove unsafe blocks when MSRV includes the safe version
+        #[allow(unused_unsafe)]
+        unsafe {
+            arch::_mm256_broadcast_ps(mem_addr)
+        }
     }
-}
 
-/// Broadcasts a double-precision (64-bit) floating-point element from memory
-/// to all elements of the returned vector.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_broadcast_sd)
-///
-/// # Note
-///
-/// If you are targeting `rustc 1.90` or later, [use the `std::arch` intrinsic
-/// directly][arch_link] instead of importing this one.
-///
-/// This function is included for legacy purposes, as it was missed in the
-/// initial safe intrinsics stabilization effort.
-///
-/// [arch_link]: https://doc.rust-lang.org/stable/core/arch/x86/fn._mm256_broadcast_sd.html
-#[inline]
-#[target_feature(enable = "avx")]
-pub fn _mm256_broadcast_sd(mem_addr: &f64) -> __m256d {
-    // FIXME: Remove unsafe blocks when MSRV includes the safe version
-    #[allow(unused_unsafe)]
-    unsafe {
-        arch::_mm256_broadcast_sd(mem_addr)
+    /// Broadcasts a double-precision (64-bit) floating-point element from memory
+    /// to all elements of the returned vector.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_broadcast_sd)
+    ///
+    /// # Note
+    ///
+    /// If you are targeting `rustc 1.90` or later, [use the `std::arch` intrinsic
+    /// directly][arch_link] instead of importing this one.
+    ///
+    /// This function is included for legacy purposes, as it was missed in the
+    /// initial safe intrinsics stabilization effort.
+    ///
+    /// [arch_link]: https://doc.rust-lang.org/stable/core/arch/x86/fn._mm256_broadcast_sd.html
+    #[method = mm256_broadcast_sd]
+    #[inline]
+    #[target_feature(enable = "avx")]
+    pub fn _mm256_broadcast_sd(mem_addr: &f64) -> __m256d {
+        // FIXME: Remove unsafe blocks when MSRV includes the safe version
+        #[allow(unused_unsafe)]
+        unsafe {
+            arch::_mm256_broadcast_sd(mem_addr)
+        }
     }
-}
 
-/// Broadcasts a single-precision (32-bit) floating-point element from memory
-/// to all elements of the returned vector.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_broadcast_ss)
-///
-/// # Note
-///
-/// If you are targeting `rustc 1.90` or later, [use the `std::arch` intrinsic
-/// directly][arch_link] instead of importing this one.
-///
-/// This function is included for legacy purposes, as it was missed in the
-/// initial safe intrinsics stabilization effort.
-///
-/// [arch_link]: https://doc.rust-lang.org/stable/core/arch/x86/fn._mm_broadcast_ss.html
-#[inline]
-#[target_feature(enable = "avx")]
-pub fn _mm_broadcast_ss(mem_addr: &f32) -> __m128 {
-    // FIXME: Remove unsafe blocks when MSRV includes the safe version
-    #[allow(unused_unsafe)]
-    unsafe {
-        arch::_mm_broadcast_ss(mem_addr)
+    /// Broadcasts a single-precision (32-bit) floating-point element from memory
+    /// to all elements of the returned vector.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_broadcast_ss)
+    ///
+    /// # Note
+    ///
+    /// If you are targeting `rustc 1.90` or later, [use the `std::arch` intrinsic
+    /// directly][arch_link] instead of importing this one.
+    ///
+    /// This function is included for legacy purposes, as it was missed in the
+    /// initial safe intrinsics stabilization effort.
+    ///
+    /// [arch_link]: https://doc.rust-lang.org/stable/core/arch/x86/fn._mm_broadcast_ss.html
+    #[method = mm_broadcast_ss]
+    #[inline]
+    #[target_feature(enable = "avx")]
+    pub fn _mm_broadcast_ss(mem_addr: &f32) -> __m128 {
+        // FIXME: Remove unsafe blocks when MSRV includes the safe version
+        #[allow(unused_unsafe)]
+        unsafe {
+            arch::_mm_broadcast_ss(mem_addr)
+        }
     }
-}
 
-/// Broadcasts a single-precision (32-bit) floating-point element from memory
-/// to all elements of the returned vector.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_broadcast_ss)
-///
-/// # Note
-///
-/// If you are targeting `rustc 1.90` or later, [use the `std::arch` intrinsic
-/// directly][arch_link] instead of importing this one.
-///
-/// This function is included for legacy purposes, as it was missed in the
-/// initial safe intrinsics stabilization effort.
-///
-/// [arch_link]: https://doc.rust-lang.org/stable/core/arch/x86/fn._mm256_broadcast_ss.html
-#[inline]
-#[target_feature(enable = "avx")]
-pub fn _mm256_broadcast_ss(mem_addr: &f32) -> __m256 {
-    // FIXME: Remove unsafe blocks when MSRV includes the safe version
-    #[allow(unused_unsafe)]
-    unsafe {
-        arch::_mm256_broadcast_ss(mem_addr)
+    /// Broadcasts a single-precision (32-bit) floating-point element from memory
+    /// to all elements of the returned vector.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_broadcast_ss)
+    ///
+    /// # Note
+    ///
+    /// If you are targeting `rustc 1.90` or later, [use the `std::arch` intrinsic
+    /// directly][arch_link] instead of importing this one.
+    ///
+    /// This function is included for legacy purposes, as it was missed in the
+    /// initial safe intrinsics stabilization effort.
+    ///
+    /// [arch_link]: https://doc.rust-lang.org/stable/core/arch/x86/fn._mm256_broadcast_ss.html
+    #[method = mm256_broadcast_ss]
+    #[inline]
+    #[target_feature(enable = "avx")]
+    pub fn _mm256_broadcast_ss(mem_addr: &f32) -> __m256 {
+        // FIXME: Remove unsafe blocks when MSRV includes the safe version
+        #[allow(unused_unsafe)]
+        unsafe {
+            arch::_mm256_broadcast_ss(mem_addr)
+        }
     }
-}
-
-/// Loads 256-bits (composed of 4 packed double-precision (64-bit)
-/// floating-point elements) from memory into result.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_loadu_pd)
-#[inline]
-#[target_feature(enable = "avx")]
-pub fn _mm256_loadu_pd(mem_addr: &[f64; 4]) -> __m256d {
-    unsafe { arch::_mm256_loadu_pd(mem_addr.as_ptr().cast()) }
-}
-
-/// Loads 256-bits (composed of 8 packed single-precision (32-bit)
-/// floating-point elements) from memory into result.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_loadu_ps)
-#[inline]
-#[target_feature(enable = "avx")]
-pub fn _mm256_loadu_ps(mem_addr: &[f32; 8]) -> __m256 {
-    unsafe { arch::_mm256_loadu_ps(mem_addr.as_ptr().cast()) }
-}
-
-/// Loads 256-bits of integer data from memory into result.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_loadu_si256)
-#[inline]
-#[target_feature(enable = "avx")]
-pub fn _mm256_loadu_si256<T: Is256BitsUnaligned>(mem_addr: &T) -> __m256i {
-    unsafe { arch::_mm256_loadu_si256(ptr::from_ref(mem_addr).cast()) }
-}
-
-/// Loads two 128-bit values (composed of 4 packed single-precision (32-bit)
-/// floating-point elements) from memory, and combine them into a 256-bit
-/// value.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_loadu2_m128)
-#[inline]
-#[target_feature(enable = "avx")]
-pub fn _mm256_loadu2_m128(hiaddr: &[f32; 4], loaddr: &[f32; 4]) -> __m256 {
-    unsafe { arch::_mm256_loadu2_m128(hiaddr.as_ptr().cast(), loaddr.as_ptr().cast()) }
-}
-
-/// Loads two 128-bit values (composed of 2 packed double-precision (64-bit)
-/// floating-point elements) from memory, and combine them into a 256-bit
-/// value.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_loadu2_m128d)
-#[inline]
-#[target_feature(enable = "avx")]
-pub fn _mm256_loadu2_m128d(hiaddr: &[f64; 2], loaddr: &[f64; 2]) -> __m256d {
-    unsafe { arch::_mm256_loadu2_m128d(hiaddr.as_ptr().cast(), loaddr.as_ptr().cast()) }
-}
-
-/// Loads two 128-bit values (composed of integer data) from memory, and combine
-/// them into a 256-bit value.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_loadu2_m128i)
-#[inline]
-#[target_feature(enable = "avx")]
-pub fn _mm256_loadu2_m128i<T: Is128BitsUnaligned>(hiaddr: &T, loaddr: &T) -> __m256i {
-    unsafe { arch::_mm256_loadu2_m128i(ptr::from_ref(hiaddr).cast(), ptr::from_ref(loaddr).cast()) }
-}
-
-/// Stores 256-bits (composed of 4 packed double-precision (64-bit)
-/// floating-point elements) from `a` into memory.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_storeu_pd)
-#[inline]
-#[target_feature(enable = "avx")]
-pub fn _mm256_storeu_pd(mem_addr: &mut [f64; 4], a: __m256d) {
-    unsafe { arch::_mm256_storeu_pd(mem_addr.as_mut_ptr().cast(), a) }
-}
-
-/// Stores 256-bits (composed of 8 packed single-precision (32-bit)
-/// floating-point elements) from `a` into memory.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_storeu_ps)
-#[inline]
-#[target_feature(enable = "avx")]
-pub fn _mm256_storeu_ps(mem_addr: &mut [f32; 8], a: __m256) {
-    unsafe { arch::_mm256_storeu_ps(mem_addr.as_mut_ptr().cast(), a) }
-}
 
-/// Stores 256-bits of integer data from `a` into memory.
-/// `mem_addr` does not need to be aligned on any particular boundary.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_storeu_si256)
-#[inline]
-#[target_feature(enable = "avx")]
-pub fn _mm256_storeu_si256<T: Is256BitsUnaligned>(mem_addr: &mut T, a: __m256i) {
-    unsafe { arch::_mm256_storeu_si256(ptr::from_mut(mem_addr).cast(), a) }
-}
-
-/// Stores the high and low 128-bit halves (each composed of 4 packed
-/// single-precision (32-bit) floating-point elements) from `a` into memory two
-/// different 128-bit locations.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_storeu2_m128)
-#[inline]
-#[target_feature(enable = "avx")]
-pub fn _mm256_storeu2_m128(hiaddr: &mut [f32; 4], loaddr: &mut [f32; 4], a: __m256) {
-    unsafe { arch::_mm256_storeu2_m128(hiaddr.as_mut_ptr().cast(), loaddr.as_mut_ptr().cast(), a) }
-}
-
-/// Stores the high and low 128-bit halves (each composed of 2 packed
-/// double-precision (64-bit) floating-point elements) from `a` into memory two
-/// different 128-bit locations.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_storeu2_m128d)
-#[inline]
-#[target_feature(enable = "avx")]
-pub fn _mm256_storeu2_m128d(hiaddr: &mut [f64; 2], loaddr: &mut [f64; 2], a: __m256d) {
-    unsafe { arch::_mm256_storeu2_m128d(hiaddr.as_mut_ptr().cast(), loaddr.as_mut_ptr().cast(), a) }
-}
-
-/// Stores the high and low 128-bit halves (each composed of integer data) from
-/// `a` into memory two different 128-bit locations.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_storeu2_m128i)
-#[inline]
-#[target_feature(enable = "avx")]
-pub fn _mm256_storeu2_m128i<T: Is128BitsUnaligned>(hiaddr: &mut T, loaddr: &mut T, a: __m256i) {
-    unsafe {
-        arch::_mm256_storeu2_m128i(
-            ptr::from_mut(hiaddr).cast(),
-            ptr::from_mut(loaddr).cast(),
-            a,
-        )
+    /// Loads 256-bits (composed of 4 packed double-precision (64-bit)
+    /// floating-point elements) from memory into result.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_loadu_pd)
+    #[method = mm256_loadu_pd]
+    #[inline]
+    #[target_feature(enable = "avx")]
+    pub fn _mm256_loadu_pd(mem_addr: &[f64; 4]) -> __m256d {
+        unsafe { arch::_mm256_loadu_pd(mem_addr.as_ptr().cast()) }
+    }
+
+    /// Loads 256-bits (composed of 8 packed single-precision (32-bit)
+    /// floating-point elements) from memory into result.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_loadu_ps)
+    #[method = mm256_loadu_ps]
+    #[inline]
+    #[target_feature(enable = "avx")]
+    pub fn _mm256_loadu_ps(mem_addr: &[f32; 8]) -> __m256 {
+        unsafe { arch::_mm256_loadu_ps(mem_addr.as_ptr().cast()) }
+    }
+
+    /// Loads 256-bits of integer data from memory into result.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_loadu_si256)
+    #[method = mm256_loadu_si256]
+    #[inline]
+    #[target_feature(enable = "avx")]
+    pub fn _mm256_loadu_si256<T: Is256BitsReadable>(mem_addr: &T) -> __m256i {
+        unsafe { arch::_mm256_loadu_si256(ptr::from_ref(mem_addr).cast()) }
+    }
+
+    /// Loads two 128-bit values (composed of 4 packed single-precision (32-bit)
+    /// floating-point elements) from memory, and combine them into a 256-bit
+    /// value.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_loadu2_m128)
+    #[method = mm256_loadu2_m128]
+    #[inline]
+    #[target_feature(enable = "avx")]
+    pub fn _mm256_loadu2_m128(hiaddr: &[f32; 4], loaddr: &[f32; 4]) -> __m256 {
+        unsafe { arch::_mm256_loadu2_m128(hiaddr.as_ptr().cast(), loaddr.as_ptr().cast()) }
+    }
+
+    /// Loads two 128-bit values (composed of 2 packed double-precision (64-bit)
+    /// floating-point elements) from memory, and combine them into a 256-bit
+    /// value.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_loadu2_m128d)
+    #[method = mm256_loadu2_m128d]
+    #[inline]
+    #[target_feature(enable = "avx")]
+    pub fn _mm256_loadu2_m128d(hiaddr: &[f64; 2], loaddr: &[f64; 2]) -> __m256d {
+        unsafe { arch::_mm256_loadu2_m128d(hiaddr.as_ptr().cast(), loaddr.as_ptr().cast()) }
+    }
+
+    /// Loads two 128-bit values (composed of integer data) from memory, and combine
+    /// them into a 256-bit value.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_loadu2_m128i)
+    #[method = mm256_loadu2_m128i]
+    #[inline]
+    #[target_feature(enable = "avx")]
+    pub fn _mm256_loadu2_m128i<T: Is128BitsReadable>(hiaddr: &T, loaddr: &T) -> __m256i {
+        unsafe { arch::_mm256_loadu2_m128i(ptr::from_ref(hiaddr).cast(), ptr::from_ref(loaddr).cast()) }
+    }
+
+    /// Stores 256-bits (composed of 4 packed double-precision (64-bit)
+    /// floating-point elements) from `a` into memory.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_storeu_pd)
+    #[method = mm256_storeu_pd]
+    #[inline]
+    #[target_feature(enable = "avx")]
+    pub fn _mm256_storeu_pd(mem_addr: &mut [f64; 4], a: __m256d) {
+        unsafe { arch::_mm256_storeu_pd(mem_addr.as_mut_ptr().cast(), a) }
+    }
+
+    /// Stores 256-bits (composed of 8 packed single-precision (32-bit)
+    /// floating-point elements) from `a` into memory.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_storeu_ps)
+    #[method = mm256_storeu_ps]
+    #[inline]
+    #[target_feature(enable = "avx")]
+    pub fn _mm256_storeu_ps(mem_addr: &mut [f32; 8], a: __m256) {
+        unsafe { arch::_mm256_storeu_ps(mem_addr.as_mut_ptr().cast(), a) }
+    }
+
+    /// Stores 256-bits of integer data from `a` into memory.
+    /// `mem_addr` does not need to be aligned on any particular boundary.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_storeu_si256)
+    #[method = mm256_storeu_si256]
+    #[inline]
+    #[target_feature(enable = "avx")]
+    pub fn _mm256_storeu_si256<T: Is256BitsUnaligned>(mem_addr: &mut T, a: __m256i) {
+        unsafe { arch::_mm256_storeu_si256(ptr::from_mut(mem_addr).cast(), a) }
+    }
+
+    /// Stores the high and low 128-bit halves (each composed of 4 packed
+    /// single-precision (32-bit) floating-point elements) from `a` into memory two
+    /// different 128-bit locations.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_storeu2_m128)
+    #[method = mm256_storeu2_m128]
+    #[inline]
+    #[target_feature(enable = "avx")]
+    pub fn _mm256_storeu2_m128(hiaddr: &mut [f32; 4], loaddr: &mut [f32; 4], a: __m256) {
+        unsafe { arch::_mm256_storeu2_m128(hiaddr.as_mut_ptr().cast(), loaddr.as_mut_ptr().cast(), a) }
+    }
+
+    /// Stores the high and low 128-bit halves (each composed of 2 packed
+    /// double-precision (64-bit) floating-point elements) from `a` into memory two
+    /// different 128-bit locations.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_storeu2_m128d)
+    #[method = mm256_storeu2_m128d]
+    #[inline]
+    #[target_feature(enable = "avx")]
+    pub fn _mm256_storeu2_m128d(hiaddr: &mut [f64; 2], loaddr: &mut [f64; 2], a: __m256d) {
+        unsafe { arch::_mm256_storeu2_m128d(hiaddr.as_mut_ptr().cast(), loaddr.as_mut_ptr().cast(), a) }
+    }
+
+    /// Stores the high and low 128-bit halves (each composed of integer data) from
+    /// `a` into memory two different 128-bit locations.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_storeu2_m128i)
+    #[method = mm256_storeu2_m128i]
+    #[inline]
+    #[target_feature(enable = "avx")]
+    pub fn _mm256_storeu2_m128i<T: Is128BitsUnaligned>(hiaddr: &mut T, loaddr: &mut T, a: __m256i) {
+        unsafe {
+            arch::_mm256_storeu2_m128i(
+                ptr::from_mut(hiaddr).cast(),
+                ptr::from_mut(loaddr).cast(),
+                a,
+            )
+        }
     }
 }
 
@@ -266,31 +287,33 @@ mod tests {
     #[cfg(target_arch = "x86_64")]
     use core::arch::x86_64::{self as arch, __m128, __m256, __m256d, __m256i};
 
+    use crate::Register;
+
     // Fail-safe for tests being run on a CPU that doesn't support `avx`
     static CPU_HAS_AVX: std::sync::LazyLock<bool> =
         std::sync::LazyLock::new(|| is_x86_feature_detected!("avx"));
 
     fn assert_eq_m256(a: __m256, b: __m256) {
-        let a: [u8; 32] = unsafe { core::mem::transmute(a) };
-        let b: [u8; 32] = unsafe { core::mem::transmute(b) };
+        let a: [u8; 32] = a.to_array();
+        let b: [u8; 32] = b.to_array();
         assert_eq!(a, b)
     }
 
     fn assert_eq_m256d(a: __m256d, b: __m256d) {
-        let a: [u8; 32] = unsafe { core::mem::transmute(a) };
-        let b: [u8; 32] = unsafe { core::mem::transmute(b) };
+        let a: [u8; 32] = a.to_array();
+        let b: [u8; 32] = b.to_array();
         assert_eq!(a, b)
     }
 
     fn assert_eq_m256i(a: __m256i, b: __m256i) {
-        let a: [u8; 32] = unsafe { core::mem::transmute(a) };
-        let b: [u8; 32] = unsafe { core::mem::transmute(b) };
+        let a: [u8; 32] = a.to_array();
+        let b: [u8; 32] = b.to_array();
         assert_eq!(a, b)
     }
 
     fn assert_eq_m128(a: __m128, b: __m128) {
-        let a: [u8; 16] = unsafe { core::mem::transmute(a) };
-        let b: [u8; 16] = unsafe { core::mem::transmute(b) };
+        let a: [u8; 16] = a.to_array();
+        let b: [u8; 16] = b.to_array();
         assert_eq!(a, b)
     }
 
@@ -9,451 +9,498 @@ use core::arch::x86_64::{
 use core::ptr;
 
 #[cfg(target_arch = "x86")]
-use crate::x86::{Is64BitsUnaligned, Is128BitsUnaligned, Is256BitsUnaligned, Is512BitsUnaligned};
+use crate::x86::{
+    Is64BitsUnaligned, Is128BitsReadable, Is128BitsUnaligned, Is256BitsReadable,
+    Is256BitsUnaligned, Is512BitsReadable, Is512BitsUnaligned,
+};
 #[cfg(target_arch = "x86_64")]
 use crate::x86_64::{
-    Is64BitsUnaligned, Is128BitsUnaligned, Is256BitsUnaligned, Is512BitsUnaligned,
+    Is64BitsUnaligned, Is128BitsReadable, Is128BitsUnaligned, Is256BitsReadable,
+    Is256BitsUnaligned, Is512BitsReadable, Is512BitsUnaligned,
 };
 
-/// Load 128-bits (composed of 8 packed 16-bit integers) from memory into dst. mem_addr does not need to be aligned on any particular boundary.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_loadu_epi16)
-#[inline]
-#[target_feature(enable = "avx512bw,avx512vl")]
-pub fn _mm_loadu_epi16<T: Is128BitsUnaligned>(mem_addr: &T) -> __m128i {
-    unsafe { arch::_mm_loadu_epi16(ptr::from_ref(mem_addr).cast()) }
-}
+token_wrappers! {
+    impl super::token::Avx512Bw;
+
+    /// Load 128-bits (composed of 8 packed 16-bit integers) from memory into dst. mem_addr does not need to be aligned on any particular boundary.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_loadu_epi16)
+    #[method = mm_loadu_epi16]
+    #[inline]
+    #[target_feature(enable = "avx512bw,avx512vl")]
+    pub fn _mm_loadu_epi16<T: Is128BitsReadable>(mem_addr: &T) -> __m128i {
+        unsafe { arch::_mm_loadu_epi16(ptr::from_ref(mem_addr).cast()) }
+    }
 
-/// Load packed 16-bit integers from memory into dst using writemask k
-/// (elements are copied from src when the corresponding mask bit is not set).
-/// mem_addr does not need to be aligned on any particular boundary.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_mask_loadu_epi16)
-#[inline]
-#[target_feature(enable = "avx512bw,avx512vl")]
-pub fn _mm_mask_loadu_epi16<T: Is128BitsUnaligned>(
-    src: __m128i,
-    k: __mmask8,
-    mem_addr: &T,
-) -> __m128i {
-    unsafe { arch::_mm_mask_loadu_epi16(src, k, ptr::from_ref(mem_addr).cast()) }
-}
+    /// Load packed 16-bit integers from memory into dst using writemask k
+    /// (elements are copied from src when the corresponding mask bit is not set).
+    /// mem_addr does not need to be aligned on any particular boundary.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_mask_loadu_epi16)
+    #[method = mm_mask_loadu_epi16]
+    #[inline]
+    #[target_feature(enable = "avx512bw,avx512vl")]
+    pub fn _mm_mask_loadu_epi16<T: Is128BitsReadable>(
+        src: __m128i,
+        k: __mmask8,
+        mem_addr: &T,
+    ) -> __m128i {
+        unsafe { arch::_mm_mask_loadu_epi16(src, k, ptr::from_ref(mem_addr).cast()) }
+    }
 
-/// Load packed 16-bit integers from memory into dst using zeromask k
-/// (elements are zeroed out when the corresponding mask bit is not set).
-/// mem_addr does not need to be aligned on any particular boundary.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_maskz_loadu_epi16)
-#[inline]
-#[target_feature(enable = "avx512bw,avx512vl")]
-pub fn _mm_maskz_loadu_epi16<T: Is128BitsUnaligned>(k: __mmask8, mem_addr: &T) -> __m128i {
-    _mm_mask_loadu_epi16(arch::_mm_setzero_si128(), k, mem_addr)
-}
+    /// Load packed 16-bit integers from memory into dst using zeromask k
+    /// (elements are zeroed out when the corresponding mask bit is not set).
+    /// mem_addr does not need to be aligned on any particular boundary.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_maskz_loadu_epi16)
+    #[method = mm_maskz_loadu_epi16]
+    #[inline]
+    #[target_feature(enable = "avx512bw,avx512vl")]
+    pub fn _mm_maskz_loadu_epi16<T: Is128BitsReadable>(k: __mmask8, mem_addr: &T) -> __m128i {
+        _mm_mask_loadu_epi16(arch::_mm_setzero_si128(), k, mem_addr)
+    }
 
-/// Load 256-bits (composed of 16 packed 16-bit integers) from memory into dst. mem_addr does not need to be aligned on any particular boundary.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_loadu_epi16)
-#[inline]
-#[target_feature(enable = "avx512bw,avx512vl")]
-pub fn _mm256_loadu_epi16<T: Is256BitsUnaligned>(mem_addr: &T) -> __m256i {
-    unsafe { arch::_mm256_loadu_epi16(ptr::from_ref(mem_addr).cast()) }
-}
+    /// Load 256-bits (composed of 16 packed 16-bit integers) from memory into dst. mem_addr does not need to be aligned on any particular boundary.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_loadu_epi16)
+    #[method = mm256_loadu_epi16]
+    #[inline]
+    #[target_feature(enable = "avx512bw,avx512vl")]
+    pub fn _mm256_loadu_epi16<T: Is256BitsReadable>(mem_addr: &T) -> __m256i {
+        unsafe { arch::_mm256_loadu_epi16(ptr::from_ref(mem_addr).cast()) }
+    }
 
-/// Load packed 16-bit integers from memory into dst using writemask k
-/// (elements are copied from src when the corresponding mask bit is not set).
-/// mem_addr does not need to be aligned on any particular boundary.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_mask_loadu_epi16)
-#[inline]
-#[target_feature(enable = "avx512bw,avx512vl")]
-pub fn _mm256_mask_loadu_epi16<T: Is256BitsUnaligned>(
-    src: __m256i,
-    k: __mmask16,
-    mem_addr: &T,
-) -> __m256i {
-    unsafe { arch::_mm256_mask_loadu_epi16(src, k, ptr::from_ref(mem_addr).cast()) }
-}
+    /// Load packed 16-bit integers from memory into dst using writemask k
+    /// (elements are copied from src when the corresponding mask bit is not set).
+    /// mem_addr does not need to be aligned on any particular boundary.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_mask_loadu_epi16)
+    #[method = mm256_mask_loadu_epi16]
+    #[inline]
+    #[target_feature(enable = "avx512bw,avx512vl")]
+    pub fn _mm256_mask_loadu_epi16<T: Is256BitsReadable>(
+        src: __m256i,
+        k: __mmask16,
+        mem_addr: &T,
+    ) -> __m256i {
+        unsafe { arch::_mm256_mask_loadu_epi16(src, k, ptr::from_ref(mem_addr).cast()) }
+    }
 
-/// Load packed 16-bit integers from memory into dst using zeromask k
-/// (elements are zeroed out when the corresponding mask bit is not set).
-/// mem_addr does not need to be aligned on any particular boundary.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_maskz_loadu_epi16)
-#[inline]
-#[target_feature(enable = "avx512bw,avx512vl")]
-pub fn _mm256_maskz_loadu_epi16<T: Is256BitsUnaligned>(k: __mmask16, mem_addr: &T) -> __m256i {
-    _mm256_mask_loadu_epi16(arch::_mm256_setzero_si256(), k, mem_addr)
-}
+    /// Load packed 16-bit integers from memory into dst using zeromask k
+    /// (elements are zeroed out when the corresponding mask bit is not set).
+    /// mem_addr does not need to be aligned on any particular boundary.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_maskz_loadu_epi16)
+    #[method = mm256_maskz_loadu_epi16]
+    #[inline]
+    #[target_feature(enable = "avx512bw,avx512vl")]
+    pub fn _mm256_maskz_loadu_epi16<T: Is256BitsReadable>(k: __mmask16, mem_addr: &T) -> __m256i {
+        _mm256_mask_loadu_epi16(arch::_mm256_setzero_si256(), k, mem_addr)
+    }
 
-/// Load 512-bits (composed of 32 packed 16-bit integers) from memory into dst. mem_addr does not need to be aligned on any particular boundary.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_loadu_epi16)
-#[inline]
-#[target_feature(enable = "avx512bw")]
-pub fn _mm512_loadu_epi16<T: Is512BitsUnaligned>(mem_addr: &T) -> __m512i {
-    unsafe { arch::_mm512_loadu_epi16(ptr::from_ref(mem_addr).cast()) }
-}
+    /// Load 512-bits (composed of 32 packed 16-bit integers) from memory into dst. mem_addr does not need to be aligned on any particular boundary.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_loadu_epi16)
+    #[method = mm512_loadu_epi16]
+    #[inline]
+    #[target_feature(enable = "avx512bw")]
+    pub fn _mm512_loadu_epi16<T: Is512BitsReadable>(mem_addr: &T) -> __m512i {
+        unsafe { arch::_mm512_loadu_epi16(ptr::from_ref(mem_addr).cast()) }
+    }
 
-/// Load packed 16-bit integers from memory into dst using writemask k
-/// (elements are copied from src when the corresponding mask bit is not set).
-/// mem_addr does not need to be aligned on any particular boundary.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_loadu_epi16)
-#[inline]
-#[target_feature(enable = "avx512bw")]
-pub fn _mm512_mask_loadu_epi16<T: Is512BitsUnaligned>(
-    src: __m512i,
-    k: __mmask32,
-    mem_addr: &T,
-) -> __m512i {
-    unsafe { arch::_mm512_mask_loadu_epi16(src, k, ptr::from_ref(mem_addr).cast()) }
-}
+    /// Load packed 16-bit integers from memory into dst using writemask k
+    /// (elements are copied from src when the corresponding mask bit is not set).
+    /// mem_addr does not need to be aligned on any particular boundary.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_loadu_epi16)
+    #[method = mm512_mask_loadu_epi16]
+    #[inline]
+    #[target_feature(enable = "avx512bw")]
+    pub fn _mm512_mask_loadu_epi16<T: Is512BitsReadable>(
+        src: __m512i,
+        k: __mmask32,
+        mem_addr: &T,
+    ) -> __m512i {
+        unsafe { arch::_mm512_mask_loadu_epi16(src, k, ptr::from_ref(mem_addr).cast()) }
+    }
 
-/// Load packed 16-bit integers from memory into dst using zeromask k
-/// (elements are zeroed out when the corresponding mask bit is not set).
-/// mem_addr does not need to be aligned on any particular boundary.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_maskz_loadu_epi16)
-#[inline]
-#[target_feature(enable = "avx512bw")]
-pub fn _mm512_maskz_loadu_epi16<T: Is512BitsUnaligned>(k: __mmask32, mem_addr: &T) -> __m512i {
-    _mm512_mask_loadu_epi16(arch::_mm512_setzero_si512(), k, mem_addr)
-}
+    /// Load packed 16-bit integers from memory into dst using zeromask k
+    /// (elements are zeroed out when the corresponding mask bit is not set).
+    /// mem_addr does not need to be aligned on any particular boundary.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_maskz_loadu_epi16)
+    #[method = mm512_maskz_loadu_epi16]
+    #[inline]
+    #[target_feature(enable = "avx512bw")]
+    pub fn _mm512_maskz_loadu_epi16<T: Is512BitsReadable>(k: __mmask32, mem_addr: &T) -> __m512i {
+        _mm512_mask_loadu_epi16(arch::_mm512_setzero_si512(), k, mem_addr)
+    }
 
-/// Load 128-bits (composed of 16 packed 8-bit integers) from memory into dst. mem_addr does not need to be aligned on any particular boundary.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_loadu_epi8)
-#[inline]
-#[target_feature(enable = "avx512bw,avx512vl")]
-pub fn _mm_loadu_epi8<T: Is128BitsUnaligned>(mem_addr: &T) -> __m128i {
-    unsafe { arch::_mm_loadu_epi8(ptr::from_ref(mem_addr).cast()) }
-}
+    /// Load 128-bits (composed of 16 packed 8-bit integers) from memory into dst. mem_addr does not need to be aligned on any particular boundary.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_loadu_epi8)
+    #[method = mm_loadu_epi8]
+    #[inline]
+    #[target_feature(enable = "avx512bw,avx512vl")]
+    pub fn _mm_loadu_epi8<T: Is128BitsReadable>(mem_addr: &T) -> __m128i {
+        unsafe { arch::_mm_loadu_epi8(ptr::from_ref(mem_addr).cast()) }
+    }
 
-/// Load packed 8-bit integers from memory into dst using writemask k
-/// (elements are copied from src when the corresponding mask bit is not set).
-/// mem_addr does not need to be aligned on any particular boundary.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_mask_loadu_epi8)
-#[inline]
-#[target_feature(enable = "avx512bw,avx512vl")]
-pub fn _mm_mask_loadu_epi8<T: Is128BitsUnaligned>(
-    src: __m128i,
-    k: __mmask16,
-    mem_addr: &T,
-) -> __m128i {
-    unsafe { arch::_mm_mask_loadu_epi8(src, k, ptr::from_ref(mem_addr).cast()) }
-}
+    /// Load packed 8-bit integers from memory into dst using writemask k
+    /// (elements are copied from src when the corresponding mask bit is not set).
+    /// mem_addr does not need to be aligned on any particular boundary.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_mask_loadu_epi8)
+    #[method = mm_mask_loadu_epi8]
+    #[inline]
+    #[target_feature(enable = "avx512bw,avx512vl")]
+    pub fn _mm_mask_loadu_epi8<T: Is128BitsReadable>(
+        src: __m128i,
+        k: __mmask16,
+        mem_addr: &T,
+    ) -> __m128i {
+        unsafe { arch::_mm_mask_loadu_epi8(src, k, ptr::from_ref(mem_addr).cast()) }
+    }
 
-/// Load packed 8-bit integers from memory into dst using zeromask k
-/// (elements are zeroed out when the corresponding mask bit is not set).
-/// mem_addr does not need to be aligned on any particular boundary.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_maskz_loadu_epi8)
-#[inline]
-#[target_feature(enable = "avx512bw,avx512vl")]
-pub fn _mm_maskz_loadu_epi8<T: Is128BitsUnaligned>(k: __mmask16, mem_addr: &T) -> __m128i {
-    _mm_mask_loadu_epi8(arch::_mm_setzero_si128(), k, mem_addr)
-}
+    /// Load packed 8-bit integers from memory into dst using zeromask k
+    /// (elements are zeroed out when the corresponding mask bit is not set).
+    /// mem_addr does not need to be aligned on any particular boundary.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_maskz_loadu_epi8)
+    #[method = mm_maskz_loadu_epi8]
+    #[inline]
+    #[target_feature(enable = "avx512bw,avx512vl")]
+    pub fn _mm_maskz_loadu_epi8<T: Is128BitsReadable>(k: __mmask16, mem_addr: &T) -> __m128i {
+        _mm_mask_loadu_epi8(arch::_mm_setzero_si128(), k, mem_addr)
+    }
 
-/// Load 256-bits (composed of 32 packed 8-bit integers) from memory into dst. mem_addr does not need to be aligned on any particular boundary.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_loadu_epi8)
-#[inline]
-#[target_feature(enable = "avx512bw,avx512vl")]
-pub fn _mm256_loadu_epi8<T: Is256BitsUnaligned>(mem_addr: &T) -> __m256i {
-    unsafe { arch::_mm256_loadu_epi8(ptr::from_ref(mem_addr).cast()) }
-}
+    /// Load 256-bits (composed of 32 packed 8-bit integers) from memory into dst. mem_addr does not need to be aligned on any particular boundary.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_loadu_epi8)
+    #[method = mm256_loadu_epi8]
+    #[inline]
+    #[target_feature(enable = "avx512bw,avx512vl")]
+    pub fn _mm256_loadu_epi8<T: Is256BitsReadable>(mem_addr: &T) -> __m256i {
+        unsafe { arch::_mm256_loadu_epi8(ptr::from_ref(mem_addr).cast()) }
+    }
 
-/// Load packed 8-bit integers from memory into dst using writemask k
-/// (elements are copied from src when the corresponding mask bit is not set).
-/// mem_addr does not need to be aligned on any particular boundary.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_mask_loadu_epi8)
-#[inline]
-#[target_feature(enable = "avx512bw,avx512vl")]
-pub fn _mm256_mask_loadu_epi8<T: Is256BitsUnaligned>(
-    src: __m256i,
-    k: __mmask32,
-    mem_addr: &T,
-) -> __m256i {
-    unsafe { arch::_mm256_mask_loadu_epi8(src, k, ptr::from_ref(mem_addr).cast()) }
-}
+    /// Load packed 8-bit integers from memory into dst using writemask k
+    /// (elements are copied from src when the corresponding mask bit is not set).
+    /// mem_addr does not need to be aligned on any particular boundary.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_mask_loadu_epi8)
+    #[method = mm256_mask_loadu_epi8]
+    #[inline]
+    #[target_feature(enable = "avx512bw,avx512vl")]
+    pub fn _mm256_mask_loadu_epi8<T: Is256BitsReadable>(
+        src: __m256i,
+        k: __mmask32,
+        mem_addr: &T,
+    ) -> __m256i {
+        unsafe { arch::_mm256_mask_loadu_epi8(src, k, ptr::from_ref(mem_addr).cast()) }
+    }
 
-/// Load packed 8-bit integers from memory into dst using zeromask k
-/// (elements are zeroed out when the corresponding mask bit is not set).
-/// mem_addr does not need to be aligned on any particular boundary.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_maskz_loadu_epi8)
-#[inline]
-#[target_feature(enable = "avx512bw,avx512vl")]
-pub fn _mm256_maskz_loadu_epi8<T: Is256BitsUnaligned>(k: __mmask32, mem_addr: &T) -> __m256i {
-    _mm256_mask_loadu_epi8(arch::_mm256_setzero_si256(), k, mem_addr)
-}
+    /// Load packed 8-bit integers from memory into dst using zeromask k
+    /// (elements are zeroed out when the corresponding mask bit is not set).
+    /// mem_addr does not need to be aligned on any particular boundary.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_maskz_loadu_epi8)
+    #[method = mm256_maskz_loadu_epi8]
+    #[inline]
+    #[target_feature(enable = "avx512bw,avx512vl")]
+    pub fn _mm256_maskz_loadu_epi8<T: Is256BitsReadable>(k: __mmask32, mem_addr: &T) -> __m256i {
+        _mm256_mask_loadu_epi8(arch::_mm256_setzero_si256(), k, mem_addr)
+    }
 
-/// Load 512-bits (composed of 64 packed 8-bit integers) from memory into dst. mem_addr does not need to be aligned on any particular boundary.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_loadu_epi8)
-#[inline]
-#[target_feature(enable = "avx512bw")]
-pub fn _mm512_loadu_epi8<T: Is512BitsUnaligned>(mem_addr: &T) -> __m512i {
-    unsafe { arch::_mm512_loadu_epi8(ptr::from_ref(mem_addr).cast()) }
-}
+    /// Load 512-bits (composed of 64 packed 8-bit integers) from memory into dst. mem_addr does not need to be aligned on any particular boundary.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_loadu_epi8)
+    #[method = mm512_loadu_epi8]
+    #[inline]
+    #[target_feature(enable = "avx512bw")]
+    pub fn _mm512_loadu_epi8<T: Is512BitsReadable>(mem_addr: &T) -> __m512i {
+        unsafe { arch::_mm512_loadu_epi8(ptr::from_ref(mem_addr).cast()) }
+    }
 
-/// Load packed 8-bit integers from memory into dst using writemask k
-/// (elements are copied from src when the corresponding mask bit is not set).
-/// mem_addr does not need to be aligned on any particular boundary.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_loadu_epi8)
-#[inline]
-#[target_feature(enable = "avx512bw")]
-pub fn _mm512_mask_loadu_epi8<T: Is512BitsUnaligned>(
-    src: __m512i,
-    k: __mmask64,
-    mem_addr: &T,
-) -> __m512i {
-    unsafe { arch::_mm512_mask_loadu_epi8(src, k, ptr::from_ref(mem_addr).cast()) }
-}
+    /// Load packed 8-bit integers from memory into dst using writemask k
+    /// (elements are copied from src when the corresponding mask bit is not set).
+    /// mem_addr does not need to be aligned on any particular boundary.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_loadu_epi8)
+    #[method = mm512_mask_loadu_epi8]
+    #[inline]
+    #[target_feature(enable = "avx512bw")]
+    pub fn _mm512_mask_loadu_epi8<T: Is512BitsReadable>(
+        src: __m512i,
+        k: __mmask64,
+        mem_addr: &T,
+    ) -> __m512i {
+        unsafe { arch::_mm512_mask_loadu_epi8(src, k, ptr::from_ref(mem_addr).cast()) }
+    }
 
-/// Load packed 8-bit integers from memory into dst using zeromask k
-/// (elements are zeroed out when the corresponding mask bit is not set).
-/// mem_addr does not need to be aligned on any particular boundary.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_maskz_loadu_epi8)
-#[inline]
-#[target_feature(enable = "avx512bw")]
-pub fn _mm512_maskz_loadu_epi8<T: Is512BitsUnaligned>(k: __mmask64, mem_addr: &T) -> __m512i {
-    _mm512_mask_loadu_epi8(arch::_mm512_setzero_si512(), k, mem_addr)
-}
+    /// Load packed 8-bit integers from memory into dst using zeromask k
+    /// (elements are zeroed out when the corresponding mask bit is not set).
+    /// mem_addr does not need to be aligned on any particular boundary.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_maskz_loadu_epi8)
+    #[method = mm512_maskz_loadu_epi8]
+    #[inline]
+    #[target_feature(enable = "avx512bw")]
+    pub fn _mm512_maskz_loadu_epi8<T: Is512BitsReadable>(k: __mmask64, mem_addr: &T) -> __m512i {
+        _mm512_mask_loadu_epi8(arch::_mm512_setzero_si512(), k, mem_addr)
+    }
 
-/// Convert packed 16-bit integers in a to packed 8-bit integers with truncation, and store the active results (those with their respective bit set in writemask k) to unaligned memory at base_addr.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_mask_cvtepi16_storeu_epi8)
-#[inline]
-#[target_feature(enable = "avx512bw,avx512vl")]
-pub fn _mm_mask_cvtepi16_storeu_epi8<T: Is64BitsUnaligned>(
-    base_addr: &mut T,
-    k: __mmask8,
-    a: __m128i,
-) {
-    unsafe { arch::_mm_mask_cvtepi16_storeu_epi8(ptr::from_mut(base_addr).cast(), k, a) }
-}
+    /// Convert packed 16-bit integers in a to packed 8-bit integers with truncation, and store the active results (those with their respective bit set in writemask k) to unaligned memory at base_addr.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_mask_cvtepi16_storeu_epi8)
+    #[method = mm_mask_cvtepi16_storeu_epi8]
+    #[inline]
+    #[target_feature(enable = "avx512bw,avx512vl")]
+    pub fn _mm_mask_cvtepi16_storeu_epi8<T: Is64BitsUnaligned>(
+        base_addr: &mut T,
+        k: __mmask8,
+        a: __m128i,
+    ) {
+        unsafe { arch::_mm_mask_cvtepi16_storeu_epi8(ptr::from_mut(base_addr).cast(), k, a) }
+    }
 
-/// Convert packed 16-bit integers in a to packed 8-bit integers with truncation, and store the active results (those with their respective bit set in writemask k) to unaligned memory at base_addr.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_mask_cvtepi16_storeu_epi8)
-#[inline]
-#[target_feature(enable = "avx512bw,avx512vl")]
-pub fn _mm256_mask_cvtepi16_storeu_epi8<T: Is128BitsUnaligned>(
-    base_addr: &mut T,
-    k: __mmask16,
-    a: __m256i,
-) {
-    unsafe { arch::_mm256_mask_cvtepi16_storeu_epi8(ptr::from_mut(base_addr).cast(), k, a) }
-}
+    /// Convert packed 16-bit integers in a to packed 8-bit integers with truncation, and store the active results (those with their respective bit set in writemask k) to unaligned memory at base_addr.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_mask_cvtepi16_storeu_epi8)
+    #[method = mm256_mask_cvtepi16_storeu_epi8]
+    #[inline]
+    #[target_feature(enable = "avx512bw,avx512vl")]
+    pub fn _mm256_mask_cvtepi16_storeu_epi8<T: Is128BitsUnaligned>(
+        base_addr: &mut T,
+        k: __mmask16,
+        a: __m256i,
+    ) {
+        unsafe { arch::_mm256_mask_cvtepi16_storeu_epi8(ptr::from_mut(base_addr).cast(), k, a) }
+    }
 
-/// Convert packed 16-bit integers in a to packed 8-bit integers with truncation, and store the active results (those with their respective bit set in writemask k) to unaligned memory at base_addr.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_cvtepi16_storeu_epi8)
-#[inline]
-#[target_feature(enable = "avx512bw")]
-pub fn _mm512_mask_cvtepi16_storeu_epi8<T: Is256BitsUnaligned>(
-    base_addr: &mut T,
-    k: __mmask32,
-    a: __m512i,
-) {
-    unsafe { arch::_mm512_mask_cvtepi16_storeu_epi8(ptr::from_mut(base_addr).cast(), k, a) }
-}
+    /// Convert packed 16-bit integers in a to packed 8-bit integers with truncation, and store the active results (those with their respective bit set in writemask k) to unaligned memory at base_addr.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_cvtepi16_storeu_epi8)
+    #[method = mm512_mask_cvtepi16_storeu_epi8]
+    #[inline]
+    #[target_feature(enable = "avx512bw")]
+    pub fn _mm512_mask_cvtepi16_storeu_epi8<T: Is256BitsUnaligned>(
+        base_addr: &mut T,
+        k: __mmask32,
+        a: __m512i,
+    ) {
+        unsafe { arch::_mm512_mask_cvtepi16_storeu_epi8(ptr::from_mut(base_addr).cast(), k, a) }
+    }
 
-/// Convert packed signed 16-bit integers in a to packed 8-bit integers with signed saturation, and store the active results (those with their respective bit set in writemask k) to unaligned memory at base_addr.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_mask_cvtsepi16_storeu_epi8)
-#[inline]
-#[target_feature(enable = "avx512bw,avx512vl")]
-pub fn _mm_mask_cvtsepi16_storeu_epi8<T: Is64BitsUnaligned>(
-    base_addr: &mut T,
-    k: __mmask8,
-    a: __m128i,
-) {
-    unsafe { arch::_mm_mask_cvtsepi16_storeu_epi8(ptr::from_mut(base_addr).cast(), k, a) }
-}
+    /// Convert packed signed 16-bit integers in a to packed 8-bit integers with signed saturation, and store the active results (those with their respective bit set in writemask k) to unaligned memory at base_addr.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_mask_cvtsepi16_storeu_epi8)
+    #[method = mm_mask_cvtsepi16_storeu_epi8]
+    #[inline]
+    #[target_feature(enable = "avx512bw,avx512vl")]
+    pub fn _mm_mask_cvtsepi16_storeu_epi8<T: Is64BitsUnaligned>(
+        base_addr: &mut T,
+        k: __mmask8,
+        a: __m128i,
+    ) {
+        unsafe { arch::_mm_mask_cvtsepi16_storeu_epi8(ptr::from_mut(base_addr).cast(), k, a) }
+    }
 
-/// Convert packed signed 16-bit integers in a to packed 8-bit integers with signed saturation, and store the active results (those with their respective bit set in writemask k) to unaligned memory at base_addr.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_mask_cvtsepi16_storeu_epi8)
-#[inline]
-#[target_feature(enable = "avx512bw,avx512vl")]
-pub fn _mm256_mask_cvtsepi16_storeu_epi8<T: Is128BitsUnaligned>(
-    base_addr: &mut T,
-    k: __mmask16,
-    a: __m256i,
-) {
-    unsafe { arch::_mm256_mask_cvtsepi16_storeu_epi8(ptr::from_mut(base_addr).cast(), k, a) }
-}
+    /// Convert packed signed 16-bit integers in a to packed 8-bit integers with signed saturation, and store the active results (those with their respective bit set in writemask k) to unaligned memory at base_addr.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_mask_cvtsepi16_storeu_epi8)
+    #[method = mm256_mask_cvtsepi16_storeu_epi8]
+    #[inline]
+    #[target_feature(enable = "avx512bw,avx512vl")]
+    pub fn _mm256_mask_cvtsepi16_storeu_epi8<T: Is128BitsUnaligned>(
+        base_addr: &mut T,
+        k: __mmask16,
+        a: __m256i,
+    ) {
+        unsafe { arch::_mm256_mask_cvtsepi16_storeu_epi8(ptr::from_mut(base_addr).cast(), k, a) }
+    }
 
-/// Convert packed signed 16-bit integers in a to packed 8-bit integers with signed saturation, and store the active results (those with their respective bit set in writemask k) to unaligned memory at base_addr.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_cvtsepi16_storeu_epi8)
-#[inline]
-#[target_feature(enable = "avx512bw")]
-pub fn _mm512_mask_cvtsepi16_storeu_epi8<T: Is256BitsUnaligned>(
-    base_addr: &mut T,
-    k: __mmask32,
-    a: __m512i,
-) {
-    unsafe { arch::_mm512_mask_cvtsepi16_storeu_epi8(ptr::from_mut(base_addr).cast(), k, a) }
-}
+    /// Convert packed signed 16-bit integers in a to packed 8-bit integers with signed saturation, and store the active results (those with their respective bit set in writemask k) to unaligned memory at base_addr.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_cvtsepi16_storeu_epi8)
+    #[method = mm512_mask_cvtsepi16_storeu_epi8]
+    #[inline]
+    #[target_feature(enable = "avx512bw")]
+    pub fn _mm512_mask_cvtsepi16_storeu_epi8<T: Is256BitsUnaligned>(
+        base_addr: &mut T,
+        k: __mmask32,
+        a: __m512i,
+    ) {
+        unsafe { arch::_mm512_mask_cvtsepi16_storeu_epi8(ptr::from_mut(base_addr).cast(), k, a) }
+    }
 
-/// Convert packed unsigned 16-bit integers in a to packed unsigned 8-bit integers with unsigned saturation, and store the active results (those with their respective bit set in writemask k) to unaligned memory at base_addr.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_mask_cvtusepi16_storeu_epi8)
-#[inline]
-#[target_feature(enable = "avx512bw,avx512vl")]
-pub fn _mm_mask_cvtusepi16_storeu_epi8<T: Is64BitsUnaligned>(
-    base_addr: &mut T,
-    k: __mmask8,
-    a: __m128i,
-) {
-    unsafe { arch::_mm_mask_cvtusepi16_storeu_epi8(ptr::from_mut(base_addr).cast(), k, a) }
-}
+    /// Convert packed unsigned 16-bit integers in a to packed unsigned 8-bit integers with unsigned saturation, and store the active results (those with their respective bit set in writemask k) to unaligned memory at base_addr.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_mask_cvtusepi16_storeu_epi8)
+    #[method = mm_mask_cvtusepi16_storeu_epi8]
+    #[inline]
+    #[target_feature(enable = "avx512bw,avx512vl")]
+    pub fn _mm_mask_cvtusepi16_storeu_epi8<T: Is64BitsUnaligned>(
+        base_addr: &mut T,
+        k: __mmask8,
+        a: __m128i,
+    ) {
+        unsafe { arch::_mm_mask_cvtusepi16_storeu_epi8(ptr::from_mut(base_addr).cast(), k, a) }
+    }
 
-/// Convert packed unsigned 16-bit integers in a to packed unsigned 8-bit integers with unsigned saturation, and store the active results (those with their respective bit set in writemask k) to unaligned memory at base_addr.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_mask_cvtusepi16_storeu_epi8)
-#[inline]
-#[target_feature(enable = "avx512bw,avx512vl")]
-pub fn _mm256_mask_cvtusepi16_storeu_epi8<T: Is128BitsUnaligned>(
-    base_addr: &mut T,
-    k: __mmask16,
-    a: __m256i,
-) {
-    unsafe { arch::_mm256_mask_cvtusepi16_storeu_epi8(ptr::from_mut(base_addr).cast(), k, a) }
-}
+    /// Convert packed unsigned 16-bit integers in a to packed unsigned 8-bit integers with unsigned saturation, and store the active results (those with their respective bit set in writemask k) to unaligned memory at base_addr.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_mask_cvtusepi16_storeu_epi8)
+    #[method = mm256_mask_cvtusepi16_storeu_epi8]
+    #[inline]
+    #[target_feature(enable = "avx512bw,avx512vl")]
+    pub fn _mm256_mask_cvtusepi16_storeu_epi8<T: Is128BitsUnaligned>(
+        base_addr: &mut T,
+        k: __mmask16,
+        a: __m256i,
+    ) {
+        unsafe { arch::_mm256_mask_cvtusepi16_storeu_epi8(ptr::from_mut(base_addr).cast(), k, a) }
+    }
 
-/// Convert packed unsigned 16-bit integers in a to packed unsigned 8-bit integers with unsigned saturation, and store the active results (those with their respective bit set in writemask k) to unaligned memory at base_addr.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_cvtusepi16_storeu_epi8)
-#[inline]
-#[target_feature(enable = "avx512bw")]
-pub fn _mm512_mask_cvtusepi16_storeu_epi8<T: Is256BitsUnaligned>(
-    base_addr: &mut T,
-    k: __mmask32,
-    a: __m512i,
-) {
-    unsafe { arch::_mm512_mask_cvtusepi16_storeu_epi8(ptr::from_mut(base_addr).cast(), k, a) }
-}
+    /// Convert packed unsigned 16-bit integers in a to packed unsigned 8-bit integers with unsigned saturation, and store the active results (those with their respective bit set in writemask k) to unaligned memory at base_addr.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_cvtusepi16_storeu_epi8)
+    #[method = mm512_mask_cvtusepi16_storeu_epi8]
+    #[inline]
+    #[target_feature(enable = "avx512bw")]
+    pub fn _mm512_mask_cvtusepi16_storeu_epi8<T: Is256BitsUnaligned>(
+        base_addr: &mut T,
+        k: __mmask32,
+        a: __m512i,
+    ) {
+        unsafe { arch::_mm512_mask_cvtusepi16_storeu_epi8(ptr::from_mut(base_addr).cast(), k, a) }
+    }
 
-/// Store packed 16-bit integers from a into memory using writemask k.
-/// mem_addr does not need to be aligned on any particular boundary.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_mask_storeu_epi16)
-#[inline]
-#[target_feature(enable = "avx512bw,avx512vl")]
-pub fn _mm_mask_storeu_epi16<T: Is128BitsUnaligned>(mem_addr: &mut T, k: __mmask8, a: __m128i) {
-    unsafe { arch::_mm_mask_storeu_epi16(ptr::from_mut(mem_addr).cast(), k, a) }
-}
+    /// Store packed 16-bit integers from a into memory using writemask k.
+    /// mem_addr does not need to be aligned on any particular boundary.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_mask_storeu_epi16)
+    #[method = mm_mask_storeu_epi16]
+    #[inline]
+    #[target_feature(enable = "avx512bw,avx512vl")]
+    pub fn _mm_mask_storeu_epi16<T: Is128BitsUnaligned>(mem_addr: &mut T, k: __mmask8, a: __m128i) {
+        unsafe { arch::_mm_mask_storeu_epi16(ptr::from_mut(mem_addr).cast(), k, a) }
+    }
 
-/// Store 128-bits (composed of 8 packed 16-bit integers) from a into memory. mem_addr does not need to be aligned on any particular boundary.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_storeu_epi16)
-#[inline]
-#[target_feature(enable = "avx512bw,avx512vl")]
-pub fn _mm_storeu_epi16<T: Is128BitsUnaligned>(mem_addr: &mut T, a: __m128i) {
-    unsafe { arch::_mm_storeu_epi16(ptr::from_mut(mem_addr).cast(), a) }
-}
+    /// Store 128-bits (composed of 8 packed 16-bit integers) from a into memory. mem_addr does not need to be aligned on any particular boundary.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_storeu_epi16)
+    #[method = mm_storeu_epi16]
+    #[inline]
+    #[target_feature(enable = "avx512bw,avx512vl")]
+    pub fn _mm_storeu_epi16<T: Is128BitsUnaligned>(mem_addr: &mut T, a: __m128i) {
+        unsafe { arch::_mm_storeu_epi16(ptr::from_mut(mem_addr).cast(), a) }
+    }
 
-/// Store packed 16-bit integers from a into memory using writemask k.
-/// mem_addr does not need to be aligned on any particular boundary.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_mask_storeu_epi16)
-#[inline]
-#[target_feature(enable = "avx512bw,avx512vl")]
-pub fn _mm256_mask_storeu_epi16<T: Is256BitsUnaligned>(mem_addr: &mut T, k: __mmask16, a: __m256i) {
-    unsafe { arch::_mm256_mask_storeu_epi16(ptr::from_mut(mem_addr).cast(), k, a) }
-}
+    /// Store packed 16-bit integers from a into memory using writemask k.
+    /// mem_addr does not need to be aligned on any particular boundary.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_mask_storeu_epi16)
+    #[method = mm256_mask_storeu_epi16]
+    #[inline]
+    #[target_feature(enable = "avx512bw,avx512vl")]
+    pub fn _mm256_mask_storeu_epi16<T: Is256BitsUnaligned>(mem_addr: &mut T, k: __mmask16, a: __m256i) {
+        unsafe { arch::_mm256_mask_storeu_epi16(ptr::from_mut(mem_addr).cast(), k, a) }
+    }
 
-/// Store 256-bits (composed of 16 packed 16-bit integers) from a into memory. mem_addr does not need to be aligned on any particular boundary.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_storeu_epi16)
-#[inline]
-#[target_feature(enable = "avx512bw,avx512vl")]
-pub fn _mm256_storeu_epi16<T: Is256BitsUnaligned>(mem_addr: &mut T, a: __m256i) {
-    unsafe { arch::_mm256_storeu_epi16(ptr::from_mut(mem_addr).cast(), a) }
-}
+    /// Store 256-bits (composed of 16 packed 16-bit integers) from a into memory. mem_addr does not need to be aligned on any particular boundary.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_storeu_epi16)
+    #[method = mm256_storeu_epi16]
+    #[inline]
+    #[target_feature(enable = "avx512bw,avx512vl")]
+    pub fn _mm256_storeu_epi16<T: Is256BitsUnaligned>(mem_addr: &mut T, a: __m256i) {
+        unsafe { arch::_mm256_storeu_epi16(ptr::from_mut(mem_addr).cast(), a) }
+    }
 
-/// Store packed 16-bit integers from a into memory using writemask k.
-/// mem_addr does not need to be aligned on any particular boundary.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_storeu_epi16)
-#[inline]
-#[target_feature(enable = "avx512bw")]
-pub fn _mm512_mask_storeu_epi16<T: Is512BitsUnaligned>(mem_addr: &mut T, k: __mmask32, a: __m512i) {
-    unsafe { arch::_mm512_mask_storeu_epi16(ptr::from_mut(mem_addr).cast(), k, a) }
-}
+    /// Store packed 16-bit integers from a into memory using writemask k.
+    /// mem_addr does not need to be aligned on any particular boundary.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_storeu_epi16)
+    #[method = mm512_mask_storeu_epi16]
+    #[inline]
+    #[target_feature(enable = "avx512bw")]
+    pub fn _mm512_mask_storeu_epi16<T: Is512BitsUnaligned>(mem_addr: &mut T, k: __mmask32, a: __m512i) {
+        unsafe { arch::_mm512_mask_storeu_epi16(ptr::from_mut(mem_addr).cast(), k, a) }
+    }
 
-/// Store 512-bits (composed of 32 packed 16-bit integers) from a into memory. mem_addr does not need to be aligned on any particular boundary.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_storeu_epi16)
-#[inline]
-#[target_feature(enable = "avx512bw")]
-pub fn _mm512_storeu_epi16<T: Is512BitsUnaligned>(mem_addr: &mut T, a: __m512i) {
-    unsafe { arch::_mm512_storeu_epi16(ptr::from_mut(mem_addr).cast(), a) }
-}
+    /// Store 512-bits (composed of 32 packed 16-bit integers) from a into memory. mem_addr does not need to be aligned on any particular boundary.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_storeu_epi16)
+    #[method = mm512_storeu_epi16]
+    #[inline]
+    #[target_feature(enable = "avx512bw")]
+    pub fn _mm512_storeu_epi16<T: Is512BitsUnaligned>(mem_addr: &mut T, a: __m512i) {
+        unsafe { arch::_mm512_storeu_epi16(ptr::from_mut(mem_addr).cast(), a) }
+    }
 
-/// Store packed 8-bit integers from a into memory using writemask k.
-/// mem_addr does not need to be aligned on any particular boundary.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_mask_storeu_epi8)
-#[inline]
-#[target_feature(enable = "avx512bw,avx512vl")]
-pub fn _mm_mask_storeu_epi8<T: Is128BitsUnaligned>(mem_addr: &mut T, k: __mmask16, a: __m128i) {
-    unsafe { arch::_mm_mask_storeu_epi8(ptr::from_mut(mem_addr).cast(), k, a) }
-}
+    /// Store packed 8-bit integers from a into memory using writemask k.
+    /// mem_addr does not need to be aligned on any particular boundary.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_mask_storeu_epi8)
+    #[method = mm_mask_storeu_epi8]
+    #[inline]
+    #[target_feature(enable = "avx512bw,avx512vl")]
+    pub fn _mm_mask_storeu_epi8<T: Is128BitsUnaligned>(mem_addr: &mut T, k: __mmask16, a: __m128i) {
+        unsafe { arch::_mm_mask_storeu_epi8(ptr::from_mut(mem_addr).cast(), k, a) }
+    }
 
-/// Store 128-bits (composed of 16 packed 8-bit integers) from a into memory. mem_addr does not need to be aligned on any particular boundary.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_storeu_epi8)
-#[inline]
-#[target_feature(enable = "avx512bw,avx512vl")]
-pub fn _mm_storeu_epi8<T: Is128BitsUnaligned>(mem_addr: &mut T, a: __m128i) {
-    unsafe { arch::_mm_storeu_epi8(ptr::from_mut(mem_addr).cast(), a) }
-}
+    /// Store 128-bits (composed of 16 packed 8-bit integers) from a into memory. mem_addr does not need to be aligned on any particular boundary.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_storeu_epi8)
+    #[method = mm_storeu_epi8]
+    #[inline]
+    #[target_feature(enable = "avx512bw,avx512vl")]
+    pub fn _mm_storeu_epi8<T: Is128BitsUnaligned>(mem_addr: &mut T, a: __m128i) {
+        unsafe { arch::_mm_storeu_epi8(ptr::from_mut(mem_addr).cast(), a) }
+    }
 
-/// Store packed 8-bit integers from a into memory using writemask k.
-/// mem_addr does not need to be aligned on any particular boundary.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_mask_storeu_epi8)
-#[inline]
-#[target_feature(enable = "avx512bw,avx512vl")]
-pub fn _mm256_mask_storeu_epi8<T: Is256BitsUnaligned>(mem_addr: &mut T, k: __mmask32, a: __m256i) {
-    unsafe { arch::_mm256_mask_storeu_epi8(ptr::from_mut(mem_addr).cast(), k, a) }
-}
+    /// Store packed 8-bit integers from a into memory using writemask k.
+    /// mem_addr does not need to be aligned on any particular boundary.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_mask_storeu_epi8)
+    #[method = mm256_mask_storeu_epi8]
+    #[inline]
+    #[target_feature(enable = "avx512bw,avx512vl")]
+    pub fn _mm256_mask_storeu_epi8<T: Is256BitsUnaligned>(mem_addr: &mut T, k: __mmask32, a: __m256i) {
+        unsafe { arch::_mm256_mask_storeu_epi8(ptr::from_mut(mem_addr).cast(), k, a) }
+    }
 
-/// Store 256-bits (composed of 32 packed 8-bit integers) from a into memory. mem_addr does not need to be aligned on any particular boundary.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_storeu_epi8)
-#[inline]
-#[target_feature(enable = "avx512bw,avx512vl")]
-pub fn _mm256_storeu_epi8<T: Is256BitsUnaligned>(mem_addr: &mut T, a: __m256i) {
-    unsafe { arch::_mm256_storeu_epi8(ptr::from_mut(mem_addr).cast(), a) }
-}
+    /// Store 256-bits (composed of 32 packed 8-bit integers) from a into memory. mem_addr does not need to be aligned on any particular boundary.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_storeu_epi8)
+    #[method = mm256_storeu_epi8]
+    #[inline]
+    #[target_feature(enable = "avx512bw,avx512vl")]
+    pub fn _mm256_storeu_epi8<T: Is256BitsUnaligned>(mem_addr: &mut T, a: __m256i) {
+        unsafe { arch::_mm256_storeu_epi8(ptr::from_mut(mem_addr).cast(), a) }
+    }
 
-/// Store packed 8-bit integers from a into memory using writemask k.
-/// mem_addr does not need to be aligned on any particular boundary.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_storeu_epi8)
-#[inline]
-#[target_feature(enable = "avx512bw")]
-pub fn _mm512_mask_storeu_epi8<T: Is512BitsUnaligned>(mem_addr: &mut T, k: __mmask64, a: __m512i) {
-    unsafe { arch::_mm512_mask_storeu_epi8(ptr::from_mut(mem_addr).cast(), k, a) }
-}
+    /// Store packed 8-bit integers from a into memory using writemask k.
+    /// mem_addr does not need to be aligned on any particular boundary.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_storeu_epi8)
+    #[method = mm512_mask_storeu_epi8]
+    #[inline]
+    #[target_feature(enable = "avx512bw")]
+    pub fn _mm512_mask_storeu_epi8<T: Is512BitsUnaligned>(mem_addr: &mut T, k: __mmask64, a: __m512i) {
+        unsafe { arch::_mm512_mask_storeu_epi8(ptr::from_mut(mem_addr).cast(), k, a) }
+    }
 
-/// Store 512-bits (composed of 64 packed 8-bit integers) from a into memory. mem_addr does not need to be aligned on any particular boundary.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_storeu_epi8)
-#[inline]
-#[target_feature(enable = "avx512bw")]
-pub fn _mm512_storeu_epi8<T: Is512BitsUnaligned>(mem_addr: &mut T, a: __m512i) {
-    unsafe { arch::_mm512_storeu_epi8(ptr::from_mut(mem_addr).cast(), a) }
+    /// Store 512-bits (composed of 64 packed 8-bit integers) from a into memory. mem_addr does not need to be aligned on any particular boundary.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_storeu_epi8)
+    #[method = mm512_storeu_epi8]
+    #[inline]
+    #[target_feature(enable = "avx512bw")]
+    pub fn _mm512_storeu_epi8<T: Is512BitsUnaligned>(mem_addr: &mut T, a: __m512i) {
+        unsafe { arch::_mm512_storeu_epi8(ptr::from_mut(mem_addr).cast(), a) }
+    }
 }
 
 #[cfg(test)]
@@ -465,25 +512,27 @@ mod tests {
 
     use core::hint::black_box;
 
+    use crate::Register;
+
     // Fail-safe for tests being run on a CPU that doesn't support the instruction set
     static CPU_HAS_AVX512BW: std::sync::LazyLock<bool> =
         std::sync::LazyLock::new(|| is_x86_feature_detected!("avx512bw"));
 
     fn assert_eq_m128i(a: __m128i, b: __m128i) {
-        let a: [u8; 16] = unsafe { core::mem::transmute(a) };
-        let b: [u8; 16] = unsafe { core::mem::transmute(b) };
+        let a: [u8; 16] = a.to_array();
+        let b: [u8; 16] = b.to_array();
         assert_eq!(a, b)
     }
 
     fn assert_eq_m256i(a: __m256i, b: __m256i) {
-        let a: [u8; 32] = unsafe { core::mem::transmute(a) };
-        let b: [u8; 32] = unsafe { core::mem::transmute(b) };
+        let a: [u8; 32] = a.to_array();
+        let b: [u8; 32] = b.to_array();
         assert_eq!(a, b)
     }
 
     fn assert_eq_m512i(a: __m512i, b: __m512i) {
-        let a: [u8; 64] = unsafe { core::mem::transmute(a) };
-        let b: [u8; 64] = unsafe { core::mem::transmute(b) };
+        let a: [u8; 64] = a.to_array();
+        let b: [u8; 64] = b.to_array();
         assert_eq!(a, b)
     }
 
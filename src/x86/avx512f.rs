@@ -12,1553 +12,1689 @@ use core::ptr;
 
 #[cfg(target_arch = "x86")]
 use crate::x86::{
-    Is16BitsUnaligned, Is32BitsUnaligned, Is64BitsUnaligned, Is128BitsUnaligned,
-    Is256BitsUnaligned, Is512BitsUnaligned,
+    Is16BitsUnaligned, Is32BitsUnaligned, Is64BitsUnaligned, Is128BitsReadable, Is128BitsUnaligned,
+    Is256BitsReadable, Is256BitsUnaligned, Is512BitsReadable, Is512BitsUnaligned,
 };
 #[cfg(target_arch = "x86_64")]
 use crate::x86_64::{
-    Is16BitsUnaligned, Is32BitsUnaligned, Is64BitsUnaligned, Is128BitsUnaligned,
-    Is256BitsUnaligned, Is512BitsUnaligned,
+    Is16BitsUnaligned, Is32BitsUnaligned, Is64BitsUnaligned, Is128BitsReadable, Is128BitsUnaligned,
+    Is256BitsReadable, Is256BitsUnaligned, Is512BitsReadable, Is512BitsUnaligned,
 };
 
-/// Load contiguous active 32-bit integers from unaligned memory at mem_addr (those with their respective bit set in mask k), and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_mask_expandloadu_epi32)
-#[inline]
-#[target_feature(enable = "avx512f,avx512vl")]
-pub fn _mm_mask_expandloadu_epi32<T: Is128BitsUnaligned>(
-    src: __m128i,
-    k: __mmask8,
-    mem_addr: &T,
-) -> __m128i {
-    unsafe { arch::_mm_mask_expandloadu_epi32(src, k, ptr::from_ref(mem_addr).cast()) }
-}
-
-/// Load contiguous active 32-bit integers from unaligned memory at mem_addr (those with their respective bit set in mask k), and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_maskz_expandloadu_epi32)
-#[inline]
-#[target_feature(enable = "avx512f,avx512vl")]
-pub fn _mm_maskz_expandloadu_epi32<T: Is128BitsUnaligned>(k: __mmask8, mem_addr: &T) -> __m128i {
-    _mm_mask_expandloadu_epi32(arch::_mm_setzero_si128(), k, mem_addr)
-}
-
-/// Load contiguous active 32-bit integers from unaligned memory at mem_addr (those with their respective bit set in mask k), and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_mask_expandloadu_epi32)
-#[inline]
-#[target_feature(enable = "avx512f,avx512vl")]
-pub fn _mm256_mask_expandloadu_epi32<T: Is256BitsUnaligned>(
-    src: __m256i,
-    k: __mmask8,
-    mem_addr: &T,
-) -> __m256i {
-    unsafe { arch::_mm256_mask_expandloadu_epi32(src, k, ptr::from_ref(mem_addr).cast()) }
-}
-
-/// Load contiguous active 32-bit integers from unaligned memory at mem_addr (those with their respective bit set in mask k), and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_maskz_expandloadu_epi32)
-#[inline]
-#[target_feature(enable = "avx512f,avx512vl")]
-pub fn _mm256_maskz_expandloadu_epi32<T: Is256BitsUnaligned>(k: __mmask8, mem_addr: &T) -> __m256i {
-    _mm256_mask_expandloadu_epi32(arch::_mm256_setzero_si256(), k, mem_addr)
-}
-
-/// Load contiguous active 32-bit integers from unaligned memory at mem_addr (those with their respective bit set in mask k), and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_expandloadu_epi32)
-#[inline]
-#[target_feature(enable = "avx512f")]
-pub fn _mm512_mask_expandloadu_epi32<T: Is512BitsUnaligned>(
-    src: __m512i,
-    k: __mmask16,
-    mem_addr: &T,
-) -> __m512i {
-    unsafe { arch::_mm512_mask_expandloadu_epi32(src, k, ptr::from_ref(mem_addr).cast()) }
-}
-
-/// Load contiguous active 32-bit integers from unaligned memory at mem_addr (those with their respective bit set in mask k), and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_maskz_expandloadu_epi32)
-#[inline]
-#[target_feature(enable = "avx512f")]
-pub fn _mm512_maskz_expandloadu_epi32<T: Is512BitsUnaligned>(
-    k: __mmask16,
-    mem_addr: &T,
-) -> __m512i {
-    _mm512_mask_expandloadu_epi32(arch::_mm512_setzero_si512(), k, mem_addr)
-}
-
-/// Load contiguous active 64-bit integers from unaligned memory at mem_addr (those with their respective bit set in mask k), and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_mask_expandloadu_epi64)
-#[inline]
-#[target_feature(enable = "avx512f,avx512vl")]
-pub fn _mm_mask_expandloadu_epi64<T: Is128BitsUnaligned>(
-    src: __m128i,
-    k: __mmask8,
-    mem_addr: &T,
-) -> __m128i {
-    unsafe { arch::_mm_mask_expandloadu_epi64(src, k, ptr::from_ref(mem_addr).cast()) }
-}
-
-/// Load contiguous active 64-bit integers from unaligned memory at mem_addr (those with their respective bit set in mask k), and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_maskz_expandloadu_epi64)
-#[inline]
-#[target_feature(enable = "avx512f,avx512vl")]
-pub fn _mm_maskz_expandloadu_epi64<T: Is128BitsUnaligned>(k: __mmask8, mem_addr: &T) -> __m128i {
-    _mm_mask_expandloadu_epi64(arch::_mm_setzero_si128(), k, mem_addr)
-}
-
-/// Load contiguous active 64-bit integers from unaligned memory at mem_addr (those with their respective bit set in mask k), and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_mask_expandloadu_epi64)
-#[inline]
-#[target_feature(enable = "avx512f,avx512vl")]
-pub fn _mm256_mask_expandloadu_epi64<T: Is256BitsUnaligned>(
-    src: __m256i,
-    k: __mmask8,
-    mem_addr: &T,
-) -> __m256i {
-    unsafe { arch::_mm256_mask_expandloadu_epi64(src, k, ptr::from_ref(mem_addr).cast()) }
-}
-
-/// Load contiguous active 64-bit integers from unaligned memory at mem_addr (those with their respective bit set in mask k), and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_maskz_expandloadu_epi64)
-#[inline]
-#[target_feature(enable = "avx512f,avx512vl")]
-pub fn _mm256_maskz_expandloadu_epi64<T: Is256BitsUnaligned>(k: __mmask8, mem_addr: &T) -> __m256i {
-    _mm256_mask_expandloadu_epi64(arch::_mm256_setzero_si256(), k, mem_addr)
-}
-
-/// Load contiguous active 64-bit integers from unaligned memory at mem_addr (those with their respective bit set in mask k), and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_expandloadu_epi64)
-#[inline]
-#[target_feature(enable = "avx512f")]
-pub fn _mm512_mask_expandloadu_epi64<T: Is512BitsUnaligned>(
-    src: __m512i,
-    k: __mmask8,
-    mem_addr: &T,
-) -> __m512i {
-    unsafe { arch::_mm512_mask_expandloadu_epi64(src, k, ptr::from_ref(mem_addr).cast()) }
-}
-
-/// Load contiguous active 64-bit integers from unaligned memory at mem_addr (those with their respective bit set in mask k), and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_maskz_expandloadu_epi64)
-#[inline]
-#[target_feature(enable = "avx512f")]
-pub fn _mm512_maskz_expandloadu_epi64<T: Is512BitsUnaligned>(k: __mmask8, mem_addr: &T) -> __m512i {
-    _mm512_mask_expandloadu_epi64(arch::_mm512_setzero_si512(), k, mem_addr)
-}
-
-/// Load contiguous active double-precision (64-bit) floating-point elements from unaligned memory at mem_addr (those with their respective bit set in mask k), and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_mask_expandloadu_pd)
-#[inline]
-#[target_feature(enable = "avx512f,avx512vl")]
-pub fn _mm_mask_expandloadu_pd(src: __m128d, k: __mmask8, mem_addr: &[f64; 2]) -> __m128d {
-    unsafe { arch::_mm_mask_expandloadu_pd(src, k, ptr::from_ref(mem_addr).cast()) }
-}
-
-/// Load contiguous active double-precision (64-bit) floating-point elements from unaligned memory at mem_addr (those with their respective bit set in mask k), and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_maskz_expandloadu_pd)
-#[inline]
-#[target_feature(enable = "avx512f,avx512vl")]
-pub fn _mm_maskz_expandloadu_pd(k: __mmask8, mem_addr: &[f64; 2]) -> __m128d {
-    _mm_mask_expandloadu_pd(arch::_mm_setzero_pd(), k, mem_addr)
-}
-
-/// Load contiguous active double-precision (64-bit) floating-point elements from unaligned memory at mem_addr (those with their respective bit set in mask k), and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_mask_expandloadu_pd)
-#[inline]
-#[target_feature(enable = "avx512f,avx512vl")]
-pub fn _mm256_mask_expandloadu_pd(src: __m256d, k: __mmask8, mem_addr: &[f64; 4]) -> __m256d {
-    unsafe { arch::_mm256_mask_expandloadu_pd(src, k, ptr::from_ref(mem_addr).cast()) }
-}
-
-/// Load contiguous active double-precision (64-bit) floating-point elements from unaligned memory at mem_addr (those with their respective bit set in mask k), and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_maskz_expandloadu_pd)
-#[inline]
-#[target_feature(enable = "avx512f,avx512vl")]
-pub fn _mm256_maskz_expandloadu_pd(k: __mmask8, mem_addr: &[f64; 4]) -> __m256d {
-    _mm256_mask_expandloadu_pd(arch::_mm256_setzero_pd(), k, mem_addr)
-}
-
-/// Load contiguous active double-precision (64-bit) floating-point elements from unaligned memory at mem_addr (those with their respective bit set in mask k), and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_expandloadu_pd)
-#[inline]
-#[target_feature(enable = "avx512f")]
-pub fn _mm512_mask_expandloadu_pd(src: __m512d, k: __mmask8, mem_addr: &[f64; 8]) -> __m512d {
-    unsafe { arch::_mm512_mask_expandloadu_pd(src, k, ptr::from_ref(mem_addr).cast()) }
-}
-
-/// Load contiguous active double-precision (64-bit) floating-point elements from unaligned memory at mem_addr (those with their respective bit set in mask k), and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_maskz_expandloadu_pd)
-#[inline]
-#[target_feature(enable = "avx512f")]
-pub fn _mm512_maskz_expandloadu_pd(k: __mmask8, mem_addr: &[f64; 8]) -> __m512d {
-    _mm512_mask_expandloadu_pd(arch::_mm512_setzero_pd(), k, mem_addr)
-}
-
-/// Load contiguous active single-precision (32-bit) floating-point elements from unaligned memory at mem_addr (those with their respective bit set in mask k), and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_mask_expandloadu_ps)
-#[inline]
-#[target_feature(enable = "avx512f,avx512vl")]
-pub fn _mm_mask_expandloadu_ps(src: __m128, k: __mmask8, mem_addr: &[f32; 4]) -> __m128 {
-    unsafe { arch::_mm_mask_expandloadu_ps(src, k, ptr::from_ref(mem_addr).cast()) }
-}
-
-/// Load contiguous active single-precision (32-bit) floating-point elements from unaligned memory at mem_addr (those with their respective bit set in mask k), and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_maskz_expandloadu_ps)
-#[inline]
-#[target_feature(enable = "avx512f,avx512vl")]
-pub fn _mm_maskz_expandloadu_ps(k: __mmask8, mem_addr: &[f32; 4]) -> __m128 {
-    _mm_mask_expandloadu_ps(arch::_mm_setzero_ps(), k, mem_addr)
-}
-
-/// Load contiguous active single-precision (32-bit) floating-point elements from unaligned memory at mem_addr (those with their respective bit set in mask k), and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_mask_expandloadu_ps)
-#[inline]
-#[target_feature(enable = "avx512f,avx512vl")]
-pub fn _mm256_mask_expandloadu_ps(src: __m256, k: __mmask8, mem_addr: &[f32; 8]) -> __m256 {
-    unsafe { arch::_mm256_mask_expandloadu_ps(src, k, ptr::from_ref(mem_addr).cast()) }
-}
-
-/// Load contiguous active single-precision (32-bit) floating-point elements from unaligned memory at mem_addr (those with their respective bit set in mask k), and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_maskz_expandloadu_ps)
-#[inline]
-#[target_feature(enable = "avx512f,avx512vl")]
-pub fn _mm256_maskz_expandloadu_ps(k: __mmask8, mem_addr: &[f32; 8]) -> __m256 {
-    _mm256_mask_expandloadu_ps(arch::_mm256_setzero_ps(), k, mem_addr)
-}
-
-/// Load contiguous active single-precision (32-bit) floating-point elements from unaligned memory at mem_addr (those with their respective bit set in mask k), and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_expandloadu_ps)
-#[inline]
-#[target_feature(enable = "avx512f")]
-pub fn _mm512_mask_expandloadu_ps(src: __m512, k: __mmask16, mem_addr: &[f32; 16]) -> __m512 {
-    unsafe { arch::_mm512_mask_expandloadu_ps(src, k, ptr::from_ref(mem_addr).cast()) }
-}
-
-/// Load contiguous active single-precision (32-bit) floating-point elements from unaligned memory at mem_addr (those with their respective bit set in mask k), and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_maskz_expandloadu_ps)
-#[inline]
-#[target_feature(enable = "avx512f")]
-pub fn _mm512_maskz_expandloadu_ps(k: __mmask16, mem_addr: &[f32; 16]) -> __m512 {
-    _mm512_mask_expandloadu_ps(arch::_mm512_setzero_ps(), k, mem_addr)
-}
-
-/// Load 128-bits (composed of 4 packed 32-bit integers) from memory into dst. mem_addr does not need to be aligned on any particular boundary.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_loadu_epi32)
-#[inline]
-#[target_feature(enable = "avx512f,avx512vl")]
-pub fn _mm_loadu_epi32<T: Is128BitsUnaligned>(mem_addr: &T) -> __m128i {
-    unsafe { arch::_mm_loadu_epi32(ptr::from_ref(mem_addr).cast()) }
-}
-
-/// Load packed 32-bit integers from memory into dst using writemask k
-/// (elements are copied from src when the corresponding mask bit is not set).
-/// mem_addr does not need to be aligned on any particular boundary.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_mask_loadu_epi32)
-#[inline]
-#[target_feature(enable = "avx512f,avx512vl")]
-pub fn _mm_mask_loadu_epi32<T: Is128BitsUnaligned>(
-    src: __m128i,
-    k: __mmask8,
-    mem_addr: &T,
-) -> __m128i {
-    unsafe { arch::_mm_mask_loadu_epi32(src, k, ptr::from_ref(mem_addr).cast()) }
-}
-
-/// Load packed 32-bit integers from memory into dst using zeromask k
-/// (elements are zeroed out when the corresponding mask bit is not set).
-/// mem_addr does not need to be aligned on any particular boundary.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_maskz_loadu_epi32)
-#[inline]
-#[target_feature(enable = "avx512f,avx512vl")]
-pub fn _mm_maskz_loadu_epi32<T: Is128BitsUnaligned>(k: __mmask8, mem_addr: &T) -> __m128i {
-    _mm_mask_loadu_epi32(arch::_mm_setzero_si128(), k, mem_addr)
-}
-
-/// Load 256-bits (composed of 8 packed 32-bit integers) from memory into dst. mem_addr does not need to be aligned on any particular boundary.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_loadu_epi32)
-#[inline]
-#[target_feature(enable = "avx512f,avx512vl")]
-pub fn _mm256_loadu_epi32<T: Is256BitsUnaligned>(mem_addr: &T) -> __m256i {
-    unsafe { arch::_mm256_loadu_epi32(ptr::from_ref(mem_addr).cast()) }
-}
-
-/// Load packed 32-bit integers from memory into dst using writemask k
-/// (elements are copied from src when the corresponding mask bit is not set).
-/// mem_addr does not need to be aligned on any particular boundary.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_mask_loadu_epi32)
-#[inline]
-#[target_feature(enable = "avx512f,avx512vl")]
-pub fn _mm256_mask_loadu_epi32<T: Is256BitsUnaligned>(
-    src: __m256i,
-    k: __mmask8,
-    mem_addr: &T,
-) -> __m256i {
-    unsafe { arch::_mm256_mask_loadu_epi32(src, k, ptr::from_ref(mem_addr).cast()) }
-}
-
-/// Load packed 32-bit integers from memory into dst using zeromask k
-/// (elements are zeroed out when the corresponding mask bit is not set).
-/// mem_addr does not need to be aligned on any particular boundary.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_maskz_loadu_epi32)
-#[inline]
-#[target_feature(enable = "avx512f,avx512vl")]
-pub fn _mm256_maskz_loadu_epi32<T: Is256BitsUnaligned>(k: __mmask8, mem_addr: &T) -> __m256i {
-    _mm256_mask_loadu_epi32(arch::_mm256_setzero_si256(), k, mem_addr)
-}
-
-/// Load 512-bits (composed of 16 packed 32-bit integers) from memory into dst. mem_addr does not need to be aligned on any particular boundary.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_loadu_epi32)
-#[inline]
-#[target_feature(enable = "avx512f")]
-pub fn _mm512_loadu_epi32<T: Is512BitsUnaligned>(mem_addr: &T) -> __m512i {
-    unsafe { arch::_mm512_loadu_epi32(ptr::from_ref(mem_addr).cast()) }
-}
-
-/// Load packed 32-bit integers from memory into dst using writemask k
-/// (elements are copied from src when the corresponding mask bit is not set).
-/// mem_addr does not need to be aligned on any particular boundary.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_loadu_epi32)
-#[inline]
-#[target_feature(enable = "avx512f")]
-pub fn _mm512_mask_loadu_epi32<T: Is512BitsUnaligned>(
-    src: __m512i,
-    k: __mmask16,
-    mem_addr: &T,
-) -> __m512i {
-    unsafe { arch::_mm512_mask_loadu_epi32(src, k, ptr::from_ref(mem_addr).cast()) }
-}
-
-/// Load packed 32-bit integers from memory into dst using zeromask k
-/// (elements are zeroed out when the corresponding mask bit is not set).
-/// mem_addr does not need to be aligned on any particular boundary.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_maskz_loadu_epi32)
-#[inline]
-#[target_feature(enable = "avx512f")]
-pub fn _mm512_maskz_loadu_epi32<T: Is512BitsUnaligned>(k: __mmask16, mem_addr: &T) -> __m512i {
-    _mm512_mask_loadu_epi32(arch::_mm512_setzero_si512(), k, mem_addr)
-}
-
-/// Load 128-bits (composed of 2 packed 64-bit integers) from memory into dst. mem_addr does not need to be aligned on any particular boundary.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_loadu_epi64)
-#[inline]
-#[target_feature(enable = "avx512f,avx512vl")]
-pub fn _mm_loadu_epi64<T: Is128BitsUnaligned>(mem_addr: &T) -> __m128i {
-    unsafe { arch::_mm_loadu_epi64(ptr::from_ref(mem_addr).cast()) }
-}
-
-/// Load packed 64-bit integers from memory into dst using writemask k
-/// (elements are copied from src when the corresponding mask bit is not set).
-/// mem_addr does not need to be aligned on any particular boundary.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_mask_loadu_epi64)
-#[inline]
-#[target_feature(enable = "avx512f,avx512vl")]
-pub fn _mm_mask_loadu_epi64<T: Is128BitsUnaligned>(
-    src: __m128i,
-    k: __mmask8,
-    mem_addr: &T,
-) -> __m128i {
-    unsafe { arch::_mm_mask_loadu_epi64(src, k, ptr::from_ref(mem_addr).cast()) }
-}
-
-/// Load packed 64-bit integers from memory into dst using zeromask k
-/// (elements are zeroed out when the corresponding mask bit is not set).
-/// mem_addr does not need to be aligned on any particular boundary.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_maskz_loadu_epi64)
-#[inline]
-#[target_feature(enable = "avx512f,avx512vl")]
-pub fn _mm_maskz_loadu_epi64<T: Is128BitsUnaligned>(k: __mmask8, mem_addr: &T) -> __m128i {
-    _mm_mask_loadu_epi64(arch::_mm_setzero_si128(), k, mem_addr)
-}
-
-/// Load 256-bits (composed of 4 packed 64-bit integers) from memory into dst. mem_addr does not need to be aligned on any particular boundary.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_loadu_epi64)
-#[inline]
-#[target_feature(enable = "avx512f,avx512vl")]
-pub fn _mm256_loadu_epi64<T: Is256BitsUnaligned>(mem_addr: &T) -> __m256i {
-    unsafe { arch::_mm256_loadu_epi64(ptr::from_ref(mem_addr).cast()) }
-}
-
-/// Load packed 64-bit integers from memory into dst using writemask k
-/// (elements are copied from src when the corresponding mask bit is not set).
-/// mem_addr does not need to be aligned on any particular boundary.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_mask_loadu_epi64)
-#[inline]
-#[target_feature(enable = "avx512f,avx512vl")]
-pub fn _mm256_mask_loadu_epi64<T: Is256BitsUnaligned>(
-    src: __m256i,
-    k: __mmask8,
-    mem_addr: &T,
-) -> __m256i {
-    unsafe { arch::_mm256_mask_loadu_epi64(src, k, ptr::from_ref(mem_addr).cast()) }
-}
-
-/// Load packed 64-bit integers from memory into dst using zeromask k
-/// (elements are zeroed out when the corresponding mask bit is not set).
-/// mem_addr does not need to be aligned on any particular boundary.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_maskz_loadu_epi64)
-#[inline]
-#[target_feature(enable = "avx512f,avx512vl")]
-pub fn _mm256_maskz_loadu_epi64<T: Is256BitsUnaligned>(k: __mmask8, mem_addr: &T) -> __m256i {
-    _mm256_mask_loadu_epi64(arch::_mm256_setzero_si256(), k, mem_addr)
-}
-
-/// Load 512-bits (composed of 8 packed 64-bit integers) from memory into dst. mem_addr does not need to be aligned on any particular boundary.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_loadu_epi64)
-#[inline]
-#[target_feature(enable = "avx512f")]
-pub fn _mm512_loadu_epi64<T: Is512BitsUnaligned>(mem_addr: &T) -> __m512i {
-    unsafe { arch::_mm512_loadu_epi64(ptr::from_ref(mem_addr).cast()) }
-}
-
-/// Load packed 64-bit integers from memory into dst using writemask k
-/// (elements are copied from src when the corresponding mask bit is not set).
-/// mem_addr does not need to be aligned on any particular boundary.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_loadu_epi64)
-#[inline]
-#[target_feature(enable = "avx512f")]
-pub fn _mm512_mask_loadu_epi64<T: Is512BitsUnaligned>(
-    src: __m512i,
-    k: __mmask8,
-    mem_addr: &T,
-) -> __m512i {
-    unsafe { arch::_mm512_mask_loadu_epi64(src, k, ptr::from_ref(mem_addr).cast()) }
-}
-
-/// Load packed 64-bit integers from memory into dst using zeromask k
-/// (elements are zeroed out when the corresponding mask bit is not set).
-/// mem_addr does not need to be aligned on any particular boundary.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_maskz_loadu_epi64)
-#[inline]
-#[target_feature(enable = "avx512f")]
-pub fn _mm512_maskz_loadu_epi64<T: Is512BitsUnaligned>(k: __mmask8, mem_addr: &T) -> __m512i {
-    _mm512_mask_loadu_epi64(arch::_mm512_setzero_si512(), k, mem_addr)
-}
-
-/// Load packed double-precision (64-bit) floating-point elements from memory into dst using writemask k
-/// (elements are copied from src when the corresponding mask bit is not set).
-/// mem_addr does not need to be aligned on any particular boundary.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_mask_loadu_pd)
-#[inline]
-#[target_feature(enable = "avx512f,avx512vl")]
-pub fn _mm_mask_loadu_pd(src: __m128d, k: __mmask8, mem_addr: &[f64; 2]) -> __m128d {
-    unsafe { arch::_mm_mask_loadu_pd(src, k, mem_addr.as_ptr()) }
-}
-
-/// Load packed double-precision (64-bit) floating-point elements from memory into dst using zeromask k
-/// (elements are zeroed out when the corresponding mask bit is not set).
-/// mem_addr does not need to be aligned on any particular boundary.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_maskz_loadu_pd)
-#[inline]
-#[target_feature(enable = "avx512f,avx512vl")]
-pub fn _mm_maskz_loadu_pd(k: __mmask8, mem_addr: &[f64; 2]) -> __m128d {
-    _mm_mask_loadu_pd(arch::_mm_setzero_pd(), k, mem_addr)
-}
-
-/// Load packed double-precision (64-bit) floating-point elements from memory into dst using writemask k
-/// (elements are copied from src when the corresponding mask bit is not set).
-/// mem_addr does not need to be aligned on any particular boundary.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_mask_loadu_pd)
-#[inline]
-#[target_feature(enable = "avx512f,avx512vl")]
-pub fn _mm256_mask_loadu_pd(src: __m256d, k: __mmask8, mem_addr: &[f64; 4]) -> __m256d {
-    unsafe { arch::_mm256_mask_loadu_pd(src, k, mem_addr.as_ptr()) }
-}
-
-/// Load packed double-precision (64-bit) floating-point elements from memory into dst using zeromask k
-/// (elements are zeroed out when the corresponding mask bit is not set).
-/// mem_addr does not need to be aligned on any particular boundary.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_maskz_loadu_pd)
-#[inline]
-#[target_feature(enable = "avx512f,avx512vl")]
-pub fn _mm256_maskz_loadu_pd(k: __mmask8, mem_addr: &[f64; 4]) -> __m256d {
-    _mm256_mask_loadu_pd(arch::_mm256_setzero_pd(), k, mem_addr)
-}
-
-/// Loads 512-bits (composed of 8 packed double-precision (64-bit)
-/// floating-point elements) from memory into result.
-/// `mem_addr` does not need to be aligned on any particular boundary.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_loadu_pd)
-#[inline]
-#[target_feature(enable = "avx512f")]
-pub fn _mm512_loadu_pd(mem_addr: &[f64; 8]) -> __m512d {
-    unsafe { arch::_mm512_loadu_pd(mem_addr.as_ptr()) }
-}
-
-/// Load packed double-precision (64-bit) floating-point elements from memory into dst using writemask k
-/// (elements are copied from src when the corresponding mask bit is not set).
-/// mem_addr does not need to be aligned on any particular boundary.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_loadu_pd)
-#[inline]
-#[target_feature(enable = "avx512f")]
-pub fn _mm512_mask_loadu_pd(src: __m512d, k: __mmask8, mem_addr: &[f64; 8]) -> __m512d {
-    unsafe { arch::_mm512_mask_loadu_pd(src, k, mem_addr.as_ptr()) }
-}
-
-/// Load packed double-precision (64-bit) floating-point elements from memory into dst using zeromask k
-/// (elements are zeroed out when the corresponding mask bit is not set).
-/// mem_addr does not need to be aligned on any particular boundary.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_maskz_loadu_pd)
-#[inline]
-#[target_feature(enable = "avx512f")]
-pub fn _mm512_maskz_loadu_pd(k: __mmask8, mem_addr: &[f64; 8]) -> __m512d {
-    _mm512_mask_loadu_pd(arch::_mm512_setzero_pd(), k, mem_addr)
-}
-
-/// Load packed single-precision (32-bit) floating-point elements from memory into dst using writemask k
-/// (elements are copied from src when the corresponding mask bit is not set).
-/// mem_addr does not need to be aligned on any particular boundary.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_mask_loadu_ps)
-#[inline]
-#[target_feature(enable = "avx512f,avx512vl")]
-pub fn _mm_mask_loadu_ps(src: __m128, k: __mmask8, mem_addr: &[f32; 4]) -> __m128 {
-    unsafe { arch::_mm_mask_loadu_ps(src, k, mem_addr.as_ptr()) }
-}
-
-/// Load packed single-precision (32-bit) floating-point elements from memory into dst using zeromask k
-/// (elements are zeroed out when the corresponding mask bit is not set).
-/// mem_addr does not need to be aligned on any particular boundary.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_maskz_loadu_ps)
-#[inline]
-#[target_feature(enable = "avx512f,avx512vl")]
-pub fn _mm_maskz_loadu_ps(k: __mmask8, mem_addr: &[f32; 4]) -> __m128 {
-    _mm_mask_loadu_ps(arch::_mm_setzero_ps(), k, mem_addr)
-}
-
-/// Load packed single-precision (32-bit) floating-point elements from memory into dst using writemask k
-/// (elements are copied from src when the corresponding mask bit is not set).
-/// mem_addr does not need to be aligned on any particular boundary.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_mask_loadu_ps)
-#[inline]
-#[target_feature(enable = "avx512f,avx512vl")]
-pub fn _mm256_mask_loadu_ps(src: __m256, k: __mmask8, mem_addr: &[f32; 8]) -> __m256 {
-    unsafe { arch::_mm256_mask_loadu_ps(src, k, mem_addr.as_ptr()) }
-}
-
-/// Load packed single-precision (32-bit) floating-point elements from memory into dst using zeromask k
-/// (elements are zeroed out when the corresponding mask bit is not set).
-/// mem_addr does not need to be aligned on any particular boundary.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_maskz_loadu_ps)
-#[inline]
-#[target_feature(enable = "avx512f,avx512vl")]
-pub fn _mm256_maskz_loadu_ps(k: __mmask8, mem_addr: &[f32; 8]) -> __m256 {
-    _mm256_mask_loadu_ps(arch::_mm256_setzero_ps(), k, mem_addr)
-}
-
-/// Loads 512-bits (composed of 16 packed single-precision (32-bit)
-/// floating-point elements) from memory into result.
-/// `mem_addr` does not need to be aligned on any particular boundary.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_loadu_ps)
-#[inline]
-#[target_feature(enable = "avx512f")]
-pub fn _mm512_loadu_ps(mem_addr: &[f32; 16]) -> __m512 {
-    unsafe { arch::_mm512_loadu_ps(mem_addr.as_ptr()) }
-}
-
-/// Load packed single-precision (32-bit) floating-point elements from memory into dst using writemask k
-/// (elements are copied from src when the corresponding mask bit is not set).
-/// mem_addr does not need to be aligned on any particular boundary.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_loadu_ps)
-#[inline]
-#[target_feature(enable = "avx512f")]
-pub fn _mm512_mask_loadu_ps(src: __m512, k: __mmask16, mem_addr: &[f32; 16]) -> __m512 {
-    unsafe { arch::_mm512_mask_loadu_ps(src, k, mem_addr.as_ptr()) }
-}
-
-/// Load packed single-precision (32-bit) floating-point elements from memory into dst using zeromask k
-/// (elements are zeroed out when the corresponding mask bit is not set).
-/// mem_addr does not need to be aligned on any particular boundary.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_maskz_loadu_ps)
-#[inline]
-#[target_feature(enable = "avx512f")]
-pub fn _mm512_maskz_loadu_ps(k: __mmask16, mem_addr: &[f32; 16]) -> __m512 {
-    _mm512_mask_loadu_ps(arch::_mm512_setzero_ps(), k, mem_addr)
-}
-
-/// Load 512-bits of integer data from memory into dst. mem_addr does not need to be aligned on any particular boundary.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_loadu_si512)
-#[inline]
-#[target_feature(enable = "avx512f")]
-pub fn _mm512_loadu_si512<T: Is512BitsUnaligned>(mem_addr: &T) -> __m512i {
-    unsafe { arch::_mm512_loadu_si512(ptr::from_ref(mem_addr).cast()) }
-}
-
-// Store intrinsics
-
-/// Contiguously store the active 32-bit integers in a (those with their respective bit set in writemask k) to unaligned memory at base_addr.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_mask_compressstoreu_epi32)
-#[inline]
-#[target_feature(enable = "avx512f,avx512vl")]
-pub fn _mm_mask_compressstoreu_epi32<T: Is128BitsUnaligned>(
-    base_addr: &mut T,
-    k: __mmask8,
-    a: __m128i,
-) {
-    unsafe { arch::_mm_mask_compressstoreu_epi32(ptr::from_mut(base_addr).cast(), k, a) }
-}
-
-/// Contiguously store the active 32-bit integers in a (those with their respective bit set in writemask k) to unaligned memory at base_addr.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_mask_compressstoreu_epi32)
-#[inline]
-#[target_feature(enable = "avx512f,avx512vl")]
-pub fn _mm256_mask_compressstoreu_epi32<T: Is256BitsUnaligned>(
-    base_addr: &mut T,
-    k: __mmask8,
-    a: __m256i,
-) {
-    unsafe { arch::_mm256_mask_compressstoreu_epi32(ptr::from_mut(base_addr).cast(), k, a) }
-}
-
-/// Contiguously store the active 32-bit integers in a (those with their respective bit set in writemask k) to unaligned memory at base_addr.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_compressstoreu_epi32)
-#[inline]
-#[target_feature(enable = "avx512f")]
-pub fn _mm512_mask_compressstoreu_epi32<T: Is512BitsUnaligned>(
-    base_addr: &mut T,
-    k: __mmask16,
-    a: __m512i,
-) {
-    unsafe { arch::_mm512_mask_compressstoreu_epi32(ptr::from_mut(base_addr).cast(), k, a) }
-}
-
-/// Contiguously store the active 64-bit integers in a (those with their respective bit set in writemask k) to unaligned memory at base_addr.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_mask_compressstoreu_epi64)
-#[inline]
-#[target_feature(enable = "avx512f,avx512vl")]
-pub fn _mm_mask_compressstoreu_epi64<T: Is128BitsUnaligned>(
-    base_addr: &mut T,
-    k: __mmask8,
-    a: __m128i,
-) {
-    unsafe { arch::_mm_mask_compressstoreu_epi64(ptr::from_mut(base_addr).cast(), k, a) }
-}
-
-/// Contiguously store the active 64-bit integers in a (those with their respective bit set in writemask k) to unaligned memory at base_addr.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_mask_compressstoreu_epi64)
-#[inline]
-#[target_feature(enable = "avx512f,avx512vl")]
-pub fn _mm256_mask_compressstoreu_epi64<T: Is256BitsUnaligned>(
-    base_addr: &mut T,
-    k: __mmask8,
-    a: __m256i,
-) {
-    unsafe { arch::_mm256_mask_compressstoreu_epi64(ptr::from_mut(base_addr).cast(), k, a) }
-}
-
-/// Contiguously store the active 64-bit integers in a (those with their respective bit set in writemask k) to unaligned memory at base_addr.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_compressstoreu_epi64)
-#[inline]
-#[target_feature(enable = "avx512f")]
-pub fn _mm512_mask_compressstoreu_epi64<T: Is512BitsUnaligned>(
-    base_addr: &mut T,
-    k: __mmask8,
-    a: __m512i,
-) {
-    unsafe { arch::_mm512_mask_compressstoreu_epi64(ptr::from_mut(base_addr).cast(), k, a) }
-}
-
-/// Contiguously store the active double-precision (64-bit) floating-point elements in a (those with their respective bit set in writemask k) to unaligned memory at base_addr.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_mask_compressstoreu_pd)
-#[inline]
-#[target_feature(enable = "avx512f,avx512vl")]
-pub fn _mm_mask_compressstoreu_pd(base_addr: &mut [f64; 2], k: __mmask8, a: __m128d) {
-    unsafe { arch::_mm_mask_compressstoreu_pd(base_addr.as_mut_ptr().cast(), k, a) }
-}
-
-/// Contiguously store the active double-precision (64-bit) floating-point elements in a (those with their respective bit set in writemask k) to unaligned memory at base_addr.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_mask_compressstoreu_pd)
-#[inline]
-#[target_feature(enable = "avx512f,avx512vl")]
-pub fn _mm256_mask_compressstoreu_pd(base_addr: &mut [f64; 4], k: __mmask8, a: __m256d) {
-    unsafe { arch::_mm256_mask_compressstoreu_pd(base_addr.as_mut_ptr().cast(), k, a) }
-}
-
-/// Contiguously store the active double-precision (64-bit) floating-point elements in a (those with their respective bit set in writemask k) to unaligned memory at base_addr.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_compressstoreu_pd)
-#[inline]
-#[target_feature(enable = "avx512f")]
-pub fn _mm512_mask_compressstoreu_pd(base_addr: &mut [f64; 8], k: __mmask8, a: __m512d) {
-    unsafe { arch::_mm512_mask_compressstoreu_pd(base_addr.as_mut_ptr().cast(), k, a) }
-}
-
-/// Contiguously store the active single-precision (32-bit) floating-point elements in a (those with their respective bit set in writemask k) to unaligned memory at base_addr.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_mask_compressstoreu_ps)
-#[inline]
-#[target_feature(enable = "avx512f,avx512vl")]
-pub fn _mm_mask_compressstoreu_ps(base_addr: &mut [f32; 4], k: __mmask8, a: __m128) {
-    unsafe { arch::_mm_mask_compressstoreu_ps(base_addr.as_mut_ptr().cast(), k, a) }
-}
-
-/// Contiguously store the active single-precision (32-bit) floating-point elements in a (those with their respective bit set in writemask k) to unaligned memory at base_addr.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_mask_compressstoreu_ps)
-#[inline]
-#[target_feature(enable = "avx512f,avx512vl")]
-pub fn _mm256_mask_compressstoreu_ps(base_addr: &mut [f32; 8], k: __mmask8, a: __m256) {
-    unsafe { arch::_mm256_mask_compressstoreu_ps(base_addr.as_mut_ptr().cast(), k, a) }
-}
-
-/// Contiguously store the active single-precision (32-bit) floating-point elements in a (those with their respective bit set in writemask k) to unaligned memory at base_addr.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_compressstoreu_ps)
-#[inline]
-#[target_feature(enable = "avx512f")]
-pub fn _mm512_mask_compressstoreu_ps(base_addr: &mut [f32; 16], k: __mmask16, a: __m512) {
-    unsafe { arch::_mm512_mask_compressstoreu_ps(base_addr.as_mut_ptr().cast(), k, a) }
-}
-
-/// Convert packed 32-bit integers in a to packed 16-bit integers with truncation, and store the active results (those with their respective bit set in writemask k) to unaligned memory at base_addr.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_mask_cvtepi32_storeu_epi16)
-#[inline]
-#[target_feature(enable = "avx512f,avx512vl")]
-pub fn _mm_mask_cvtepi32_storeu_epi16<T: Is64BitsUnaligned>(
-    base_addr: &mut T,
-    k: __mmask8,
-    a: __m128i,
-) {
-    unsafe { arch::_mm_mask_cvtepi32_storeu_epi16(ptr::from_mut(base_addr).cast(), k, a) }
-}
-
-/// Convert packed 32-bit integers in a to packed 16-bit integers with truncation, and store the active results (those with their respective bit set in writemask k) to unaligned memory at base_addr.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_mask_cvtepi32_storeu_epi16)
-#[inline]
-#[target_feature(enable = "avx512f,avx512vl")]
-pub fn _mm256_mask_cvtepi32_storeu_epi16<T: Is128BitsUnaligned>(
-    base_addr: &mut T,
-    k: __mmask8,
-    a: __m256i,
-) {
-    unsafe { arch::_mm256_mask_cvtepi32_storeu_epi16(ptr::from_mut(base_addr).cast(), k, a) }
-}
-
-/// Convert packed 32-bit integers in a to packed 16-bit integers with truncation, and store the active results (those with their respective bit set in writemask k) to unaligned memory at base_addr.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_cvtepi32_storeu_epi16)
-#[inline]
-#[target_feature(enable = "avx512f")]
-pub fn _mm512_mask_cvtepi32_storeu_epi16<T: Is256BitsUnaligned>(
-    base_addr: &mut T,
-    k: __mmask16,
-    a: __m512i,
-) {
-    unsafe { arch::_mm512_mask_cvtepi32_storeu_epi16(ptr::from_mut(base_addr).cast(), k, a) }
-}
-
-/// Convert packed 32-bit integers in a to packed 8-bit integers with truncation, and store the active results (those with their respective bit set in writemask k) to unaligned memory at base_addr.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_mask_cvtepi32_storeu_epi8)
-#[inline]
-#[target_feature(enable = "avx512f,avx512vl")]
-pub fn _mm_mask_cvtepi32_storeu_epi8<T: Is64BitsUnaligned>(
-    base_addr: &mut T,
-    k: __mmask8,
-    a: __m128i,
-) {
-    unsafe { arch::_mm_mask_cvtepi32_storeu_epi8(ptr::from_mut(base_addr).cast(), k, a) }
-}
-
-/// Convert packed 32-bit integers in a to packed 8-bit integers with truncation, and store the active results (those with their respective bit set in writemask k) to unaligned memory at base_addr.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_mask_cvtepi32_storeu_epi8)
-#[inline]
-#[target_feature(enable = "avx512f,avx512vl")]
-pub fn _mm256_mask_cvtepi32_storeu_epi8<T: Is64BitsUnaligned>(
-    base_addr: &mut T,
-    k: __mmask8,
-    a: __m256i,
-) {
-    unsafe { arch::_mm256_mask_cvtepi32_storeu_epi8(ptr::from_mut(base_addr).cast(), k, a) }
-}
-
-/// Convert packed 32-bit integers in a to packed 8-bit integers with truncation, and store the active results (those with their respective bit set in writemask k) to unaligned memory at base_addr.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_cvtepi32_storeu_epi8)
-#[inline]
-#[target_feature(enable = "avx512f")]
-pub fn _mm512_mask_cvtepi32_storeu_epi8<T: Is128BitsUnaligned>(
-    base_addr: &mut T,
-    k: __mmask16,
-    a: __m512i,
-) {
-    unsafe { arch::_mm512_mask_cvtepi32_storeu_epi8(ptr::from_mut(base_addr).cast(), k, a) }
-}
-
-/// Convert packed 64-bit integers in a to packed 16-bit integers with truncation, and store the active results (those with their respective bit set in writemask k) to unaligned memory at base_addr.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_mask_cvtepi64_storeu_epi16)
-#[inline]
-#[target_feature(enable = "avx512f,avx512vl")]
-pub fn _mm_mask_cvtepi64_storeu_epi16<T: Is32BitsUnaligned>(
-    base_addr: &mut T,
-    k: __mmask8,
-    a: __m128i,
-) {
-    unsafe { arch::_mm_mask_cvtepi64_storeu_epi16(ptr::from_mut(base_addr).cast(), k, a) }
-}
-
-/// Convert packed 64-bit integers in a to packed 16-bit integers with truncation, and store the active results (those with their respective bit set in writemask k) to unaligned memory at base_addr.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_mask_cvtepi64_storeu_epi16)
-#[inline]
-#[target_feature(enable = "avx512f,avx512vl")]
-pub fn _mm256_mask_cvtepi64_storeu_epi16<T: Is64BitsUnaligned>(
-    base_addr: &mut T,
-    k: __mmask8,
-    a: __m256i,
-) {
-    unsafe { arch::_mm256_mask_cvtepi64_storeu_epi16(ptr::from_mut(base_addr).cast(), k, a) }
-}
-
-/// Convert packed 64-bit integers in a to packed 16-bit integers with truncation, and store the active results (those with their respective bit set in writemask k) to unaligned memory at base_addr.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_cvtepi64_storeu_epi16)
-#[inline]
-#[target_feature(enable = "avx512f")]
-pub fn _mm512_mask_cvtepi64_storeu_epi16<T: Is128BitsUnaligned>(
-    base_addr: &mut T,
-    k: __mmask8,
-    a: __m512i,
-) {
-    unsafe { arch::_mm512_mask_cvtepi64_storeu_epi16(ptr::from_mut(base_addr).cast(), k, a) }
-}
-
-///Convert packed 64-bit integers in a to packed 32-bit integers with truncation, and store the active results (those with their respective bit set in writemask k) to unaligned memory at base_addr.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_mask_cvtepi64_storeu_epi32)
-#[inline]
-#[target_feature(enable = "avx512f,avx512vl")]
-pub fn _mm_mask_cvtepi64_storeu_epi32<T: Is64BitsUnaligned>(
-    base_addr: &mut T,
-    k: __mmask8,
-    a: __m128i,
-) {
-    unsafe { arch::_mm_mask_cvtepi64_storeu_epi32(ptr::from_mut(base_addr).cast(), k, a) }
-}
-
-///Convert packed 64-bit integers in a to packed 32-bit integers with truncation, and store the active results (those with their respective bit set in writemask k) to unaligned memory at base_addr.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_mask_cvtepi64_storeu_epi32)
-#[inline]
-#[target_feature(enable = "avx512f,avx512vl")]
-pub fn _mm256_mask_cvtepi64_storeu_epi32<T: Is128BitsUnaligned>(
-    base_addr: &mut T,
-    k: __mmask8,
-    a: __m256i,
-) {
-    unsafe { arch::_mm256_mask_cvtepi64_storeu_epi32(ptr::from_mut(base_addr).cast(), k, a) }
-}
-
-///Convert packed 64-bit integers in a to packed 32-bit integers with truncation, and store the active results (those with their respective bit set in writemask k) to unaligned memory at base_addr.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_cvtepi64_storeu_epi32)
-#[inline]
-#[target_feature(enable = "avx512f")]
-pub fn _mm512_mask_cvtepi64_storeu_epi32<T: Is256BitsUnaligned>(
-    base_addr: &mut T,
-    k: __mmask8,
-    a: __m512i,
-) {
-    unsafe { arch::_mm512_mask_cvtepi64_storeu_epi32(ptr::from_mut(base_addr).cast(), k, a) }
-}
-
-/// Convert packed 64-bit integers in a to packed 8-bit integers with truncation, and store the active results (those with their respective bit set in writemask k) to unaligned memory at base_addr.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_mask_cvtepi64_storeu_epi8)
-#[inline]
-#[target_feature(enable = "avx512f,avx512vl")]
-pub fn _mm_mask_cvtepi64_storeu_epi8<T: Is16BitsUnaligned>(
-    base_addr: &mut T,
-    k: __mmask8,
-    a: __m128i,
-) {
-    unsafe { arch::_mm_mask_cvtepi64_storeu_epi8(ptr::from_mut(base_addr).cast(), k, a) }
-}
-
-/// Convert packed 64-bit integers in a to packed 8-bit integers with truncation, and store the active results (those with their respective bit set in writemask k) to unaligned memory at base_addr.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_mask_cvtepi64_storeu_epi8)
-#[inline]
-#[target_feature(enable = "avx512f,avx512vl")]
-pub fn _mm256_mask_cvtepi64_storeu_epi8<T: Is32BitsUnaligned>(
-    base_addr: &mut T,
-    k: __mmask8,
-    a: __m256i,
-) {
-    unsafe { arch::_mm256_mask_cvtepi64_storeu_epi8(ptr::from_mut(base_addr).cast(), k, a) }
-}
-
-/// Convert packed 64-bit integers in a to packed 8-bit integers with truncation, and store the active results (those with their respective bit set in writemask k) to unaligned memory at base_addr.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_cvtepi64_storeu_epi8)
-#[inline]
-#[target_feature(enable = "avx512f")]
-pub fn _mm512_mask_cvtepi64_storeu_epi8<T: Is64BitsUnaligned>(
-    base_addr: &mut T,
-    k: __mmask8,
-    a: __m512i,
-) {
-    unsafe { arch::_mm512_mask_cvtepi64_storeu_epi8(ptr::from_mut(base_addr).cast(), k, a) }
-}
-
-/// Convert packed signed 32-bit integers in a to packed 16-bit integers with signed saturation, and store the active results (those with their respective bit set in writemask k) to unaligned memory at base_addr.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_mask_cvtsepi32_storeu_epi16)
-#[inline]
-#[target_feature(enable = "avx512f,avx512vl")]
-pub fn _mm_mask_cvtsepi32_storeu_epi16<T: Is64BitsUnaligned>(
-    base_addr: &mut T,
-    k: __mmask8,
-    a: __m128i,
-) {
-    unsafe { arch::_mm_mask_cvtsepi32_storeu_epi16(ptr::from_mut(base_addr).cast(), k, a) }
-}
-
-/// Convert packed signed 32-bit integers in a to packed 16-bit integers with signed saturation, and store the active results (those with their respective bit set in writemask k) to unaligned memory at base_addr.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_mask_cvtsepi32_storeu_epi16)
-#[inline]
-#[target_feature(enable = "avx512f,avx512vl")]
-pub fn _mm256_mask_cvtsepi32_storeu_epi16<T: Is128BitsUnaligned>(
-    base_addr: &mut T,
-    k: __mmask8,
-    a: __m256i,
-) {
-    unsafe { arch::_mm256_mask_cvtsepi32_storeu_epi16(ptr::from_mut(base_addr).cast(), k, a) }
-}
-
-/// Convert packed signed 32-bit integers in a to packed 16-bit integers with signed saturation, and store the active results (those with their respective bit set in writemask k) to unaligned memory at base_addr.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_cvtsepi32_storeu_epi16)
-#[inline]
-#[target_feature(enable = "avx512f")]
-pub fn _mm512_mask_cvtsepi32_storeu_epi16<T: Is256BitsUnaligned>(
-    base_addr: &mut T,
-    k: __mmask16,
-    a: __m512i,
-) {
-    unsafe { arch::_mm512_mask_cvtsepi32_storeu_epi16(ptr::from_mut(base_addr).cast(), k, a) }
-}
-
-/// Convert packed signed 32-bit integers in a to packed 8-bit integers with signed saturation, and store the active results (those with their respective bit set in writemask k) to unaligned memory at base_addr.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_mask_cvtsepi32_storeu_epi8)
-#[inline]
-#[target_feature(enable = "avx512f,avx512vl")]
-pub fn _mm_mask_cvtsepi32_storeu_epi8<T: Is32BitsUnaligned>(
-    base_addr: &mut T,
-    k: __mmask8,
-    a: __m128i,
-) {
-    unsafe { arch::_mm_mask_cvtsepi32_storeu_epi8(ptr::from_mut(base_addr).cast(), k, a) }
-}
-
-/// Convert packed signed 32-bit integers in a to packed 8-bit integers with signed saturation, and store the active results (those with their respective bit set in writemask k) to unaligned memory at base_addr.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_mask_cvtsepi32_storeu_epi8)
-#[inline]
-#[target_feature(enable = "avx512f,avx512vl")]
-pub fn _mm256_mask_cvtsepi32_storeu_epi8<T: Is64BitsUnaligned>(
-    base_addr: &mut T,
-    k: __mmask8,
-    a: __m256i,
-) {
-    unsafe { arch::_mm256_mask_cvtsepi32_storeu_epi8(ptr::from_mut(base_addr).cast(), k, a) }
-}
-
-/// Convert packed signed 32-bit integers in a to packed 8-bit integers with signed saturation, and store the active results (those with their respective bit set in writemask k) to unaligned memory at base_addr.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_cvtsepi32_storeu_epi8)
-#[inline]
-#[target_feature(enable = "avx512f")]
-pub fn _mm512_mask_cvtsepi32_storeu_epi8<T: Is128BitsUnaligned>(
-    base_addr: &mut T,
-    k: __mmask16,
-    a: __m512i,
-) {
-    unsafe { arch::_mm512_mask_cvtsepi32_storeu_epi8(ptr::from_mut(base_addr).cast(), k, a) }
-}
-
-/// Convert packed signed 64-bit integers in a to packed 16-bit integers with signed saturation, and store the active results (those with their respective bit set in writemask k) to unaligned memory at base_addr.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_mask_cvtsepi64_storeu_epi16)
-#[inline]
-#[target_feature(enable = "avx512f,avx512vl")]
-pub fn _mm_mask_cvtsepi64_storeu_epi16<T: Is32BitsUnaligned>(
-    base_addr: &mut T,
-    k: __mmask8,
-    a: __m128i,
-) {
-    unsafe { arch::_mm_mask_cvtsepi64_storeu_epi16(ptr::from_mut(base_addr).cast(), k, a) }
-}
-
-/// Convert packed signed 64-bit integers in a to packed 16-bit integers with signed saturation, and store the active results (those with their respective bit set in writemask k) to unaligned memory at base_addr.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_mask_cvtsepi64_storeu_epi16)
-#[inline]
-#[target_feature(enable = "avx512f,avx512vl")]
-pub fn _mm256_mask_cvtsepi64_storeu_epi16<T: Is64BitsUnaligned>(
-    base_addr: &mut T,
-    k: __mmask8,
-    a: __m256i,
-) {
-    unsafe { arch::_mm256_mask_cvtsepi64_storeu_epi16(ptr::from_mut(base_addr).cast(), k, a) }
-}
-
-/// Convert packed signed 64-bit integers in a to packed 16-bit integers with signed saturation, and store the active results (those with their respective bit set in writemask k) to unaligned memory at base_addr.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_cvtsepi64_storeu_epi16)
-#[inline]
-#[target_feature(enable = "avx512f")]
-pub fn _mm512_mask_cvtsepi64_storeu_epi16<T: Is128BitsUnaligned>(
-    base_addr: &mut T,
-    k: __mmask8,
-    a: __m512i,
-) {
-    unsafe { arch::_mm512_mask_cvtsepi64_storeu_epi16(ptr::from_mut(base_addr).cast(), k, a) }
-}
-
-/// Convert packed signed 64-bit integers in a to packed 32-bit integers with signed saturation, and store the active results (those with their respective bit set in writemask k) to unaligned memory at base_addr.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_mask_cvtsepi64_storeu_epi32)
-#[inline]
-#[target_feature(enable = "avx512f,avx512vl")]
-pub fn _mm_mask_cvtsepi64_storeu_epi32<T: Is64BitsUnaligned>(
-    base_addr: &mut T,
-    k: __mmask8,
-    a: __m128i,
-) {
-    unsafe { arch::_mm_mask_cvtsepi64_storeu_epi32(ptr::from_mut(base_addr).cast(), k, a) }
-}
-
-/// Convert packed signed 64-bit integers in a to packed 32-bit integers with signed saturation, and store the active results (those with their respective bit set in writemask k) to unaligned memory at base_addr.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_mask_cvtsepi64_storeu_epi32)
-#[inline]
-#[target_feature(enable = "avx512f,avx512vl")]
-pub fn _mm256_mask_cvtsepi64_storeu_epi32<T: Is128BitsUnaligned>(
-    base_addr: &mut T,
-    k: __mmask8,
-    a: __m256i,
-) {
-    unsafe { arch::_mm256_mask_cvtsepi64_storeu_epi32(ptr::from_mut(base_addr).cast(), k, a) }
-}
-
-/// Convert packed signed 64-bit integers in a to packed 32-bit integers with signed saturation, and store the active results (those with their respective bit set in writemask k) to unaligned memory at base_addr.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_cvtsepi64_storeu_epi32)
-#[inline]
-#[target_feature(enable = "avx512f")]
-pub fn _mm512_mask_cvtsepi64_storeu_epi32<T: Is256BitsUnaligned>(
-    base_addr: &mut T,
-    k: __mmask8,
-    a: __m512i,
-) {
-    unsafe { arch::_mm512_mask_cvtsepi64_storeu_epi32(ptr::from_mut(base_addr).cast(), k, a) }
-}
-
-/// Convert packed signed 64-bit integers in a to packed 8-bit integers with signed saturation, and store the active results (those with their respective bit set in writemask k) to unaligned memory at base_addr.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_mask_cvtsepi64_storeu_epi8)
-#[inline]
-#[target_feature(enable = "avx512f,avx512vl")]
-pub fn _mm_mask_cvtsepi64_storeu_epi8<T: Is16BitsUnaligned>(
-    base_addr: &mut T,
-    k: __mmask8,
-    a: __m128i,
-) {
-    unsafe { arch::_mm_mask_cvtsepi64_storeu_epi8(ptr::from_mut(base_addr).cast(), k, a) }
-}
-
-/// Convert packed signed 64-bit integers in a to packed 8-bit integers with signed saturation, and store the active results (those with their respective bit set in writemask k) to unaligned memory at base_addr.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_mask_cvtsepi64_storeu_epi8)
-#[inline]
-#[target_feature(enable = "avx512f,avx512vl")]
-pub fn _mm256_mask_cvtsepi64_storeu_epi8<T: Is32BitsUnaligned>(
-    base_addr: &mut T,
-    k: __mmask8,
-    a: __m256i,
-) {
-    unsafe { arch::_mm256_mask_cvtsepi64_storeu_epi8(ptr::from_mut(base_addr).cast(), k, a) }
-}
-
-/// Convert packed signed 64-bit integers in a to packed 8-bit integers with signed saturation, and store the active results (those with their respective bit set in writemask k) to unaligned memory at base_addr.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_cvtsepi64_storeu_epi8)
-#[inline]
-#[target_feature(enable = "avx512f")]
-pub fn _mm512_mask_cvtsepi64_storeu_epi8<T: Is64BitsUnaligned>(
-    base_addr: &mut T,
-    k: __mmask8,
-    a: __m512i,
-) {
-    unsafe { arch::_mm512_mask_cvtsepi64_storeu_epi8(ptr::from_mut(base_addr).cast(), k, a) }
-}
-
-/// Convert packed unsigned 32-bit integers in a to packed unsigned 16-bit integers with unsigned saturation, and store the active results (those with their respective bit set in writemask k) to unaligned memory at base_addr.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_mask_cvtusepi32_storeu_epi16)
-#[inline]
-#[target_feature(enable = "avx512f,avx512vl")]
-pub fn _mm_mask_cvtusepi32_storeu_epi16<T: Is64BitsUnaligned>(
-    base_addr: &mut T,
-    k: __mmask8,
-    a: __m128i,
-) {
-    unsafe { arch::_mm_mask_cvtusepi32_storeu_epi16(ptr::from_mut(base_addr).cast(), k, a) }
-}
-
-/// Convert packed unsigned 32-bit integers in a to packed unsigned 16-bit integers with unsigned saturation, and store the active results (those with their respective bit set in writemask k) to unaligned memory at base_addr.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_mask_cvtusepi32_storeu_epi16)
-#[inline]
-#[target_feature(enable = "avx512f,avx512vl")]
-pub fn _mm256_mask_cvtusepi32_storeu_epi16<T: Is128BitsUnaligned>(
-    base_addr: &mut T,
-    k: __mmask8,
-    a: __m256i,
-) {
-    unsafe { arch::_mm256_mask_cvtusepi32_storeu_epi16(ptr::from_mut(base_addr).cast(), k, a) }
-}
-
-/// Convert packed unsigned 32-bit integers in a to packed 16-bit integers with unsigned saturation, and store the active results (those with their respective bit set in writemask k) to unaligned memory at base_addr.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_cvtusepi32_storeu_epi16)
-#[inline]
-#[target_feature(enable = "avx512f")]
-pub fn _mm512_mask_cvtusepi32_storeu_epi16<T: Is256BitsUnaligned>(
-    base_addr: &mut T,
-    k: __mmask16,
-    a: __m512i,
-) {
-    unsafe { arch::_mm512_mask_cvtusepi32_storeu_epi16(ptr::from_mut(base_addr).cast(), k, a) }
-}
-
-/// Convert packed unsigned 32-bit integers in a to packed 8-bit integers with unsigned saturation, and store the active results (those with their respective bit set in writemask k) to unaligned memory at base_addr.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_mask_cvtusepi32_storeu_epi8)
-#[inline]
-#[target_feature(enable = "avx512f,avx512vl")]
-pub fn _mm_mask_cvtusepi32_storeu_epi8<T: Is32BitsUnaligned>(
-    base_addr: &mut T,
-    k: __mmask8,
-    a: __m128i,
-) {
-    unsafe { arch::_mm_mask_cvtusepi32_storeu_epi8(ptr::from_mut(base_addr).cast(), k, a) }
-}
-
-/// Convert packed unsigned 32-bit integers in a to packed 8-bit integers with unsigned saturation, and store the active results (those with their respective bit set in writemask k) to unaligned memory at base_addr.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_mask_cvtusepi32_storeu_epi8)
-#[inline]
-#[target_feature(enable = "avx512f,avx512vl")]
-pub fn _mm256_mask_cvtusepi32_storeu_epi8<T: Is64BitsUnaligned>(
-    base_addr: &mut T,
-    k: __mmask8,
-    a: __m256i,
-) {
-    unsafe { arch::_mm256_mask_cvtusepi32_storeu_epi8(ptr::from_mut(base_addr).cast(), k, a) }
-}
-
-/// Convert packed unsigned 32-bit integers in a to packed 8-bit integers with unsigned saturation, and store the active results (those with their respective bit set in writemask k) to unaligned memory at base_addr.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_cvtusepi32_storeu_epi8)
-#[inline]
-#[target_feature(enable = "avx512f")]
-pub fn _mm512_mask_cvtusepi32_storeu_epi8<T: Is128BitsUnaligned>(
-    base_addr: &mut T,
-    k: __mmask16,
-    a: __m512i,
-) {
-    unsafe { arch::_mm512_mask_cvtusepi32_storeu_epi8(ptr::from_mut(base_addr).cast(), k, a) }
-}
-
-/// Convert packed unsigned 64-bit integers in a to packed 16-bit integers with unsigned saturation, and store the active results (those with their respective bit set in writemask k) to unaligned memory at base_addr.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_mask_cvtusepi64_storeu_epi16)
-#[inline]
-#[target_feature(enable = "avx512f,avx512vl")]
-pub fn _mm_mask_cvtusepi64_storeu_epi16<T: Is32BitsUnaligned>(
-    base_addr: &mut T,
-    k: __mmask8,
-    a: __m128i,
-) {
-    unsafe { arch::_mm_mask_cvtusepi64_storeu_epi16(ptr::from_mut(base_addr).cast(), k, a) }
-}
-
-/// Convert packed unsigned 64-bit integers in a to packed 16-bit integers with unsigned saturation, and store the active results (those with their respective bit set in writemask k) to unaligned memory at base_addr.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_mask_cvtusepi64_storeu_epi16)
-#[inline]
-#[target_feature(enable = "avx512f,avx512vl")]
-pub fn _mm256_mask_cvtusepi64_storeu_epi16<T: Is64BitsUnaligned>(
-    base_addr: &mut T,
-    k: __mmask8,
-    a: __m256i,
-) {
-    unsafe { arch::_mm256_mask_cvtusepi64_storeu_epi16(ptr::from_mut(base_addr).cast(), k, a) }
-}
-
-/// Convert packed unsigned 64-bit integers in a to packed 16-bit integers with unsigned saturation, and store the active results (those with their respective bit set in writemask k) to unaligned memory at base_addr.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_cvtusepi64_storeu_epi16)
-#[inline]
-#[target_feature(enable = "avx512f")]
-pub fn _mm512_mask_cvtusepi64_storeu_epi16<T: Is128BitsUnaligned>(
-    base_addr: &mut T,
-    k: __mmask8,
-    a: __m512i,
-) {
-    unsafe { arch::_mm512_mask_cvtusepi64_storeu_epi16(ptr::from_mut(base_addr).cast(), k, a) }
-}
-
-/// Convert packed unsigned 64-bit integers in a to packed 32-bit integers with unsigned saturation, and store the active results (those with their respective bit set in writemask k) to unaligned memory at base_addr.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_mask_cvtusepi64_storeu_epi32)
-#[inline]
-#[target_feature(enable = "avx512f,avx512vl")]
-pub fn _mm_mask_cvtusepi64_storeu_epi32<T: Is64BitsUnaligned>(
-    base_addr: &mut T,
-    k: __mmask8,
-    a: __m128i,
-) {
-    unsafe { arch::_mm_mask_cvtusepi64_storeu_epi32(ptr::from_mut(base_addr).cast(), k, a) }
-}
-
-/// Convert packed unsigned 64-bit integers in a to packed 32-bit integers with unsigned saturation, and store the active results (those with their respective bit set in writemask k) to unaligned memory at base_addr.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_mask_cvtusepi64_storeu_epi32)
-#[inline]
-#[target_feature(enable = "avx512f,avx512vl")]
-pub fn _mm256_mask_cvtusepi64_storeu_epi32<T: Is128BitsUnaligned>(
-    base_addr: &mut T,
-    k: __mmask8,
-    a: __m256i,
-) {
-    unsafe { arch::_mm256_mask_cvtusepi64_storeu_epi32(ptr::from_mut(base_addr).cast(), k, a) }
-}
-
-/// Convert packed unsigned 64-bit integers in a to packed 32-bit integers with unsigned saturation, and store the active results (those with their respective bit set in writemask k) to unaligned memory at base_addr.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_cvtusepi64_storeu_epi32)
-#[inline]
-#[target_feature(enable = "avx512f")]
-pub fn _mm512_mask_cvtusepi64_storeu_epi32<T: Is256BitsUnaligned>(
-    base_addr: &mut T,
-    k: __mmask8,
-    a: __m512i,
-) {
-    unsafe { arch::_mm512_mask_cvtusepi64_storeu_epi32(ptr::from_mut(base_addr).cast(), k, a) }
-}
-
-/// Convert packed unsigned 64-bit integers in a to packed 8-bit integers with unsigned saturation, and store the active results (those with their respective bit set in writemask k) to unaligned memory at base_addr.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_mask_cvtusepi64_storeu_epi8)
-#[inline]
-#[target_feature(enable = "avx512f,avx512vl")]
-pub fn _mm_mask_cvtusepi64_storeu_epi8<T: Is16BitsUnaligned>(
-    base_addr: &mut T,
-    k: __mmask8,
-    a: __m128i,
-) {
-    unsafe { arch::_mm_mask_cvtusepi64_storeu_epi8(ptr::from_mut(base_addr).cast(), k, a) }
-}
-
-/// Convert packed unsigned 64-bit integers in a to packed 8-bit integers with unsigned saturation, and store the active results (those with their respective bit set in writemask k) to unaligned memory at base_addr.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_mask_cvtusepi64_storeu_epi8)
-#[inline]
-#[target_feature(enable = "avx512f,avx512vl")]
-pub fn _mm256_mask_cvtusepi64_storeu_epi8<T: Is32BitsUnaligned>(
-    base_addr: &mut T,
-    k: __mmask8,
-    a: __m256i,
-) {
-    unsafe { arch::_mm256_mask_cvtusepi64_storeu_epi8(ptr::from_mut(base_addr).cast(), k, a) }
-}
-
-/// Convert packed unsigned 64-bit integers in a to packed 8-bit integers with unsigned saturation, and store the active results (those with their respective bit set in writemask k) to unaligned memory at base_addr.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_cvtusepi64_storeu_epi8)
-#[inline]
-#[target_feature(enable = "avx512f")]
-pub fn _mm512_mask_cvtusepi64_storeu_epi8<T: Is64BitsUnaligned>(
-    base_addr: &mut T,
-    k: __mmask8,
-    a: __m512i,
-) {
-    unsafe { arch::_mm512_mask_cvtusepi64_storeu_epi8(ptr::from_mut(base_addr).cast(), k, a) }
-}
-
-/// Store packed 32-bit integers from a into memory using writemask k.
-/// mem_addr does not need to be aligned on any particular boundary.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_mask_storeu_epi32)
-#[inline]
-#[target_feature(enable = "avx512f,avx512vl")]
-pub fn _mm_mask_storeu_epi32<T: Is128BitsUnaligned>(mem_addr: &mut T, k: __mmask8, a: __m128i) {
-    unsafe { arch::_mm_mask_storeu_epi32(ptr::from_mut(mem_addr).cast(), k, a) }
-}
-
-/// Store 128-bits (composed of 4 packed 32-bit integers) from a into memory. mem_addr does not need to be aligned on any particular boundary.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_storeu_epi32)
-#[inline]
-#[target_feature(enable = "avx512f,avx512vl")]
-pub fn _mm_storeu_epi32<T: Is128BitsUnaligned>(mem_addr: &mut T, a: __m128i) {
-    unsafe { arch::_mm_storeu_epi32(ptr::from_mut(mem_addr).cast(), a) }
-}
-
-/// Store packed 32-bit integers from a into memory using writemask k.
-/// mem_addr does not need to be aligned on any particular boundary.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_mask_storeu_epi32)
-#[inline]
-#[target_feature(enable = "avx512f,avx512vl")]
-pub fn _mm256_mask_storeu_epi32<T: Is256BitsUnaligned>(mem_addr: &mut T, k: __mmask8, a: __m256i) {
-    unsafe { arch::_mm256_mask_storeu_epi32(ptr::from_mut(mem_addr).cast(), k, a) }
-}
-
-/// Store 256-bits (composed of 8 packed 32-bit integers) from a into memory. mem_addr does not need to be aligned on any particular boundary.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_storeu_epi32)
-#[inline]
-#[target_feature(enable = "avx512f,avx512vl")]
-pub fn _mm256_storeu_epi32<T: Is256BitsUnaligned>(mem_addr: &mut T, a: __m256i) {
-    unsafe { arch::_mm256_storeu_epi32(ptr::from_mut(mem_addr).cast(), a) }
-}
-
-/// Store packed 32-bit integers from a into memory using writemask k.
-/// mem_addr does not need to be aligned on any particular boundary.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_storeu_epi32)
-#[inline]
-#[target_feature(enable = "avx512f")]
-pub fn _mm512_mask_storeu_epi32<T: Is512BitsUnaligned>(mem_addr: &mut T, k: __mmask16, a: __m512i) {
-    unsafe { arch::_mm512_mask_storeu_epi32(ptr::from_mut(mem_addr).cast(), k, a) }
-}
-
-/// Store 512-bits (composed of 16 packed 32-bit integers) from a into memory. mem_addr does not need to be aligned on any particular boundary.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_storeu_epi32)
-#[inline]
-#[target_feature(enable = "avx512f")]
-pub fn _mm512_storeu_epi32<T: Is512BitsUnaligned>(mem_addr: &mut T, a: __m512i) {
-    unsafe { arch::_mm512_storeu_epi32(ptr::from_mut(mem_addr).cast(), a) }
-}
-
-/// Store packed 64-bit integers from a into memory using writemask k.
-/// mem_addr does not need to be aligned on any particular boundary.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_mask_storeu_epi64)
-#[inline]
-#[target_feature(enable = "avx512f,avx512vl")]
-pub fn _mm_mask_storeu_epi64<T: Is128BitsUnaligned>(mem_addr: &mut T, k: __mmask8, a: __m128i) {
-    unsafe { arch::_mm_mask_storeu_epi64(ptr::from_mut(mem_addr).cast(), k, a) }
-}
-
-/// Store 128-bits (composed of 2 packed 64-bit integers) from a into memory. mem_addr does not need to be aligned on any particular boundary.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_storeu_epi64)
-#[inline]
-#[target_feature(enable = "avx512f,avx512vl")]
-pub fn _mm_storeu_epi64<T: Is128BitsUnaligned>(mem_addr: &mut T, a: __m128i) {
-    unsafe { arch::_mm_storeu_epi64(ptr::from_mut(mem_addr).cast(), a) }
-}
-
-/// Store packed 64-bit integers from a into memory using writemask k.
-/// mem_addr does not need to be aligned on any particular boundary.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_mask_storeu_epi64)
-#[inline]
-#[target_feature(enable = "avx512f,avx512vl")]
-pub fn _mm256_mask_storeu_epi64<T: Is256BitsUnaligned>(mem_addr: &mut T, k: __mmask8, a: __m256i) {
-    unsafe { arch::_mm256_mask_storeu_epi64(ptr::from_mut(mem_addr).cast(), k, a) }
-}
-
-/// Store 256-bits (composed of 4 packed 64-bit integers) from a into memory. mem_addr does not need to be aligned on any particular boundary.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_storeu_epi64)
-#[inline]
-#[target_feature(enable = "avx512f,avx512vl")]
-pub fn _mm256_storeu_epi64<T: Is256BitsUnaligned>(mem_addr: &mut T, a: __m256i) {
-    unsafe { arch::_mm256_storeu_epi64(ptr::from_mut(mem_addr).cast(), a) }
-}
-
-/// Store packed 64-bit integers from a into memory using writemask k.
-/// mem_addr does not need to be aligned on any particular boundary.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_storeu_epi64)
-#[inline]
-#[target_feature(enable = "avx512f")]
-pub fn _mm512_mask_storeu_epi64<T: Is512BitsUnaligned>(mem_addr: &mut T, k: __mmask8, a: __m512i) {
-    unsafe { arch::_mm512_mask_storeu_epi64(ptr::from_mut(mem_addr).cast(), k, a) }
-}
-
-/// Store 512-bits (composed of 8 packed 64-bit integers) from a into memory. mem_addr does not need to be aligned on any particular boundary.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_storeu_epi64)
-#[inline]
-#[target_feature(enable = "avx512f")]
-pub fn _mm512_storeu_epi64<T: Is512BitsUnaligned>(mem_addr: &mut T, a: __m512i) {
-    unsafe { arch::_mm512_storeu_epi64(ptr::from_mut(mem_addr).cast(), a) }
-}
-
-/// Store packed double-precision (64-bit) floating-point elements from a into memory using writemask k.
-/// mem_addr does not need to be aligned on any particular boundary.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_mask_storeu_pd)
-#[inline]
-#[target_feature(enable = "avx512f,avx512vl")]
-pub fn _mm_mask_storeu_pd(mem_addr: &mut [f64; 2], k: __mmask8, a: __m128d) {
-    unsafe { arch::_mm_mask_storeu_pd(mem_addr.as_mut_ptr().cast(), k, a) }
-}
-
-/// Store packed double-precision (64-bit) floating-point elements from a into memory using writemask k.
-/// mem_addr does not need to be aligned on any particular boundary.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_mask_storeu_pd)
-#[inline]
-#[target_feature(enable = "avx512f,avx512vl")]
-pub fn _mm256_mask_storeu_pd(mem_addr: &mut [f64; 4], k: __mmask8, a: __m256d) {
-    unsafe { arch::_mm256_mask_storeu_pd(mem_addr.as_mut_ptr().cast(), k, a) }
-}
-
-/// Store packed double-precision (64-bit) floating-point elements from a into memory using writemask k.
-/// mem_addr does not need to be aligned on any particular boundary.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_storeu_pd)
-#[inline]
-#[target_feature(enable = "avx512f")]
-pub fn _mm512_mask_storeu_pd(mem_addr: &mut [f64; 8], k: __mmask8, a: __m512d) {
-    unsafe { arch::_mm512_mask_storeu_pd(mem_addr.as_mut_ptr().cast(), k, a) }
-}
-
-/// Stores 512-bits (composed of 8 packed double-precision (64-bit)
-/// floating-point elements) from `a` into memory.
-/// `mem_addr` does not need to be aligned on any particular boundary.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_storeu_pd)
-#[inline]
-#[target_feature(enable = "avx512f")]
-pub fn _mm512_storeu_pd(mem_addr: &mut [f64; 8], a: __m512d) {
-    unsafe { arch::_mm512_storeu_pd(mem_addr.as_mut_ptr().cast(), a) }
-}
-
-/// Store packed single-precision (32-bit) floating-point elements from a into memory using writemask k.
-/// mem_addr does not need to be aligned on any particular boundary.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_mask_storeu_ps)
-#[inline]
-#[target_feature(enable = "avx512f,avx512vl")]
-pub fn _mm_mask_storeu_ps(mem_addr: &mut [f32; 4], k: __mmask8, a: __m128) {
-    unsafe { arch::_mm_mask_storeu_ps(mem_addr.as_mut_ptr().cast(), k, a) }
-}
-
-/// Store packed single-precision (32-bit) floating-point elements from a into memory using writemask k.
-/// mem_addr does not need to be aligned on any particular boundary.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_mask_storeu_ps)
-#[inline]
-#[target_feature(enable = "avx512f,avx512vl")]
-pub fn _mm256_mask_storeu_ps(mem_addr: &mut [f32; 8], k: __mmask8, a: __m256) {
-    unsafe { arch::_mm256_mask_storeu_ps(mem_addr.as_mut_ptr().cast(), k, a) }
-}
-
-/// Store packed single-precision (32-bit) floating-point elements from a into memory using writemask k.
-/// mem_addr does not need to be aligned on any particular boundary.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_storeu_ps)
-#[inline]
-#[target_feature(enable = "avx512f")]
-pub fn _mm512_mask_storeu_ps(mem_addr: &mut [f32; 16], k: __mmask16, a: __m512) {
-    unsafe { arch::_mm512_mask_storeu_ps(mem_addr.as_mut_ptr().cast(), k, a) }
-}
-
-/// Stores 512-bits (composed of 16 packed single-precision (32-bit)
-/// floating-point elements) from `a` into memory.
-/// `mem_addr` does not need to be aligned on any particular boundary.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_storeu_ps)
-#[inline]
-#[target_feature(enable = "avx512f")]
-pub fn _mm512_storeu_ps(mem_addr: &mut [f32; 16], a: __m512) {
-    unsafe { arch::_mm512_storeu_ps(mem_addr.as_mut_ptr().cast(), a) }
-}
-
-/// Store 512-bits of integer data from a into memory. mem_addr does not need to be aligned on any particular boundary.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_storeu_si512)
-#[inline]
-#[target_feature(enable = "avx512f")]
-pub fn _mm512_storeu_si512<T: Is512BitsUnaligned>(mem_addr: &mut T, a: __m512i) {
-    unsafe { arch::_mm512_storeu_si512(ptr::from_mut(mem_addr).cast(), a) }
+token_wrappers! {
+    impl super::token::Avx512f;
+
+    /// Load contiguous active 32-bit integers from unaligned memory at mem_addr (those with their respective bit set in mask k), and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_mask_expandloadu_epi32)
+    #[method = mm_mask_expandloadu_epi32]
+    #[inline]
+    #[target_feature(enable = "avx512f,avx512vl")]
+    pub fn _mm_mask_expandloadu_epi32<T: Is128BitsReadable>(
+        src: __m128i,
+        k: __mmask8,
+        mem_addr: &T,
+    ) -> __m128i {
+        unsafe { arch::_mm_mask_expandloadu_epi32(src, k, ptr::from_ref(mem_addr).cast()) }
+    }
+
+    /// Load contiguous active 32-bit integers from unaligned memory at mem_addr (those with their respective bit set in mask k), and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_maskz_expandloadu_epi32)
+    #[method = mm_maskz_expandloadu_epi32]
+    #[inline]
+    #[target_feature(enable = "avx512f,avx512vl")]
+    pub fn _mm_maskz_expandloadu_epi32<T: Is128BitsReadable>(k: __mmask8, mem_addr: &T) -> __m128i {
+        _mm_mask_expandloadu_epi32(arch::_mm_setzero_si128(), k, mem_addr)
+    }
+
+    /// Load contiguous active 32-bit integers from unaligned memory at mem_addr (those with their respective bit set in mask k), and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_mask_expandloadu_epi32)
+    #[method = mm256_mask_expandloadu_epi32]
+    #[inline]
+    #[target_feature(enable = "avx512f,avx512vl")]
+    pub fn _mm256_mask_expandloadu_epi32<T: Is256BitsReadable>(
+        src: __m256i,
+        k: __mmask8,
+        mem_addr: &T,
+    ) -> __m256i {
+        unsafe { arch::_mm256_mask_expandloadu_epi32(src, k, ptr::from_ref(mem_addr).cast()) }
+    }
+
+    /// Load contiguous active 32-bit integers from unaligned memory at mem_addr (those with their respective bit set in mask k), and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_maskz_expandloadu_epi32)
+    #[method = mm256_maskz_expandloadu_epi32]
+    #[inline]
+    #[target_feature(enable = "avx512f,avx512vl")]
+    pub fn _mm256_maskz_expandloadu_epi32<T: Is256BitsReadable>(k: __mmask8, mem_addr: &T) -> __m256i {
+        _mm256_mask_expandloadu_epi32(arch::_mm256_setzero_si256(), k, mem_addr)
+    }
+
+    /// Load contiguous active 32-bit integers from unaligned memory at mem_addr (those with their respective bit set in mask k), and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_expandloadu_epi32)
+    #[method = mm512_mask_expandloadu_epi32]
+    #[inline]
+    #[target_feature(enable = "avx512f")]
+    pub fn _mm512_mask_expandloadu_epi32<T: Is512BitsReadable>(
+        src: __m512i,
+        k: __mmask16,
+        mem_addr: &T,
+    ) -> __m512i {
+        unsafe { arch::_mm512_mask_expandloadu_epi32(src, k, ptr::from_ref(mem_addr).cast()) }
+    }
+
+    /// Load contiguous active 32-bit integers from unaligned memory at mem_addr (those with their respective bit set in mask k), and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_maskz_expandloadu_epi32)
+    #[method = mm512_maskz_expandloadu_epi32]
+    #[inline]
+    #[target_feature(enable = "avx512f")]
+    pub fn _mm512_maskz_expandloadu_epi32<T: Is512BitsReadable>(k: __mmask16, mem_addr: &T) -> __m512i {
+        _mm512_mask_expandloadu_epi32(arch::_mm512_setzero_si512(), k, mem_addr)
+    }
+
+    /// Load contiguous active 64-bit integers from unaligned memory at mem_addr (those with their respective bit set in mask k), and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_mask_expandloadu_epi64)
+    #[method = mm_mask_expandloadu_epi64]
+    #[inline]
+    #[target_feature(enable = "avx512f,avx512vl")]
+    pub fn _mm_mask_expandloadu_epi64<T: Is128BitsReadable>(
+        src: __m128i,
+        k: __mmask8,
+        mem_addr: &T,
+    ) -> __m128i {
+        unsafe { arch::_mm_mask_expandloadu_epi64(src, k, ptr::from_ref(mem_addr).cast()) }
+    }
+
+    /// Load contiguous active 64-bit integers from unaligned memory at mem_addr (those with their respective bit set in mask k), and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_maskz_expandloadu_epi64)
+    #[method = mm_maskz_expandloadu_epi64]
+    #[inline]
+    #[target_feature(enable = "avx512f,avx512vl")]
+    pub fn _mm_maskz_expandloadu_epi64<T: Is128BitsReadable>(k: __mmask8, mem_addr: &T) -> __m128i {
+        _mm_mask_expandloadu_epi64(arch::_mm_setzero_si128(), k, mem_addr)
+    }
+
+    /// Load contiguous active 64-bit integers from unaligned memory at mem_addr (those with their respective bit set in mask k), and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_mask_expandloadu_epi64)
+    #[method = mm256_mask_expandloadu_epi64]
+    #[inline]
+    #[target_feature(enable = "avx512f,avx512vl")]
+    pub fn _mm256_mask_expandloadu_epi64<T: Is256BitsReadable>(
+        src: __m256i,
+        k: __mmask8,
+        mem_addr: &T,
+    ) -> __m256i {
+        unsafe { arch::_mm256_mask_expandloadu_epi64(src, k, ptr::from_ref(mem_addr).cast()) }
+    }
+
+    /// Load contiguous active 64-bit integers from unaligned memory at mem_addr (those with their respective bit set in mask k), and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_maskz_expandloadu_epi64)
+    #[method = mm256_maskz_expandloadu_epi64]
+    #[inline]
+    #[target_feature(enable = "avx512f,avx512vl")]
+    pub fn _mm256_maskz_expandloadu_epi64<T: Is256BitsReadable>(k: __mmask8, mem_addr: &T) -> __m256i {
+        _mm256_mask_expandloadu_epi64(arch::_mm256_setzero_si256(), k, mem_addr)
+    }
+
+    /// Load contiguous active 64-bit integers from unaligned memory at mem_addr (those with their respective bit set in mask k), and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_expandloadu_epi64)
+    #[method = mm512_mask_expandloadu_epi64]
+    #[inline]
+    #[target_feature(enable = "avx512f")]
+    pub fn _mm512_mask_expandloadu_epi64<T: Is512BitsReadable>(
+        src: __m512i,
+        k: __mmask8,
+        mem_addr: &T,
+    ) -> __m512i {
+        unsafe { arch::_mm512_mask_expandloadu_epi64(src, k, ptr::from_ref(mem_addr).cast()) }
+    }
+
+    /// Load contiguous active 64-bit integers from unaligned memory at mem_addr (those with their respective bit set in mask k), and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_maskz_expandloadu_epi64)
+    #[method = mm512_maskz_expandloadu_epi64]
+    #[inline]
+    #[target_feature(enable = "avx512f")]
+    pub fn _mm512_maskz_expandloadu_epi64<T: Is512BitsReadable>(k: __mmask8, mem_addr: &T) -> __m512i {
+        _mm512_mask_expandloadu_epi64(arch::_mm512_setzero_si512(), k, mem_addr)
+    }
+
+    /// Load contiguous active double-precision (64-bit) floating-point elements from unaligned memory at mem_addr (those with their respective bit set in mask k), and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_mask_expandloadu_pd)
+    #[method = mm_mask_expandloadu_pd]
+    #[inline]
+    #[target_feature(enable = "avx512f,avx512vl")]
+    pub fn _mm_mask_expandloadu_pd(src: __m128d, k: __mmask8, mem_addr: &[f64; 2]) -> __m128d {
+        unsafe { arch::_mm_mask_expandloadu_pd(src, k, ptr::from_ref(mem_addr).cast()) }
+    }
+
+    /// Load contiguous active double-precision (64-bit) floating-point elements from unaligned memory at mem_addr (those with their respective bit set in mask k), and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_maskz_expandloadu_pd)
+    #[method = mm_maskz_expandloadu_pd]
+    #[inline]
+    #[target_feature(enable = "avx512f,avx512vl")]
+    pub fn _mm_maskz_expandloadu_pd(k: __mmask8, mem_addr: &[f64; 2]) -> __m128d {
+        _mm_mask_expandloadu_pd(arch::_mm_setzero_pd(), k, mem_addr)
+    }
+
+    /// Load contiguous active double-precision (64-bit) floating-point elements from unaligned memory at mem_addr (those with their respective bit set in mask k), and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_mask_expandloadu_pd)
+    #[method = mm256_mask_expandloadu_pd]
+    #[inline]
+    #[target_feature(enable = "avx512f,avx512vl")]
+    pub fn _mm256_mask_expandloadu_pd(src: __m256d, k: __mmask8, mem_addr: &[f64; 4]) -> __m256d {
+        unsafe { arch::_mm256_mask_expandloadu_pd(src, k, ptr::from_ref(mem_addr).cast()) }
+    }
+
+    /// Load contiguous active double-precision (64-bit) floating-point elements from unaligned memory at mem_addr (those with their respective bit set in mask k), and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_maskz_expandloadu_pd)
+    #[method = mm256_maskz_expandloadu_pd]
+    #[inline]
+    #[target_feature(enable = "avx512f,avx512vl")]
+    pub fn _mm256_maskz_expandloadu_pd(k: __mmask8, mem_addr: &[f64; 4]) -> __m256d {
+        _mm256_mask_expandloadu_pd(arch::_mm256_setzero_pd(), k, mem_addr)
+    }
+
+    /// Load contiguous active double-precision (64-bit) floating-point elements from unaligned memory at mem_addr (those with their respective bit set in mask k), and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_expandloadu_pd)
+    #[method = mm512_mask_expandloadu_pd]
+    #[inline]
+    #[target_feature(enable = "avx512f")]
+    pub fn _mm512_mask_expandloadu_pd(src: __m512d, k: __mmask8, mem_addr: &[f64; 8]) -> __m512d {
+        unsafe { arch::_mm512_mask_expandloadu_pd(src, k, ptr::from_ref(mem_addr).cast()) }
+    }
+
+    /// Load contiguous active double-precision (64-bit) floating-point elements from unaligned memory at mem_addr (those with their respective bit set in mask k), and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_maskz_expandloadu_pd)
+    #[method = mm512_maskz_expandloadu_pd]
+    #[inline]
+    #[target_feature(enable = "avx512f")]
+    pub fn _mm512_maskz_expandloadu_pd(k: __mmask8, mem_addr: &[f64; 8]) -> __m512d {
+        _mm512_mask_expandloadu_pd(arch::_mm512_setzero_pd(), k, mem_addr)
+    }
+
+    /// Load contiguous active single-precision (32-bit) floating-point elements from unaligned memory at mem_addr (those with their respective bit set in mask k), and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_mask_expandloadu_ps)
+    #[method = mm_mask_expandloadu_ps]
+    #[inline]
+    #[target_feature(enable = "avx512f,avx512vl")]
+    pub fn _mm_mask_expandloadu_ps(src: __m128, k: __mmask8, mem_addr: &[f32; 4]) -> __m128 {
+        unsafe { arch::_mm_mask_expandloadu_ps(src, k, ptr::from_ref(mem_addr).cast()) }
+    }
+
+    /// Load contiguous active single-precision (32-bit) floating-point elements from unaligned memory at mem_addr (those with their respective bit set in mask k), and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_maskz_expandloadu_ps)
+    #[method = mm_maskz_expandloadu_ps]
+    #[inline]
+    #[target_feature(enable = "avx512f,avx512vl")]
+    pub fn _mm_maskz_expandloadu_ps(k: __mmask8, mem_addr: &[f32; 4]) -> __m128 {
+        _mm_mask_expandloadu_ps(arch::_mm_setzero_ps(), k, mem_addr)
+    }
+
+    /// Load contiguous active single-precision (32-bit) floating-point elements from unaligned memory at mem_addr (those with their respective bit set in mask k), and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_mask_expandloadu_ps)
+    #[method = mm256_mask_expandloadu_ps]
+    #[inline]
+    #[target_feature(enable = "avx512f,avx512vl")]
+    pub fn _mm256_mask_expandloadu_ps(src: __m256, k: __mmask8, mem_addr: &[f32; 8]) -> __m256 {
+        unsafe { arch::_mm256_mask_expandloadu_ps(src, k, ptr::from_ref(mem_addr).cast()) }
+    }
+
+    /// Load contiguous active single-precision (32-bit) floating-point elements from unaligned memory at mem_addr (those with their respective bit set in mask k), and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_maskz_expandloadu_ps)
+    #[method = mm256_maskz_expandloadu_ps]
+    #[inline]
+    #[target_feature(enable = "avx512f,avx512vl")]
+    pub fn _mm256_maskz_expandloadu_ps(k: __mmask8, mem_addr: &[f32; 8]) -> __m256 {
+        _mm256_mask_expandloadu_ps(arch::_mm256_setzero_ps(), k, mem_addr)
+    }
+
+    /// Load contiguous active single-precision (32-bit) floating-point elements from unaligned memory at mem_addr (those with their respective bit set in mask k), and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_expandloadu_ps)
+    #[method = mm512_mask_expandloadu_ps]
+    #[inline]
+    #[target_feature(enable = "avx512f")]
+    pub fn _mm512_mask_expandloadu_ps(src: __m512, k: __mmask16, mem_addr: &[f32; 16]) -> __m512 {
+        unsafe { arch::_mm512_mask_expandloadu_ps(src, k, ptr::from_ref(mem_addr).cast()) }
+    }
+
+    /// Load contiguous active single-precision (32-bit) floating-point elements from unaligned memory at mem_addr (those with their respective bit set in mask k), and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_maskz_expandloadu_ps)
+    #[method = mm512_maskz_expandloadu_ps]
+    #[inline]
+    #[target_feature(enable = "avx512f")]
+    pub fn _mm512_maskz_expandloadu_ps(k: __mmask16, mem_addr: &[f32; 16]) -> __m512 {
+        _mm512_mask_expandloadu_ps(arch::_mm512_setzero_ps(), k, mem_addr)
+    }
+
+    /// Load 128-bits (composed of 4 packed 32-bit integers) from memory into dst. mem_addr does not need to be aligned on any particular boundary.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_loadu_epi32)
+    #[method = mm_loadu_epi32]
+    #[inline]
+    #[target_feature(enable = "avx512f,avx512vl")]
+    pub fn _mm_loadu_epi32<T: Is128BitsReadable>(mem_addr: &T) -> __m128i {
+        unsafe { arch::_mm_loadu_epi32(ptr::from_ref(mem_addr).cast()) }
+    }
+
+    /// Load packed 32-bit integers from memory into dst using writemask k
+    /// (elements are copied from src when the corresponding mask bit is not set).
+    /// mem_addr does not need to be aligned on any particular boundary.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_mask_loadu_epi32)
+    #[method = mm_mask_loadu_epi32]
+    #[inline]
+    #[target_feature(enable = "avx512f,avx512vl")]
+    pub fn _mm_mask_loadu_epi32<T: Is128BitsReadable>(
+        src: __m128i,
+        k: __mmask8,
+        mem_addr: &T,
+    ) -> __m128i {
+        unsafe { arch::_mm_mask_loadu_epi32(src, k, ptr::from_ref(mem_addr).cast()) }
+    }
+
+    /// Load packed 32-bit integers from memory into dst using zeromask k
+    /// (elements are zeroed out when the corresponding mask bit is not set).
+    /// mem_addr does not need to be aligned on any particular boundary.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_maskz_loadu_epi32)
+    #[method = mm_maskz_loadu_epi32]
+    #[inline]
+    #[target_feature(enable = "avx512f,avx512vl")]
+    pub fn _mm_maskz_loadu_epi32<T: Is128BitsReadable>(k: __mmask8, mem_addr: &T) -> __m128i {
+        _mm_mask_loadu_epi32(arch::_mm_setzero_si128(), k, mem_addr)
+    }
+
+    /// Load 256-bits (composed of 8 packed 32-bit integers) from memory into dst. mem_addr does not need to be aligned on any particular boundary.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_loadu_epi32)
+    #[method = mm256_loadu_epi32]
+    #[inline]
+    #[target_feature(enable = "avx512f,avx512vl")]
+    pub fn _mm256_loadu_epi32<T: Is256BitsReadable>(mem_addr: &T) -> __m256i {
+        unsafe { arch::_mm256_loadu_epi32(ptr::from_ref(mem_addr).cast()) }
+    }
+
+    /// Load packed 32-bit integers from memory into dst using writemask k
+    /// (elements are copied from src when the corresponding mask bit is not set).
+    /// mem_addr does not need to be aligned on any particular boundary.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_mask_loadu_epi32)
+    #[method = mm256_mask_loadu_epi32]
+    #[inline]
+    #[target_feature(enable = "avx512f,avx512vl")]
+    pub fn _mm256_mask_loadu_epi32<T: Is256BitsReadable>(
+        src: __m256i,
+        k: __mmask8,
+        mem_addr: &T,
+    ) -> __m256i {
+        unsafe { arch::_mm256_mask_loadu_epi32(src, k, ptr::from_ref(mem_addr).cast()) }
+    }
+
+    /// Load packed 32-bit integers from memory into dst using zeromask k
+    /// (elements are zeroed out when the corresponding mask bit is not set).
+    /// mem_addr does not need to be aligned on any particular boundary.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_maskz_loadu_epi32)
+    #[method = mm256_maskz_loadu_epi32]
+    #[inline]
+    #[target_feature(enable = "avx512f,avx512vl")]
+    pub fn _mm256_maskz_loadu_epi32<T: Is256BitsReadable>(k: __mmask8, mem_addr: &T) -> __m256i {
+        _mm256_mask_loadu_epi32(arch::_mm256_setzero_si256(), k, mem_addr)
+    }
+
+    /// Load 512-bits (composed of 16 packed 32-bit integers) from memory into dst. mem_addr does not need to be aligned on any particular boundary.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_loadu_epi32)
+    #[method = mm512_loadu_epi32]
+    #[inline]
+    #[target_feature(enable = "avx512f")]
+    pub fn _mm512_loadu_epi32<T: Is512BitsReadable>(mem_addr: &T) -> __m512i {
+        unsafe { arch::_mm512_loadu_epi32(ptr::from_ref(mem_addr).cast()) }
+    }
+
+    /// Load packed 32-bit integers from memory into dst using writemask k
+    /// (elements are copied from src when the corresponding mask bit is not set).
+    /// mem_addr does not need to be aligned on any particular boundary.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_loadu_epi32)
+    #[method = mm512_mask_loadu_epi32]
+    #[inline]
+    #[target_feature(enable = "avx512f")]
+    pub fn _mm512_mask_loadu_epi32<T: Is512BitsReadable>(
+        src: __m512i,
+        k: __mmask16,
+        mem_addr: &T,
+    ) -> __m512i {
+        unsafe { arch::_mm512_mask_loadu_epi32(src, k, ptr::from_ref(mem_addr).cast()) }
+    }
+
+    /// Load packed 32-bit integers from memory into dst using zeromask k
+    /// (elements are zeroed out when the corresponding mask bit is not set).
+    /// mem_addr does not need to be aligned on any particular boundary.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_maskz_loadu_epi32)
+    #[method = mm512_maskz_loadu_epi32]
+    #[inline]
+    #[target_feature(enable = "avx512f")]
+    pub fn _mm512_maskz_loadu_epi32<T: Is512BitsReadable>(k: __mmask16, mem_addr: &T) -> __m512i {
+        _mm512_mask_loadu_epi32(arch::_mm512_setzero_si512(), k, mem_addr)
+    }
+
+    /// Load 128-bits (composed of 2 packed 64-bit integers) from memory into dst. mem_addr does not need to be aligned on any particular boundary.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_loadu_epi64)
+    #[method = mm_loadu_epi64]
+    #[inline]
+    #[target_feature(enable = "avx512f,avx512vl")]
+    pub fn _mm_loadu_epi64<T: Is128BitsReadable>(mem_addr: &T) -> __m128i {
+        unsafe { arch::_mm_loadu_epi64(ptr::from_ref(mem_addr).cast()) }
+    }
+
+    /// Load packed 64-bit integers from memory into dst using writemask k
+    /// (elements are copied from src when the corresponding mask bit is not set).
+    /// mem_addr does not need to be aligned on any particular boundary.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_mask_loadu_epi64)
+    #[method = mm_mask_loadu_epi64]
+    #[inline]
+    #[target_feature(enable = "avx512f,avx512vl")]
+    pub fn _mm_mask_loadu_epi64<T: Is128BitsReadable>(
+        src: __m128i,
+        k: __mmask8,
+        mem_addr: &T,
+    ) -> __m128i {
+        unsafe { arch::_mm_mask_loadu_epi64(src, k, ptr::from_ref(mem_addr).cast()) }
+    }
+
+    /// Load packed 64-bit integers from memory into dst using zeromask k
+    /// (elements are zeroed out when the corresponding mask bit is not set).
+    /// mem_addr does not need to be aligned on any particular boundary.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_maskz_loadu_epi64)
+    #[method = mm_maskz_loadu_epi64]
+    #[inline]
+    #[target_feature(enable = "avx512f,avx512vl")]
+    pub fn _mm_maskz_loadu_epi64<T: Is128BitsReadable>(k: __mmask8, mem_addr: &T) -> __m128i {
+        _mm_mask_loadu_epi64(arch::_mm_setzero_si128(), k, mem_addr)
+    }
+
+    /// Load 256-bits (composed of 4 packed 64-bit integers) from memory into dst. mem_addr does not need to be aligned on any particular boundary.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_loadu_epi64)
+    #[method = mm256_loadu_epi64]
+    #[inline]
+    #[target_feature(enable = "avx512f,avx512vl")]
+    pub fn _mm256_loadu_epi64<T: Is256BitsReadable>(mem_addr: &T) -> __m256i {
+        unsafe { arch::_mm256_loadu_epi64(ptr::from_ref(mem_addr).cast()) }
+    }
+
+    /// Load packed 64-bit integers from memory into dst using writemask k
+    /// (elements are copied from src when the corresponding mask bit is not set).
+    /// mem_addr does not need to be aligned on any particular boundary.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_mask_loadu_epi64)
+    #[method = mm256_mask_loadu_epi64]
+    #[inline]
+    #[target_feature(enable = "avx512f,avx512vl")]
+    pub fn _mm256_mask_loadu_epi64<T: Is256BitsReadable>(
+        src: __m256i,
+        k: __mmask8,
+        mem_addr: &T,
+    ) -> __m256i {
+        unsafe { arch::_mm256_mask_loadu_epi64(src, k, ptr::from_ref(mem_addr).cast()) }
+    }
+
+    /// Load packed 64-bit integers from memory into dst using zeromask k
+    /// (elements are zeroed out when the corresponding mask bit is not set).
+    /// mem_addr does not need to be aligned on any particular boundary.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_maskz_loadu_epi64)
+    #[method = mm256_maskz_loadu_epi64]
+    #[inline]
+    #[target_feature(enable = "avx512f,avx512vl")]
+    pub fn _mm256_maskz_loadu_epi64<T: Is256BitsReadable>(k: __mmask8, mem_addr: &T) -> __m256i {
+        _mm256_mask_loadu_epi64(arch::_mm256_setzero_si256(), k, mem_addr)
+    }
+
+    /// Load 512-bits (composed of 8 packed 64-bit integers) from memory into dst. mem_addr does not need to be aligned on any particular boundary.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_loadu_epi64)
+    #[method = mm512_loadu_epi64]
+    #[inline]
+    #[target_feature(enable = "avx512f")]
+    pub fn _mm512_loadu_epi64<T: Is512BitsReadable>(mem_addr: &T) -> __m512i {
+        unsafe { arch::_mm512_loadu_epi64(ptr::from_ref(mem_addr).cast()) }
+    }
+
+    /// Load packed 64-bit integers from memory into dst using writemask k
+    /// (elements are copied from src when the corresponding mask bit is not set).
+    /// mem_addr does not need to be aligned on any particular boundary.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_loadu_epi64)
+    #[method = mm512_mask_loadu_epi64]
+    #[inline]
+    #[target_feature(enable = "avx512f")]
+    pub fn _mm512_mask_loadu_epi64<T: Is512BitsReadable>(
+        src: __m512i,
+        k: __mmask8,
+        mem_addr: &T,
+    ) -> __m512i {
+        unsafe { arch::_mm512_mask_loadu_epi64(src, k, ptr::from_ref(mem_addr).cast()) }
+    }
+
+    /// Load packed 64-bit integers from memory into dst using zeromask k
+    /// (elements are zeroed out when the corresponding mask bit is not set).
+    /// mem_addr does not need to be aligned on any particular boundary.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_maskz_loadu_epi64)
+    #[method = mm512_maskz_loadu_epi64]
+    #[inline]
+    #[target_feature(enable = "avx512f")]
+    pub fn _mm512_maskz_loadu_epi64<T: Is512BitsReadable>(k: __mmask8, mem_addr: &T) -> __m512i {
+        _mm512_mask_loadu_epi64(arch::_mm512_setzero_si512(), k, mem_addr)
+    }
+
+    /// Load packed double-precision (64-bit) floating-point elements from memory into dst using writemask k
+    /// (elements are copied from src when the corresponding mask bit is not set).
+    /// mem_addr does not need to be aligned on any particular boundary.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_mask_loadu_pd)
+    #[method = mm_mask_loadu_pd]
+    #[inline]
+    #[target_feature(enable = "avx512f,avx512vl")]
+    pub fn _mm_mask_loadu_pd(src: __m128d, k: __mmask8, mem_addr: &[f64; 2]) -> __m128d {
+        unsafe { arch::_mm_mask_loadu_pd(src, k, mem_addr.as_ptr()) }
+    }
+
+    /// Load packed double-precision (64-bit) floating-point elements from memory into dst using zeromask k
+    /// (elements are zeroed out when the corresponding mask bit is not set).
+    /// mem_addr does not need to be aligned on any particular boundary.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_maskz_loadu_pd)
+    #[method = mm_maskz_loadu_pd]
+    #[inline]
+    #[target_feature(enable = "avx512f,avx512vl")]
+    pub fn _mm_maskz_loadu_pd(k: __mmask8, mem_addr: &[f64; 2]) -> __m128d {
+        _mm_mask_loadu_pd(arch::_mm_setzero_pd(), k, mem_addr)
+    }
+
+    /// Load packed double-precision (64-bit) floating-point elements from memory into dst using writemask k
+    /// (elements are copied from src when the corresponding mask bit is not set).
+    /// mem_addr does not need to be aligned on any particular boundary.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_mask_loadu_pd)
+    #[method = mm256_mask_loadu_pd]
+    #[inline]
+    #[target_feature(enable = "avx512f,avx512vl")]
+    pub fn _mm256_mask_loadu_pd(src: __m256d, k: __mmask8, mem_addr: &[f64; 4]) -> __m256d {
+        unsafe { arch::_mm256_mask_loadu_pd(src, k, mem_addr.as_ptr()) }
+    }
+
+    /// Load packed double-precision (64-bit) floating-point elements from memory into dst using zeromask k
+    /// (elements are zeroed out when the corresponding mask bit is not set).
+    /// mem_addr does not need to be aligned on any particular boundary.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_maskz_loadu_pd)
+    #[method = mm256_maskz_loadu_pd]
+    #[inline]
+    #[target_feature(enable = "avx512f,avx512vl")]
+    pub fn _mm256_maskz_loadu_pd(k: __mmask8, mem_addr: &[f64; 4]) -> __m256d {
+        _mm256_mask_loadu_pd(arch::_mm256_setzero_pd(), k, mem_addr)
+    }
+
+    /// Loads 512-bits (composed of 8 packed double-precision (64-bit)
+    /// floating-point elements) from memory into result.
+    /// `mem_addr` does not need to be aligned on any particular boundary.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_loadu_pd)
+    #[method = mm512_loadu_pd]
+    #[inline]
+    #[target_feature(enable = "avx512f")]
+    pub fn _mm512_loadu_pd(mem_addr: &[f64; 8]) -> __m512d {
+        unsafe { arch::_mm512_loadu_pd(mem_addr.as_ptr()) }
+    }
+
+    /// Load packed double-precision (64-bit) floating-point elements from memory into dst using writemask k
+    /// (elements are copied from src when the corresponding mask bit is not set).
+    /// mem_addr does not need to be aligned on any particular boundary.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_loadu_pd)
+    #[method = mm512_mask_loadu_pd]
+    #[inline]
+    #[target_feature(enable = "avx512f")]
+    pub fn _mm512_mask_loadu_pd(src: __m512d, k: __mmask8, mem_addr: &[f64; 8]) -> __m512d {
+        unsafe { arch::_mm512_mask_loadu_pd(src, k, mem_addr.as_ptr()) }
+    }
+
+    /// Load packed double-precision (64-bit) floating-point elements from memory into dst using zeromask k
+    /// (elements are zeroed out when the corresponding mask bit is not set).
+    /// mem_addr does not need to be aligned on any particular boundary.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_maskz_loadu_pd)
+    #[method = mm512_maskz_loadu_pd]
+    #[inline]
+    #[target_feature(enable = "avx512f")]
+    pub fn _mm512_maskz_loadu_pd(k: __mmask8, mem_addr: &[f64; 8]) -> __m512d {
+        _mm512_mask_loadu_pd(arch::_mm512_setzero_pd(), k, mem_addr)
+    }
+
+    /// Load packed single-precision (32-bit) floating-point elements from memory into dst using writemask k
+    /// (elements are copied from src when the corresponding mask bit is not set).
+    /// mem_addr does not need to be aligned on any particular boundary.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_mask_loadu_ps)
+    #[method = mm_mask_loadu_ps]
+    #[inline]
+    #[target_feature(enable = "avx512f,avx512vl")]
+    pub fn _mm_mask_loadu_ps(src: __m128, k: __mmask8, mem_addr: &[f32; 4]) -> __m128 {
+        unsafe { arch::_mm_mask_loadu_ps(src, k, mem_addr.as_ptr()) }
+    }
+
+    /// Load packed single-precision (32-bit) floating-point elements from memory into dst using zeromask k
+    /// (elements are zeroed out when the corresponding mask bit is not set).
+    /// mem_addr does not need to be aligned on any particular boundary.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_maskz_loadu_ps)
+    #[method = mm_maskz_loadu_ps]
+    #[inline]
+    #[target_feature(enable = "avx512f,avx512vl")]
+    pub fn _mm_maskz_loadu_ps(k: __mmask8, mem_addr: &[f32; 4]) -> __m128 {
+        _mm_mask_loadu_ps(arch::_mm_setzero_ps(), k, mem_addr)
+    }
+
+    /// Load packed single-precision (32-bit) floating-point elements from memory into dst using writemask k
+    /// (elements are copied from src when the corresponding mask bit is not set).
+    /// mem_addr does not need to be aligned on any particular boundary.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_mask_loadu_ps)
+    #[method = mm256_mask_loadu_ps]
+    #[inline]
+    #[target_feature(enable = "avx512f,avx512vl")]
+    pub fn _mm256_mask_loadu_ps(src: __m256, k: __mmask8, mem_addr: &[f32; 8]) -> __m256 {
+        unsafe { arch::_mm256_mask_loadu_ps(src, k, mem_addr.as_ptr()) }
+    }
+
+    /// Load packed single-precision (32-bit) floating-point elements from memory into dst using zeromask k
+    /// (elements are zeroed out when the corresponding mask bit is not set).
+    /// mem_addr does not need to be aligned on any particular boundary.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_maskz_loadu_ps)
+    #[method = mm256_maskz_loadu_ps]
+    #[inline]
+    #[target_feature(enable = "avx512f,avx512vl")]
+    pub fn _mm256_maskz_loadu_ps(k: __mmask8, mem_addr: &[f32; 8]) -> __m256 {
+        _mm256_mask_loadu_ps(arch::_mm256_setzero_ps(), k, mem_addr)
+    }
+
+    /// Loads 512-bits (composed of 16 packed single-precision (32-bit)
+    /// floating-point elements) from memory into result.
+    /// `mem_addr` does not need to be aligned on any particular boundary.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_loadu_ps)
+    #[method = mm512_loadu_ps]
+    #[inline]
+    #[target_feature(enable = "avx512f")]
+    pub fn _mm512_loadu_ps(mem_addr: &[f32; 16]) -> __m512 {
+        unsafe { arch::_mm512_loadu_ps(mem_addr.as_ptr()) }
+    }
+
+    /// Load packed single-precision (32-bit) floating-point elements from memory into dst using writemask k
+    /// (elements are copied from src when the corresponding mask bit is not set).
+    /// mem_addr does not need to be aligned on any particular boundary.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_loadu_ps)
+    #[method = mm512_mask_loadu_ps]
+    #[inline]
+    #[target_feature(enable = "avx512f")]
+    pub fn _mm512_mask_loadu_ps(src: __m512, k: __mmask16, mem_addr: &[f32; 16]) -> __m512 {
+        unsafe { arch::_mm512_mask_loadu_ps(src, k, mem_addr.as_ptr()) }
+    }
+
+    /// Load packed single-precision (32-bit) floating-point elements from memory into dst using zeromask k
+    /// (elements are zeroed out when the corresponding mask bit is not set).
+    /// mem_addr does not need to be aligned on any particular boundary.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_maskz_loadu_ps)
+    #[method = mm512_maskz_loadu_ps]
+    #[inline]
+    #[target_feature(enable = "avx512f")]
+    pub fn _mm512_maskz_loadu_ps(k: __mmask16, mem_addr: &[f32; 16]) -> __m512 {
+        _mm512_mask_loadu_ps(arch::_mm512_setzero_ps(), k, mem_addr)
+    }
+
+    /// Load 512-bits of integer data from memory into dst. mem_addr does not need to be aligned on any particular boundary.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_loadu_si512)
+    #[method = mm512_loadu_si512]
+    #[inline]
+    #[target_feature(enable = "avx512f")]
+    pub fn _mm512_loadu_si512<T: Is512BitsReadable>(mem_addr: &T) -> __m512i {
+        unsafe { arch::_mm512_loadu_si512(ptr::from_ref(mem_addr).cast()) }
+    }
+
+    // Store intrinsics
+
+    /// Contiguously store the active 32-bit integers in a (those with their respective bit set in writemask k) to unaligned memory at base_addr.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_mask_compressstoreu_epi32)
+    #[method = mm_mask_compressstoreu_epi32]
+    #[inline]
+    #[target_feature(enable = "avx512f,avx512vl")]
+    pub fn _mm_mask_compressstoreu_epi32<T: Is128BitsUnaligned>(
+        base_addr: &mut T,
+        k: __mmask8,
+        a: __m128i,
+    ) {
+        unsafe { arch::_mm_mask_compressstoreu_epi32(ptr::from_mut(base_addr).cast(), k, a) }
+    }
+
+    /// Contiguously store the active 32-bit integers in a (those with their respective bit set in writemask k) to unaligned memory at base_addr.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_mask_compressstoreu_epi32)
+    #[method = mm256_mask_compressstoreu_epi32]
+    #[inline]
+    #[target_feature(enable = "avx512f,avx512vl")]
+    pub fn _mm256_mask_compressstoreu_epi32<T: Is256BitsUnaligned>(
+        base_addr: &mut T,
+        k: __mmask8,
+        a: __m256i,
+    ) {
+        unsafe { arch::_mm256_mask_compressstoreu_epi32(ptr::from_mut(base_addr).cast(), k, a) }
+    }
+
+    /// Contiguously store the active 32-bit integers in a (those with their respective bit set in writemask k) to unaligned memory at base_addr.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_compressstoreu_epi32)
+    #[method = mm512_mask_compressstoreu_epi32]
+    #[inline]
+    #[target_feature(enable = "avx512f")]
+    pub fn _mm512_mask_compressstoreu_epi32<T: Is512BitsUnaligned>(
+        base_addr: &mut T,
+        k: __mmask16,
+        a: __m512i,
+    ) {
+        unsafe { arch::_mm512_mask_compressstoreu_epi32(ptr::from_mut(base_addr).cast(), k, a) }
+    }
+
+    /// Contiguously store the active 64-bit integers in a (those with their respective bit set in writemask k) to unaligned memory at base_addr.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_mask_compressstoreu_epi64)
+    #[method = mm_mask_compressstoreu_epi64]
+    #[inline]
+    #[target_feature(enable = "avx512f,avx512vl")]
+    pub fn _mm_mask_compressstoreu_epi64<T: Is128BitsUnaligned>(
+        base_addr: &mut T,
+        k: __mmask8,
+        a: __m128i,
+    ) {
+        unsafe { arch::_mm_mask_compressstoreu_epi64(ptr::from_mut(base_addr).cast(), k, a) }
+    }
+
+    /// Contiguously store the active 64-bit integers in a (those with their respective bit set in writemask k) to unaligned memory at base_addr.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_mask_compressstoreu_epi64)
+    #[method = mm256_mask_compressstoreu_epi64]
+    #[inline]
+    #[target_feature(enable = "avx512f,avx512vl")]
+    pub fn _mm256_mask_compressstoreu_epi64<T: Is256BitsUnaligned>(
+        base_addr: &mut T,
+        k: __mmask8,
+        a: __m256i,
+    ) {
+        unsafe { arch::_mm256_mask_compressstoreu_epi64(ptr::from_mut(base_addr).cast(), k, a) }
+    }
+
+    /// Contiguously store the active 64-bit integers in a (those with their respective bit set in writemask k) to unaligned memory at base_addr.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_compressstoreu_epi64)
+    #[method = mm512_mask_compressstoreu_epi64]
+    #[inline]
+    #[target_feature(enable = "avx512f")]
+    pub fn _mm512_mask_compressstoreu_epi64<T: Is512BitsUnaligned>(
+        base_addr: &mut T,
+        k: __mmask8,
+        a: __m512i,
+    ) {
+        unsafe { arch::_mm512_mask_compressstoreu_epi64(ptr::from_mut(base_addr).cast(), k, a) }
+    }
+
+    /// Contiguously store the active double-precision (64-bit) floating-point elements in a (those with their respective bit set in writemask k) to unaligned memory at base_addr.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_mask_compressstoreu_pd)
+    #[method = mm_mask_compressstoreu_pd]
+    #[inline]
+    #[target_feature(enable = "avx512f,avx512vl")]
+    pub fn _mm_mask_compressstoreu_pd(base_addr: &mut [f64; 2], k: __mmask8, a: __m128d) {
+        unsafe { arch::_mm_mask_compressstoreu_pd(base_addr.as_mut_ptr().cast(), k, a) }
+    }
+
+    /// Contiguously store the active double-precision (64-bit) floating-point elements in a (those with their respective bit set in writemask k) to unaligned memory at base_addr.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_mask_compressstoreu_pd)
+    #[method = mm256_mask_compressstoreu_pd]
+    #[inline]
+    #[target_feature(enable = "avx512f,avx512vl")]
+    pub fn _mm256_mask_compressstoreu_pd(base_addr: &mut [f64; 4], k: __mmask8, a: __m256d) {
+        unsafe { arch::_mm256_mask_compressstoreu_pd(base_addr.as_mut_ptr().cast(), k, a) }
+    }
+
+    /// Contiguously store the active double-precision (64-bit) floating-point elements in a (those with their respective bit set in writemask k) to unaligned memory at base_addr.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_compressstoreu_pd)
+    #[method = mm512_mask_compressstoreu_pd]
+    #[inline]
+    #[target_feature(enable = "avx512f")]
+    pub fn _mm512_mask_compressstoreu_pd(base_addr: &mut [f64; 8], k: __mmask8, a: __m512d) {
+        unsafe { arch::_mm512_mask_compressstoreu_pd(base_addr.as_mut_ptr().cast(), k, a) }
+    }
+
+    /// Contiguously store the active single-precision (32-bit) floating-point elements in a (those with their respective bit set in writemask k) to unaligned memory at base_addr.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_mask_compressstoreu_ps)
+    #[method = mm_mask_compressstoreu_ps]
+    #[inline]
+    #[target_feature(enable = "avx512f,avx512vl")]
+    pub fn _mm_mask_compressstoreu_ps(base_addr: &mut [f32; 4], k: __mmask8, a: __m128) {
+        unsafe { arch::_mm_mask_compressstoreu_ps(base_addr.as_mut_ptr().cast(), k, a) }
+    }
+
+    /// Contiguously store the active single-precision (32-bit) floating-point elements in a (those with their respective bit set in writemask k) to unaligned memory at base_addr.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_mask_compressstoreu_ps)
+    #[method = mm256_mask_compressstoreu_ps]
+    #[inline]
+    #[target_feature(enable = "avx512f,avx512vl")]
+    pub fn _mm256_mask_compressstoreu_ps(base_addr: &mut [f32; 8], k: __mmask8, a: __m256) {
+        unsafe { arch::_mm256_mask_compressstoreu_ps(base_addr.as_mut_ptr().cast(), k, a) }
+    }
+
+    /// Contiguously store the active single-precision (32-bit) floating-point elements in a (those with their respective bit set in writemask k) to unaligned memory at base_addr.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_compressstoreu_ps)
+    #[method = mm512_mask_compressstoreu_ps]
+    #[inline]
+    #[target_feature(enable = "avx512f")]
+    pub fn _mm512_mask_compressstoreu_ps(base_addr: &mut [f32; 16], k: __mmask16, a: __m512) {
+        unsafe { arch::_mm512_mask_compressstoreu_ps(base_addr.as_mut_ptr().cast(), k, a) }
+    }
+
+    /// Convert packed 32-bit integers in a to packed 16-bit integers with truncation, and store the active results (those with their respective bit set in writemask k) to unaligned memory at base_addr.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_mask_cvtepi32_storeu_epi16)
+    #[method = mm_mask_cvtepi32_storeu_epi16]
+    #[inline]
+    #[target_feature(enable = "avx512f,avx512vl")]
+    pub fn _mm_mask_cvtepi32_storeu_epi16<T: Is64BitsUnaligned>(
+        base_addr: &mut T,
+        k: __mmask8,
+        a: __m128i,
+    ) {
+        unsafe { arch::_mm_mask_cvtepi32_storeu_epi16(ptr::from_mut(base_addr).cast(), k, a) }
+    }
+
+    /// Convert packed 32-bit integers in a to packed 16-bit integers with truncation, and store the active results (those with their respective bit set in writemask k) to unaligned memory at base_addr.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_mask_cvtepi32_storeu_epi16)
+    #[method = mm256_mask_cvtepi32_storeu_epi16]
+    #[inline]
+    #[target_feature(enable = "avx512f,avx512vl")]
+    pub fn _mm256_mask_cvtepi32_storeu_epi16<T: Is128BitsUnaligned>(
+        base_addr: &mut T,
+        k: __mmask8,
+        a: __m256i,
+    ) {
+        unsafe { arch::_mm256_mask_cvtepi32_storeu_epi16(ptr::from_mut(base_addr).cast(), k, a) }
+    }
+
+    /// Convert packed 32-bit integers in a to packed 16-bit integers with truncation, and store the active results (those with their respective bit set in writemask k) to unaligned memory at base_addr.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_cvtepi32_storeu_epi16)
+    #[method = mm512_mask_cvtepi32_storeu_epi16]
+    #[inline]
+    #[target_feature(enable = "avx512f")]
+    pub fn _mm512_mask_cvtepi32_storeu_epi16<T: Is256BitsUnaligned>(
+        base_addr: &mut T,
+        k: __mmask16,
+        a: __m512i,
+    ) {
+        unsafe { arch::_mm512_mask_cvtepi32_storeu_epi16(ptr::from_mut(base_addr).cast(), k, a) }
+    }
+
+    /// Convert packed 32-bit integers in a to packed 8-bit integers with truncation, and store the active results (those with their respective bit set in writemask k) to unaligned memory at base_addr.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_mask_cvtepi32_storeu_epi8)
+    #[method = mm_mask_cvtepi32_storeu_epi8]
+    #[inline]
+    #[target_feature(enable = "avx512f,avx512vl")]
+    pub fn _mm_mask_cvtepi32_storeu_epi8<T: Is64BitsUnaligned>(
+        base_addr: &mut T,
+        k: __mmask8,
+        a: __m128i,
+    ) {
+        unsafe { arch::_mm_mask_cvtepi32_storeu_epi8(ptr::from_mut(base_addr).cast(), k, a) }
+    }
+
+    /// Convert packed 32-bit integers in a to packed 8-bit integers with truncation, and store the active results (those with their respective bit set in writemask k) to unaligned memory at base_addr.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_mask_cvtepi32_storeu_epi8)
+    #[method = mm256_mask_cvtepi32_storeu_epi8]
+    #[inline]
+    #[target_feature(enable = "avx512f,avx512vl")]
+    pub fn _mm256_mask_cvtepi32_storeu_epi8<T: Is64BitsUnaligned>(
+        base_addr: &mut T,
+        k: __mmask8,
+        a: __m256i,
+    ) {
+        unsafe { arch::_mm256_mask_cvtepi32_storeu_epi8(ptr::from_mut(base_addr).cast(), k, a) }
+    }
+
+    /// Convert packed 32-bit integers in a to packed 8-bit integers with truncation, and store the active results (those with their respective bit set in writemask k) to unaligned memory at base_addr.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_cvtepi32_storeu_epi8)
+    #[method = mm512_mask_cvtepi32_storeu_epi8]
+    #[inline]
+    #[target_feature(enable = "avx512f")]
+    pub fn _mm512_mask_cvtepi32_storeu_epi8<T: Is128BitsUnaligned>(
+        base_addr: &mut T,
+        k: __mmask16,
+        a: __m512i,
+    ) {
+        unsafe { arch::_mm512_mask_cvtepi32_storeu_epi8(ptr::from_mut(base_addr).cast(), k, a) }
+    }
+
+    /// Convert packed 64-bit integers in a to packed 16-bit integers with truncation, and store the active results (those with their respective bit set in writemask k) to unaligned memory at base_addr.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_mask_cvtepi64_storeu_epi16)
+    #[method = mm_mask_cvtepi64_storeu_epi16]
+    #[inline]
+    #[target_feature(enable = "avx512f,avx512vl")]
+    pub fn _mm_mask_cvtepi64_storeu_epi16<T: Is32BitsUnaligned>(
+        base_addr: &mut T,
+        k: __mmask8,
+        a: __m128i,
+    ) {
+        unsafe { arch::_mm_mask_cvtepi64_storeu_epi16(ptr::from_mut(base_addr).cast(), k, a) }
+    }
+
+    /// Convert packed 64-bit integers in a to packed 16-bit integers with truncation, and store the active results (those with their respective bit set in writemask k) to unaligned memory at base_addr.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_mask_cvtepi64_storeu_epi16)
+    #[method = mm256_mask_cvtepi64_storeu_epi16]
+    #[inline]
+    #[target_feature(enable = "avx512f,avx512vl")]
+    pub fn _mm256_mask_cvtepi64_storeu_epi16<T: Is64BitsUnaligned>(
+        base_addr: &mut T,
+        k: __mmask8,
+        a: __m256i,
+    ) {
+        unsafe { arch::_mm256_mask_cvtepi64_storeu_epi16(ptr::from_mut(base_addr).cast(), k, a) }
+    }
+
+    /// Convert packed 64-bit integers in a to packed 16-bit integers with truncation, and store the active results (those with their respective bit set in writemask k) to unaligned memory at base_addr.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_cvtepi64_storeu_epi16)
+    #[method = mm512_mask_cvtepi64_storeu_epi16]
+    #[inline]
+    #[target_feature(enable = "avx512f")]
+    pub fn _mm512_mask_cvtepi64_storeu_epi16<T: Is128BitsUnaligned>(
+        base_addr: &mut T,
+        k: __mmask8,
+        a: __m512i,
+    ) {
+        unsafe { arch::_mm512_mask_cvtepi64_storeu_epi16(ptr::from_mut(base_addr).cast(), k, a) }
+    }
+
+    ///Convert packed 64-bit integers in a to packed 32-bit integers with truncation, and store the active results (those with their respective bit set in writemask k) to unaligned memory at base_addr.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_mask_cvtepi64_storeu_epi32)
+    #[method = mm_mask_cvtepi64_storeu_epi32]
+    #[inline]
+    #[target_feature(enable = "avx512f,avx512vl")]
+    pub fn _mm_mask_cvtepi64_storeu_epi32<T: Is64BitsUnaligned>(
+        base_addr: &mut T,
+        k: __mmask8,
+        a: __m128i,
+    ) {
+        unsafe { arch::_mm_mask_cvtepi64_storeu_epi32(ptr::from_mut(base_addr).cast(), k, a) }
+    }
+
+    ///Convert packed 64-bit integers in a to packed 32-bit integers with truncation, and store the active results (those with their respective bit set in writemask k) to unaligned memory at base_addr.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_mask_cvtepi64_storeu_epi32)
+    #[method = mm256_mask_cvtepi64_storeu_epi32]
+    #[inline]
+    #[target_feature(enable = "avx512f,avx512vl")]
+    pub fn _mm256_mask_cvtepi64_storeu_epi32<T: Is128BitsUnaligned>(
+        base_addr: &mut T,
+        k: __mmask8,
+        a: __m256i,
+    ) {
+        unsafe { arch::_mm256_mask_cvtepi64_storeu_epi32(ptr::from_mut(base_addr).cast(), k, a) }
+    }
+
+    ///Convert packed 64-bit integers in a to packed 32-bit integers with truncation, and store the active results (those with their respective bit set in writemask k) to unaligned memory at base_addr.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_cvtepi64_storeu_epi32)
+    #[method = mm512_mask_cvtepi64_storeu_epi32]
+    #[inline]
+    #[target_feature(enable = "avx512f")]
+    pub fn _mm512_mask_cvtepi64_storeu_epi32<T: Is256BitsUnaligned>(
+        base_addr: &mut T,
+        k: __mmask8,
+        a: __m512i,
+    ) {
+        unsafe { arch::_mm512_mask_cvtepi64_storeu_epi32(ptr::from_mut(base_addr).cast(), k, a) }
+    }
+
+    /// Convert packed 64-bit integers in a to packed 8-bit integers with truncation, and store the active results (those with their respective bit set in writemask k) to unaligned memory at base_addr.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_mask_cvtepi64_storeu_epi8)
+    #[method = mm_mask_cvtepi64_storeu_epi8]
+    #[inline]
+    #[target_feature(enable = "avx512f,avx512vl")]
+    pub fn _mm_mask_cvtepi64_storeu_epi8<T: Is16BitsUnaligned>(
+        base_addr: &mut T,
+        k: __mmask8,
+        a: __m128i,
+    ) {
+        unsafe { arch::_mm_mask_cvtepi64_storeu_epi8(ptr::from_mut(base_addr).cast(), k, a) }
+    }
+
+    /// Convert packed 64-bit integers in a to packed 8-bit integers with truncation, and store the active results (those with their respective bit set in writemask k) to unaligned memory at base_addr.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_mask_cvtepi64_storeu_epi8)
+    #[method = mm256_mask_cvtepi64_storeu_epi8]
+    #[inline]
+    #[target_feature(enable = "avx512f,avx512vl")]
+    pub fn _mm256_mask_cvtepi64_storeu_epi8<T: Is32BitsUnaligned>(
+        base_addr: &mut T,
+        k: __mmask8,
+        a: __m256i,
+    ) {
+        unsafe { arch::_mm256_mask_cvtepi64_storeu_epi8(ptr::from_mut(base_addr).cast(), k, a) }
+    }
+
+    /// Convert packed 64-bit integers in a to packed 8-bit integers with truncation, and store the active results (those with their respective bit set in writemask k) to unaligned memory at base_addr.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_cvtepi64_storeu_epi8)
+    #[method = mm512_mask_cvtepi64_storeu_epi8]
+    #[inline]
+    #[target_feature(enable = "avx512f")]
+    pub fn _mm512_mask_cvtepi64_storeu_epi8<T: Is64BitsUnaligned>(
+        base_addr: &mut T,
+        k: __mmask8,
+        a: __m512i,
+    ) {
+        unsafe { arch::_mm512_mask_cvtepi64_storeu_epi8(ptr::from_mut(base_addr).cast(), k, a) }
+    }
+
+    /// Convert packed signed 32-bit integers in a to packed 16-bit integers with signed saturation, and store the active results (those with their respective bit set in writemask k) to unaligned memory at base_addr.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_mask_cvtsepi32_storeu_epi16)
+    #[method = mm_mask_cvtsepi32_storeu_epi16]
+    #[inline]
+    #[target_feature(enable = "avx512f,avx512vl")]
+    pub fn _mm_mask_cvtsepi32_storeu_epi16<T: Is64BitsUnaligned>(
+        base_addr: &mut T,
+        k: __mmask8,
+        a: __m128i,
+    ) {
+        unsafe { arch::_mm_mask_cvtsepi32_storeu_epi16(ptr::from_mut(base_addr).cast(), k, a) }
+    }
+
+    /// Convert packed signed 32-bit integers in a to packed 16-bit integers with signed saturation, and store the active results (those with their respective bit set in writemask k) to unaligned memory at base_addr.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_mask_cvtsepi32_storeu_epi16)
+    #[method = mm256_mask_cvtsepi32_storeu_epi16]
+    #[inline]
+    #[target_feature(enable = "avx512f,avx512vl")]
+    pub fn _mm256_mask_cvtsepi32_storeu_epi16<T: Is128BitsUnaligned>(
+        base_addr: &mut T,
+        k: __mmask8,
+        a: __m256i,
+    ) {
+        unsafe { arch::_mm256_mask_cvtsepi32_storeu_epi16(ptr::from_mut(base_addr).cast(), k, a) }
+    }
+
+    /// Convert packed signed 32-bit integers in a to packed 16-bit integers with signed saturation, and store the active results (those with their respective bit set in writemask k) to unaligned memory at base_addr.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_cvtsepi32_storeu_epi16)
+    #[method = mm512_mask_cvtsepi32_storeu_epi16]
+    #[inline]
+    #[target_feature(enable = "avx512f")]
+    pub fn _mm512_mask_cvtsepi32_storeu_epi16<T: Is256BitsUnaligned>(
+        base_addr: &mut T,
+        k: __mmask16,
+        a: __m512i,
+    ) {
+        unsafe { arch::_mm512_mask_cvtsepi32_storeu_epi16(ptr::from_mut(base_addr).cast(), k, a) }
+    }
+
+    /// Convert packed signed 32-bit integers in a to packed 8-bit integers with signed saturation, and store the active results (those with their respective bit set in writemask k) to unaligned memory at base_addr.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_mask_cvtsepi32_storeu_epi8)
+    #[method = mm_mask_cvtsepi32_storeu_epi8]
+    #[inline]
+    #[target_feature(enable = "avx512f,avx512vl")]
+    pub fn _mm_mask_cvtsepi32_storeu_epi8<T: Is32BitsUnaligned>(
+        base_addr: &mut T,
+        k: __mmask8,
+        a: __m128i,
+    ) {
+        unsafe { arch::_mm_mask_cvtsepi32_storeu_epi8(ptr::from_mut(base_addr).cast(), k, a) }
+    }
+
+    /// Convert packed signed 32-bit integers in a to packed 8-bit integers with signed saturation, and store the active results (those with their respective bit set in writemask k) to unaligned memory at base_addr.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_mask_cvtsepi32_storeu_epi8)
+    #[method = mm256_mask_cvtsepi32_storeu_epi8]
+    #[inline]
+    #[target_feature(enable = "avx512f,avx512vl")]
+    pub fn _mm256_mask_cvtsepi32_storeu_epi8<T: Is64BitsUnaligned>(
+        base_addr: &mut T,
+        k: __mmask8,
+        a: __m256i,
+    ) {
+        unsafe { arch::_mm256_mask_cvtsepi32_storeu_epi8(ptr::from_mut(base_addr).cast(), k, a) }
+    }
+
+    /// Convert packed signed 32-bit integers in a to packed 8-bit integers with signed saturation, and store the active results (those with their respective bit set in writemask k) to unaligned memory at base_addr.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_cvtsepi32_storeu_epi8)
+    #[method = mm512_mask_cvtsepi32_storeu_epi8]
+    #[inline]
+    #[target_feature(enable = "avx512f")]
+    pub fn _mm512_mask_cvtsepi32_storeu_epi8<T: Is128BitsUnaligned>(
+        base_addr: &mut T,
+        k: __mmask16,
+        a: __m512i,
+    ) {
+        unsafe { arch::_mm512_mask_cvtsepi32_storeu_epi8(ptr::from_mut(base_addr).cast(), k, a) }
+    }
+
+    /// Convert packed signed 64-bit integers in a to packed 16-bit integers with signed saturation, and store the active results (those with their respective bit set in writemask k) to unaligned memory at base_addr.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_mask_cvtsepi64_storeu_epi16)
+    #[method = mm_mask_cvtsepi64_storeu_epi16]
+    #[inline]
+    #[target_feature(enable = "avx512f,avx512vl")]
+    pub fn _mm_mask_cvtsepi64_storeu_epi16<T: Is32BitsUnaligned>(
+        base_addr: &mut T,
+        k: __mmask8,
+        a: __m128i,
+    ) {
+        unsafe { arch::_mm_mask_cvtsepi64_storeu_epi16(ptr::from_mut(base_addr).cast(), k, a) }
+    }
+
+    /// Convert packed signed 64-bit integers in a to packed 16-bit integers with signed saturation, and store the active results (those with their respective bit set in writemask k) to unaligned memory at base_addr.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_mask_cvtsepi64_storeu_epi16)
+    #[method = mm256_mask_cvtsepi64_storeu_epi16]
+    #[inline]
+    #[target_feature(enable = "avx512f,avx512vl")]
+    pub fn _mm256_mask_cvtsepi64_storeu_epi16<T: Is64BitsUnaligned>(
+        base_addr: &mut T,
+        k: __mmask8,
+        a: __m256i,
+    ) {
+        unsafe { arch::_mm256_mask_cvtsepi64_storeu_epi16(ptr::from_mut(base_addr).cast(), k, a) }
+    }
+
+    /// Convert packed signed 64-bit integers in a to packed 16-bit integers with signed saturation, and store the active results (those with their respective bit set in writemask k) to unaligned memory at base_addr.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_cvtsepi64_storeu_epi16)
+    #[method = mm512_mask_cvtsepi64_storeu_epi16]
+    #[inline]
+    #[target_feature(enable = "avx512f")]
+    pub fn _mm512_mask_cvtsepi64_storeu_epi16<T: Is128BitsUnaligned>(
+        base_addr: &mut T,
+        k: __mmask8,
+        a: __m512i,
+    ) {
+        unsafe { arch::_mm512_mask_cvtsepi64_storeu_epi16(ptr::from_mut(base_addr).cast(), k, a) }
+    }
+
+    /// Convert packed signed 64-bit integers in a to packed 32-bit integers with signed saturation, and store the active results (those with their respective bit set in writemask k) to unaligned memory at base_addr.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_mask_cvtsepi64_storeu_epi32)
+    #[method = mm_mask_cvtsepi64_storeu_epi32]
+    #[inline]
+    #[target_feature(enable = "avx512f,avx512vl")]
+    pub fn _mm_mask_cvtsepi64_storeu_epi32<T: Is64BitsUnaligned>(
+        base_addr: &mut T,
+        k: __mmask8,
+        a: __m128i,
+    ) {
+        unsafe { arch::_mm_mask_cvtsepi64_storeu_epi32(ptr::from_mut(base_addr).cast(), k, a) }
+    }
+
+    /// Convert packed signed 64-bit integers in a to packed 32-bit integers with signed saturation, and store the active results (those with their respective bit set in writemask k) to unaligned memory at base_addr.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_mask_cvtsepi64_storeu_epi32)
+    #[method = mm256_mask_cvtsepi64_storeu_epi32]
+    #[inline]
+    #[target_feature(enable = "avx512f,avx512vl")]
+    pub fn _mm256_mask_cvtsepi64_storeu_epi32<T: Is128BitsUnaligned>(
+        base_addr: &mut T,
+        k: __mmask8,
+        a: __m256i,
+    ) {
+        unsafe { arch::_mm256_mask_cvtsepi64_storeu_epi32(ptr::from_mut(base_addr).cast(), k, a) }
+    }
+
+    /// Convert packed signed 64-bit integers in a to packed 32-bit integers with signed saturation, and store the active results (those with their respective bit set in writemask k) to unaligned memory at base_addr.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_cvtsepi64_storeu_epi32)
+    #[method = mm512_mask_cvtsepi64_storeu_epi32]
+    #[inline]
+    #[target_feature(enable = "avx512f")]
+    pub fn _mm512_mask_cvtsepi64_storeu_epi32<T: Is256BitsUnaligned>(
+        base_addr: &mut T,
+        k: __mmask8,
+        a: __m512i,
+    ) {
+        unsafe { arch::_mm512_mask_cvtsepi64_storeu_epi32(ptr::from_mut(base_addr).cast(), k, a) }
+    }
+
+    /// Convert packed signed 64-bit integers in a to packed 8-bit integers with signed saturation, and store the active results (those with their respective bit set in writemask k) to unaligned memory at base_addr.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_mask_cvtsepi64_storeu_epi8)
+    #[method = mm_mask_cvtsepi64_storeu_epi8]
+    #[inline]
+    #[target_feature(enable = "avx512f,avx512vl")]
+    pub fn _mm_mask_cvtsepi64_storeu_epi8<T: Is16BitsUnaligned>(
+        base_addr: &mut T,
+        k: __mmask8,
+        a: __m128i,
+    ) {
+        unsafe { arch::_mm_mask_cvtsepi64_storeu_epi8(ptr::from_mut(base_addr).cast(), k, a) }
+    }
+
+    /// Convert packed signed 64-bit integers in a to packed 8-bit integers with signed saturation, and store the active results (those with their respective bit set in writemask k) to unaligned memory at base_addr.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_mask_cvtsepi64_storeu_epi8)
+    #[method = mm256_mask_cvtsepi64_storeu_epi8]
+    #[inline]
+    #[target_feature(enable = "avx512f,avx512vl")]
+    pub fn _mm256_mask_cvtsepi64_storeu_epi8<T: Is32BitsUnaligned>(
+        base_addr: &mut T,
+        k: __mmask8,
+        a: __m256i,
+    ) {
+        unsafe { arch::_mm256_mask_cvtsepi64_storeu_epi8(ptr::from_mut(base_addr).cast(), k, a) }
+    }
+
+    /// Convert packed signed 64-bit integers in a to packed 8-bit integers with signed saturation, and store the active results (those with their respective bit set in writemask k) to unaligned memory at base_addr.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_cvtsepi64_storeu_epi8)
+    #[method = mm512_mask_cvtsepi64_storeu_epi8]
+    #[inline]
+    #[target_feature(enable = "avx512f")]
+    pub fn _mm512_mask_cvtsepi64_storeu_epi8<T: Is64BitsUnaligned>(
+        base_addr: &mut T,
+        k: __mmask8,
+        a: __m512i,
+    ) {
+        unsafe { arch::_mm512_mask_cvtsepi64_storeu_epi8(ptr::from_mut(base_addr).cast(), k, a) }
+    }
+
+    /// Convert packed unsigned 32-bit integers in a to packed unsigned 16-bit integers with unsigned saturation, and store the active results (those with their respective bit set in writemask k) to unaligned memory at base_addr.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_mask_cvtusepi32_storeu_epi16)
+    #[method = mm_mask_cvtusepi32_storeu_epi16]
+    #[inline]
+    #[target_feature(enable = "avx512f,avx512vl")]
+    pub fn _mm_mask_cvtusepi32_storeu_epi16<T: Is64BitsUnaligned>(
+        base_addr: &mut T,
+        k: __mmask8,
+        a: __m128i,
+    ) {
+        unsafe { arch::_mm_mask_cvtusepi32_storeu_epi16(ptr::from_mut(base_addr).cast(), k, a) }
+    }
+
+    /// Convert packed unsigned 32-bit integers in a to packed unsigned 16-bit integers with unsigned saturation, and store the active results (those with their respective bit set in writemask k) to unaligned memory at base_addr.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_mask_cvtusepi32_storeu_epi16)
+    #[method = mm256_mask_cvtusepi32_storeu_epi16]
+    #[inline]
+    #[target_feature(enable = "avx512f,avx512vl")]
+    pub fn _mm256_mask_cvtusepi32_storeu_epi16<T: Is128BitsUnaligned>(
+        base_addr: &mut T,
+        k: __mmask8,
+        a: __m256i,
+    ) {
+        unsafe { arch::_mm256_mask_cvtusepi32_storeu_epi16(ptr::from_mut(base_addr).cast(), k, a) }
+    }
+
+    /// Convert packed unsigned 32-bit integers in a to packed 16-bit integers with unsigned saturation, and store the active results (those with their respective bit set in writemask k) to unaligned memory at base_addr.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_cvtusepi32_storeu_epi16)
+    #[method = mm512_mask_cvtusepi32_storeu_epi16]
+    #[inline]
+    #[target_feature(enable = "avx512f")]
+    pub fn _mm512_mask_cvtusepi32_storeu_epi16<T: Is256BitsUnaligned>(
+        base_addr: &mut T,
+        k: __mmask16,
+        a: __m512i,
+    ) {
+        unsafe { arch::_mm512_mask_cvtusepi32_storeu_epi16(ptr::from_mut(base_addr).cast(), k, a) }
+    }
+
+    /// Convert packed unsigned 32-bit integers in a to packed 8-bit integers with unsigned saturation, and store the active results (those with their respective bit set in writemask k) to unaligned memory at base_addr.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_mask_cvtusepi32_storeu_epi8)
+    #[method = mm_mask_cvtusepi32_storeu_epi8]
+    #[inline]
+    #[target_feature(enable = "avx512f,avx512vl")]
+    pub fn _mm_mask_cvtusepi32_storeu_epi8<T: Is32BitsUnaligned>(
+        base_addr: &mut T,
+        k: __mmask8,
+        a: __m128i,
+    ) {
+        unsafe { arch::_mm_mask_cvtusepi32_storeu_epi8(ptr::from_mut(base_addr).cast(), k, a) }
+    }
+
+    /// Convert packed unsigned 32-bit integers in a to packed 8-bit integers with unsigned saturation, and store the active results (those with their respective bit set in writemask k) to unaligned memory at base_addr.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_mask_cvtusepi32_storeu_epi8)
+    #[method = mm256_mask_cvtusepi32_storeu_epi8]
+    #[inline]
+    #[target_feature(enable = "avx512f,avx512vl")]
+    pub fn _mm256_mask_cvtusepi32_storeu_epi8<T: Is64BitsUnaligned>(
+        base_addr: &mut T,
+        k: __mmask8,
+        a: __m256i,
+    ) {
+        unsafe { arch::_mm256_mask_cvtusepi32_storeu_epi8(ptr::from_mut(base_addr).cast(), k, a) }
+    }
+
+    /// Convert packed unsigned 32-bit integers in a to packed 8-bit integers with unsigned saturation, and store the active results (those with their respective bit set in writemask k) to unaligned memory at base_addr.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_cvtusepi32_storeu_epi8)
+    #[method = mm512_mask_cvtusepi32_storeu_epi8]
+    #[inline]
+    #[target_feature(enable = "avx512f")]
+    pub fn _mm512_mask_cvtusepi32_storeu_epi8<T: Is128BitsUnaligned>(
+        base_addr: &mut T,
+        k: __mmask16,
+        a: __m512i,
+    ) {
+        unsafe { arch::_mm512_mask_cvtusepi32_storeu_epi8(ptr::from_mut(base_addr).cast(), k, a) }
+    }
+
+    /// Convert packed unsigned 64-bit integers in a to packed 16-bit integers with unsigned saturation, and store the active results (those with their respective bit set in writemask k) to unaligned memory at base_addr.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_mask_cvtusepi64_storeu_epi16)
+    #[method = mm_mask_cvtusepi64_storeu_epi16]
+    #[inline]
+    #[target_feature(enable = "avx512f,avx512vl")]
+    pub fn _mm_mask_cvtusepi64_storeu_epi16<T: Is32BitsUnaligned>(
+        base_addr: &mut T,
+        k: __mmask8,
+        a: __m128i,
+    ) {
+        unsafe { arch::_mm_mask_cvtusepi64_storeu_epi16(ptr::from_mut(base_addr).cast(), k, a) }
+    }
+
+    /// Convert packed unsigned 64-bit integers in a to packed 16-bit integers with unsigned saturation, and store the active results (those with their respective bit set in writemask k) to unaligned memory at base_addr.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_mask_cvtusepi64_storeu_epi16)
+    #[method = mm256_mask_cvtusepi64_storeu_epi16]
+    #[inline]
+    #[target_feature(enable = "avx512f,avx512vl")]
+    pub fn _mm256_mask_cvtusepi64_storeu_epi16<T: Is64BitsUnaligned>(
+        base_addr: &mut T,
+        k: __mmask8,
+        a: __m256i,
+    ) {
+        unsafe { arch::_mm256_mask_cvtusepi64_storeu_epi16(ptr::from_mut(base_addr).cast(), k, a) }
+    }
+
+    /// Convert packed unsigned 64-bit integers in a to packed 16-bit integers with unsigned saturation, and store the active results (those with their respective bit set in writemask k) to unaligned memory at base_addr.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_cvtusepi64_storeu_epi16)
+    #[method = mm512_mask_cvtusepi64_storeu_epi16]
+    #[inline]
+    #[target_feature(enable = "avx512f")]
+    pub fn _mm512_mask_cvtusepi64_storeu_epi16<T: Is128BitsUnaligned>(
+        base_addr: &mut T,
+        k: __mmask8,
+        a: __m512i,
+    ) {
+        unsafe { arch::_mm512_mask_cvtusepi64_storeu_epi16(ptr::from_mut(base_addr).cast(), k, a) }
+    }
+
+    /// Convert packed unsigned 64-bit integers in a to packed 32-bit integers with unsigned saturation, and store the active results (those with their respective bit set in writemask k) to unaligned memory at base_addr.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_mask_cvtusepi64_storeu_epi32)
+    #[method = mm_mask_cvtusepi64_storeu_epi32]
+    #[inline]
+    #[target_feature(enable = "avx512f,avx512vl")]
+    pub fn _mm_mask_cvtusepi64_storeu_epi32<T: Is64BitsUnaligned>(
+        base_addr: &mut T,
+        k: __mmask8,
+        a: __m128i,
+    ) {
+        unsafe { arch::_mm_mask_cvtusepi64_storeu_epi32(ptr::from_mut(base_addr).cast(), k, a) }
+    }
+
+    /// Convert packed unsigned 64-bit integers in a to packed 32-bit integers with unsigned saturation, and store the active results (those with their respective bit set in writemask k) to unaligned memory at base_addr.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_mask_cvtusepi64_storeu_epi32)
+    #[method = mm256_mask_cvtusepi64_storeu_epi32]
+    #[inline]
+    #[target_feature(enable = "avx512f,avx512vl")]
+    pub fn _mm256_mask_cvtusepi64_storeu_epi32<T: Is128BitsUnaligned>(
+        base_addr: &mut T,
+        k: __mmask8,
+        a: __m256i,
+    ) {
+        unsafe { arch::_mm256_mask_cvtusepi64_storeu_epi32(ptr::from_mut(base_addr).cast(), k, a) }
+    }
+
+    /// Convert packed unsigned 64-bit integers in a to packed 32-bit integers with unsigned saturation, and store the active results (those with their respective bit set in writemask k) to unaligned memory at base_addr.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_cvtusepi64_storeu_epi32)
+    #[method = mm512_mask_cvtusepi64_storeu_epi32]
+    #[inline]
+    #[target_feature(enable = "avx512f")]
+    pub fn _mm512_mask_cvtusepi64_storeu_epi32<T: Is256BitsUnaligned>(
+        base_addr: &mut T,
+        k: __mmask8,
+        a: __m512i,
+    ) {
+        unsafe { arch::_mm512_mask_cvtusepi64_storeu_epi32(ptr::from_mut(base_addr).cast(), k, a) }
+    }
+
+    /// Convert packed unsigned 64-bit integers in a to packed 8-bit integers with unsigned saturation, and store the active results (those with their respective bit set in writemask k) to unaligned memory at base_addr.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_mask_cvtusepi64_storeu_epi8)
+    #[method = mm_mask_cvtusepi64_storeu_epi8]
+    #[inline]
+    #[target_feature(enable = "avx512f,avx512vl")]
+    pub fn _mm_mask_cvtusepi64_storeu_epi8<T: Is16BitsUnaligned>(
+        base_addr: &mut T,
+        k: __mmask8,
+        a: __m128i,
+    ) {
+        unsafe { arch::_mm_mask_cvtusepi64_storeu_epi8(ptr::from_mut(base_addr).cast(), k, a) }
+    }
+
+    /// Convert packed unsigned 64-bit integers in a to packed 8-bit integers with unsigned saturation, and store the active results (those with their respective bit set in writemask k) to unaligned memory at base_addr.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_mask_cvtusepi64_storeu_epi8)
+    #[method = mm256_mask_cvtusepi64_storeu_epi8]
+    #[inline]
+    #[target_feature(enable = "avx512f,avx512vl")]
+    pub fn _mm256_mask_cvtusepi64_storeu_epi8<T: Is32BitsUnaligned>(
+        base_addr: &mut T,
+        k: __mmask8,
+        a: __m256i,
+    ) {
+        unsafe { arch::_mm256_mask_cvtusepi64_storeu_epi8(ptr::from_mut(base_addr).cast(), k, a) }
+    }
+
+    /// Convert packed unsigned 64-bit integers in a to packed 8-bit integers with unsigned saturation, and store the active results (those with their respective bit set in writemask k) to unaligned memory at base_addr.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_cvtusepi64_storeu_epi8)
+    #[method = mm512_mask_cvtusepi64_storeu_epi8]
+    #[inline]
+    #[target_feature(enable = "avx512f")]
+    pub fn _mm512_mask_cvtusepi64_storeu_epi8<T: Is64BitsUnaligned>(
+        base_addr: &mut T,
+        k: __mmask8,
+        a: __m512i,
+    ) {
+        unsafe { arch::_mm512_mask_cvtusepi64_storeu_epi8(ptr::from_mut(base_addr).cast(), k, a) }
+    }
+
+    /// Store packed 32-bit integers from a into memory using writemask k.
+    /// mem_addr does not need to be aligned on any particular boundary.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_mask_storeu_epi32)
+    #[method = mm_mask_storeu_epi32]
+    #[inline]
+    #[target_feature(enable = "avx512f,avx512vl")]
+    pub fn _mm_mask_storeu_epi32<T: Is128BitsUnaligned>(mem_addr: &mut T, k: __mmask8, a: __m128i) {
+        unsafe { arch::_mm_mask_storeu_epi32(ptr::from_mut(mem_addr).cast(), k, a) }
+    }
+
+    /// Store 128-bits (composed of 4 packed 32-bit integers) from a into memory. mem_addr does not need to be aligned on any particular boundary.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_storeu_epi32)
+    #[method = mm_storeu_epi32]
+    #[inline]
+    #[target_feature(enable = "avx512f,avx512vl")]
+    pub fn _mm_storeu_epi32<T: Is128BitsUnaligned>(mem_addr: &mut T, a: __m128i) {
+        unsafe { arch::_mm_storeu_epi32(ptr::from_mut(mem_addr).cast(), a) }
+    }
+
+    /// Store packed 32-bit integers from a into memory using writemask k.
+    /// mem_addr does not need to be aligned on any particular boundary.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_mask_storeu_epi32)
+    #[method = mm256_mask_storeu_epi32]
+    #[inline]
+    #[target_feature(enable = "avx512f,avx512vl")]
+    pub fn _mm256_mask_storeu_epi32<T: Is256BitsUnaligned>(mem_addr: &mut T, k: __mmask8, a: __m256i) {
+        unsafe { arch::_mm256_mask_storeu_epi32(ptr::from_mut(mem_addr).cast(), k, a) }
+    }
+
+    /// Store 256-bits (composed of 8 packed 32-bit integers) from a into memory. mem_addr does not need to be aligned on any particular boundary.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_storeu_epi32)
+    #[method = mm256_storeu_epi32]
+    #[inline]
+    #[target_feature(enable = "avx512f,avx512vl")]
+    pub fn _mm256_storeu_epi32<T: Is256BitsUnaligned>(mem_addr: &mut T, a: __m256i) {
+        unsafe { arch::_mm256_storeu_epi32(ptr::from_mut(mem_addr).cast(), a) }
+    }
+
+    /// Store packed 32-bit integers from a into memory using writemask k.
+    /// mem_addr does not need to be aligned on any particular boundary.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_storeu_epi32)
+    #[method = mm512_mask_storeu_epi32]
+    #[inline]
+    #[target_feature(enable = "avx512f")]
+    pub fn _mm512_mask_storeu_epi32<T: Is512BitsUnaligned>(mem_addr: &mut T, k: __mmask16, a: __m512i) {
+        unsafe { arch::_mm512_mask_storeu_epi32(ptr::from_mut(mem_addr).cast(), k, a) }
+    }
+
+    /// Store 512-bits (composed of 16 packed 32-bit integers) from a into memory. mem_addr does not need to be aligned on any particular boundary.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_storeu_epi32)
+    #[method = mm512_storeu_epi32]
+    #[inline]
+    #[target_feature(enable = "avx512f")]
+    pub fn _mm512_storeu_epi32<T: Is512BitsUnaligned>(mem_addr: &mut T, a: __m512i) {
+        unsafe { arch::_mm512_storeu_epi32(ptr::from_mut(mem_addr).cast(), a) }
+    }
+
+    /// Store packed 64-bit integers from a into memory using writemask k.
+    /// mem_addr does not need to be aligned on any particular boundary.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_mask_storeu_epi64)
+    #[method = mm_mask_storeu_epi64]
+    #[inline]
+    #[target_feature(enable = "avx512f,avx512vl")]
+    pub fn _mm_mask_storeu_epi64<T: Is128BitsUnaligned>(mem_addr: &mut T, k: __mmask8, a: __m128i) {
+        unsafe { arch::_mm_mask_storeu_epi64(ptr::from_mut(mem_addr).cast(), k, a) }
+    }
+
+    /// Store 128-bits (composed of 2 packed 64-bit integers) from a into memory. mem_addr does not need to be aligned on any particular boundary.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_storeu_epi64)
+    #[method = mm_storeu_epi64]
+    #[inline]
+    #[target_feature(enable = "avx512f,avx512vl")]
+    pub fn _mm_storeu_epi64<T: Is128BitsUnaligned>(mem_addr: &mut T, a: __m128i) {
+        unsafe { arch::_mm_storeu_epi64(ptr::from_mut(mem_addr).cast(), a) }
+    }
+
+    /// Store packed 64-bit integers from a into memory using writemask k.
+    /// mem_addr does not need to be aligned on any particular boundary.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_mask_storeu_epi64)
+    #[method = mm256_mask_storeu_epi64]
+    #[inline]
+    #[target_feature(enable = "avx512f,avx512vl")]
+    pub fn _mm256_mask_storeu_epi64<T: Is256BitsUnaligned>(mem_addr: &mut T, k: __mmask8, a: __m256i) {
+        unsafe { arch::_mm256_mask_storeu_epi64(ptr::from_mut(mem_addr).cast(), k, a) }
+    }
+
+    /// Store 256-bits (composed of 4 packed 64-bit integers) from a into memory. mem_addr does not need to be aligned on any particular boundary.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_storeu_epi64)
+    #[method = mm256_storeu_epi64]
+    #[inline]
+    #[target_feature(enable = "avx512f,avx512vl")]
+    pub fn _mm256_storeu_epi64<T: Is256BitsUnaligned>(mem_addr: &mut T, a: __m256i) {
+        unsafe { arch::_mm256_storeu_epi64(ptr::from_mut(mem_addr).cast(), a) }
+    }
+
+    /// Store packed 64-bit integers from a into memory using writemask k.
+    /// mem_addr does not need to be aligned on any particular boundary.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_storeu_epi64)
+    #[method = mm512_mask_storeu_epi64]
+    #[inline]
+    #[target_feature(enable = "avx512f")]
+    pub fn _mm512_mask_storeu_epi64<T: Is512BitsUnaligned>(mem_addr: &mut T, k: __mmask8, a: __m512i) {
+        unsafe { arch::_mm512_mask_storeu_epi64(ptr::from_mut(mem_addr).cast(), k, a) }
+    }
+
+    /// Store 512-bits (composed of 8 packed 64-bit integers) from a into memory. mem_addr does not need to be aligned on any particular boundary.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_storeu_epi64)
+    #[method = mm512_storeu_epi64]
+    #[inline]
+    #[target_feature(enable = "avx512f")]
+    pub fn _mm512_storeu_epi64<T: Is512BitsUnaligned>(mem_addr: &mut T, a: __m512i) {
+        unsafe { arch::_mm512_storeu_epi64(ptr::from_mut(mem_addr).cast(), a) }
+    }
+
+    /// Store packed double-precision (64-bit) floating-point elements from a into memory using writemask k.
+    /// mem_addr does not need to be aligned on any particular boundary.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_mask_storeu_pd)
+    #[method = mm_mask_storeu_pd]
+    #[inline]
+    #[target_feature(enable = "avx512f,avx512vl")]
+    pub fn _mm_mask_storeu_pd(mem_addr: &mut [f64; 2], k: __mmask8, a: __m128d) {
+        unsafe { arch::_mm_mask_storeu_pd(mem_addr.as_mut_ptr().cast(), k, a) }
+    }
+
+    /// Store packed double-precision (64-bit) floating-point elements from a into memory using writemask k.
+    /// mem_addr does not need to be aligned on any particular boundary.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_mask_storeu_pd)
+    #[method = mm256_mask_storeu_pd]
+    #[inline]
+    #[target_feature(enable = "avx512f,avx512vl")]
+    pub fn _mm256_mask_storeu_pd(mem_addr: &mut [f64; 4], k: __mmask8, a: __m256d) {
+        unsafe { arch::_mm256_mask_storeu_pd(mem_addr.as_mut_ptr().cast(), k, a) }
+    }
+
+    /// Store packed double-precision (64-bit) floating-point elements from a into memory using writemask k.
+    /// mem_addr does not need to be aligned on any particular boundary.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_storeu_pd)
+    #[method = mm512_mask_storeu_pd]
+    #[inline]
+    #[target_feature(enable = "avx512f")]
+    pub fn _mm512_mask_storeu_pd(mem_addr: &mut [f64; 8], k: __mmask8, a: __m512d) {
+        unsafe { arch::_mm512_mask_storeu_pd(mem_addr.as_mut_ptr().cast(), k, a) }
+    }
+
+    /// Stores 512-bits (composed of 8 packed double-precision (64-bit)
+    /// floating-point elements) from `a` into memory.
+    /// `mem_addr` does not need to be aligned on any particular boundary.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_storeu_pd)
+    #[method = mm512_storeu_pd]
+    #[inline]
+    #[target_feature(enable = "avx512f")]
+    pub fn _mm512_storeu_pd(mem_addr: &mut [f64; 8], a: __m512d) {
+        unsafe { arch::_mm512_storeu_pd(mem_addr.as_mut_ptr().cast(), a) }
+    }
+
+    /// Store packed single-precision (32-bit) floating-point elements from a into memory using writemask k.
+    /// mem_addr does not need to be aligned on any particular boundary.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_mask_storeu_ps)
+    #[method = mm_mask_storeu_ps]
+    #[inline]
+    #[target_feature(enable = "avx512f,avx512vl")]
+    pub fn _mm_mask_storeu_ps(mem_addr: &mut [f32; 4], k: __mmask8, a: __m128) {
+        unsafe { arch::_mm_mask_storeu_ps(mem_addr.as_mut_ptr().cast(), k, a) }
+    }
+
+    /// Store packed single-precision (32-bit) floating-point elements from a into memory using writemask k.
+    /// mem_addr does not need to be aligned on any particular boundary.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_mask_storeu_ps)
+    #[method = mm256_mask_storeu_ps]
+    #[inline]
+    #[target_feature(enable = "avx512f,avx512vl")]
+    pub fn _mm256_mask_storeu_ps(mem_addr: &mut [f32; 8], k: __mmask8, a: __m256) {
+        unsafe { arch::_mm256_mask_storeu_ps(mem_addr.as_mut_ptr().cast(), k, a) }
+    }
+
+    /// Store packed single-precision (32-bit) floating-point elements from a into memory using writemask k.
+    /// mem_addr does not need to be aligned on any particular boundary.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_storeu_ps)
+    #[method = mm512_mask_storeu_ps]
+    #[inline]
+    #[target_feature(enable = "avx512f")]
+    pub fn _mm512_mask_storeu_ps(mem_addr: &mut [f32; 16], k: __mmask16, a: __m512) {
+        unsafe { arch::_mm512_mask_storeu_ps(mem_addr.as_mut_ptr().cast(), k, a) }
+    }
+
+    /// Stores 512-bits (composed of 16 packed single-precision (32-bit)
+    /// floating-point elements) from `a` into memory.
+    /// `mem_addr` does not need to be aligned on any particular boundary.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_storeu_ps)
+    #[method = mm512_storeu_ps]
+    #[inline]
+    #[target_feature(enable = "avx512f")]
+    pub fn _mm512_storeu_ps(mem_addr: &mut [f32; 16], a: __m512) {
+        unsafe { arch::_mm512_storeu_ps(mem_addr.as_mut_ptr().cast(), a) }
+    }
+
+    /// Store 512-bits of integer data from a into memory. mem_addr does not need to be aligned on any particular boundary.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_storeu_si512)
+    #[method = mm512_storeu_si512]
+    #[inline]
+    #[target_feature(enable = "avx512f")]
+    pub fn _mm512_storeu_si512<T: Is512BitsUnaligned>(mem_addr: &mut T, a: __m512i) {
+        unsafe { arch::_mm512_storeu_si512(ptr::from_mut(mem_addr).cast(), a) }
+    }
 }
 
 #[cfg(test)]
@@ -1579,61 +1715,63 @@ mod tests {
 
     use core::hint::black_box;
 
+    use crate::Register;
+
     // Fail-safe for tests being run on a CPU that doesn't support the instruction set
     static CPU_HAS_AVX512VL: std::sync::LazyLock<bool> =
         std::sync::LazyLock::new(|| is_x86_feature_detected!("avx512vl"));
 
     fn assert_eq_m128(a: __m128, b: __m128) {
-        let a: [u8; 16] = unsafe { core::mem::transmute(a) };
-        let b: [u8; 16] = unsafe { core::mem::transmute(b) };
+        let a: [u8; 16] = a.to_array();
+        let b: [u8; 16] = b.to_array();
         assert_eq!(a, b)
     }
 
     fn assert_eq_m128d(a: __m128d, b: __m128d) {
-        let a: [u8; 16] = unsafe { core::mem::transmute(a) };
-        let b: [u8; 16] = unsafe { core::mem::transmute(b) };
+        let a: [u8; 16] = a.to_array();
+        let b: [u8; 16] = b.to_array();
         assert_eq!(a, b)
     }
 
     fn assert_eq_m128i(a: __m128i, b: __m128i) {
-        let a: [u8; 16] = unsafe { core::mem::transmute(a) };
-        let b: [u8; 16] = unsafe { core::mem::transmute(b) };
+        let a: [u8; 16] = a.to_array();
+        let b: [u8; 16] = b.to_array();
         assert_eq!(a, b)
     }
 
     fn assert_eq_m256(a: __m256, b: __m256) {
-        let a: [u8; 32] = unsafe { core::mem::transmute(a) };
-        let b: [u8; 32] = unsafe { core::mem::transmute(b) };
+        let a: [u8; 32] = a.to_array();
+        let b: [u8; 32] = b.to_array();
         assert_eq!(a, b)
     }
 
     fn assert_eq_m256d(a: __m256d, b: __m256d) {
-        let a: [u8; 32] = unsafe { core::mem::transmute(a) };
-        let b: [u8; 32] = unsafe { core::mem::transmute(b) };
+        let a: [u8; 32] = a.to_array();
+        let b: [u8; 32] = b.to_array();
         assert_eq!(a, b)
     }
 
     fn assert_eq_m256i(a: __m256i, b: __m256i) {
-        let a: [u8; 32] = unsafe { core::mem::transmute(a) };
-        let b: [u8; 32] = unsafe { core::mem::transmute(b) };
+        let a: [u8; 32] = a.to_array();
+        let b: [u8; 32] = b.to_array();
         assert_eq!(a, b)
     }
 
     fn assert_eq_m512(a: __m512, b: __m512) {
-        let a: [u8; 64] = unsafe { core::mem::transmute(a) };
-        let b: [u8; 64] = unsafe { core::mem::transmute(b) };
+        let a: [u8; 64] = a.to_array();
+        let b: [u8; 64] = b.to_array();
         assert_eq!(a, b)
     }
 
     fn assert_eq_m512d(a: __m512d, b: __m512d) {
-        let a: [u8; 64] = unsafe { core::mem::transmute(a) };
-        let b: [u8; 64] = unsafe { core::mem::transmute(b) };
+        let a: [u8; 64] = a.to_array();
+        let b: [u8; 64] = b.to_array();
         assert_eq!(a, b)
     }
 
     fn assert_eq_m512i(a: __m512i, b: __m512i) {
-        let a: [u8; 64] = unsafe { core::mem::transmute(a) };
-        let b: [u8; 64] = unsafe { core::mem::transmute(b) };
+        let a: [u8; 64] = a.to_array();
+        let b: [u8; 64] = b.to_array();
         assert_eq!(a, b)
     }
 
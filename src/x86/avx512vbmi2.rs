@@ -9,224 +9,246 @@ use core::arch::x86_64::{
 use core::ptr;
 
 #[cfg(target_arch = "x86")]
-use crate::x86::{Is128BitsUnaligned, Is256BitsUnaligned, Is512BitsUnaligned};
+use crate::x86::{
+    Is128BitsReadable, Is128BitsUnaligned, Is256BitsReadable, Is256BitsUnaligned,
+    Is512BitsReadable, Is512BitsUnaligned,
+};
 #[cfg(target_arch = "x86_64")]
-use crate::x86_64::{Is128BitsUnaligned, Is256BitsUnaligned, Is512BitsUnaligned};
-
-/// Load contiguous active 16-bit integers from unaligned memory at mem_addr (those with their respective bit set in mask k), and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_mask_expandloadu_epi16)
-#[inline]
-#[target_feature(enable = "avx512vbmi2,avx512vl")]
-pub fn _mm_mask_expandloadu_epi16<T: Is128BitsUnaligned>(
-    src: __m128i,
-    k: __mmask8,
-    mem_addr: &T,
-) -> __m128i {
-    unsafe { arch::_mm_mask_expandloadu_epi16(src, k, ptr::from_ref(mem_addr).cast()) }
-}
+use crate::x86_64::{
+    Is128BitsReadable, Is128BitsUnaligned, Is256BitsReadable, Is256BitsUnaligned,
+    Is512BitsReadable, Is512BitsUnaligned,
+};
 
-/// Load contiguous active 16-bit integers from unaligned memory at mem_addr (those with their respective bit set in mask k), and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_maskz_expandloadu_epi16)
-#[inline]
-#[target_feature(enable = "avx512vbmi2,avx512vl")]
-pub fn _mm_maskz_expandloadu_epi16<T: Is128BitsUnaligned>(k: __mmask8, mem_addr: &T) -> __m128i {
-    _mm_mask_expandloadu_epi16(arch::_mm_setzero_si128(), k, mem_addr)
-}
+token_wrappers! {
+    impl super::token::Avx512Vbmi2;
+
+    /// Load contiguous active 16-bit integers from unaligned memory at mem_addr (those with their respective bit set in mask k), and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_mask_expandloadu_epi16)
+    #[method = mm_mask_expandloadu_epi16]
+    #[inline]
+    #[target_feature(enable = "avx512vbmi2,avx512vl")]
+    pub fn _mm_mask_expandloadu_epi16<T: Is128BitsReadable>(
+        src: __m128i,
+        k: __mmask8,
+        mem_addr: &T,
+    ) -> __m128i {
+        unsafe { arch::_mm_mask_expandloadu_epi16(src, k, ptr::from_ref(mem_addr).cast()) }
+    }
 
-/// Load contiguous active 16-bit integers from unaligned memory at mem_addr (those with their respective bit set in mask k), and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_mask_expandloadu_epi16)
-#[inline]
-#[target_feature(enable = "avx512vbmi2,avx512vl")]
-pub fn _mm256_mask_expandloadu_epi16<T: Is256BitsUnaligned>(
-    src: __m256i,
-    k: __mmask16,
-    mem_addr: &T,
-) -> __m256i {
-    unsafe { arch::_mm256_mask_expandloadu_epi16(src, k, ptr::from_ref(mem_addr).cast()) }
-}
+    /// Load contiguous active 16-bit integers from unaligned memory at mem_addr (those with their respective bit set in mask k), and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_maskz_expandloadu_epi16)
+    #[method = mm_maskz_expandloadu_epi16]
+    #[inline]
+    #[target_feature(enable = "avx512vbmi2,avx512vl")]
+    pub fn _mm_maskz_expandloadu_epi16<T: Is128BitsReadable>(k: __mmask8, mem_addr: &T) -> __m128i {
+        _mm_mask_expandloadu_epi16(arch::_mm_setzero_si128(), k, mem_addr)
+    }
 
-/// Load contiguous active 16-bit integers from unaligned memory at mem_addr (those with their respective bit set in mask k), and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_maskz_expandloadu_epi16)
-#[inline]
-#[target_feature(enable = "avx512vbmi2,avx512vl")]
-pub fn _mm256_maskz_expandloadu_epi16<T: Is256BitsUnaligned>(
-    k: __mmask16,
-    mem_addr: &T,
-) -> __m256i {
-    _mm256_mask_expandloadu_epi16(arch::_mm256_setzero_si256(), k, mem_addr)
-}
+    /// Load contiguous active 16-bit integers from unaligned memory at mem_addr (those with their respective bit set in mask k), and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_mask_expandloadu_epi16)
+    #[method = mm256_mask_expandloadu_epi16]
+    #[inline]
+    #[target_feature(enable = "avx512vbmi2,avx512vl")]
+    pub fn _mm256_mask_expandloadu_epi16<T: Is256BitsReadable>(
+        src: __m256i,
+        k: __mmask16,
+        mem_addr: &T,
+    ) -> __m256i {
+        unsafe { arch::_mm256_mask_expandloadu_epi16(src, k, ptr::from_ref(mem_addr).cast()) }
+    }
 
-/// Load contiguous active 16-bit integers from unaligned memory at mem_addr (those with their respective bit set in mask k), and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_expandloadu_epi16)
-#[inline]
-#[target_feature(enable = "avx512vbmi2")]
-pub fn _mm512_mask_expandloadu_epi16<T: Is512BitsUnaligned>(
-    src: __m512i,
-    k: __mmask32,
-    mem_addr: &T,
-) -> __m512i {
-    unsafe { arch::_mm512_mask_expandloadu_epi16(src, k, ptr::from_ref(mem_addr).cast()) }
-}
+    /// Load contiguous active 16-bit integers from unaligned memory at mem_addr (those with their respective bit set in mask k), and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_maskz_expandloadu_epi16)
+    #[method = mm256_maskz_expandloadu_epi16]
+    #[inline]
+    #[target_feature(enable = "avx512vbmi2,avx512vl")]
+    pub fn _mm256_maskz_expandloadu_epi16<T: Is256BitsReadable>(k: __mmask16, mem_addr: &T) -> __m256i {
+        _mm256_mask_expandloadu_epi16(arch::_mm256_setzero_si256(), k, mem_addr)
+    }
 
-/// Load contiguous active 16-bit integers from unaligned memory at mem_addr (those with their respective bit set in mask k), and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_maskz_expandloadu_epi16)
-#[inline]
-#[target_feature(enable = "avx512vbmi2")]
-pub fn _mm512_maskz_expandloadu_epi16<T: Is512BitsUnaligned>(
-    k: __mmask32,
-    mem_addr: &T,
-) -> __m512i {
-    _mm512_mask_expandloadu_epi16(arch::_mm512_setzero_si512(), k, mem_addr)
-}
+    /// Load contiguous active 16-bit integers from unaligned memory at mem_addr (those with their respective bit set in mask k), and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_expandloadu_epi16)
+    #[method = mm512_mask_expandloadu_epi16]
+    #[inline]
+    #[target_feature(enable = "avx512vbmi2")]
+    pub fn _mm512_mask_expandloadu_epi16<T: Is512BitsReadable>(
+        src: __m512i,
+        k: __mmask32,
+        mem_addr: &T,
+    ) -> __m512i {
+        unsafe { arch::_mm512_mask_expandloadu_epi16(src, k, ptr::from_ref(mem_addr).cast()) }
+    }
 
-/// Load contiguous active 8-bit integers from unaligned memory at mem_addr (those with their respective bit set in mask k), and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_mask_expandloadu_epi8)
-#[inline]
-#[target_feature(enable = "avx512vbmi2,avx512vl")]
-pub fn _mm_mask_expandloadu_epi8<T: Is128BitsUnaligned>(
-    src: __m128i,
-    k: __mmask16,
-    mem_addr: &T,
-) -> __m128i {
-    unsafe { arch::_mm_mask_expandloadu_epi8(src, k, ptr::from_ref(mem_addr).cast()) }
-}
+    /// Load contiguous active 16-bit integers from unaligned memory at mem_addr (those with their respective bit set in mask k), and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_maskz_expandloadu_epi16)
+    #[method = mm512_maskz_expandloadu_epi16]
+    #[inline]
+    #[target_feature(enable = "avx512vbmi2")]
+    pub fn _mm512_maskz_expandloadu_epi16<T: Is512BitsReadable>(k: __mmask32, mem_addr: &T) -> __m512i {
+        _mm512_mask_expandloadu_epi16(arch::_mm512_setzero_si512(), k, mem_addr)
+    }
 
-/// Load contiguous active 8-bit integers from unaligned memory at mem_addr (those with their respective bit set in mask k), and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_maskz_expandloadu_epi8)
-#[inline]
-#[target_feature(enable = "avx512vbmi2,avx512vl")]
-pub fn _mm_maskz_expandloadu_epi8<T: Is128BitsUnaligned>(k: __mmask16, mem_addr: &T) -> __m128i {
-    _mm_mask_expandloadu_epi8(arch::_mm_setzero_si128(), k, mem_addr)
-}
+    /// Load contiguous active 8-bit integers from unaligned memory at mem_addr (those with their respective bit set in mask k), and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_mask_expandloadu_epi8)
+    #[method = mm_mask_expandloadu_epi8]
+    #[inline]
+    #[target_feature(enable = "avx512vbmi2,avx512vl")]
+    pub fn _mm_mask_expandloadu_epi8<T: Is128BitsReadable>(
+        src: __m128i,
+        k: __mmask16,
+        mem_addr: &T,
+    ) -> __m128i {
+        unsafe { arch::_mm_mask_expandloadu_epi8(src, k, ptr::from_ref(mem_addr).cast()) }
+    }
 
-/// Load contiguous active 8-bit integers from unaligned memory at mem_addr (those with their respective bit set in mask k), and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_mask_expandloadu_epi8)
-#[inline]
-#[target_feature(enable = "avx512vbmi2,avx512vl")]
-pub fn _mm256_mask_expandloadu_epi8<T: Is256BitsUnaligned>(
-    src: __m256i,
-    k: __mmask32,
-    mem_addr: &T,
-) -> __m256i {
-    unsafe { arch::_mm256_mask_expandloadu_epi8(src, k, ptr::from_ref(mem_addr).cast()) }
-}
+    /// Load contiguous active 8-bit integers from unaligned memory at mem_addr (those with their respective bit set in mask k), and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_maskz_expandloadu_epi8)
+    #[method = mm_maskz_expandloadu_epi8]
+    #[inline]
+    #[target_feature(enable = "avx512vbmi2,avx512vl")]
+    pub fn _mm_maskz_expandloadu_epi8<T: Is128BitsReadable>(k: __mmask16, mem_addr: &T) -> __m128i {
+        _mm_mask_expandloadu_epi8(arch::_mm_setzero_si128(), k, mem_addr)
+    }
 
-/// Load contiguous active 8-bit integers from unaligned memory at mem_addr (those with their respective bit set in mask k), and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_maskz_expandloadu_epi8)
-#[inline]
-#[target_feature(enable = "avx512vbmi2,avx512vl")]
-pub fn _mm256_maskz_expandloadu_epi8<T: Is256BitsUnaligned>(k: __mmask32, mem_addr: &T) -> __m256i {
-    _mm256_mask_expandloadu_epi8(arch::_mm256_setzero_si256(), k, mem_addr)
-}
+    /// Load contiguous active 8-bit integers from unaligned memory at mem_addr (those with their respective bit set in mask k), and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_mask_expandloadu_epi8)
+    #[method = mm256_mask_expandloadu_epi8]
+    #[inline]
+    #[target_feature(enable = "avx512vbmi2,avx512vl")]
+    pub fn _mm256_mask_expandloadu_epi8<T: Is256BitsReadable>(
+        src: __m256i,
+        k: __mmask32,
+        mem_addr: &T,
+    ) -> __m256i {
+        unsafe { arch::_mm256_mask_expandloadu_epi8(src, k, ptr::from_ref(mem_addr).cast()) }
+    }
 
-/// Load contiguous active 8-bit integers from unaligned memory at mem_addr (those with their respective bit set in mask k), and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_expandloadu_epi8)
-#[inline]
-#[target_feature(enable = "avx512vbmi2")]
-pub fn _mm512_mask_expandloadu_epi8<T: Is512BitsUnaligned>(
-    src: __m512i,
-    k: __mmask64,
-    mem_addr: &T,
-) -> __m512i {
-    unsafe { arch::_mm512_mask_expandloadu_epi8(src, k, ptr::from_ref(mem_addr).cast()) }
-}
+    /// Load contiguous active 8-bit integers from unaligned memory at mem_addr (those with their respective bit set in mask k), and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_maskz_expandloadu_epi8)
+    #[method = mm256_maskz_expandloadu_epi8]
+    #[inline]
+    #[target_feature(enable = "avx512vbmi2,avx512vl")]
+    pub fn _mm256_maskz_expandloadu_epi8<T: Is256BitsReadable>(k: __mmask32, mem_addr: &T) -> __m256i {
+        _mm256_mask_expandloadu_epi8(arch::_mm256_setzero_si256(), k, mem_addr)
+    }
 
-/// Load contiguous active 8-bit integers from unaligned memory at mem_addr (those with their respective bit set in mask k), and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_maskz_expandloadu_epi8)
-#[inline]
-#[target_feature(enable = "avx512vbmi2")]
-pub fn _mm512_maskz_expandloadu_epi8<T: Is512BitsUnaligned>(k: __mmask64, mem_addr: &T) -> __m512i {
-    _mm512_mask_expandloadu_epi8(arch::_mm512_setzero_si512(), k, mem_addr)
-}
+    /// Load contiguous active 8-bit integers from unaligned memory at mem_addr (those with their respective bit set in mask k), and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_expandloadu_epi8)
+    #[method = mm512_mask_expandloadu_epi8]
+    #[inline]
+    #[target_feature(enable = "avx512vbmi2")]
+    pub fn _mm512_mask_expandloadu_epi8<T: Is512BitsReadable>(
+        src: __m512i,
+        k: __mmask64,
+        mem_addr: &T,
+    ) -> __m512i {
+        unsafe { arch::_mm512_mask_expandloadu_epi8(src, k, ptr::from_ref(mem_addr).cast()) }
+    }
 
-/// Contiguously store the active 16-bit integers in a (those with their respective bit set in writemask k) to unaligned memory at base_addr.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_mask_compressstoreu_epi16)
-#[inline]
-#[target_feature(enable = "avx512vbmi2,avx512vl")]
-pub fn _mm_mask_compressstoreu_epi16<T: Is128BitsUnaligned>(
-    base_addr: &mut T,
-    k: __mmask8,
-    a: __m128i,
-) {
-    unsafe { arch::_mm_mask_compressstoreu_epi16(ptr::from_mut(base_addr).cast(), k, a) }
-}
+    /// Load contiguous active 8-bit integers from unaligned memory at mem_addr (those with their respective bit set in mask k), and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_maskz_expandloadu_epi8)
+    #[method = mm512_maskz_expandloadu_epi8]
+    #[inline]
+    #[target_feature(enable = "avx512vbmi2")]
+    pub fn _mm512_maskz_expandloadu_epi8<T: Is512BitsReadable>(k: __mmask64, mem_addr: &T) -> __m512i {
+        _mm512_mask_expandloadu_epi8(arch::_mm512_setzero_si512(), k, mem_addr)
+    }
 
-/// Contiguously store the active 16-bit integers in a (those with their respective bit set in writemask k) to unaligned memory at base_addr.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_mask_compressstoreu_epi16)
-#[inline]
-#[target_feature(enable = "avx512vbmi2,avx512vl")]
-pub fn _mm256_mask_compressstoreu_epi16<T: Is256BitsUnaligned>(
-    base_addr: &mut T,
-    k: __mmask16,
-    a: __m256i,
-) {
-    unsafe { arch::_mm256_mask_compressstoreu_epi16(ptr::from_mut(base_addr).cast(), k, a) }
-}
+    /// Contiguously store the active 16-bit integers in a (those with their respective bit set in writemask k) to unaligned memory at base_addr.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_mask_compressstoreu_epi16)
+    #[method = mm_mask_compressstoreu_epi16]
+    #[inline]
+    #[target_feature(enable = "avx512vbmi2,avx512vl")]
+    pub fn _mm_mask_compressstoreu_epi16<T: Is128BitsUnaligned>(
+        base_addr: &mut T,
+        k: __mmask8,
+        a: __m128i,
+    ) {
+        unsafe { arch::_mm_mask_compressstoreu_epi16(ptr::from_mut(base_addr).cast(), k, a) }
+    }
 
-/// Contiguously store the active 16-bit integers in a (those with their respective bit set in writemask k) to unaligned memory at base_addr.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_compressstoreu_epi16)
-#[inline]
-#[target_feature(enable = "avx512vbmi2")]
-pub fn _mm512_mask_compressstoreu_epi16<T: Is512BitsUnaligned>(
-    base_addr: &mut T,
-    k: __mmask32,
-    a: __m512i,
-) {
-    unsafe { arch::_mm512_mask_compressstoreu_epi16(ptr::from_mut(base_addr).cast(), k, a) }
-}
+    /// Contiguously store the active 16-bit integers in a (those with their respective bit set in writemask k) to unaligned memory at base_addr.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_mask_compressstoreu_epi16)
+    #[method = mm256_mask_compressstoreu_epi16]
+    #[inline]
+    #[target_feature(enable = "avx512vbmi2,avx512vl")]
+    pub fn _mm256_mask_compressstoreu_epi16<T: Is256BitsUnaligned>(
+        base_addr: &mut T,
+        k: __mmask16,
+        a: __m256i,
+    ) {
+        unsafe { arch::_mm256_mask_compressstoreu_epi16(ptr::from_mut(base_addr).cast(), k, a) }
+    }
 
-/// Contiguously store the active 8-bit integers in a (those with their respective bit set in writemask k) to unaligned memory at base_addr.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_mask_compressstoreu_epi8)
-#[inline]
-#[target_feature(enable = "avx512vbmi2,avx512vl")]
-pub fn _mm_mask_compressstoreu_epi8<T: Is128BitsUnaligned>(
-    base_addr: &mut T,
-    k: __mmask16,
-    a: __m128i,
-) {
-    unsafe { arch::_mm_mask_compressstoreu_epi8(ptr::from_mut(base_addr).cast(), k, a) }
-}
+    /// Contiguously store the active 16-bit integers in a (those with their respective bit set in writemask k) to unaligned memory at base_addr.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_compressstoreu_epi16)
+    #[method = mm512_mask_compressstoreu_epi16]
+    #[inline]
+    #[target_feature(enable = "avx512vbmi2")]
+    pub fn _mm512_mask_compressstoreu_epi16<T: Is512BitsUnaligned>(
+        base_addr: &mut T,
+        k: __mmask32,
+        a: __m512i,
+    ) {
+        unsafe { arch::_mm512_mask_compressstoreu_epi16(ptr::from_mut(base_addr).cast(), k, a) }
+    }
 
-/// Contiguously store the active 8-bit integers in a (those with their respective bit set in writemask k) to unaligned memory at base_addr.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_mask_compressstoreu_epi8)
-#[inline]
-#[target_feature(enable = "avx512vbmi2,avx512vl")]
-pub fn _mm256_mask_compressstoreu_epi8<T: Is256BitsUnaligned>(
-    base_addr: &mut T,
-    k: __mmask32,
-    a: __m256i,
-) {
-    unsafe { arch::_mm256_mask_compressstoreu_epi8(ptr::from_mut(base_addr).cast(), k, a) }
-}
+    /// Contiguously store the active 8-bit integers in a (those with their respective bit set in writemask k) to unaligned memory at base_addr.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_mask_compressstoreu_epi8)
+    #[method = mm_mask_compressstoreu_epi8]
+    #[inline]
+    #[target_feature(enable = "avx512vbmi2,avx512vl")]
+    pub fn _mm_mask_compressstoreu_epi8<T: Is128BitsUnaligned>(
+        base_addr: &mut T,
+        k: __mmask16,
+        a: __m128i,
+    ) {
+        unsafe { arch::_mm_mask_compressstoreu_epi8(ptr::from_mut(base_addr).cast(), k, a) }
+    }
 
-/// Contiguously store the active 8-bit integers in a (those with their respective bit set in writemask k) to unaligned memory at base_addr.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_compressstoreu_epi8)
-#[inline]
-#[target_feature(enable = "avx512vbmi2")]
-pub fn _mm512_mask_compressstoreu_epi8<T: Is512BitsUnaligned>(
-    base_addr: &mut T,
-    k: __mmask64,
-    a: __m512i,
-) {
-    unsafe { arch::_mm512_mask_compressstoreu_epi8(ptr::from_mut(base_addr).cast(), k, a) }
+    /// Contiguously store the active 8-bit integers in a (those with their respective bit set in writemask k) to unaligned memory at base_addr.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_mask_compressstoreu_epi8)
+    #[method = mm256_mask_compressstoreu_epi8]
+    #[inline]
+    #[target_feature(enable = "avx512vbmi2,avx512vl")]
+    pub fn _mm256_mask_compressstoreu_epi8<T: Is256BitsUnaligned>(
+        base_addr: &mut T,
+        k: __mmask32,
+        a: __m256i,
+    ) {
+        unsafe { arch::_mm256_mask_compressstoreu_epi8(ptr::from_mut(base_addr).cast(), k, a) }
+    }
+
+    /// Contiguously store the active 8-bit integers in a (those with their respective bit set in writemask k) to unaligned memory at base_addr.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_compressstoreu_epi8)
+    #[method = mm512_mask_compressstoreu_epi8]
+    #[inline]
+    #[target_feature(enable = "avx512vbmi2")]
+    pub fn _mm512_mask_compressstoreu_epi8<T: Is512BitsUnaligned>(
+        base_addr: &mut T,
+        k: __mmask64,
+        a: __m512i,
+    ) {
+        unsafe { arch::_mm512_mask_compressstoreu_epi8(ptr::from_mut(base_addr).cast(), k, a) }
+    }
 }
 
 #[cfg(test)]
@@ -238,25 +260,27 @@ mod tests {
 
     use core::hint::black_box;
 
+    use crate::Register;
+
     // Fail-safe for tests being run on a CPU that doesn't support the instruction set
     static CPU_HAS_AVX512VBMI2: std::sync::LazyLock<bool> =
         std::sync::LazyLock::new(|| is_x86_feature_detected!("avx512vbmi2"));
 
     fn assert_eq_m128i(a: __m128i, b: __m128i) {
-        let a: [u8; 16] = unsafe { core::mem::transmute(a) };
-        let b: [u8; 16] = unsafe { core::mem::transmute(b) };
+        let a: [u8; 16] = a.to_array();
+        let b: [u8; 16] = b.to_array();
         assert_eq!(a, b)
     }
 
     fn assert_eq_m256i(a: __m256i, b: __m256i) {
-        let a: [u8; 32] = unsafe { core::mem::transmute(a) };
-        let b: [u8; 32] = unsafe { core::mem::transmute(b) };
+        let a: [u8; 32] = a.to_array();
+        let b: [u8; 32] = b.to_array();
         assert_eq!(a, b)
     }
 
     fn assert_eq_m512i(a: __m512i, b: __m512i) {
-        let a: [u8; 64] = unsafe { core::mem::transmute(a) };
-        let b: [u8; 64] = unsafe { core::mem::transmute(b) };
+        let a: [u8; 64] = a.to_array();
+        let b: [u8; 64] = b.to_array();
         assert_eq!(a, b)
     }
 
@@ -108,11 +108,13 @@ mod tests {
 
     use core::{array, cell::Cell};
 
+    use crate::Register;
+
     // SAFETY: The `x86_64` target baseline includes `sse` and `sse2`.
 
     fn assert_eq_m128i(a: __m128i, b: __m128i) {
-        let a: [u8; 16] = unsafe { core::mem::transmute(a) };
-        let b: [u8; 16] = unsafe { core::mem::transmute(b) };
+        let a: [u8; 16] = a.to_array();
+        let b: [u8; 16] = b.to_array();
         assert_eq!(a, b)
     }
 
@@ -0,0 +1,245 @@
+//! Functions for [`Complex`] arrays, enabled by the `num-complex` feature.
+//!
+//! `Complex<T>` is `repr(C)` with the real part followed by the imaginary
+//! part, so `[Complex<f32>; 4]` has the layout of `[f32; 8]`. These wrappers
+//! accept the complex arrays in place of the float arrays of the parent
+//! module. The loaded registers hold interleaved real and imaginary parts.
+//!
+//! ```rust
+//! # unsafe { conjugate() }
+//! use num_complex::Complex;
+//!
+//! #[cfg(target_arch = "x86")]
+//! use safe_unaligned_simd::x86::complex;
+//! #[cfg(target_arch = "x86_64")]
+//! use safe_unaligned_simd::x86_64::complex;
+//! #[cfg(target_arch = "x86")]
+//! use core::arch::x86::{_mm_set_ps, _mm_xor_ps};
+//! #[cfg(target_arch = "x86_64")]
+//! use core::arch::x86_64::{_mm_set_ps, _mm_xor_ps};
+//!
+//! #[target_feature(enable = "sse")]
+//! fn conjugate() {
+//!     let mut a = [Complex::new(1.0f32, 2.0), Complex::new(3.0, -4.0)];
+//!
+//!     let r = complex::_mm_loadu_ps(&a);
+//!     let r = _mm_xor_ps(r, _mm_set_ps(-0.0, 0.0, -0.0, 0.0));
+//!     complex::_mm_storeu_ps(&mut a, r);
+//!
+//!     assert_eq!(a, [Complex::new(1.0, -2.0), Complex::new(3.0, 4.0)]);
+//! }
+//! ```
+#[cfg(target_arch = "x86")]
+use core::arch::x86::{self as arch, __m128, __m128d, __m256, __m256d};
+#[cfg(all(target_arch = "x86", feature = "avx512"))]
+use core::arch::x86::{__m512, __m512d};
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::{self as arch, __m128, __m128d, __m256, __m256d};
+#[cfg(all(target_arch = "x86_64", feature = "avx512"))]
+use core::arch::x86_64::{__m512, __m512d};
+
+use num_complex::Complex;
+
+/// Loads two `Complex<f32>` values from memory into a [`__m128`].
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_loadu_ps)
+#[inline]
+#[target_feature(enable = "sse")]
+pub fn _mm_loadu_ps(mem_addr: &[Complex<f32>; 2]) -> __m128 {
+    unsafe { arch::_mm_loadu_ps(mem_addr.as_ptr().cast()) }
+}
+
+/// Stores two `Complex<f32>` values from a [`__m128`] into memory.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_storeu_ps)
+#[inline]
+#[target_feature(enable = "sse")]
+pub fn _mm_storeu_ps(mem_addr: &mut [Complex<f32>; 2], a: __m128) {
+    unsafe { arch::_mm_storeu_ps(mem_addr.as_mut_ptr().cast(), a) }
+}
+
+/// Loads one `Complex<f64>` value from memory into a [`__m128d`].
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_loadu_pd)
+#[inline]
+#[target_feature(enable = "sse2")]
+pub fn _mm_loadu_pd(mem_addr: &Complex<f64>) -> __m128d {
+    unsafe { arch::_mm_loadu_pd(core::ptr::from_ref(mem_addr).cast()) }
+}
+
+/// Stores one `Complex<f64>` value from a [`__m128d`] into memory.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_storeu_pd)
+#[inline]
+#[target_feature(enable = "sse2")]
+pub fn _mm_storeu_pd(mem_addr: &mut Complex<f64>, a: __m128d) {
+    unsafe { arch::_mm_storeu_pd(core::ptr::from_mut(mem_addr).cast(), a) }
+}
+
+/// Loads four `Complex<f32>` values from memory into a [`__m256`].
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_loadu_ps)
+#[inline]
+#[target_feature(enable = "avx")]
+pub fn _mm256_loadu_ps(mem_addr: &[Complex<f32>; 4]) -> __m256 {
+    unsafe { arch::_mm256_loadu_ps(mem_addr.as_ptr().cast()) }
+}
+
+/// Stores four `Complex<f32>` values from a [`__m256`] into memory.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_storeu_ps)
+#[inline]
+#[target_feature(enable = "avx")]
+pub fn _mm256_storeu_ps(mem_addr: &mut [Complex<f32>; 4], a: __m256) {
+    unsafe { arch::_mm256_storeu_ps(mem_addr.as_mut_ptr().cast(), a) }
+}
+
+/// Loads two `Complex<f64>` values from memory into a [`__m256d`].
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_loadu_pd)
+#[inline]
+#[target_feature(enable = "avx")]
+pub fn _mm256_loadu_pd(mem_addr: &[Complex<f64>; 2]) -> __m256d {
+    unsafe { arch::_mm256_loadu_pd(mem_addr.as_ptr().cast()) }
+}
+
+/// Stores two `Complex<f64>` values from a [`__m256d`] into memory.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_storeu_pd)
+#[inline]
+#[target_feature(enable = "avx")]
+pub fn _mm256_storeu_pd(mem_addr: &mut [Complex<f64>; 2], a: __m256d) {
+    unsafe { arch::_mm256_storeu_pd(mem_addr.as_mut_ptr().cast(), a) }
+}
+
+/// Loads eight `Complex<f32>` values from memory into a [`__m512`].
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_loadu_ps)
+#[cfg(feature = "avx512")]
+#[inline]
+#[target_feature(enable = "avx512f")]
+pub fn _mm512_loadu_ps(mem_addr: &[Complex<f32>; 8]) -> __m512 {
+    unsafe { arch::_mm512_loadu_ps(mem_addr.as_ptr().cast()) }
+}
+
+/// Stores eight `Complex<f32>` values from a [`__m512`] into memory.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_storeu_ps)
+#[cfg(feature = "avx512")]
+#[inline]
+#[target_feature(enable = "avx512f")]
+pub fn _mm512_storeu_ps(mem_addr: &mut [Complex<f32>; 8], a: __m512) {
+    unsafe { arch::_mm512_storeu_ps(mem_addr.as_mut_ptr().cast(), a) }
+}
+
+/// Loads four `Complex<f64>` values from memory into a [`__m512d`].
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_loadu_pd)
+#[cfg(feature = "avx512")]
+#[inline]
+#[target_feature(enable = "avx512f")]
+pub fn _mm512_loadu_pd(mem_addr: &[Complex<f64>; 4]) -> __m512d {
+    unsafe { arch::_mm512_loadu_pd(mem_addr.as_ptr().cast()) }
+}
+
+/// Stores four `Complex<f64>` values from a [`__m512d`] into memory.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_storeu_pd)
+#[cfg(feature = "avx512")]
+#[inline]
+#[target_feature(enable = "avx512f")]
+pub fn _mm512_storeu_pd(mem_addr: &mut [Complex<f64>; 4], a: __m512d) {
+    unsafe { arch::_mm512_storeu_pd(mem_addr.as_mut_ptr().cast(), a) }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(target_arch = "x86")]
+    use core::arch::x86::{self as arch, __m128, __m128d};
+    #[cfg(target_arch = "x86_64")]
+    use core::arch::x86_64::{self as arch, __m128, __m128d};
+
+    use num_complex::Complex;
+
+    use crate::Register;
+
+    fn assert_eq_m128(a: __m128, b: __m128) {
+        let a: [u8; 16] = a.to_array();
+        let b: [u8; 16] = b.to_array();
+        assert_eq!(a, b)
+    }
+
+    fn assert_eq_m128d(a: __m128d, b: __m128d) {
+        let a: [u8; 16] = a.to_array();
+        let b: [u8; 16] = b.to_array();
+        assert_eq!(a, b)
+    }
+
+    // SAFETY: The `x86_64` target baseline includes `sse` and `sse2`.
+
+    #[test]
+    fn test_mm_loadu_storeu_ps() {
+        let a = [Complex::new(1.0f32, 2.0), Complex::new(3.0, 4.0)];
+        unsafe { test(&a) }
+
+        #[target_feature(enable = "sse")]
+        fn test(a: &[Complex<f32>; 2]) {
+            let r = super::_mm_loadu_ps(a);
+            let target = arch::_mm_setr_ps(1.0, 2.0, 3.0, 4.0);
+            assert_eq_m128(r, target);
+
+            let mut b = [Complex::new(0.0, 0.0); 2];
+            super::_mm_storeu_ps(&mut b, r);
+            assert_eq!(&b, a);
+        }
+    }
+
+    #[test]
+    fn test_mm_loadu_storeu_pd() {
+        let a = Complex::new(1.0f64, 2.0);
+        unsafe { test(&a) }
+
+        #[target_feature(enable = "sse2")]
+        fn test(a: &Complex<f64>) {
+            let r = super::_mm_loadu_pd(a);
+            let target = arch::_mm_setr_pd(1.0, 2.0);
+            assert_eq_m128d(r, target);
+
+            let mut b = Complex::new(0.0, 0.0);
+            super::_mm_storeu_pd(&mut b, r);
+            assert_eq!(&b, a);
+        }
+    }
+
+    #[cfg(feature = "_avx_test")]
+    #[test]
+    fn test_mm256_loadu_storeu_ps() {
+        assert!(is_x86_feature_detected!("avx"));
+
+        let a: [Complex<f32>; 4] = core::array::from_fn(|i| Complex::new(i as f32, -(i as f32)));
+        unsafe { test(&a) }
+
+        #[target_feature(enable = "avx")]
+        fn test(a: &[Complex<f32>; 4]) {
+            let mut b = [Complex::new(0.0, 0.0); 4];
+            super::_mm256_storeu_ps(&mut b, super::_mm256_loadu_ps(a));
+            assert_eq!(&b, a);
+        }
+    }
+
+    #[cfg(feature = "_avx_test")]
+    #[test]
+    fn test_mm256_loadu_storeu_pd() {
+        assert!(is_x86_feature_detected!("avx"));
+
+        let a = [Complex::new(1.0f64, 2.0), Complex::new(3.0, 4.0)];
+        unsafe { test(&a) }
+
+        #[target_feature(enable = "avx")]
+        fn test(a: &[Complex<f64>; 2]) {
+            let mut b = [Complex::new(0.0, 0.0); 2];
+            super::_mm256_storeu_pd(&mut b, super::_mm256_loadu_pd(a));
+            assert_eq!(&b, a);
+        }
+    }
+}
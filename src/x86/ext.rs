@@ -0,0 +1,252 @@
+//! Extension traits with method syntax for the unaligned loads and stores.
+//!
+//! Each trait is implemented for every type of the bound it is named after, so generic code
+//! bounded by [`Is128BitsUnaligned`] can call `data.storeu_si128(sse2, v)` without naming the
+//! wrapper. The methods take the [capability token](super::token) of their target features in
+//! place of a `#[target_feature]` attribute, which trait methods cannot carry safely. Tokens of
+//! wider feature sets dereference to the narrower ones, so `*avx` passes an [`Avx`] token where
+//! an [`Sse2`] token is expected.
+//!
+//! ```rust
+//! #[cfg(target_arch = "x86")]
+//! use safe_unaligned_simd::x86::{ext::*, token::Sse2, Is128BitsUnaligned};
+//! #[cfg(target_arch = "x86_64")]
+//! use safe_unaligned_simd::x86_64::{ext::*, token::Sse2, Is128BitsUnaligned};
+//!
+//! fn copy<T: Is128BitsUnaligned>(sse2: Sse2, from: &T, into: &mut T) {
+//!     into.storeu_si128(sse2, from.loadu_si128(sse2));
+//! }
+//!
+//! if let Some(sse2) = Sse2::try_new() {
+//!     let mut out = [0u16; 8];
+//!     copy(sse2, &[1, 2, 3, 4, 5, 6, 7, 8], &mut out);
+//!     assert_eq!(out, [1, 2, 3, 4, 5, 6, 7, 8]);
+//! }
+//! ```
+#[cfg(target_arch = "x86")]
+use core::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::*;
+
+#[cfg(feature = "avx512")]
+use super::token::Avx512f;
+use super::token::{Avx, Sse2};
+use super::{
+    Is16BitsReadable, Is16BitsUnaligned, Is16CellUnaligned, Is32BitsReadable, Is32BitsUnaligned,
+    Is32CellUnaligned, Is64BitsReadable, Is64BitsUnaligned, Is64CellUnaligned, Is128BitsReadable,
+    Is128BitsUnaligned, Is128CellUnaligned, Is256BitsReadable, Is256BitsUnaligned,
+    Is256CellUnaligned,
+};
+#[cfg(feature = "avx512")]
+use super::{Is512BitsReadable, Is512BitsUnaligned};
+
+ext_trait! {
+    /// Loads from [`Is16BitsReadable`] types.
+    pub trait Is16BitsReadableExt: Is16BitsReadable {
+        /// Calls [`_mm_loadu_si16`](super::_mm_loadu_si16) on `self`.
+        fn loadu_si16(&self, token: Sse2) -> __m128i = super::_mm_loadu_si16;
+    }
+}
+
+ext_trait! {
+    /// Stores into [`Is16BitsUnaligned`] types.
+    pub trait Is16BitsUnalignedExt: Is16BitsUnaligned {
+        /// Calls [`_mm_storeu_si16`](super::_mm_storeu_si16) on `self`.
+        fn storeu_si16(&mut self, token: Sse2, a: __m128i) = super::_mm_storeu_si16;
+    }
+}
+
+ext_trait! {
+    /// Loads from [`Is32BitsReadable`] types.
+    pub trait Is32BitsReadableExt: Is32BitsReadable {
+        /// Calls [`_mm_loadu_si32`](super::_mm_loadu_si32) on `self`.
+        fn loadu_si32(&self, token: Sse2) -> __m128i = super::_mm_loadu_si32;
+    }
+}
+
+ext_trait! {
+    /// Stores into [`Is32BitsUnaligned`] types.
+    pub trait Is32BitsUnalignedExt: Is32BitsUnaligned {
+        /// Calls [`_mm_storeu_si32`](super::_mm_storeu_si32) on `self`.
+        fn storeu_si32(&mut self, token: Sse2, a: __m128i) = super::_mm_storeu_si32;
+    }
+}
+
+ext_trait! {
+    /// Loads from [`Is64BitsReadable`] types.
+    pub trait Is64BitsReadableExt: Is64BitsReadable {
+        /// Calls [`_mm_loadu_si64`](super::_mm_loadu_si64) on `self`.
+        fn loadu_si64(&self, token: Sse2) -> __m128i = super::_mm_loadu_si64;
+    }
+}
+
+ext_trait! {
+    /// Stores into [`Is64BitsUnaligned`] types.
+    pub trait Is64BitsUnalignedExt: Is64BitsUnaligned {
+        /// Calls [`_mm_storeu_si64`](super::_mm_storeu_si64) on `self`.
+        fn storeu_si64(&mut self, token: Sse2, a: __m128i) = super::_mm_storeu_si64;
+    }
+}
+
+ext_trait! {
+    /// Loads from [`Is128BitsReadable`] types.
+    pub trait Is128BitsReadableExt: Is128BitsReadable {
+        /// Calls [`_mm_loadu_si128`](super::_mm_loadu_si128) on `self`.
+        fn loadu_si128(&self, token: Sse2) -> __m128i = super::_mm_loadu_si128;
+    }
+}
+
+ext_trait! {
+    /// Stores into [`Is128BitsUnaligned`] types.
+    pub trait Is128BitsUnalignedExt: Is128BitsUnaligned {
+        /// Calls [`_mm_storeu_si128`](super::_mm_storeu_si128) on `self`.
+        fn storeu_si128(&mut self, token: Sse2, a: __m128i) = super::_mm_storeu_si128;
+    }
+}
+
+ext_trait! {
+    /// Loads from [`Is256BitsReadable`] types.
+    pub trait Is256BitsReadableExt: Is256BitsReadable {
+        /// Calls [`_mm256_loadu_si256`](super::_mm256_loadu_si256) on `self`.
+        fn loadu_si256(&self, token: Avx) -> __m256i = super::_mm256_loadu_si256;
+    }
+}
+
+ext_trait! {
+    /// Stores into [`Is256BitsUnaligned`] types.
+    pub trait Is256BitsUnalignedExt: Is256BitsUnaligned {
+        /// Calls [`_mm256_storeu_si256`](super::_mm256_storeu_si256) on `self`.
+        fn storeu_si256(&mut self, token: Avx, a: __m256i) = super::_mm256_storeu_si256;
+    }
+}
+
+#[cfg(feature = "avx512")]
+ext_trait! {
+    /// Loads from [`Is512BitsReadable`] types.
+    pub trait Is512BitsReadableExt: Is512BitsReadable {
+        /// Calls [`_mm512_loadu_si512`](super::_mm512_loadu_si512) on `self`.
+        fn loadu_si512(&self, token: Avx512f) -> __m512i = super::_mm512_loadu_si512;
+    }
+}
+
+#[cfg(feature = "avx512")]
+ext_trait! {
+    /// Stores into [`Is512BitsUnaligned`] types.
+    pub trait Is512BitsUnalignedExt: Is512BitsUnaligned {
+        /// Calls [`_mm512_storeu_si512`](super::_mm512_storeu_si512) on `self`.
+        fn storeu_si512(&mut self, token: Avx512f, a: __m512i) = super::_mm512_storeu_si512;
+    }
+}
+
+ext_trait! {
+    /// Loads from and stores into [`Is16CellUnaligned`] types.
+    pub trait Is16CellUnalignedExt: Is16CellUnaligned {
+        /// Calls [`cell::_mm_loadu_si16`](super::cell::_mm_loadu_si16) on `self`.
+        fn loadu_si16(&self, token: Sse2) -> __m128i = super::cell::_mm_loadu_si16;
+        /// Calls [`cell::_mm_storeu_si16`](super::cell::_mm_storeu_si16) on `self`.
+        fn storeu_si16(&self, token: Sse2, a: __m128i) = super::cell::_mm_storeu_si16;
+    }
+}
+
+ext_trait! {
+    /// Loads from and stores into [`Is32CellUnaligned`] types.
+    pub trait Is32CellUnalignedExt: Is32CellUnaligned {
+        /// Calls [`cell::_mm_loadu_si32`](super::cell::_mm_loadu_si32) on `self`.
+        fn loadu_si32(&self, token: Sse2) -> __m128i = super::cell::_mm_loadu_si32;
+        /// Calls [`cell::_mm_storeu_si32`](super::cell::_mm_storeu_si32) on `self`.
+        fn storeu_si32(&self, token: Sse2, a: __m128i) = super::cell::_mm_storeu_si32;
+    }
+}
+
+ext_trait! {
+    /// Loads from and stores into [`Is64CellUnaligned`] types.
+    pub trait Is64CellUnalignedExt: Is64CellUnaligned {
+        /// Calls [`cell::_mm_loadu_si64`](super::cell::_mm_loadu_si64) on `self`.
+        fn loadu_si64(&self, token: Sse2) -> __m128i = super::cell::_mm_loadu_si64;
+        /// Calls [`cell::_mm_storeu_si64`](super::cell::_mm_storeu_si64) on `self`.
+        fn storeu_si64(&self, token: Sse2, a: __m128i) = super::cell::_mm_storeu_si64;
+    }
+}
+
+ext_trait! {
+    /// Loads from and stores into [`Is128CellUnaligned`] types.
+    pub trait Is128CellUnalignedExt: Is128CellUnaligned {
+        /// Calls [`cell::_mm_loadu_si128`](super::cell::_mm_loadu_si128) on `self`.
+        fn loadu_si128(&self, token: Sse2) -> __m128i = super::cell::_mm_loadu_si128;
+        /// Calls [`cell::_mm_storeu_si128`](super::cell::_mm_storeu_si128) on `self`.
+        fn storeu_si128(&self, token: Sse2, a: __m128i) = super::cell::_mm_storeu_si128;
+    }
+}
+
+ext_trait! {
+    /// Loads from and stores into [`Is256CellUnaligned`] types.
+    pub trait Is256CellUnalignedExt: Is256CellUnaligned {
+        /// Calls [`cell::_mm256_loadu_si256`](super::cell::_mm256_loadu_si256) on `self`.
+        fn loadu_si256(&self, token: Avx) -> __m256i = super::cell::_mm256_loadu_si256;
+        /// Calls [`cell::_mm256_storeu_si256`](super::cell::_mm256_storeu_si256) on `self`.
+        fn storeu_si256(&self, token: Avx, a: __m256i) = super::cell::_mm256_storeu_si256;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::cell::Cell;
+
+    use super::*;
+
+    #[test]
+    fn test_bits_ext() {
+        let Some(sse2) = Sse2::try_new() else {
+            return;
+        };
+
+        let a = [1u32, 2, 3, 4];
+        let mut b = [0u8; 16];
+        b.storeu_si128(sse2, a.loadu_si128(sse2));
+        assert_eq!(b, a.map(u32::to_ne_bytes).as_flattened());
+
+        let mut c = [0u16; 4];
+        c.storeu_si64(sse2, 0x0004_0003_0002_0001u64.loadu_si64(sse2));
+        assert_eq!(c, [1, 2, 3, 4]);
+
+        let mut d = [0u8; 2];
+        d.storeu_si16(sse2, [5u8, 6].loadu_si16(sse2));
+        assert_eq!(d, [5, 6]);
+    }
+
+    #[test]
+    fn test_cell_ext() {
+        let Some(sse2) = Sse2::try_new() else {
+            return;
+        };
+
+        let mut a = [0u16, 1, 2, 3, 4, 5, 6, 7, 8];
+        let cells = Cell::from_mut(&mut a[..]).as_slice_of_cells();
+        let load: &[Cell<u16>; 8] = cells[..8].try_into().unwrap();
+        let store: &[Cell<u16>; 8] = cells[1..].try_into().unwrap();
+
+        store.storeu_si128(sse2, load.loadu_si128(sse2));
+        assert_eq!(a, [0, 0, 1, 2, 3, 4, 5, 6, 7]);
+    }
+
+    #[test]
+    fn test_avx_ext() {
+        let Some(avx) = Avx::try_new() else {
+            return;
+        };
+
+        let a = [3u64; 4];
+        let mut b = [0u64; 4];
+        b.storeu_si256(avx, a.loadu_si256(avx));
+        assert_eq!(a, b);
+
+        let c = Cell::new([0u8; 32]);
+        c.storeu_si256(avx, [9u8; 32].loadu_si256(avx));
+        assert_eq!(c.get(), [9; 32]);
+
+        // Wider tokens dereference to the narrower ones.
+        let mut d = [0u8; 16];
+        d.storeu_si128(*avx, [1u8; 16].loadu_si128(*avx));
+        assert_eq!(d, [1; 16]);
+    }
+}
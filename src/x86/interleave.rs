@@ -0,0 +1,579 @@
+//! De-interleaving loads and interleaving stores, like `vldNq` and `vstNq` of `aarch64`.
+//!
+//! `load_deinterleave3_m128i` reads an array of interleaved elements, such as the `[u8; 48]` of 16
+//! RGB pixels, into three registers holding every third element: register `k` holds the elements
+//! `k`, `k + 3`, `k + 6` and so on. The `store_interleave*` functions are the inverse. The arrays
+//! have the same shapes as the arguments of the `aarch64` wrappers and the registers hold the same
+//! lanes, so `load_deinterleave3_m128i(&[u8; 48])` corresponds to `vld3q_u8(&[u8; 48])`.
+//!
+//! The element type may be any [`ReadableElement`] for loads or [`UnalignedElement`] for stores
+//! of 1, 2, 4 or 8 bytes, and the array must cover exactly two, three or four registers. Both
+//! are checked at compile time. Nested arrays count as one element, so `[[u8; 4]; 16]` is
+//! de-interleaved as 4-byte elements rather than bytes; flatten them to select the bytes. The
+//! registers are always integer registers, which the `_mm_castsi128_ps` family of intrinsics
+//! converts to float registers.
+//!
+//! The 128-bit functions require `ssse3`, the 256-bit functions `avx2` and the 512-bit
+//! functions, with the `avx512` feature, `avx512bw`. Every 128-bit lane of the wider registers
+//! is de-interleaved separately, which yields the same lanes as a de-interleave of the whole
+//! array.
+//!
+//! ```rust
+//! # if is_x86_feature_detected!("ssse3") { unsafe { split_rgb() } }
+//! #[cfg(target_arch = "x86")]
+//! use safe_unaligned_simd::x86::interleave;
+//! #[cfg(target_arch = "x86_64")]
+//! use safe_unaligned_simd::x86_64::interleave;
+//!
+//! #[target_feature(enable = "ssse3")]
+//! fn split_rgb() {
+//!     let pixels: [u8; 48] = core::array::from_fn(|i| [(i / 3) as u8, 0x40, 0x80][i % 3]);
+//!     let [r, g, b] = interleave::load_deinterleave3_m128i(&pixels);
+//!
+//!     let mut out = [0u8; 48];
+//!     interleave::store_interleave3_m128i(&mut out, [b, g, r]);
+//!     assert_eq!(out[..6], [0x80, 0x40, 0, 0x80, 0x40, 1]);
+//! }
+//! ```
+#[cfg(all(target_arch = "x86", feature = "avx512"))]
+use core::arch::x86::__m512i;
+#[cfg(target_arch = "x86")]
+use core::arch::x86::{self as arch, __m128i, __m256i};
+#[cfg(all(target_arch = "x86_64", feature = "avx512"))]
+use core::arch::x86_64::__m512i;
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::{self as arch, __m128i, __m256i};
+
+use crate::common_slice::{as_bytes, as_bytes_mut};
+use crate::common_traits::{ReadableElement, UnalignedElement};
+
+/// Shuffle masks of `_mm_shuffle_epi8`, where `masks[a][b]` moves the bytes of input register
+/// `b` that belong to output register `a` into place and zeroes the others.
+type Masks<const K: usize> = [[[u8; 16]; K]; K];
+
+/// The shuffles (de-)interleaving `K` registers of `width`-byte elements.
+///
+/// Two and four registers are (de-)interleaved with the unpack instructions like a transpose,
+/// which takes 2 or 8 unpacks and, to de-interleave elements narrower than `16 / K` bytes, one
+/// `_mm_shuffle_epi8` per register beforehand. Shuffling every input into every output instead
+/// would take 4 or 16 shuffles and 2 or 12 ORs. Three registers do not split into halves, so each
+/// output gathers its bytes from all inputs with 9 shuffles and 6 ORs in total.
+struct Plan<const K: usize> {
+    width: usize,
+    /// Gathers the bytes of each output into its unit of `16 / K` bytes before a de-interleave of
+    /// two or four registers, unused by interleaves.
+    group: [u8; 16],
+    /// The masks of three registers.
+    masks: Masks<K>,
+}
+
+impl<const K: usize> Plan<K> {
+    const fn deinterleave(width: usize) -> Self {
+        // Byte `p` of a grouped register is byte `q % width` of the element `q / width` of unit
+        // `p / unit`, where `q = p % unit`.
+        let unit = 16 / K;
+        let mut group = [0; 16];
+        let mut p = 0;
+        while p < 16 {
+            let q = p % unit;
+            group[p] = ((K * (q / width) + p / unit) * width + q % width) as u8;
+            p += 1;
+        }
+
+        Plan {
+            width,
+            group,
+            masks: deinterleave_masks::<K>(width),
+        }
+    }
+
+    const fn interleave(width: usize) -> Self {
+        Plan {
+            width,
+            group: [0x80; 16],
+            masks: interleave_masks::<K>(width),
+        }
+    }
+}
+
+/// The element width of an array of `N` elements `T` that covers `K` registers of `BYTES` bytes.
+///
+/// Fails to compile, when used in a constant, for other array sizes or element widths.
+const fn width<T, const N: usize>(bytes: usize) -> usize {
+    assert!(
+        N * size_of::<T>() == bytes,
+        "the array must cover the registers exactly"
+    );
+    assert!(
+        matches!(size_of::<T>(), 1 | 2 | 4 | 8),
+        "the element must be 1, 2, 4 or 8 bytes large"
+    );
+    size_of::<T>()
+}
+
+/// Masks de-interleaving `K` 16-byte chunks of `width`-byte elements: byte `p` of output `k` is
+/// byte `p % width` of element `K * (p / width) + k`.
+const fn deinterleave_masks<const K: usize>(width: usize) -> Masks<K> {
+    let mut masks = [[[0x80; 16]; K]; K];
+    let mut k = 0;
+    while k < K {
+        let mut p = 0;
+        while p < 16 {
+            let byte = (K * (p / width) + k) * width + p % width;
+            masks[k][byte / 16][p] = (byte % 16) as u8;
+            p += 1;
+        }
+        k += 1;
+    }
+    masks
+}
+
+/// Masks interleaving `K` registers of `width`-byte elements, the inverse of
+/// [`deinterleave_masks`]: byte `q` of output chunk `j` is taken from element `e / K` of register
+/// `e % K`, where `e` is the element index of the byte in memory.
+const fn interleave_masks<const K: usize>(width: usize) -> Masks<K> {
+    let mut masks = [[[0x80; 16]; K]; K];
+    let mut j = 0;
+    while j < K {
+        let mut q = 0;
+        while q < 16 {
+            let byte = j * 16 + q;
+            let element = byte / width;
+            masks[j][element % K][q] = ((element / K) * width + byte % width) as u8;
+            q += 1;
+        }
+        j += 1;
+    }
+    masks
+}
+
+// Every instruction below works on each 128-bit lane separately, so the wider registers are
+// (de-)interleaved lane by lane.
+macro_rules! shuffles {
+    (
+        target_feature: $feature:literal;
+        register: $reg:ident;
+        fn $deinterleave:ident, $interleave:ident, $unpack:ident, $shuffle:ident;
+        broadcast: |$mask:ident| $broadcast:expr;
+        $shuffle_epi8:ident, $or:ident;
+        unpack: [
+            $lo8:ident, $hi8:ident;
+            $lo16:ident, $hi16:ident;
+            $lo32:ident, $hi32:ident;
+            $lo64:ident, $hi64:ident
+        ];
+    ) => {
+        /// The low and high halves of the elements of `a` and `b` interleaved, as by the unpack
+        /// instructions of the element width.
+        #[inline]
+        #[target_feature(enable = $feature)]
+        fn $unpack(a: $reg, b: $reg, width: usize) -> ($reg, $reg) {
+            match width {
+                1 => (arch::$lo8(a, b), arch::$hi8(a, b)),
+                2 => (arch::$lo16(a, b), arch::$hi16(a, b)),
+                4 => (arch::$lo32(a, b), arch::$hi32(a, b)),
+                _ => (arch::$lo64(a, b), arch::$hi64(a, b)),
+            }
+        }
+
+        #[inline]
+        #[target_feature(enable = $feature)]
+        fn $shuffle<const K: usize>(regs: [$reg; K], masks: &Masks<K>) -> [$reg; K] {
+            // Every mask is broadcast once, outside of the shuffles.
+            let mut shuffles = [regs; K];
+            for (shuffles, masks) in shuffles.iter_mut().zip(masks) {
+                for (shuffle, $mask) in shuffles.iter_mut().zip(masks) {
+                    *shuffle = $broadcast;
+                }
+            }
+
+            let mut out = regs;
+            for (out, shuffles) in out.iter_mut().zip(shuffles) {
+                *out = arch::$shuffle_epi8(regs[0], shuffles[0]);
+                for (&reg, &shuffle) in regs[1..].iter().zip(&shuffles[1..]) {
+                    *out = arch::$or(*out, arch::$shuffle_epi8(reg, shuffle));
+                }
+            }
+            out
+        }
+
+        #[inline]
+        #[target_feature(enable = $feature)]
+        fn $deinterleave<const K: usize>(mut regs: [$reg; K], plan: &Plan<K>) -> [$reg; K] {
+            let width = plan.width;
+            if matches!(K, 2 | 4) && width < 16 / K {
+                let group = {
+                    let $mask = &plan.group;
+                    $broadcast
+                };
+                for reg in &mut regs {
+                    *reg = arch::$shuffle_epi8(*reg, group);
+                }
+            }
+
+            match regs.as_mut_slice() {
+                [a, b] => (*a, *b) = $unpack(*a, *b, 8),
+                [a, b, c, d] if width == 8 => {
+                    ((*a, *b), (*c, *d)) = ($unpack(*a, *c, 8), $unpack(*b, *d, 8));
+                }
+                [a, b, c, d] => {
+                    // A transpose of the 4-byte units.
+                    let (t0, t2) = $unpack(*a, *b, 4);
+                    let (t1, t3) = $unpack(*c, *d, 4);
+                    ((*a, *b), (*c, *d)) = ($unpack(t0, t1, 8), $unpack(t2, t3, 8));
+                }
+                _ => return $shuffle(regs, &plan.masks),
+            }
+            regs
+        }
+
+        #[inline]
+        #[target_feature(enable = $feature)]
+        fn $interleave<const K: usize>(mut regs: [$reg; K], plan: &Plan<K>) -> [$reg; K] {
+            let width = plan.width;
+            match regs.as_mut_slice() {
+                [a, b] => (*a, *b) = $unpack(*a, *b, width),
+                [a, b, c, d] => {
+                    let (t0, t2) = $unpack(*a, *b, width);
+                    let (t1, t3) = $unpack(*c, *d, width);
+                    ((*a, *b), (*c, *d)) = if width == 8 {
+                        ((t0, t1), (t2, t3))
+                    } else {
+                        ($unpack(t0, t1, 2 * width), $unpack(t2, t3, 2 * width))
+                    };
+                }
+                _ => return $shuffle(regs, &plan.masks),
+            }
+            regs
+        }
+    };
+}
+
+shuffles! {
+    target_feature: "ssse3";
+    register: __m128i;
+    fn deinterleave_m128i, interleave_m128i, unpack_m128i, shuffle_m128i;
+    broadcast: |mask| super::_mm_loadu_si128(mask);
+    _mm_shuffle_epi8, _mm_or_si128;
+    unpack: [
+        _mm_unpacklo_epi8, _mm_unpackhi_epi8;
+        _mm_unpacklo_epi16, _mm_unpackhi_epi16;
+        _mm_unpacklo_epi32, _mm_unpackhi_epi32;
+        _mm_unpacklo_epi64, _mm_unpackhi_epi64
+    ];
+}
+
+shuffles! {
+    target_feature: "avx2";
+    register: __m256i;
+    fn deinterleave_m256i, interleave_m256i, unpack_m256i, shuffle_m256i;
+    broadcast: |mask| arch::_mm256_broadcastsi128_si256(super::_mm_loadu_si128(mask));
+    _mm256_shuffle_epi8, _mm256_or_si256;
+    unpack: [
+        _mm256_unpacklo_epi8, _mm256_unpackhi_epi8;
+        _mm256_unpacklo_epi16, _mm256_unpackhi_epi16;
+        _mm256_unpacklo_epi32, _mm256_unpackhi_epi32;
+        _mm256_unpacklo_epi64, _mm256_unpackhi_epi64
+    ];
+}
+
+#[cfg(feature = "avx512")]
+shuffles! {
+    target_feature: "avx512bw";
+    register: __m512i;
+    fn deinterleave_m512i, interleave_m512i, unpack_m512i, shuffle_m512i;
+    broadcast: |mask| arch::_mm512_broadcast_i32x4(super::_mm_loadu_si128(mask));
+    _mm512_shuffle_epi8, _mm512_or_si512;
+    unpack: [
+        _mm512_unpacklo_epi8, _mm512_unpackhi_epi8;
+        _mm512_unpacklo_epi16, _mm512_unpackhi_epi16;
+        _mm512_unpacklo_epi32, _mm512_unpackhi_epi32;
+        _mm512_unpacklo_epi64, _mm512_unpackhi_epi64
+    ];
+}
+
+#[inline]
+#[target_feature(enable = "ssse3")]
+fn load_deinterleave_m128i<T: ReadableElement, const N: usize, const K: usize>(
+    from: &[T; N],
+) -> [__m128i; K] {
+    let plan = const { &Plan::<K>::deinterleave(width::<T, N>(K * 16)) };
+    let (chunks, _) = as_bytes(from).as_chunks::<16>();
+
+    let mut regs = [arch::_mm_setzero_si128(); K];
+    for (reg, chunk) in regs.iter_mut().zip(chunks) {
+        *reg = super::_mm_loadu_si128(chunk);
+    }
+    deinterleave_m128i(regs, plan)
+}
+
+#[inline]
+#[target_feature(enable = "ssse3")]
+fn store_interleave_m128i<T: UnalignedElement, const N: usize, const K: usize>(
+    into: &mut [T; N],
+    regs: [__m128i; K],
+) {
+    let plan = const { &Plan::<K>::interleave(width::<T, N>(K * 16)) };
+    let (chunks, _) = as_bytes_mut(into).as_chunks_mut::<16>();
+
+    for (chunk, reg) in chunks.iter_mut().zip(interleave_m128i(regs, plan)) {
+        super::_mm_storeu_si128(chunk, reg);
+    }
+}
+
+// The low 128-bit lanes hold the first `K` chunks and the high lanes the next `K` chunks, so each
+// lane is de-interleaved like a 128-bit register.
+#[inline]
+#[target_feature(enable = "avx2")]
+fn load_deinterleave_m256i<T: ReadableElement, const N: usize, const K: usize>(
+    from: &[T; N],
+) -> [__m256i; K] {
+    let plan = const { &Plan::<K>::deinterleave(width::<T, N>(K * 32)) };
+    let (chunks, _) = as_bytes(from).as_chunks::<16>();
+    let (lo, hi) = chunks.split_at(K);
+
+    let mut regs = [arch::_mm256_setzero_si256(); K];
+    for ((reg, lo), hi) in regs.iter_mut().zip(lo).zip(hi) {
+        *reg = super::_mm256_loadu2_m128i(hi, lo);
+    }
+    deinterleave_m256i(regs, plan)
+}
+
+#[inline]
+#[target_feature(enable = "avx2")]
+fn store_interleave_m256i<T: UnalignedElement, const N: usize, const K: usize>(
+    into: &mut [T; N],
+    regs: [__m256i; K],
+) {
+    let plan = const { &Plan::<K>::interleave(width::<T, N>(K * 32)) };
+    let (chunks, _) = as_bytes_mut(into).as_chunks_mut::<16>();
+    let (lo, hi) = chunks.split_at_mut(K);
+
+    for ((lo, hi), reg) in lo.iter_mut().zip(hi).zip(interleave_m256i(regs, plan)) {
+        super::_mm256_storeu2_m128i(hi, lo, reg);
+    }
+}
+
+// The 128-bit lane `l` of every register holds the chunks `l * K..(l + 1) * K`.
+#[cfg(feature = "avx512")]
+#[inline]
+#[target_feature(enable = "avx512bw")]
+fn load_deinterleave_m512i<T: ReadableElement, const N: usize, const K: usize>(
+    from: &[T; N],
+) -> [__m512i; K] {
+    let plan = const { &Plan::<K>::deinterleave(width::<T, N>(K * 64)) };
+    let (chunks, _) = as_bytes(from).as_chunks::<16>();
+
+    let mut regs = [arch::_mm512_setzero_si512(); K];
+    for (j, reg) in regs.iter_mut().enumerate() {
+        let lo = super::_mm256_loadu2_m128i(&chunks[K + j], &chunks[j]);
+        let hi = super::_mm256_loadu2_m128i(&chunks[3 * K + j], &chunks[2 * K + j]);
+        *reg = arch::_mm512_inserti64x4::<1>(arch::_mm512_castsi256_si512(lo), hi);
+    }
+    deinterleave_m512i(regs, plan)
+}
+
+#[cfg(feature = "avx512")]
+#[inline]
+#[target_feature(enable = "avx512bw")]
+fn store_interleave_m512i<T: UnalignedElement, const N: usize, const K: usize>(
+    into: &mut [T; N],
+    regs: [__m512i; K],
+) {
+    let plan = const { &Plan::<K>::interleave(width::<T, N>(K * 64)) };
+    let (chunks, _) = as_bytes_mut(into).as_chunks_mut::<16>();
+    let (lanes01, lanes23) = chunks.split_at_mut(2 * K);
+    let (lane0, lane1) = lanes01.split_at_mut(K);
+    let (lane2, lane3) = lanes23.split_at_mut(K);
+
+    for (j, reg) in interleave_m512i(regs, plan).into_iter().enumerate() {
+        let lo = arch::_mm512_castsi512_si256(reg);
+        let hi = arch::_mm512_extracti64x4_epi64::<1>(reg);
+        super::_mm256_storeu2_m128i(&mut lane1[j], &mut lane0[j], lo);
+        super::_mm256_storeu2_m128i(&mut lane3[j], &mut lane2[j], hi);
+    }
+}
+
+macro_rules! interleave {
+    (
+        target_feature: $feature:literal;
+        register: $reg:ident, $bits:literal;
+        $(
+            fn $load:ident, $store:ident => $load_impl:ident, $store_impl:ident, $k:literal;
+        )*
+    ) => {
+        $(
+            #[doc = concat!(
+                "Loads ", stringify!($k), " registers of ", stringify!($bits),
+                " bits from interleaved elements, where register `k` holds the elements `k`, `k + ",
+                stringify!($k), "`, `k + 2 * ", stringify!($k), "` and so on.",
+            )]
+            ///
+            /// The element width must be 1, 2, 4 or 8 bytes and the array must cover the registers
+            /// exactly, which is checked at compile time.
+            #[inline]
+            #[target_feature(enable = $feature)]
+            pub fn $load<T: ReadableElement, const N: usize>(from: &[T; N]) -> [$reg; $k] {
+                $load_impl(from)
+            }
+
+            #[doc = concat!(
+                "Stores ", stringify!($k), " registers of ", stringify!($bits),
+                " bits as interleaved elements, the inverse of [`", stringify!($load), "`].",
+            )]
+            ///
+            /// The element width must be 1, 2, 4 or 8 bytes and the array must cover the registers
+            /// exactly, which is checked at compile time.
+            #[inline]
+            #[target_feature(enable = $feature)]
+            pub fn $store<T: UnalignedElement, const N: usize>(into: &mut [T; N], regs: [$reg; $k]) {
+                $store_impl(into, regs)
+            }
+        )*
+    };
+}
+
+interleave! {
+    target_feature: "ssse3";
+    register: __m128i, 128;
+
+    fn load_deinterleave2_m128i, store_interleave2_m128i
+        => load_deinterleave_m128i, store_interleave_m128i, 2;
+    fn load_deinterleave3_m128i, store_interleave3_m128i
+        => load_deinterleave_m128i, store_interleave_m128i, 3;
+    fn load_deinterleave4_m128i, store_interleave4_m128i
+        => load_deinterleave_m128i, store_interleave_m128i, 4;
+}
+
+interleave! {
+    target_feature: "avx2";
+    register: __m256i, 256;
+
+    fn load_deinterleave2_m256i, store_interleave2_m256i
+        => load_deinterleave_m256i, store_interleave_m256i, 2;
+    fn load_deinterleave3_m256i, store_interleave3_m256i
+        => load_deinterleave_m256i, store_interleave_m256i, 3;
+    fn load_deinterleave4_m256i, store_interleave4_m256i
+        => load_deinterleave_m256i, store_interleave_m256i, 4;
+}
+
+#[cfg(feature = "avx512")]
+interleave! {
+    target_feature: "avx512bw";
+    register: __m512i, 512;
+
+    fn load_deinterleave2_m512i, store_interleave2_m512i
+        => load_deinterleave_m512i, store_interleave_m512i, 2;
+    fn load_deinterleave3_m512i, store_interleave3_m512i
+        => load_deinterleave_m512i, store_interleave_m512i, 3;
+    fn load_deinterleave4_m512i, store_interleave4_m512i
+        => load_deinterleave_m512i, store_interleave_m512i, 4;
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::LazyLock;
+
+    use crate::Register;
+
+    // The tests are skipped on CPUs without the instruction set, which includes Miri unless the
+    // target features are enabled at compile time.
+    static CPU_HAS_SSSE3: LazyLock<bool> = LazyLock::new(|| is_x86_feature_detected!("ssse3"));
+    #[cfg(feature = "_avx_test")]
+    static CPU_HAS_AVX2: LazyLock<bool> = LazyLock::new(|| is_x86_feature_detected!("avx2"));
+    #[cfg(feature = "avx512")]
+    static CPU_HAS_AVX512BW: LazyLock<bool> =
+        LazyLock::new(|| is_x86_feature_detected!("avx512bw"));
+
+    // Loads `N` elements counting up from one, checks every register against the scalar
+    // de-interleave and stores the registers back. Shared with the expectations of `vldNq`:
+    // register `k` lane `i` is element `K * i + k`.
+    macro_rules! test_interleave {
+        (
+            $(#[$attr:meta])*
+            fn $testname:ident, $feature:tt if $detected:ident, $reg:ident, $bytes:literal;
+            $($load:ident, $store:ident, $k:literal;)*
+        ) => {
+            #[test]
+            $(#[$attr])*
+            fn $testname() {
+                if !*$detected {
+                    return;
+                }
+                unsafe { test() }
+
+                #[target_feature(enable = $feature)]
+                fn test() {
+                    $(
+                        check!($load, $store, $k, u8, $bytes);
+                        check!($load, $store, $k, i16, $bytes);
+                        check!($load, $store, $k, f32, $bytes);
+                        check!($load, $store, $k, u64, $bytes);
+                    )*
+                }
+            }
+        };
+    }
+
+    macro_rules! check {
+        ($load:ident, $store:ident, $k:literal, $base:ty, $bytes:literal) => {{
+            const LANES: usize = $bytes / size_of::<$base>();
+            const N: usize = LANES * $k;
+
+            let source: [$base; N] = core::array::from_fn(|i| (i + 1) as $base);
+            let regs = super::$load(&source);
+
+            for (k, reg) in regs.iter().enumerate() {
+                let lanes: [$base; LANES] = reg.to_array();
+                let expected: [$base; LANES] = core::array::from_fn(|i| source[$k * i + k]);
+                assert_eq!(lanes, expected, "{} register {k}", stringify!($load));
+            }
+
+            let mut into = [0 as $base; N];
+            super::$store(&mut into, regs);
+            assert_eq!(into, source, "{}", stringify!($store));
+        }};
+    }
+
+    test_interleave! {
+        fn test_interleave_m128i, "ssse3" if CPU_HAS_SSSE3, __m128i, 16;
+        load_deinterleave2_m128i, store_interleave2_m128i, 2;
+        load_deinterleave3_m128i, store_interleave3_m128i, 3;
+        load_deinterleave4_m128i, store_interleave4_m128i, 4;
+    }
+
+    test_interleave! {
+        #[cfg(feature = "_avx_test")]
+        fn test_interleave_m256i, "avx2" if CPU_HAS_AVX2, __m256i, 32;
+        load_deinterleave2_m256i, store_interleave2_m256i, 2;
+        load_deinterleave3_m256i, store_interleave3_m256i, 3;
+        load_deinterleave4_m256i, store_interleave4_m256i, 4;
+    }
+
+    test_interleave! {
+        #[cfg(feature = "avx512")]
+        fn test_interleave_m512i, "avx512bw" if CPU_HAS_AVX512BW, __m512i, 64;
+        load_deinterleave2_m512i, store_interleave2_m512i, 2;
+        load_deinterleave3_m512i, store_interleave3_m512i, 3;
+        load_deinterleave4_m512i, store_interleave4_m512i, 4;
+    }
+
+    #[test]
+    fn test_rgb_pixels() {
+        if !*CPU_HAS_SSSE3 {
+            return;
+        }
+        unsafe { test() }
+
+        #[target_feature(enable = "ssse3")]
+        fn test() {
+            let pixels: [u8; 64] =
+                core::array::from_fn(|i| [(i / 4) as u8, 0x40, 0x80, 0xff][i % 4]);
+            let [r, g, b, a] = super::load_deinterleave4_m128i(&pixels);
+            assert_eq!(r.to_array::<[u8; 16]>(), core::array::from_fn(|i| i as u8));
+            assert_eq!(g.to_array::<[u8; 16]>(), [0x40; 16]);
+            assert_eq!(b.to_array::<[u8; 16]>(), [0x80; 16]);
+            assert_eq!(a.to_array::<[u8; 16]>(), [0xff; 16]);
+        }
+    }
+}
@@ -0,0 +1,319 @@
+//! Functions returning and accepting [`Simd`] vectors, enabled by the `nightly` feature.
+//!
+//! These wrappers perform the load or store of the same name in the parent
+//! module and convert between the platform register and the portable vector,
+//! so `core::simd` code does not need to transmute at the boundary. The
+//! integer variants are generic over the lane type and accept any array with
+//! the register size, such as `[f32; 8]` for a `Simd<f32, 8>`.
+//!
+//! ```rust
+//! #![feature(portable_simd)]
+//! # unsafe { scale() }
+//! use core::simd::Simd;
+//!
+//! #[cfg(target_arch = "x86")]
+//! use safe_unaligned_simd::x86::simd;
+//! #[cfg(target_arch = "x86_64")]
+//! use safe_unaligned_simd::x86_64::simd;
+//!
+//! #[target_feature(enable = "sse2")]
+//! fn scale() {
+//!     let mut a = [1u16, 2, 3, 4, 5, 6, 7, 8];
+//!
+//!     let v = simd::_mm_loadu_si128(&a) * Simd::splat(3);
+//!     simd::_mm_storeu_si128(&mut a, v);
+//!
+//!     assert_eq!(a, [3, 6, 9, 12, 15, 18, 21, 24]);
+//! }
+//! ```
+use core::simd::{Simd, SimdElement};
+
+use crate::common_simd::{from_register, to_register};
+#[cfg(target_arch = "x86")]
+use crate::x86::{Is128BitsReadable, Is128BitsUnaligned, Is256BitsReadable, Is256BitsUnaligned};
+#[cfg(all(target_arch = "x86", feature = "avx512"))]
+use crate::x86::{Is512BitsReadable, Is512BitsUnaligned};
+#[cfg(target_arch = "x86_64")]
+use crate::x86_64::{Is128BitsReadable, Is128BitsUnaligned, Is256BitsReadable, Is256BitsUnaligned};
+#[cfg(all(target_arch = "x86_64", feature = "avx512"))]
+use crate::x86_64::{Is512BitsReadable, Is512BitsUnaligned};
+
+/// Loads four packed single-precision (32-bit) floating-point elements from memory into a vector.
+///
+/// See [`_mm_loadu_ps`](super::_mm_loadu_ps).
+#[inline]
+#[target_feature(enable = "sse")]
+pub fn _mm_loadu_ps(mem_addr: &[f32; 4]) -> Simd<f32, 4> {
+    from_register(super::_mm_loadu_ps(mem_addr))
+}
+
+/// Stores four packed single-precision (32-bit) floating-point elements from a vector into memory.
+///
+/// See [`_mm_storeu_ps`](super::_mm_storeu_ps).
+#[inline]
+#[target_feature(enable = "sse")]
+pub fn _mm_storeu_ps(mem_addr: &mut [f32; 4], a: Simd<f32, 4>) {
+    super::_mm_storeu_ps(mem_addr, to_register(a))
+}
+
+/// Loads two packed double-precision (64-bit) floating-point elements from memory into a vector.
+///
+/// See [`_mm_loadu_pd`](super::_mm_loadu_pd).
+#[inline]
+#[target_feature(enable = "sse2")]
+pub fn _mm_loadu_pd(mem_addr: &[f64; 2]) -> Simd<f64, 2> {
+    from_register(super::_mm_loadu_pd(mem_addr))
+}
+
+/// Stores two packed double-precision (64-bit) floating-point elements from a vector into memory.
+///
+/// See [`_mm_storeu_pd`](super::_mm_storeu_pd).
+#[inline]
+#[target_feature(enable = "sse2")]
+pub fn _mm_storeu_pd(mem_addr: &mut [f64; 2], a: Simd<f64, 2>) {
+    super::_mm_storeu_pd(mem_addr, to_register(a))
+}
+
+/// Loads 128-bits of data from memory into a vector of any lane type.
+///
+/// See [`_mm_loadu_si128`](super::_mm_loadu_si128).
+#[inline]
+#[target_feature(enable = "sse2")]
+pub fn _mm_loadu_si128<T: SimdElement, const N: usize>(mem_addr: &[T; N]) -> Simd<T, N>
+where
+    [T; N]: Is128BitsReadable,
+{
+    from_register(super::_mm_loadu_si128(mem_addr))
+}
+
+/// Stores 128-bits of data from a vector of any lane type into memory.
+///
+/// See [`_mm_storeu_si128`](super::_mm_storeu_si128).
+#[inline]
+#[target_feature(enable = "sse2")]
+pub fn _mm_storeu_si128<T: SimdElement, const N: usize>(mem_addr: &mut [T; N], a: Simd<T, N>)
+where
+    [T; N]: Is128BitsUnaligned,
+{
+    super::_mm_storeu_si128(mem_addr, to_register(a))
+}
+
+/// Loads eight packed single-precision (32-bit) floating-point elements from memory into a vector.
+///
+/// See [`_mm256_loadu_ps`](super::_mm256_loadu_ps).
+#[inline]
+#[target_feature(enable = "avx")]
+pub fn _mm256_loadu_ps(mem_addr: &[f32; 8]) -> Simd<f32, 8> {
+    from_register(super::_mm256_loadu_ps(mem_addr))
+}
+
+/// Stores eight packed single-precision (32-bit) floating-point elements from a vector into memory.
+///
+/// See [`_mm256_storeu_ps`](super::_mm256_storeu_ps).
+#[inline]
+#[target_feature(enable = "avx")]
+pub fn _mm256_storeu_ps(mem_addr: &mut [f32; 8], a: Simd<f32, 8>) {
+    super::_mm256_storeu_ps(mem_addr, to_register(a))
+}
+
+/// Loads four packed double-precision (64-bit) floating-point elements from memory into a vector.
+///
+/// See [`_mm256_loadu_pd`](super::_mm256_loadu_pd).
+#[inline]
+#[target_feature(enable = "avx")]
+pub fn _mm256_loadu_pd(mem_addr: &[f64; 4]) -> Simd<f64, 4> {
+    from_register(super::_mm256_loadu_pd(mem_addr))
+}
+
+/// Stores four packed double-precision (64-bit) floating-point elements from a vector into memory.
+///
+/// See [`_mm256_storeu_pd`](super::_mm256_storeu_pd).
+#[inline]
+#[target_feature(enable = "avx")]
+pub fn _mm256_storeu_pd(mem_addr: &mut [f64; 4], a: Simd<f64, 4>) {
+    super::_mm256_storeu_pd(mem_addr, to_register(a))
+}
+
+/// Loads 256-bits of data from memory into a vector of any lane type.
+///
+/// See [`_mm256_loadu_si256`](super::_mm256_loadu_si256).
+#[inline]
+#[target_feature(enable = "avx")]
+pub fn _mm256_loadu_si256<T: SimdElement, const N: usize>(mem_addr: &[T; N]) -> Simd<T, N>
+where
+    [T; N]: Is256BitsReadable,
+{
+    from_register(super::_mm256_loadu_si256(mem_addr))
+}
+
+/// Stores 256-bits of data from a vector of any lane type into memory.
+///
+/// See [`_mm256_storeu_si256`](super::_mm256_storeu_si256).
+#[inline]
+#[target_feature(enable = "avx")]
+pub fn _mm256_storeu_si256<T: SimdElement, const N: usize>(mem_addr: &mut [T; N], a: Simd<T, N>)
+where
+    [T; N]: Is256BitsUnaligned,
+{
+    super::_mm256_storeu_si256(mem_addr, to_register(a))
+}
+
+/// Loads 16 packed single-precision (32-bit) floating-point elements from memory into a vector.
+///
+/// See [`_mm512_loadu_ps`](super::_mm512_loadu_ps).
+#[inline]
+#[cfg(feature = "avx512")]
+#[target_feature(enable = "avx512f")]
+pub fn _mm512_loadu_ps(mem_addr: &[f32; 16]) -> Simd<f32, 16> {
+    from_register(super::_mm512_loadu_ps(mem_addr))
+}
+
+/// Stores 16 packed single-precision (32-bit) floating-point elements from a vector into memory.
+///
+/// See [`_mm512_storeu_ps`](super::_mm512_storeu_ps).
+#[inline]
+#[cfg(feature = "avx512")]
+#[target_feature(enable = "avx512f")]
+pub fn _mm512_storeu_ps(mem_addr: &mut [f32; 16], a: Simd<f32, 16>) {
+    super::_mm512_storeu_ps(mem_addr, to_register(a))
+}
+
+/// Loads eight packed double-precision (64-bit) floating-point elements from memory into a vector.
+///
+/// See [`_mm512_loadu_pd`](super::_mm512_loadu_pd).
+#[inline]
+#[cfg(feature = "avx512")]
+#[target_feature(enable = "avx512f")]
+pub fn _mm512_loadu_pd(mem_addr: &[f64; 8]) -> Simd<f64, 8> {
+    from_register(super::_mm512_loadu_pd(mem_addr))
+}
+
+/// Stores eight packed double-precision (64-bit) floating-point elements from a vector into memory.
+///
+/// See [`_mm512_storeu_pd`](super::_mm512_storeu_pd).
+#[inline]
+#[cfg(feature = "avx512")]
+#[target_feature(enable = "avx512f")]
+pub fn _mm512_storeu_pd(mem_addr: &mut [f64; 8], a: Simd<f64, 8>) {
+    super::_mm512_storeu_pd(mem_addr, to_register(a))
+}
+
+/// Loads 512-bits of data from memory into a vector of any lane type.
+///
+/// See [`_mm512_loadu_si512`](super::_mm512_loadu_si512).
+#[inline]
+#[cfg(feature = "avx512")]
+#[target_feature(enable = "avx512f")]
+pub fn _mm512_loadu_si512<T: SimdElement, const N: usize>(mem_addr: &[T; N]) -> Simd<T, N>
+where
+    [T; N]: Is512BitsReadable,
+{
+    from_register(super::_mm512_loadu_si512(mem_addr))
+}
+
+/// Stores 512-bits of data from a vector of any lane type into memory.
+///
+/// See [`_mm512_storeu_si512`](super::_mm512_storeu_si512).
+#[inline]
+#[cfg(feature = "avx512")]
+#[target_feature(enable = "avx512f")]
+pub fn _mm512_storeu_si512<T: SimdElement, const N: usize>(mem_addr: &mut [T; N], a: Simd<T, N>)
+where
+    [T; N]: Is512BitsUnaligned,
+{
+    super::_mm512_storeu_si512(mem_addr, to_register(a))
+}
+
+#[cfg(test)]
+mod tests {
+    use core::simd::Simd;
+
+    use crate::common_traits::Is128BitsUnaligned;
+
+    fn is_128<T: Is128BitsUnaligned>() {}
+
+    #[test]
+    fn test_simd_traits() {
+        is_128::<Simd<u8, 16>>();
+        is_128::<Simd<i16, 8>>();
+        is_128::<Simd<f32, 4>>();
+        is_128::<Simd<u64, 2>>();
+    }
+
+    // SAFETY: The `x86_64` target baseline includes `sse` and `sse2`.
+
+    #[test]
+    fn test_mm_loadu_storeu_ps() {
+        unsafe { test() }
+
+        #[target_feature(enable = "sse")]
+        fn test() {
+            let mut a = [1.0f32, 2.0, 3.0, 4.0];
+            let v = super::_mm_loadu_ps(&a);
+            assert_eq!(v, Simd::from_array(a));
+
+            super::_mm_storeu_ps(&mut a, v * Simd::splat(2.0));
+            assert_eq!(a, [2.0, 4.0, 6.0, 8.0]);
+        }
+    }
+
+    #[test]
+    fn test_mm_loadu_storeu_pd() {
+        unsafe { test() }
+
+        #[target_feature(enable = "sse2")]
+        fn test() {
+            let mut a = [1.0f64, 2.0];
+            let v = super::_mm_loadu_pd(&a);
+            assert_eq!(v, Simd::from_array(a));
+
+            super::_mm_storeu_pd(&mut a, v + Simd::splat(0.5));
+            assert_eq!(a, [1.5, 2.5]);
+        }
+    }
+
+    #[test]
+    fn test_mm_loadu_storeu_si128() {
+        unsafe { test() }
+
+        #[target_feature(enable = "sse2")]
+        fn test() {
+            let a: [u8; 16] = core::array::from_fn(|i| i as u8);
+            let v = super::_mm_loadu_si128(&a);
+            assert_eq!(v, Simd::from_array(a));
+
+            let mut b = [0i32; 4];
+            super::_mm_storeu_si128(&mut b, Simd::from_array([-1, 0, 1, 2]));
+            assert_eq!(b, [-1, 0, 1, 2]);
+
+            let c = super::_mm_loadu_si128(&[0.5f32, 1.5, 2.5, 3.5]);
+            assert_eq!(c, Simd::from_array([0.5, 1.5, 2.5, 3.5]));
+        }
+    }
+
+    #[cfg(feature = "_avx_test")]
+    #[test]
+    fn test_mm256_loadu_storeu() {
+        assert!(is_x86_feature_detected!("avx"));
+        unsafe { test() }
+
+        #[target_feature(enable = "avx")]
+        fn test() {
+            let mut a: [f32; 8] = core::array::from_fn(|i| i as f32);
+            let v = super::_mm256_loadu_ps(&a);
+            assert_eq!(v, Simd::from_array(a));
+            super::_mm256_storeu_ps(&mut a, -v);
+            assert_eq!(a, core::array::from_fn(|i| -(i as f32)));
+
+            let mut b = [1.0f64, 2.0, 3.0, 4.0];
+            let v = super::_mm256_loadu_pd(&b);
+            super::_mm256_storeu_pd(&mut b, v.reverse());
+            assert_eq!(b, [4.0, 3.0, 2.0, 1.0]);
+
+            let mut c: [u16; 16] = core::array::from_fn(|i| i as u16);
+            let v = super::_mm256_loadu_si256(&c);
+            super::_mm256_storeu_si256(&mut c, v << 1);
+            assert_eq!(c, core::array::from_fn(|i| 2 * i as u16));
+        }
+    }
+}
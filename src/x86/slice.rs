@@ -0,0 +1,1154 @@
+//! Slice-taking companions of the full-register loads and stores.
+//!
+//! For each full-register `loadu` and `storeu` wrapper of the parent module,
+//! `_mm*_loadu_{ps,pd,si*}` and `_mm*_storeu_{ps,pd,si*}`, this module
+//! provides three entry points that access the start of a slice instead of an
+//! array reference:
+//!
+//! - `try_*` returns `None` if the slice is too short,
+//! - `*_slice` panics if the slice is too short,
+//! - `*_at` accesses `slice[idx..]` and panics if that is too short.
+//!
+//! The float wrappers take slices of `f32` or `f64`. The integer wrappers are
+//! generic over the element type, bounded by [`ReadableElement`] for loads and
+//! [`UnalignedElement`] for stores, and check the length of the slice in
+//! bytes. Either way the length check is a single comparison. The masked,
+//! broadcast and half-width loads have no slice companions.
+//!
+//! ```rust
+//! # unsafe { sum_pairs() }
+//! #[cfg(target_arch = "x86")]
+//! use safe_unaligned_simd::x86::slice;
+//! #[cfg(target_arch = "x86_64")]
+//! use safe_unaligned_simd::x86_64::slice;
+//! #[cfg(target_arch = "x86")]
+//! use core::arch::x86::_mm_add_epi16;
+//! #[cfg(target_arch = "x86_64")]
+//! use core::arch::x86_64::_mm_add_epi16;
+//!
+//! #[target_feature(enable = "sse2")]
+//! fn sum_pairs() {
+//!     let data: Vec<u16> = (0..20).collect();
+//!     let mut out = [0u16; 8];
+//!
+//!     let a = slice::_mm_loadu_si128_slice(&data);
+//!     let b = slice::_mm_loadu_si128_at(&data, 8);
+//!     slice::_mm_storeu_si128_slice(&mut out, _mm_add_epi16(a, b));
+//!
+//!     assert_eq!(out, [8, 10, 12, 14, 16, 18, 20, 22]);
+//!     assert!(slice::try_mm_loadu_si128(&data[16..]).is_none());
+//! }
+//! ```
+//!
+//! The `chunks_*` functions iterate over the registers loaded from
+//! consecutive chunks of a slice and expose the remainder, like
+//! [`slice::chunks_exact`](prim@slice#method.chunks_exact). Their `_mut`
+//! counterparts yield a [`ChunkMut`] per chunk, which is loaded from and
+//! stored back in place.
+//!
+//! ```rust
+//! # unsafe { add_one() }
+//! #[cfg(target_arch = "x86")]
+//! use safe_unaligned_simd::x86::slice;
+//! #[cfg(target_arch = "x86_64")]
+//! use safe_unaligned_simd::x86_64::slice;
+//! #[cfg(target_arch = "x86")]
+//! use core::arch::x86::{_mm_add_epi8, _mm_set1_epi8};
+//! #[cfg(target_arch = "x86_64")]
+//! use core::arch::x86_64::{_mm_add_epi8, _mm_set1_epi8};
+//!
+//! #[target_feature(enable = "sse2")]
+//! fn add_one() {
+//!     let mut data = [7u8; 40];
+//!
+//!     let mut chunks = slice::chunks_m128i_mut(&mut data);
+//!     for mut chunk in &mut chunks {
+//!         chunk.update(|v| _mm_add_epi8(v, _mm_set1_epi8(1)));
+//!     }
+//!     for b in chunks.into_remainder() {
+//!         *b += 1;
+//!     }
+//!
+//!     assert_eq!(data, [8; 40]);
+//! }
+//! ```
+//!
+//! The `*_partial` functions load the first elements of a slice that may be
+//! shorter than a register and fill the remaining lanes with zero, or with the
+//! lanes of another register for the `*_partial_fill` variants. The stores
+//! write only as many elements as the slice holds. Slices of fewer elements
+//! are read and written with a few overlapping scalar accesses, as there are
+//! no masked loads before AVX-512.
+//!
+//! ```rust
+//! # unsafe { sum() }
+//! #[cfg(target_arch = "x86")]
+//! use safe_unaligned_simd::x86::slice;
+//! #[cfg(target_arch = "x86_64")]
+//! use safe_unaligned_simd::x86_64::slice;
+//! #[cfg(target_arch = "x86")]
+//! use core::arch::x86::{_mm_add_ps, _mm_setzero_ps};
+//! #[cfg(target_arch = "x86_64")]
+//! use core::arch::x86_64::{_mm_add_ps, _mm_setzero_ps};
+//!
+//! #[target_feature(enable = "sse2")]
+//! fn sum() {
+//!     let data: Vec<f32> = (1..=10).map(|i| i as f32).collect();
+//!
+//!     let mut chunks = slice::chunks_m128(&data);
+//!     let mut acc = (&mut chunks).fold(_mm_setzero_ps(), |acc, v| _mm_add_ps(acc, v));
+//!     acc = _mm_add_ps(acc, slice::_mm_loadu_ps_partial(chunks.remainder()));
+//!
+//!     let mut lanes = [0.0; 4];
+//!     slice::_mm_storeu_ps_slice(&mut lanes, acc);
+//!     assert_eq!(lanes.iter().sum::<f32>(), 55.0);
+//! }
+//! ```
+//!
+//! For idempotent kernels the fastest way to handle the tail is often to
+//! process one final window that ends at the end of the slice and overlaps
+//! the previous register. The `for_each_overlapping_*` and
+//! `map_overlapping_*` functions drive such kernels.
+//!
+//! ```rust
+//! # unsafe { uppercase() }
+//! #[cfg(target_arch = "x86")]
+//! use safe_unaligned_simd::x86::slice;
+//! #[cfg(target_arch = "x86_64")]
+//! use safe_unaligned_simd::x86_64::slice;
+//! #[cfg(target_arch = "x86")]
+//! use core::arch::x86::*;
+//! #[cfg(target_arch = "x86_64")]
+//! use core::arch::x86_64::*;
+//!
+//! #[target_feature(enable = "sse2")]
+//! fn uppercase() {
+//!     let mut text = *b"the quick brown fox jumps over the lazy dog";
+//!
+//!     slice::map_overlapping_m128i(&mut text, |v| {
+//!         let above_a = _mm_cmpgt_epi8(v, _mm_set1_epi8(b'a' as i8 - 1));
+//!         let below_z = _mm_cmplt_epi8(v, _mm_set1_epi8(b'z' as i8 + 1));
+//!         let lower = _mm_and_si128(above_a, below_z);
+//!         _mm_sub_epi8(v, _mm_and_si128(lower, _mm_set1_epi8(0x20)))
+//!     });
+//!
+//!     assert_eq!(text, *b"THE QUICK BROWN FOX JUMPS OVER THE LAZY DOG");
+//! }
+//! ```
+use core::cell::Cell;
+
+#[cfg(target_arch = "x86")]
+use core::arch::x86::{self as arch, __m128, __m128d, __m128i, __m256, __m256d, __m256i};
+#[cfg(all(target_arch = "x86", feature = "avx512"))]
+use core::arch::x86::{__m512, __m512d, __m512i};
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::{self as arch, __m128, __m128d, __m128i, __m256, __m256d, __m256i};
+#[cfg(all(target_arch = "x86_64", feature = "avx512"))]
+use core::arch::x86_64::{__m512, __m512d, __m512i};
+
+pub use crate::common_slice::{ChunkMut, Chunks, ChunksMut};
+use crate::common_slice::{
+    LoadChunk, StoreChunk, as_bytes, as_bytes_mut, bytes, bytes_mut, chunk, chunk_mut, elements,
+    first_bytes, first_bytes_mut, too_short, too_short_bytes,
+};
+#[cfg(target_arch = "x86")]
+use crate::x86::{ReadableElement, UnalignedElement};
+#[cfg(target_arch = "x86_64")]
+use crate::x86_64::{ReadableElement, UnalignedElement};
+
+// Generates the three slice entry points for one wrapper of the parent module. The basic
+// structure is:
+//
+//   "sse" load fn _mm_loadu_ps(&[f32; 4]) -> __m128 => try_mm_loadu_ps, _mm_loadu_ps_slice, _mm_loadu_ps_at;
+//
+// where `&[f32; 4]` is the array taken by the parent wrapper and the names are those of the `try_`,
+// panicking and offset variants. Generic integer wrappers are written as `&[_; 16 bytes]` instead
+// and view the slice as a byte array.
+macro_rules! slice_wrappers {
+    ($(
+        $(#[$attr:meta])*
+        $feature:literal $kind:ident fn $intrinsic:ident $args:tt $(-> $ret:ty)?
+            => $try:ident, $slice:ident, $at:ident;
+    )*) => {
+        $(
+            slice_wrappers!(
+                @ $kind $(#[$attr])* $feature $intrinsic $args $(-> $ret)? => $try, $slice, $at
+            );
+        )*
+    };
+
+    (@ load $(#[$attr:meta])* $feature:literal $intrinsic:ident (&[$elem:ty; $n:literal]) -> $ret:ty
+        => $try:ident, $slice:ident, $at:ident
+    ) => {
+        #[doc = concat!("Loads from the start of `mem_addr` with [`", stringify!($intrinsic), "`](super::", stringify!($intrinsic), ").")]
+        ///
+        #[doc = concat!("Returns `None` if the slice has fewer than ", stringify!($n), " elements.")]
+        #[inline]
+        $(#[$attr])*
+        #[target_feature(enable = $feature)]
+        pub fn $try(mem_addr: &[$elem]) -> Option<$ret> {
+            let chunk = mem_addr.first_chunk::<$n>()?;
+            Some(super::$intrinsic(chunk))
+        }
+
+        #[doc = concat!("Loads from the start of `mem_addr` with [`", stringify!($intrinsic), "`](super::", stringify!($intrinsic), ").")]
+        ///
+        /// # Panics
+        ///
+        #[doc = concat!("Panics if the slice has fewer than ", stringify!($n), " elements.")]
+        #[inline]
+        #[track_caller]
+        $(#[$attr])*
+        #[target_feature(enable = $feature)]
+        pub fn $slice(mem_addr: &[$elem]) -> $ret {
+            match mem_addr.first_chunk::<$n>() {
+                Some(chunk) => super::$intrinsic(chunk),
+                None => too_short($n, mem_addr.len()),
+            }
+        }
+
+        #[doc = concat!("Loads from `mem_addr[idx..]` with [`", stringify!($intrinsic), "`](super::", stringify!($intrinsic), ").")]
+        ///
+        /// # Panics
+        ///
+        #[doc = concat!("Panics if `mem_addr[idx..]` has fewer than ", stringify!($n), " elements.")]
+        #[inline]
+        #[track_caller]
+        $(#[$attr])*
+        #[target_feature(enable = $feature)]
+        pub fn $at(mem_addr: &[$elem], idx: usize) -> $ret {
+            match mem_addr.get(idx..).and_then(<[$elem]>::first_chunk::<$n>) {
+                Some(chunk) => super::$intrinsic(chunk),
+                None => too_short(idx.saturating_add($n), mem_addr.len()),
+            }
+        }
+    };
+
+    (@ load $(#[$attr:meta])* $feature:literal $intrinsic:ident (&[_; $n:literal bytes]) -> $ret:ty
+        => $try:ident, $slice:ident, $at:ident
+    ) => {
+        #[doc = concat!("Loads from the start of `mem_addr` with [`", stringify!($intrinsic), "`](super::", stringify!($intrinsic), ").")]
+        ///
+        #[doc = concat!("Returns `None` if the slice covers fewer than ", stringify!($n), " bytes.")]
+        #[inline]
+        $(#[$attr])*
+        #[target_feature(enable = $feature)]
+        pub fn $try<T: ReadableElement>(mem_addr: &[T]) -> Option<$ret> {
+            let bytes = first_bytes::<T, $n>(mem_addr)?;
+            Some(super::$intrinsic(bytes))
+        }
+
+        #[doc = concat!("Loads from the start of `mem_addr` with [`", stringify!($intrinsic), "`](super::", stringify!($intrinsic), ").")]
+        ///
+        /// # Panics
+        ///
+        #[doc = concat!("Panics if the slice covers fewer than ", stringify!($n), " bytes.")]
+        #[inline]
+        #[track_caller]
+        $(#[$attr])*
+        #[target_feature(enable = $feature)]
+        pub fn $slice<T: ReadableElement>(mem_addr: &[T]) -> $ret {
+            match first_bytes::<T, $n>(mem_addr) {
+                Some(bytes) => super::$intrinsic(bytes),
+                None => too_short_bytes($n, size_of_val(mem_addr)),
+            }
+        }
+
+        #[doc = concat!("Loads from `mem_addr[idx..]` with [`", stringify!($intrinsic), "`](super::", stringify!($intrinsic), ").")]
+        ///
+        /// # Panics
+        ///
+        #[doc = concat!("Panics if `idx` is out of bounds or `mem_addr[idx..]` covers fewer than ", stringify!($n), " bytes.")]
+        #[inline]
+        #[track_caller]
+        $(#[$attr])*
+        #[target_feature(enable = $feature)]
+        pub fn $at<T: ReadableElement>(mem_addr: &[T], idx: usize) -> $ret {
+            $slice(&mem_addr[idx..])
+        }
+    };
+
+    (@ store $(#[$attr:meta])* $feature:literal $intrinsic:ident (&mut [$elem:ty; $n:literal], $reg:ty)
+        => $try:ident, $slice:ident, $at:ident
+    ) => {
+        #[doc = concat!("Stores to the start of `mem_addr` with [`", stringify!($intrinsic), "`](super::", stringify!($intrinsic), ").")]
+        ///
+        #[doc = concat!("Returns `None` without storing if the slice has fewer than ", stringify!($n), " elements.")]
+        #[inline]
+        $(#[$attr])*
+        #[target_feature(enable = $feature)]
+        pub fn $try(mem_addr: &mut [$elem], a: $reg) -> Option<()> {
+            let chunk = mem_addr.first_chunk_mut::<$n>()?;
+            Some(super::$intrinsic(chunk, a))
+        }
+
+        #[doc = concat!("Stores to the start of `mem_addr` with [`", stringify!($intrinsic), "`](super::", stringify!($intrinsic), ").")]
+        ///
+        /// # Panics
+        ///
+        #[doc = concat!("Panics if the slice has fewer than ", stringify!($n), " elements.")]
+        #[inline]
+        #[track_caller]
+        $(#[$attr])*
+        #[target_feature(enable = $feature)]
+        pub fn $slice(mem_addr: &mut [$elem], a: $reg) {
+            let len = mem_addr.len();
+            match mem_addr.first_chunk_mut::<$n>() {
+                Some(chunk) => super::$intrinsic(chunk, a),
+                None => too_short($n, len),
+            }
+        }
+
+        #[doc = concat!("Stores to `mem_addr[idx..]` with [`", stringify!($intrinsic), "`](super::", stringify!($intrinsic), ").")]
+        ///
+        /// # Panics
+        ///
+        #[doc = concat!("Panics if `mem_addr[idx..]` has fewer than ", stringify!($n), " elements.")]
+        #[inline]
+        #[track_caller]
+        $(#[$attr])*
+        #[target_feature(enable = $feature)]
+        pub fn $at(mem_addr: &mut [$elem], idx: usize, a: $reg) {
+            let len = mem_addr.len();
+            match mem_addr.get_mut(idx..).and_then(<[$elem]>::first_chunk_mut::<$n>) {
+                Some(chunk) => super::$intrinsic(chunk, a),
+                None => too_short(idx.saturating_add($n), len),
+            }
+        }
+    };
+
+    (@ store $(#[$attr:meta])* $feature:literal $intrinsic:ident (&mut [_; $n:literal bytes], $reg:ty)
+        => $try:ident, $slice:ident, $at:ident
+    ) => {
+        #[doc = concat!("Stores to the start of `mem_addr` with [`", stringify!($intrinsic), "`](super::", stringify!($intrinsic), ").")]
+        ///
+        #[doc = concat!("Returns `None` without storing if the slice covers fewer than ", stringify!($n), " bytes.")]
+        #[inline]
+        $(#[$attr])*
+        #[target_feature(enable = $feature)]
+        pub fn $try<T: UnalignedElement>(mem_addr: &mut [T], a: $reg) -> Option<()> {
+            let bytes = first_bytes_mut::<T, $n>(mem_addr)?;
+            Some(super::$intrinsic(bytes, a))
+        }
+
+        #[doc = concat!("Stores to the start of `mem_addr` with [`", stringify!($intrinsic), "`](super::", stringify!($intrinsic), ").")]
+        ///
+        /// # Panics
+        ///
+        #[doc = concat!("Panics if the slice covers fewer than ", stringify!($n), " bytes.")]
+        #[inline]
+        #[track_caller]
+        $(#[$attr])*
+        #[target_feature(enable = $feature)]
+        pub fn $slice<T: UnalignedElement>(mem_addr: &mut [T], a: $reg) {
+            let len = size_of_val(mem_addr);
+            match first_bytes_mut::<T, $n>(mem_addr) {
+                Some(bytes) => super::$intrinsic(bytes, a),
+                None => too_short_bytes($n, len),
+            }
+        }
+
+        #[doc = concat!("Stores to `mem_addr[idx..]` with [`", stringify!($intrinsic), "`](super::", stringify!($intrinsic), ").")]
+        ///
+        /// # Panics
+        ///
+        #[doc = concat!("Panics if `idx` is out of bounds or `mem_addr[idx..]` covers fewer than ", stringify!($n), " bytes.")]
+        #[inline]
+        #[track_caller]
+        $(#[$attr])*
+        #[target_feature(enable = $feature)]
+        pub fn $at<T: UnalignedElement>(mem_addr: &mut [T], idx: usize, a: $reg) {
+            $slice(&mut mem_addr[idx..], a)
+        }
+    };
+}
+
+slice_wrappers! {
+    "sse" load fn _mm_loadu_ps(&[f32; 4]) -> __m128
+        => try_mm_loadu_ps, _mm_loadu_ps_slice, _mm_loadu_ps_at;
+    "sse" store fn _mm_storeu_ps(&mut [f32; 4], __m128)
+        => try_mm_storeu_ps, _mm_storeu_ps_slice, _mm_storeu_ps_at;
+
+    "sse2" load fn _mm_loadu_pd(&[f64; 2]) -> __m128d
+        => try_mm_loadu_pd, _mm_loadu_pd_slice, _mm_loadu_pd_at;
+    "sse2" store fn _mm_storeu_pd(&mut [f64; 2], __m128d)
+        => try_mm_storeu_pd, _mm_storeu_pd_slice, _mm_storeu_pd_at;
+    "sse2" load fn _mm_loadu_si128(&[_; 16 bytes]) -> __m128i
+        => try_mm_loadu_si128, _mm_loadu_si128_slice, _mm_loadu_si128_at;
+    "sse2" store fn _mm_storeu_si128(&mut [_; 16 bytes], __m128i)
+        => try_mm_storeu_si128, _mm_storeu_si128_slice, _mm_storeu_si128_at;
+
+    "avx" load fn _mm256_loadu_ps(&[f32; 8]) -> __m256
+        => try_mm256_loadu_ps, _mm256_loadu_ps_slice, _mm256_loadu_ps_at;
+    "avx" store fn _mm256_storeu_ps(&mut [f32; 8], __m256)
+        => try_mm256_storeu_ps, _mm256_storeu_ps_slice, _mm256_storeu_ps_at;
+    "avx" load fn _mm256_loadu_pd(&[f64; 4]) -> __m256d
+        => try_mm256_loadu_pd, _mm256_loadu_pd_slice, _mm256_loadu_pd_at;
+    "avx" store fn _mm256_storeu_pd(&mut [f64; 4], __m256d)
+        => try_mm256_storeu_pd, _mm256_storeu_pd_slice, _mm256_storeu_pd_at;
+    "avx" load fn _mm256_loadu_si256(&[_; 32 bytes]) -> __m256i
+        => try_mm256_loadu_si256, _mm256_loadu_si256_slice, _mm256_loadu_si256_at;
+    "avx" store fn _mm256_storeu_si256(&mut [_; 32 bytes], __m256i)
+        => try_mm256_storeu_si256, _mm256_storeu_si256_slice, _mm256_storeu_si256_at;
+
+    #[cfg(feature = "avx512")]
+    "avx512f" load fn _mm512_loadu_ps(&[f32; 16]) -> __m512
+        => try_mm512_loadu_ps, _mm512_loadu_ps_slice, _mm512_loadu_ps_at;
+    #[cfg(feature = "avx512")]
+    "avx512f" store fn _mm512_storeu_ps(&mut [f32; 16], __m512)
+        => try_mm512_storeu_ps, _mm512_storeu_ps_slice, _mm512_storeu_ps_at;
+    #[cfg(feature = "avx512")]
+    "avx512f" load fn _mm512_loadu_pd(&[f64; 8]) -> __m512d
+        => try_mm512_loadu_pd, _mm512_loadu_pd_slice, _mm512_loadu_pd_at;
+    #[cfg(feature = "avx512")]
+    "avx512f" store fn _mm512_storeu_pd(&mut [f64; 8], __m512d)
+        => try_mm512_storeu_pd, _mm512_storeu_pd_slice, _mm512_storeu_pd_at;
+    #[cfg(feature = "avx512")]
+    "avx512f" load fn _mm512_loadu_si512(&[_; 64 bytes]) -> __m512i
+        => try_mm512_loadu_si512, _mm512_loadu_si512_slice, _mm512_loadu_si512_at;
+    #[cfg(feature = "avx512")]
+    "avx512f" store fn _mm512_storeu_si512(&mut [_; 64 bytes], __m512i)
+        => try_mm512_storeu_si512, _mm512_storeu_si512_slice, _mm512_storeu_si512_at;
+}
+
+// Generates the chunk iterators for one register. The basic structure is:
+//
+//   "sse" __m128 from [f32; 4] by _mm_loadu_ps, _mm_storeu_ps => chunks_m128, chunks_m128_mut;
+//
+// where the loads and stores of the parent module move the register from and to chunks of the
+// array type. Generic integer registers are written as `[_; 16 bytes]` like above.
+macro_rules! chunk_iterators {
+    ($(
+        $(#[$attr:meta])*
+        $feature:literal $reg:ident from [$($chunk:tt)*] by $load:ident, $store:ident
+            => $chunks:ident, $chunks_mut:ident;
+    )*) => {
+        $(
+            chunk_iterators!(
+                @ $(#[$attr])* $feature $reg [$($chunk)*] $load $store => $chunks, $chunks_mut
+            );
+        )*
+    };
+
+    (@ $(#[$attr:meta])* $feature:literal $reg:ident [$elem:ty; $n:literal] $load:ident $store:ident
+        => $chunks:ident, $chunks_mut:ident
+    ) => {
+        $(#[$attr])*
+        impl LoadChunk<$elem> for $reg {
+            const LEN: usize = $n;
+
+            #[inline(always)]
+            unsafe fn load(mem_addr: &[$elem]) -> Self {
+                unsafe { super::$load(chunk::<_, $n>(mem_addr)) }
+            }
+        }
+
+        $(#[$attr])*
+        impl StoreChunk<$elem> for $reg {
+            #[inline(always)]
+            unsafe fn store(mem_addr: &mut [$elem], a: Self) {
+                unsafe { super::$store(chunk_mut::<_, $n>(mem_addr), a) }
+            }
+        }
+
+        #[doc = concat!("Iterates over the `", stringify!($reg), "` registers loaded from chunks of ", stringify!($n), " elements of `mem_addr` with [`", stringify!($load), "`](super::", stringify!($load), ").")]
+        ///
+        /// The elements that do not fill a register are available from
+        /// [`Chunks::remainder`].
+        #[inline]
+        $(#[$attr])*
+        #[target_feature(enable = $feature)]
+        pub fn $chunks(mem_addr: &[$elem]) -> Chunks<'_, $elem, $reg> {
+            // Safety: The target feature is enabled.
+            unsafe { Chunks::new(mem_addr) }
+        }
+
+        #[doc = concat!("Iterates over chunks of ", stringify!($n), " elements of `mem_addr` that a `", stringify!($reg), "` register is loaded from and stored to with [`", stringify!($load), "`](super::", stringify!($load), ") and [`", stringify!($store), "`](super::", stringify!($store), ").")]
+        ///
+        /// The elements that do not fill a register are available from
+        /// [`ChunksMut::into_remainder`].
+        #[inline]
+        $(#[$attr])*
+        #[target_feature(enable = $feature)]
+        pub fn $chunks_mut(mem_addr: &mut [$elem]) -> ChunksMut<'_, $elem, $reg> {
+            // Safety: The target feature is enabled.
+            unsafe { ChunksMut::new(mem_addr) }
+        }
+    };
+
+    (@ $(#[$attr:meta])* $feature:literal $reg:ident [_; $n:literal bytes] $load:ident $store:ident
+        => $chunks:ident, $chunks_mut:ident
+    ) => {
+        $(#[$attr])*
+        impl<T: ReadableElement> LoadChunk<T> for $reg {
+            const LEN: usize = elements::<T>($n);
+
+            #[inline(always)]
+            unsafe fn load(mem_addr: &[T]) -> Self {
+                unsafe { super::$load(bytes::<T, $n>(mem_addr)) }
+            }
+        }
+
+        $(#[$attr])*
+        impl<T: UnalignedElement> StoreChunk<T> for $reg {
+            #[inline(always)]
+            unsafe fn store(mem_addr: &mut [T], a: Self) {
+                unsafe { super::$store(bytes_mut::<T, $n>(mem_addr), a) }
+            }
+        }
+
+        #[doc = concat!("Iterates over the `", stringify!($reg), "` registers loaded from chunks of ", stringify!($n), " bytes of `mem_addr` with [`", stringify!($load), "`](super::", stringify!($load), ").")]
+        ///
+        /// The elements that do not fill a register are available from
+        /// [`Chunks::remainder`]. The size of `T` must divide the size of the
+        /// register, which is checked at compile time.
+        #[inline]
+        $(#[$attr])*
+        #[target_feature(enable = $feature)]
+        pub fn $chunks<T: ReadableElement>(mem_addr: &[T]) -> Chunks<'_, T, $reg> {
+            // Safety: The target feature is enabled.
+            unsafe { Chunks::new(mem_addr) }
+        }
+
+        #[doc = concat!("Iterates over chunks of ", stringify!($n), " bytes of `mem_addr` that a `", stringify!($reg), "` register is loaded from and stored to with [`", stringify!($load), "`](super::", stringify!($load), ") and [`", stringify!($store), "`](super::", stringify!($store), ").")]
+        ///
+        /// The elements that do not fill a register are available from
+        /// [`ChunksMut::into_remainder`]. The size of `T` must divide the size
+        /// of the register, which is checked at compile time.
+        #[inline]
+        $(#[$attr])*
+        #[target_feature(enable = $feature)]
+        pub fn $chunks_mut<T: UnalignedElement>(mem_addr: &mut [T]) -> ChunksMut<'_, T, $reg> {
+            // Safety: The target feature is enabled.
+            unsafe { ChunksMut::new(mem_addr) }
+        }
+    };
+}
+
+chunk_iterators! {
+    "sse" __m128 from [f32; 4] by _mm_loadu_ps, _mm_storeu_ps
+        => chunks_m128, chunks_m128_mut;
+    "sse2" __m128d from [f64; 2] by _mm_loadu_pd, _mm_storeu_pd
+        => chunks_m128d, chunks_m128d_mut;
+    "sse2" __m128i from [_; 16 bytes] by _mm_loadu_si128, _mm_storeu_si128
+        => chunks_m128i, chunks_m128i_mut;
+
+    "avx" __m256 from [f32; 8] by _mm256_loadu_ps, _mm256_storeu_ps
+        => chunks_m256, chunks_m256_mut;
+    "avx" __m256d from [f64; 4] by _mm256_loadu_pd, _mm256_storeu_pd
+        => chunks_m256d, chunks_m256d_mut;
+    "avx" __m256i from [_; 32 bytes] by _mm256_loadu_si256, _mm256_storeu_si256
+        => chunks_m256i, chunks_m256i_mut;
+
+    #[cfg(feature = "avx512")]
+    "avx512f" __m512 from [f32; 16] by _mm512_loadu_ps, _mm512_storeu_ps
+        => chunks_m512, chunks_m512_mut;
+    #[cfg(feature = "avx512")]
+    "avx512f" __m512d from [f64; 8] by _mm512_loadu_pd, _mm512_storeu_pd
+        => chunks_m512d, chunks_m512d_mut;
+    #[cfg(feature = "avx512")]
+    "avx512f" __m512i from [_; 64 bytes] by _mm512_loadu_si512, _mm512_storeu_si512
+        => chunks_m512i, chunks_m512i_mut;
+}
+
+/// Loads the first 16 bytes of `mem_addr`, filling the lanes past its end with zero.
+///
+/// Slices of 16 bytes or more are loaded with [`_mm_loadu_si128`](super::_mm_loadu_si128), shorter
+/// ones with [`_mm_loadu_si64`](super::_mm_loadu_si64) and two overlapping
+/// [`_mm_loadu_si32`](super::_mm_loadu_si32) or [`_mm_loadu_si16`](super::_mm_loadu_si16) loads.
+#[inline]
+#[target_feature(enable = "sse2")]
+pub fn _mm_loadu_si128_partial<T: ReadableElement>(mem_addr: &[T]) -> __m128i {
+    let bytes = as_bytes(mem_addr);
+    if let Some(bytes) = bytes.first_chunk::<16>() {
+        return super::_mm_loadu_si128(bytes);
+    }
+
+    match bytes.split_first_chunk::<8>() {
+        Some((lo, hi)) => arch::_mm_unpacklo_epi64(super::_mm_loadu_si64(lo), loadu_si64_tail(hi)),
+        None => loadu_si64_tail(bytes),
+    }
+}
+
+/// Loads fewer than 8 bytes into the low lanes of a register, zeroing the others.
+#[inline]
+#[target_feature(enable = "sse2")]
+fn loadu_si64_tail(bytes: &[u8]) -> __m128i {
+    debug_assert!(bytes.len() < 8);
+    // The two loads overlap for fewer than 8 or 4 bytes, in which case the shared bytes agree.
+    // Shifting the upper load within the low 64-bit lane moves it to its offset.
+    let shift = |width: usize| arch::_mm_cvtsi32_si128(8 * (bytes.len() - width) as i32);
+    if let (Some(lo), Some(hi)) = (bytes.first_chunk::<4>(), bytes.last_chunk::<4>()) {
+        let hi = arch::_mm_sll_epi64(super::_mm_loadu_si32(hi), shift(4));
+        arch::_mm_or_si128(super::_mm_loadu_si32(lo), hi)
+    } else if let (Some(lo), Some(hi)) = (bytes.first_chunk::<2>(), bytes.last_chunk::<2>()) {
+        let hi = arch::_mm_sll_epi64(super::_mm_loadu_si16(hi), shift(2));
+        arch::_mm_or_si128(super::_mm_loadu_si16(lo), hi)
+    } else if let Some(&byte) = bytes.first() {
+        arch::_mm_cvtsi32_si128(i32::from(byte))
+    } else {
+        arch::_mm_setzero_si128()
+    }
+}
+
+/// Loads the first 16 bytes of `mem_addr`, taking the bytes past its end from `fill`.
+///
+/// See [`_mm_loadu_si128_partial`].
+#[inline]
+#[target_feature(enable = "sse2")]
+pub fn _mm_loadu_si128_partial_fill<T: ReadableElement>(mem_addr: &[T], fill: __m128i) -> __m128i {
+    if let Some(bytes) = as_bytes(mem_addr).first_chunk::<16>() {
+        return super::_mm_loadu_si128(bytes);
+    }
+
+    let len = size_of_val(mem_addr) as i8;
+    let lanes = arch::_mm_setr_epi8(0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15);
+    let loaded = arch::_mm_cmplt_epi8(lanes, arch::_mm_set1_epi8(len));
+    arch::_mm_or_si128(
+        _mm_loadu_si128_partial(mem_addr),
+        arch::_mm_andnot_si128(loaded, fill),
+    )
+}
+
+/// Stores the low bytes of `a` to `mem_addr`, as many as it holds up to 16.
+///
+/// Slices of 16 bytes or more are stored to with [`_mm_storeu_si128`](super::_mm_storeu_si128),
+/// shorter ones with [`_mm_storeu_si64`](super::_mm_storeu_si64) and two overlapping
+/// [`_mm_storeu_si32`](super::_mm_storeu_si32) or [`_mm_storeu_si16`](super::_mm_storeu_si16)
+/// stores.
+#[inline]
+#[target_feature(enable = "sse2")]
+pub fn _mm_storeu_si128_partial<T: UnalignedElement>(mem_addr: &mut [T], a: __m128i) {
+    let bytes = as_bytes_mut(mem_addr);
+    if let Some(bytes) = bytes.first_chunk_mut::<16>() {
+        return super::_mm_storeu_si128(bytes, a);
+    }
+
+    match bytes.split_first_chunk_mut::<8>() {
+        Some((lo, hi)) => {
+            super::_mm_storeu_si64(lo, a);
+            storeu_si64_tail(hi, arch::_mm_srli_si128::<8>(a));
+        }
+        None => storeu_si64_tail(bytes, a),
+    }
+}
+
+/// Stores the low bytes of `a` to fewer than 8 bytes.
+#[inline]
+#[target_feature(enable = "sse2")]
+fn storeu_si64_tail(bytes: &mut [u8], a: __m128i) {
+    debug_assert!(bytes.len() < 8);
+    // As in `loadu_si64_tail`, the upper store overlaps the lower one with the same bytes.
+    let shift = |len: usize, width: usize| arch::_mm_cvtsi32_si128(8 * (len - width) as i32);
+    let len = bytes.len();
+    if let Some(hi) = bytes.last_chunk_mut::<4>() {
+        super::_mm_storeu_si32(hi, arch::_mm_srl_epi64(a, shift(len, 4)));
+        if let Some(lo) = bytes.first_chunk_mut::<4>() {
+            super::_mm_storeu_si32(lo, a);
+        }
+    } else if let Some(hi) = bytes.last_chunk_mut::<2>() {
+        super::_mm_storeu_si16(hi, arch::_mm_srl_epi64(a, shift(len, 2)));
+        if let Some(lo) = bytes.first_chunk_mut::<2>() {
+            super::_mm_storeu_si16(lo, a);
+        }
+    } else if let Some(byte) = bytes.first_mut() {
+        *byte = arch::_mm_cvtsi128_si32(a) as u8;
+    }
+}
+
+/// Loads the first 32 bytes of `mem_addr`, filling the lanes past its end with zero.
+///
+/// Slices of 32 bytes or more are loaded with
+/// [`_mm256_loadu_si256`](super::_mm256_loadu_si256), shorter ones by halves as in
+/// [`_mm_loadu_si128_partial`].
+#[inline]
+#[target_feature(enable = "avx")]
+pub fn _mm256_loadu_si256_partial<T: ReadableElement>(mem_addr: &[T]) -> __m256i {
+    let bytes = as_bytes(mem_addr);
+    if let Some(bytes) = bytes.first_chunk::<32>() {
+        return super::_mm256_loadu_si256(bytes);
+    }
+
+    let hi = bytes.get(16..).unwrap_or_default();
+    arch::_mm256_set_m128i(_mm_loadu_si128_partial(hi), _mm_loadu_si128_partial(bytes))
+}
+
+/// Loads the first 32 bytes of `mem_addr`, taking the bytes past its end from `fill`.
+///
+/// See [`_mm256_loadu_si256_partial`].
+#[inline]
+#[target_feature(enable = "avx")]
+pub fn _mm256_loadu_si256_partial_fill<T: ReadableElement>(
+    mem_addr: &[T],
+    fill: __m256i,
+) -> __m256i {
+    let bytes = as_bytes(mem_addr);
+    if let Some(bytes) = bytes.first_chunk::<32>() {
+        return super::_mm256_loadu_si256(bytes);
+    }
+
+    let hi = bytes.get(16..).unwrap_or_default();
+    arch::_mm256_set_m128i(
+        _mm_loadu_si128_partial_fill(hi, arch::_mm256_extractf128_si256::<1>(fill)),
+        _mm_loadu_si128_partial_fill(bytes, arch::_mm256_castsi256_si128(fill)),
+    )
+}
+
+/// Stores the low bytes of `a` to `mem_addr`, as many as it holds up to 32.
+///
+/// See [`_mm_storeu_si128_partial`].
+#[inline]
+#[target_feature(enable = "avx")]
+pub fn _mm256_storeu_si256_partial<T: UnalignedElement>(mem_addr: &mut [T], a: __m256i) {
+    let bytes = as_bytes_mut(mem_addr);
+    if let Some(bytes) = bytes.first_chunk_mut::<32>() {
+        return super::_mm256_storeu_si256(bytes, a);
+    }
+
+    let (lo, hi) = bytes.split_at_mut(bytes.len().min(16));
+    _mm_storeu_si128_partial(lo, arch::_mm256_castsi256_si128(a));
+    _mm_storeu_si128_partial(hi, arch::_mm256_extractf128_si256::<1>(a));
+}
+
+// Generates the partial float loads and stores on top of the integer ones. The basic structure is:
+//
+//   "sse2" __m128 from [f32; 4] with _mm_loadu_si128_partial, _mm_loadu_si128_partial_fill,
+//       _mm_storeu_si128_partial by _mm_castsi128_ps, _mm_castps_si128
+//       => _mm_loadu_ps_partial, _mm_loadu_ps_partial_fill, _mm_storeu_ps_partial;
+//
+// where the integer functions are called through the casts to and from the integer register.
+macro_rules! float_partial {
+    ($(
+        $feature:literal $reg:ident from [$elem:ty; $n:literal]
+            with $int_load:ident, $int_load_fill:ident, $int_store:ident by $from_int:ident, $to_int:ident
+            => $load:ident, $load_fill:ident, $store:ident;
+    )*) => {
+        $(
+            #[doc = concat!("Loads the first ", stringify!($n), " elements of `mem_addr`, filling the lanes past its end with zero.")]
+            ///
+            #[doc = concat!("See [`", stringify!($int_load), "`].")]
+            #[inline]
+            #[target_feature(enable = $feature)]
+            pub fn $load(mem_addr: &[$elem]) -> $reg {
+                arch::$from_int($int_load(mem_addr))
+            }
+
+            #[doc = concat!("Loads the first ", stringify!($n), " elements of `mem_addr`, taking the lanes past its end from `fill`.")]
+            ///
+            #[doc = concat!("See [`", stringify!($int_load_fill), "`].")]
+            #[inline]
+            #[target_feature(enable = $feature)]
+            pub fn $load_fill(mem_addr: &[$elem], fill: $reg) -> $reg {
+                arch::$from_int($int_load_fill(mem_addr, arch::$to_int(fill)))
+            }
+
+            #[doc = concat!("Stores the low lanes of `a` to `mem_addr`, as many as it holds up to ", stringify!($n), ".")]
+            ///
+            #[doc = concat!("See [`", stringify!($int_store), "`].")]
+            #[inline]
+            #[target_feature(enable = $feature)]
+            pub fn $store(mem_addr: &mut [$elem], a: $reg) {
+                $int_store(mem_addr, arch::$to_int(a))
+            }
+        )*
+    };
+}
+
+float_partial! {
+    "sse2" __m128 from [f32; 4]
+        with _mm_loadu_si128_partial, _mm_loadu_si128_partial_fill, _mm_storeu_si128_partial
+        by _mm_castsi128_ps, _mm_castps_si128
+        => _mm_loadu_ps_partial, _mm_loadu_ps_partial_fill, _mm_storeu_ps_partial;
+    "sse2" __m128d from [f64; 2]
+        with _mm_loadu_si128_partial, _mm_loadu_si128_partial_fill, _mm_storeu_si128_partial
+        by _mm_castsi128_pd, _mm_castpd_si128
+        => _mm_loadu_pd_partial, _mm_loadu_pd_partial_fill, _mm_storeu_pd_partial;
+    "avx" __m256 from [f32; 8]
+        with _mm256_loadu_si256_partial, _mm256_loadu_si256_partial_fill, _mm256_storeu_si256_partial
+        by _mm256_castsi256_ps, _mm256_castps_si256
+        => _mm256_loadu_ps_partial, _mm256_loadu_ps_partial_fill, _mm256_storeu_ps_partial;
+    "avx" __m256d from [f64; 4]
+        with _mm256_loadu_si256_partial, _mm256_loadu_si256_partial_fill, _mm256_storeu_si256_partial
+        by _mm256_castsi256_pd, _mm256_castpd_si256
+        => _mm256_loadu_pd_partial, _mm256_loadu_pd_partial_fill, _mm256_storeu_pd_partial;
+}
+
+// Generates the drivers with an overlapping final window for one integer register. The basic
+// structure is:
+//
+//   "sse2" __m128i of 16 bytes by chunks_m128i, _mm_loadu_si128, _mm_storeu_si128
+//       => for_each_overlapping_m128i, map_overlapping_m128i;
+//
+// where the chunk iterator and the loads of the parent module read the windows, and the loads and
+// stores of the `cell` module update them in place.
+macro_rules! overlapping_drivers {
+    ($(
+        $feature:literal $reg:ident of $n:literal bytes by $chunks:ident, $load:ident, $store:ident
+            => $for_each:ident, $map:ident;
+    )*) => {
+        $(
+            #[doc = concat!("Calls `f` with each `", stringify!($reg), "` of `mem_addr` and one final window that ends at the end of the slice.")]
+            ///
+            /// The final window overlaps the previous one if the slice does
+            /// not divide into whole registers, so the kernel must be
+            /// idempotent, such as a reduction with `or`, `and`, `min` or
+            /// `max`. `f` also receives the element offset of each window.
+            ///
+            /// Returns `false` without calling `f` if the slice is shorter
+            #[doc = concat!("than a register. The size of `T` must divide ", stringify!($n), " bytes, which is")]
+            /// checked at compile time.
+            #[inline]
+            #[target_feature(enable = $feature)]
+            pub fn $for_each<T: ReadableElement>(
+                mem_addr: &[T],
+                mut f: impl FnMut(usize, $reg),
+            ) -> bool {
+                let len = <$reg as LoadChunk<T>>::LEN;
+                let Some(start) = mem_addr.len().checked_sub(len) else {
+                    return false;
+                };
+
+                let mut chunks = $chunks(mem_addr);
+                for (i, a) in (&mut chunks).enumerate() {
+                    f(i * len, a);
+                }
+                if !chunks.remainder().is_empty() {
+                    f(start, super::$load(bytes::<T, $n>(&mem_addr[start..])));
+                }
+                true
+            }
+
+            #[doc = concat!("Replaces each `", stringify!($reg), "` of `mem_addr` with the result of `f`, including one final window that ends at the end of the slice.")]
+            ///
+            /// The final window overlaps the previous one if the slice does
+            /// not divide into whole registers, and the overlap is processed
+            /// again after it was stored, so the kernel must be idempotent,
+            /// such as case conversion. The windows are accessed through
+            #[doc = concat!("[`cell::", stringify!($load), "`](super::cell::", stringify!($load), ") and [`cell::", stringify!($store), "`](super::cell::", stringify!($store), ").")]
+            ///
+            /// Returns `false` without calling `f` if the slice is shorter
+            #[doc = concat!("than a register. The size of `T` must divide ", stringify!($n), " bytes, which is")]
+            /// checked at compile time.
+            #[inline]
+            #[target_feature(enable = $feature)]
+            pub fn $map<T: UnalignedElement>(
+                mem_addr: &mut [T],
+                mut f: impl FnMut($reg) -> $reg,
+            ) -> bool {
+                const { elements::<T>($n) };
+                let cells = Cell::from_mut(as_bytes_mut(mem_addr)).as_slice_of_cells();
+                let Some(last) = cells.last_chunk::<$n>() else {
+                    return false;
+                };
+
+                let (chunks, rest) = cells.as_chunks::<$n>();
+                for chunk in chunks {
+                    super::cell::$store(chunk, f(super::cell::$load(chunk)));
+                }
+                if !rest.is_empty() {
+                    super::cell::$store(last, f(super::cell::$load(last)));
+                }
+                true
+            }
+        )*
+    };
+}
+
+overlapping_drivers! {
+    "sse2" __m128i of 16 bytes by chunks_m128i, _mm_loadu_si128, _mm_storeu_si128
+        => for_each_overlapping_m128i, map_overlapping_m128i;
+    "avx" __m256i of 32 bytes by chunks_m256i, _mm256_loadu_si256, _mm256_storeu_si256
+        => for_each_overlapping_m256i, map_overlapping_m256i;
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(target_arch = "x86")]
+    use core::arch::x86 as arch;
+    #[cfg(target_arch = "x86_64")]
+    use core::arch::x86_64 as arch;
+
+    // SAFETY: The `x86_64` target baseline includes `sse` and `sse2`.
+
+    #[test]
+    fn test_mm_loadu_storeu_ps() {
+        unsafe { test() }
+
+        #[target_feature(enable = "sse")]
+        fn test() {
+            let a = [1.0f32, 2.0, 3.0, 4.0, 5.0];
+            let mut b = [0.0f32; 6];
+
+            assert!(super::try_mm_loadu_ps(&a[2..]).is_none());
+            assert!(super::try_mm_storeu_ps(&mut b[3..], arch::_mm_setzero_ps()).is_none());
+
+            let v = super::try_mm_loadu_ps(&a).unwrap();
+            super::_mm_storeu_ps_at(&mut b, 2, v);
+            assert_eq!(b, [0.0, 0.0, 1.0, 2.0, 3.0, 4.0]);
+
+            super::_mm_storeu_ps_slice(&mut b, super::_mm_loadu_ps_at(&a, 1));
+            assert_eq!(b, [2.0, 3.0, 4.0, 5.0, 3.0, 4.0]);
+        }
+    }
+
+    #[test]
+    fn test_mm_loadu_storeu_si128() {
+        unsafe { test() }
+
+        #[target_feature(enable = "sse2")]
+        fn test() {
+            let a: [u32; 5] = core::array::from_fn(|i| i as u32);
+            let mut b = [0u8; 20];
+
+            assert!(super::try_mm_loadu_si128(&a[2..]).is_none());
+            assert!(super::try_mm_storeu_si128(&mut b[5..], arch::_mm_setzero_si128()).is_none());
+
+            super::_mm_storeu_si128_at(&mut b, 4, super::_mm_loadu_si128_at(&a, 1));
+            assert_eq!(b[..4], [0; 4]);
+            assert_eq!(b[4..], [1, 0, 0, 0, 2, 0, 0, 0, 3, 0, 0, 0, 4, 0, 0, 0]);
+
+            let c = [[true, false]; 8];
+            let mut d = [0u16; 8];
+            super::_mm_storeu_si128_slice(&mut d, super::_mm_loadu_si128_slice(&c));
+            assert_eq!(d, [1; 8]);
+        }
+    }
+
+    #[test]
+    #[should_panic = "range end index 7 out of range for slice of length 5"]
+    fn test_mm_loadu_ps_at_panics() {
+        unsafe { test() }
+
+        #[target_feature(enable = "sse")]
+        fn test() {
+            super::_mm_loadu_ps_at(&[0.0; 5], 3);
+        }
+    }
+
+    #[test]
+    #[should_panic = "access of 16 bytes out of range for slice of 12 bytes"]
+    fn test_mm_loadu_si128_slice_panics() {
+        unsafe { test() }
+
+        #[target_feature(enable = "sse2")]
+        fn test() {
+            super::_mm_loadu_si128_slice(&[0u32; 3]);
+        }
+    }
+
+    #[test]
+    fn test_chunks_m128i() {
+        unsafe { test() }
+
+        #[target_feature(enable = "sse2")]
+        fn test() {
+            let a: [u16; 20] = core::array::from_fn(|i| i as u16);
+
+            let mut chunks = super::chunks_m128i(&a);
+            assert_eq!(chunks.len(), 2);
+            assert_eq!(chunks.remainder(), [16, 17, 18, 19]);
+
+            let mut b = [0u16; 16];
+            for (v, out) in (&mut chunks).zip(b.chunks_exact_mut(8)) {
+                super::_mm_storeu_si128_slice(out, v);
+            }
+            assert_eq!(b, a[..16]);
+            assert!(chunks.next().is_none());
+        }
+    }
+
+    #[test]
+    fn test_chunks_m128_mut() {
+        unsafe { test() }
+
+        #[target_feature(enable = "sse")]
+        fn test() {
+            let mut a: [f32; 10] = core::array::from_fn(|i| i as f32);
+
+            let mut chunks = super::chunks_m128_mut(&mut a);
+            assert_eq!(chunks.len(), 2);
+            for mut chunk in &mut chunks {
+                chunk.update(|v| arch::_mm_mul_ps(v, arch::_mm_set1_ps(2.0)));
+            }
+            assert_eq!(chunks.into_remainder(), [8.0, 9.0]);
+            assert_eq!(a, [0.0, 2.0, 4.0, 6.0, 8.0, 10.0, 12.0, 14.0, 8.0, 9.0]);
+
+            let mut b = [0.0f32; 4];
+            let mut chunk = super::chunks_m128_mut(&mut b).next().unwrap();
+            chunk.store(arch::_mm_set1_ps(1.0));
+            assert_eq!(chunk.as_mut_slice(), [1.0; 4]);
+        }
+    }
+
+    #[test]
+    fn test_mm_loadu_storeu_si128_partial() {
+        unsafe { test() }
+
+        #[target_feature(enable = "sse2")]
+        fn test() {
+            let a: [u8; 17] = core::array::from_fn(|i| i as u8 + 1);
+            let fill = arch::_mm_set1_epi8(-1);
+
+            for n in 0..=17 {
+                let len = n.min(16);
+                let mut expected = [0u8; 16];
+                expected[..len].copy_from_slice(&a[..len]);
+
+                let mut b = [0u8; 16];
+                super::_mm_storeu_si128_slice(&mut b, super::_mm_loadu_si128_partial(&a[..n]));
+                assert_eq!(b, expected, "{n} bytes");
+
+                expected[len..].fill(0xff);
+                let v = super::_mm_loadu_si128_partial_fill(&a[..n], fill);
+                super::_mm_storeu_si128_slice(&mut b, v);
+                assert_eq!(b, expected, "{n} bytes");
+
+                let mut c = [0u8; 17];
+                super::_mm_storeu_si128_partial(&mut c[..n], v);
+                assert_eq!(c[..len], a[..len], "{n} bytes");
+                assert!(c[len..].iter().all(|&b| b == 0), "{n} bytes");
+            }
+        }
+    }
+
+    #[test]
+    fn test_mm_loadu_storeu_ps_partial() {
+        unsafe { test() }
+
+        #[target_feature(enable = "sse2")]
+        fn test() {
+            let a = [1.0f32, 2.0, 3.0];
+            let mut b = [0.0f32; 4];
+
+            super::_mm_storeu_ps_slice(&mut b, super::_mm_loadu_ps_partial(&a));
+            assert_eq!(b, [1.0, 2.0, 3.0, 0.0]);
+
+            let v = super::_mm_loadu_ps_partial_fill(&a[..1], arch::_mm_set1_ps(-1.0));
+            super::_mm_storeu_ps_slice(&mut b, v);
+            assert_eq!(b, [1.0, -1.0, -1.0, -1.0]);
+
+            super::_mm_storeu_ps_partial(&mut b[..2], arch::_mm_set1_ps(4.0));
+            assert_eq!(b, [4.0, 4.0, -1.0, -1.0]);
+
+            let mut c = [0.0f64; 3];
+            super::_mm_storeu_pd_partial(&mut c, super::_mm_loadu_pd_partial(&[5.0]));
+            assert_eq!(c, [5.0, 0.0, 0.0]);
+        }
+    }
+
+    #[test]
+    fn test_for_each_overlapping_m128i() {
+        unsafe { test() }
+
+        #[target_feature(enable = "sse2")]
+        fn test() {
+            let a: [u16; 13] = core::array::from_fn(|i| i as u16);
+            let mut offsets = Vec::new();
+            let mut max = arch::_mm_setzero_si128();
+
+            assert!(super::for_each_overlapping_m128i(&a, |offset, v| {
+                offsets.push(offset);
+                max = arch::_mm_max_epi16(max, v);
+            }));
+            assert_eq!(offsets, [0, 5]);
+
+            let mut lanes = [0u16; 8];
+            super::_mm_storeu_si128_slice(&mut lanes, max);
+            assert_eq!(lanes, [5, 6, 7, 8, 9, 10, 11, 12]);
+
+            assert!(!super::for_each_overlapping_m128i(
+                &a[..7],
+                |_, _| unreachable!()
+            ));
+        }
+    }
+
+    #[test]
+    fn test_map_overlapping_m128i() {
+        unsafe { test() }
+
+        #[target_feature(enable = "sse2")]
+        fn test() {
+            let mut a = [0xf0u8; 40];
+            a[39] = 0x0f;
+
+            let mut calls = 0;
+            assert!(super::map_overlapping_m128i(&mut a, |v| {
+                calls += 1;
+                arch::_mm_or_si128(v, arch::_mm_set1_epi8(1))
+            }));
+            assert_eq!(calls, 3);
+            assert_eq!(a[..39], [0xf1; 39]);
+            assert_eq!(a[39], 0x0f);
+
+            let mut b = [0u32; 3];
+            assert!(!super::map_overlapping_m128i(&mut b, |_| unreachable!()));
+        }
+    }
+
+    #[cfg(feature = "_avx_test")]
+    #[test]
+    fn test_mm256_loadu_storeu() {
+        assert!(is_x86_feature_detected!("avx"));
+        unsafe { test() }
+
+        #[target_feature(enable = "avx")]
+        fn test() {
+            let a: [f64; 6] = core::array::from_fn(|i| i as f64);
+            let mut b = [0.0f64; 4];
+            super::_mm256_storeu_pd_slice(&mut b, super::_mm256_loadu_pd_at(&a, 2));
+            assert_eq!(b, [2.0, 3.0, 4.0, 5.0]);
+            assert!(super::try_mm256_loadu_ps(&[0.0; 7]).is_none());
+
+            let c: [i16; 16] = core::array::from_fn(|i| i as i16);
+            let mut d = [0i16; 17];
+            super::_mm256_storeu_si256_at(&mut d, 1, super::_mm256_loadu_si256_slice(&c));
+            assert_eq!(d[1..], c);
+
+            for mut chunk in super::chunks_m256i_mut(&mut d) {
+                chunk.store(arch::_mm256_setzero_si256());
+            }
+            assert_eq!(d[..16], [0; 16]);
+            assert_eq!(super::chunks_m256d(&a).remainder(), [4.0, 5.0]);
+
+            let e: [u8; 33] = core::array::from_fn(|i| i as u8 + 1);
+            let fill = arch::_mm256_set1_epi8(-1);
+            for n in 0..=33 {
+                let len = n.min(32);
+                let mut expected = [0xffu8; 32];
+                expected[..len].copy_from_slice(&e[..len]);
+
+                let v = super::_mm256_loadu_si256_partial_fill(&e[..n], fill);
+                let mut f = [0u8; 32];
+                super::_mm256_storeu_si256_slice(&mut f, v);
+                assert_eq!(f, expected, "{n} bytes");
+
+                let mut g = [0u8; 33];
+                super::_mm256_storeu_si256_partial(
+                    &mut g[..n],
+                    super::_mm256_loadu_si256_partial(&e[..n]),
+                );
+                assert_eq!(g[..len], e[..len], "{n} bytes");
+                assert!(g[len..].iter().all(|&b| b == 0), "{n} bytes");
+            }
+
+            let mut h = [0.0f32; 8];
+            super::_mm256_storeu_ps_slice(
+                &mut h,
+                super::_mm256_loadu_ps_partial_fill(&[1.0; 5], arch::_mm256_set1_ps(2.0)),
+            );
+            assert_eq!(h, [1.0, 1.0, 1.0, 1.0, 1.0, 2.0, 2.0, 2.0]);
+
+            let mut text = [b'a'; 70];
+            let case = arch::_mm256_castsi256_ps(arch::_mm256_set1_epi8(0x20));
+            assert!(super::map_overlapping_m256i(&mut text, |v| {
+                let v = arch::_mm256_xor_ps(arch::_mm256_castsi256_ps(v), case);
+                arch::_mm256_castps_si256(v)
+            }));
+            // The overlap is processed twice, which shows for a kernel that is not idempotent.
+            assert_eq!(text[..38], [b'A'; 38]);
+            assert_eq!(text[38..64], [b'a'; 26]);
+            assert_eq!(text[64..], [b'A'; 6]);
+        }
+    }
+}
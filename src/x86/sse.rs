@@ -3,73 +3,83 @@ use core::arch::x86::{self as arch, __m128};
 #[cfg(target_arch = "x86_64")]
 use core::arch::x86_64::{self as arch, __m128};
 
-/// Construct a [`__m128`] by duplicating the value read from `mem_addr` into
-/// all elements.
-///
-/// This corresponds to instructions `VMOVSS` / `MOVSS` followed by some
-/// shuffling.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_load1_ps)
-#[inline]
-#[target_feature(enable = "sse")]
-pub fn _mm_load1_ps(mem_addr: &f32) -> __m128 {
-    unsafe { arch::_mm_load1_ps(mem_addr) }
-}
+token_wrappers! {
+    impl super::token::Sse;
+
+    /// Construct a [`__m128`] by duplicating the value read from `mem_addr` into
+    /// all elements.
+    ///
+    /// This corresponds to instructions `VMOVSS` / `MOVSS` followed by some
+    /// shuffling.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_load1_ps)
+    #[method = mm_load1_ps]
+    #[inline]
+    #[target_feature(enable = "sse")]
+    pub fn _mm_load1_ps(mem_addr: &f32) -> __m128 {
+        unsafe { arch::_mm_load1_ps(mem_addr) }
+    }
 
-/// Alias for [`_mm_load1_ps`].
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_load_ps1)
-#[inline]
-#[target_feature(enable = "sse")]
-pub fn _mm_load_ps1(mem_addr: &f32) -> __m128 {
-    _mm_load1_ps(mem_addr)
-}
+    /// Alias for [`_mm_load1_ps`].
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_load_ps1)
+    #[method = mm_load_ps1]
+    #[inline]
+    #[target_feature(enable = "sse")]
+    pub fn _mm_load_ps1(mem_addr: &f32) -> __m128 {
+        _mm_load1_ps(mem_addr)
+    }
 
-/// Construct a [`__m128`] with the lowest element read from `mem_addr` and the
-/// other elements set to zero.
-///
-/// This corresponds to instructions `VMOVSS` / `MOVSS`.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_load_ss)
-#[inline]
-#[target_feature(enable = "sse")]
-pub fn _mm_load_ss(mem_addr: &f32) -> __m128 {
-    unsafe { arch::_mm_load_ss(mem_addr) }
-}
+    /// Construct a [`__m128`] with the lowest element read from `mem_addr` and the
+    /// other elements set to zero.
+    ///
+    /// This corresponds to instructions `VMOVSS` / `MOVSS`.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_load_ss)
+    #[method = mm_load_ss]
+    #[inline]
+    #[target_feature(enable = "sse")]
+    pub fn _mm_load_ss(mem_addr: &f32) -> __m128 {
+        unsafe { arch::_mm_load_ss(mem_addr) }
+    }
 
-/// Loads four `f32` values from memory into a [`__m128`]. There are no
-/// restrictions on memory alignment.
-///
-/// This corresponds to instructions `VMOVUPS` / `MOVUPS`.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_loadu_ps)
-#[inline]
-#[target_feature(enable = "sse")]
-pub fn _mm_loadu_ps(mem_addr: &[f32; 4]) -> __m128 {
-    unsafe { arch::_mm_loadu_ps(mem_addr.as_ptr()) }
-}
+    /// Loads four `f32` values from memory into a [`__m128`]. There are no
+    /// restrictions on memory alignment.
+    ///
+    /// This corresponds to instructions `VMOVUPS` / `MOVUPS`.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_loadu_ps)
+    #[method = mm_loadu_ps]
+    #[inline]
+    #[target_feature(enable = "sse")]
+    pub fn _mm_loadu_ps(mem_addr: &[f32; 4]) -> __m128 {
+        unsafe { arch::_mm_loadu_ps(mem_addr.as_ptr()) }
+    }
 
-/// Stores the lowest 32-bit float of `a` into memory.
-///
-/// This intrinsic corresponds to the `MOVSS` instruction.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_store_ss)
-#[inline]
-#[target_feature(enable = "sse")]
-pub fn _mm_store_ss(mem_addr: &mut f32, a: __m128) {
-    unsafe { arch::_mm_store_ss(mem_addr, a) }
-}
+    /// Stores the lowest 32-bit float of `a` into memory.
+    ///
+    /// This intrinsic corresponds to the `MOVSS` instruction.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_store_ss)
+    #[method = mm_store_ss]
+    #[inline]
+    #[target_feature(enable = "sse")]
+    pub fn _mm_store_ss(mem_addr: &mut f32, a: __m128) {
+        unsafe { arch::_mm_store_ss(mem_addr, a) }
+    }
 
-/// Stores four 32-bit floats into memory. There are no restrictions on memory
-/// alignment.
-///
-/// This corresponds to instructions `VMOVUPS` / `MOVUPS`.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_storeu_ps)
-#[inline]
-#[target_feature(enable = "sse")]
-pub fn _mm_storeu_ps(mem_addr: &mut [f32; 4], a: __m128) {
-    unsafe { arch::_mm_storeu_ps(mem_addr.as_mut_ptr(), a) }
+    /// Stores four 32-bit floats into memory. There are no restrictions on memory
+    /// alignment.
+    ///
+    /// This corresponds to instructions `VMOVUPS` / `MOVUPS`.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_storeu_ps)
+    #[method = mm_storeu_ps]
+    #[inline]
+    #[target_feature(enable = "sse")]
+    pub fn _mm_storeu_ps(mem_addr: &mut [f32; 4], a: __m128) {
+        unsafe { arch::_mm_storeu_ps(mem_addr.as_mut_ptr(), a) }
+    }
 }
 
 #[cfg(test)]
@@ -79,9 +89,11 @@ mod tests {
     #[cfg(target_arch = "x86_64")]
     use core::arch::x86_64::{self as arch, __m128};
 
+    use crate::Register;
+
     fn assert_eq_m128(a: __m128, b: __m128) {
-        let a: [u8; 16] = unsafe { core::mem::transmute(a) };
-        let b: [u8; 16] = unsafe { core::mem::transmute(b) };
+        let a: [u8; 16] = a.to_array();
+        let b: [u8; 16] = b.to_array();
         assert_eq!(a, b)
     }
 
@@ -5,200 +5,230 @@ use core::arch::x86_64::{self as arch, __m128d, __m128i};
 use core::ptr;
 
 #[cfg(target_arch = "x86")]
-use crate::x86::{Is16BitsUnaligned, Is32BitsUnaligned, Is64BitsUnaligned, Is128BitsUnaligned};
+use crate::x86::{
+    Is16BitsReadable, Is16BitsUnaligned, Is32BitsReadable, Is32BitsUnaligned, Is64BitsReadable,
+    Is64BitsUnaligned, Is128BitsReadable, Is128BitsUnaligned,
+};
 #[cfg(target_arch = "x86_64")]
-use crate::x86_64::{Is16BitsUnaligned, Is32BitsUnaligned, Is64BitsUnaligned, Is128BitsUnaligned};
-
-/// Loads a double-precision (64-bit) floating-point element from memory
-/// into both elements of returned vector.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_load_pd1)
-#[inline]
-#[target_feature(enable = "sse2")]
-pub fn _mm_load_pd1(mem_addr: &f64) -> __m128d {
-    _mm_load1_pd(mem_addr)
-}
-
-/// Loads a 64-bit double-precision value to the low element of a
-/// 128-bit integer vector and clears the upper element.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_load_sd)
-#[inline]
-#[target_feature(enable = "sse2")]
-pub fn _mm_load_sd(mem_addr: &f64) -> __m128d {
-    unsafe { arch::_mm_load_sd(mem_addr) }
-}
-
-/// Loads a double-precision (64-bit) floating-point element from memory
-/// into both elements of returned vector.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_load1_pd)
-#[inline]
-#[target_feature(enable = "sse2")]
-pub fn _mm_load1_pd(mem_addr: &f64) -> __m128d {
-    unsafe { arch::_mm_load1_pd(mem_addr) }
-}
-
-/// Loads a double-precision value into the high-order bits of a 128-bit
-/// vector of `[2 x double]`. The low-order bits are copied from the low-order
-/// bits of the first operand.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_loadh_pd)
-#[inline]
-#[target_feature(enable = "sse2")]
-pub fn _mm_loadh_pd(a: __m128d, mem_addr: &f64) -> __m128d {
-    unsafe { arch::_mm_loadh_pd(a, mem_addr) }
-}
-
-/// Loads a 64-bit integer from memory into first element of returned vector.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_loadl_epi64)
-#[inline]
-#[target_feature(enable = "sse2")]
-pub fn _mm_loadl_epi64<T: Is128BitsUnaligned>(mem_addr: &T) -> __m128i {
-    unsafe { arch::_mm_loadl_epi64(ptr::from_ref(mem_addr).cast()) }
-}
-
-/// Loads a double-precision value into the low-order bits of a 128-bit
-/// vector of `[2 x double]`. The high-order bits are copied from the
-/// high-order bits of the first operand.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_loadl_pd)
-#[inline]
-#[target_feature(enable = "sse2")]
-pub fn _mm_loadl_pd(a: __m128d, mem_addr: &f64) -> __m128d {
-    unsafe { arch::_mm_loadl_pd(a, mem_addr) }
-}
-
-/// Loads 128-bits (composed of 2 packed double-precision (64-bit)
-/// floating-point elements) from memory into the returned vector.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_loadu_pd)
-#[inline]
-#[target_feature(enable = "sse2")]
-pub fn _mm_loadu_pd(mem_addr: &[f64; 2]) -> __m128d {
-    unsafe { arch::_mm_loadu_pd(mem_addr.as_ptr()) }
-}
-
-/// Loads 128-bits of integer data from memory into a new vector.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_loadu_si128)
-#[inline]
-#[target_feature(enable = "sse2")]
-pub fn _mm_loadu_si128<T: Is128BitsUnaligned>(mem_addr: &T) -> __m128i {
-    unsafe { arch::_mm_loadu_si128(ptr::from_ref(mem_addr).cast()) }
-}
-
-/// Loads unaligned 16-bits of integer data from memory into new vector.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_loadu_si16)
-#[inline]
-#[target_feature(enable = "sse2")]
-pub fn _mm_loadu_si16<T: Is16BitsUnaligned>(mem_addr: &T) -> __m128i {
-    unsafe { arch::_mm_loadu_si16(ptr::from_ref(mem_addr).cast()) }
-}
-
-/// Loads unaligned 32-bits of integer data from memory into new vector.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_loadu_si32)
-#[inline]
-#[target_feature(enable = "sse2")]
-pub fn _mm_loadu_si32<T: Is32BitsUnaligned>(mem_addr: &T) -> __m128i {
-    unsafe { arch::_mm_loadu_si32(ptr::from_ref(mem_addr).cast()) }
-}
-
-/// Loads unaligned 64-bits of integer data from memory into new vector.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_loadu_si64)
-#[inline]
-#[target_feature(enable = "sse2")]
-pub fn _mm_loadu_si64<T: Is64BitsUnaligned>(mem_addr: &T) -> __m128i {
-    unsafe { arch::_mm_loadu_si64(ptr::from_ref(mem_addr).cast()) }
-}
-
-/// Stores the lower 64 bits of a 128-bit vector of `[2 x double]` to a
-/// memory location.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_store_sd)
-#[inline]
-#[target_feature(enable = "sse2")]
-pub fn _mm_store_sd(mem_addr: &mut f64, a: __m128d) {
-    unsafe { arch::_mm_store_sd(ptr::from_mut(mem_addr), a) }
-}
-
-/// Stores the upper 64 bits of a 128-bit vector of `[2 x double]` to a
-/// memory location.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_storeh_pd)
-#[inline]
-#[target_feature(enable = "sse2")]
-pub fn _mm_storeh_pd(mem_addr: &mut f64, a: __m128d) {
-    unsafe { arch::_mm_storeh_pd(ptr::from_mut(mem_addr), a) }
-}
-
-/// Stores the lower 64-bit integer `a` to a memory location.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_storel_epi64)
-#[inline]
-#[target_feature(enable = "sse2")]
-pub fn _mm_storel_epi64<T: Is128BitsUnaligned>(mem_addr: &mut T, a: __m128i) {
-    unsafe { arch::_mm_storel_epi64(ptr::from_mut(mem_addr).cast(), a) }
-}
-
-/// Stores the lower 64 bits of a 128-bit vector of `[2 x double]` to a
-/// memory location.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_storel_pd)
-#[inline]
-#[target_feature(enable = "sse2")]
-pub fn _mm_storel_pd(mem_addr: &mut f64, a: __m128d) {
-    unsafe { arch::_mm_storel_pd(ptr::from_mut(mem_addr), a) }
-}
-
-/// Stores 128-bits (composed of 2 packed double-precision (64-bit)
-/// floating-point elements) from `a` into memory.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_storeu_pd)
-#[inline]
-#[target_feature(enable = "sse2")]
-pub fn _mm_storeu_pd(mem_addr: &mut [f64; 2], a: __m128d) {
-    unsafe { arch::_mm_storeu_pd(mem_addr.as_mut_ptr().cast(), a) }
-}
-
-/// Stores 128-bits of integer data from `a` into memory.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_storeu_si128)
-#[inline]
-#[target_feature(enable = "sse2")]
-pub fn _mm_storeu_si128<T: Is128BitsUnaligned>(mem_addr: &mut T, a: __m128i) {
-    unsafe { arch::_mm_storeu_si128(ptr::from_mut(mem_addr).cast(), a) }
-}
-
-/// Store 16-bit integer from the first element of `a` into memory.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_storeu_si16)
-#[inline]
-#[target_feature(enable = "sse2")]
-pub fn _mm_storeu_si16<T: Is16BitsUnaligned>(mem_addr: &mut T, a: __m128i) {
-    unsafe { arch::_mm_storeu_si16(ptr::from_mut(mem_addr).cast(), a) }
-}
-
-/// Store 32-bit integer from the first element of `a` into memory.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_storeu_si32)
-#[inline]
-#[target_feature(enable = "sse2")]
-pub fn _mm_storeu_si32<T: Is32BitsUnaligned>(mem_addr: &mut T, a: __m128i) {
-    unsafe { arch::_mm_storeu_si32(ptr::from_mut(mem_addr).cast(), a) }
-}
-
-/// Store 64-bit integer from the first element of `a` into memory.
-///
-/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_storeu_si64)
-#[inline]
-#[target_feature(enable = "sse2")]
-pub fn _mm_storeu_si64<T: Is64BitsUnaligned>(mem_addr: &mut T, a: __m128i) {
-    unsafe { arch::_mm_storeu_si64(ptr::from_mut(mem_addr).cast(), a) }
+use crate::x86_64::{
+    Is16BitsReadable, Is16BitsUnaligned, Is32BitsReadable, Is32BitsUnaligned, Is64BitsReadable,
+    Is64BitsUnaligned, Is128BitsReadable, Is128BitsUnaligned,
+};
+
+token_wrappers! {
+    impl super::token::Sse2;
+
+    /// Loads a double-precision (64-bit) floating-point element from memory
+    /// into both elements of returned vector.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_load_pd1)
+    #[method = mm_load_pd1]
+    #[inline]
+    #[target_feature(enable = "sse2")]
+    pub fn _mm_load_pd1(mem_addr: &f64) -> __m128d {
+        _mm_load1_pd(mem_addr)
+    }
+
+    /// Loads a 64-bit double-precision value to the low element of a
+    /// 128-bit integer vector and clears the upper element.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_load_sd)
+    #[method = mm_load_sd]
+    #[inline]
+    #[target_feature(enable = "sse2")]
+    pub fn _mm_load_sd(mem_addr: &f64) -> __m128d {
+        unsafe { arch::_mm_load_sd(mem_addr) }
+    }
+
+    /// Loads a double-precision (64-bit) floating-point element from memory
+    /// into both elements of returned vector.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_load1_pd)
+    #[method = mm_load1_pd]
+    #[inline]
+    #[target_feature(enable = "sse2")]
+    pub fn _mm_load1_pd(mem_addr: &f64) -> __m128d {
+        unsafe { arch::_mm_load1_pd(mem_addr) }
+    }
+
+    /// Loads a double-precision value into the high-order bits of a 128-bit
+    /// vector of `[2 x double]`. The low-order bits are copied from the low-order
+    /// bits of the first operand.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_loadh_pd)
+    #[method = mm_loadh_pd]
+    #[inline]
+    #[target_feature(enable = "sse2")]
+    pub fn _mm_loadh_pd(a: __m128d, mem_addr: &f64) -> __m128d {
+        unsafe { arch::_mm_loadh_pd(a, mem_addr) }
+    }
+
+    /// Loads a 64-bit integer from memory into first element of returned vector.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_loadl_epi64)
+    #[method = mm_loadl_epi64]
+    #[inline]
+    #[target_feature(enable = "sse2")]
+    pub fn _mm_loadl_epi64<T: Is128BitsReadable>(mem_addr: &T) -> __m128i {
+        unsafe { arch::_mm_loadl_epi64(ptr::from_ref(mem_addr).cast()) }
+    }
+
+    /// Loads a double-precision value into the low-order bits of a 128-bit
+    /// vector of `[2 x double]`. The high-order bits are copied from the
+    /// high-order bits of the first operand.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_loadl_pd)
+    #[method = mm_loadl_pd]
+    #[inline]
+    #[target_feature(enable = "sse2")]
+    pub fn _mm_loadl_pd(a: __m128d, mem_addr: &f64) -> __m128d {
+        unsafe { arch::_mm_loadl_pd(a, mem_addr) }
+    }
+
+    /// Loads 128-bits (composed of 2 packed double-precision (64-bit)
+    /// floating-point elements) from memory into the returned vector.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_loadu_pd)
+    #[method = mm_loadu_pd]
+    #[inline]
+    #[target_feature(enable = "sse2")]
+    pub fn _mm_loadu_pd(mem_addr: &[f64; 2]) -> __m128d {
+        unsafe { arch::_mm_loadu_pd(mem_addr.as_ptr()) }
+    }
+
+    /// Loads 128-bits of integer data from memory into a new vector.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_loadu_si128)
+    #[method = mm_loadu_si128]
+    #[inline]
+    #[target_feature(enable = "sse2")]
+    pub fn _mm_loadu_si128<T: Is128BitsReadable>(mem_addr: &T) -> __m128i {
+        unsafe { arch::_mm_loadu_si128(ptr::from_ref(mem_addr).cast()) }
+    }
+
+    /// Loads unaligned 16-bits of integer data from memory into new vector.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_loadu_si16)
+    #[method = mm_loadu_si16]
+    #[inline]
+    #[target_feature(enable = "sse2")]
+    pub fn _mm_loadu_si16<T: Is16BitsReadable>(mem_addr: &T) -> __m128i {
+        unsafe { arch::_mm_loadu_si16(ptr::from_ref(mem_addr).cast()) }
+    }
+
+    /// Loads unaligned 32-bits of integer data from memory into new vector.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_loadu_si32)
+    #[method = mm_loadu_si32]
+    #[inline]
+    #[target_feature(enable = "sse2")]
+    pub fn _mm_loadu_si32<T: Is32BitsReadable>(mem_addr: &T) -> __m128i {
+        unsafe { arch::_mm_loadu_si32(ptr::from_ref(mem_addr).cast()) }
+    }
+
+    /// Loads unaligned 64-bits of integer data from memory into new vector.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_loadu_si64)
+    #[method = mm_loadu_si64]
+    #[inline]
+    #[target_feature(enable = "sse2")]
+    pub fn _mm_loadu_si64<T: Is64BitsReadable>(mem_addr: &T) -> __m128i {
+        unsafe { arch::_mm_loadu_si64(ptr::from_ref(mem_addr).cast()) }
+    }
+
+    /// Stores the lower 64 bits of a 128-bit vector of `[2 x double]` to a
+    /// memory location.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_store_sd)
+    #[method = mm_store_sd]
+    #[inline]
+    #[target_feature(enable = "sse2")]
+    pub fn _mm_store_sd(mem_addr: &mut f64, a: __m128d) {
+        unsafe { arch::_mm_store_sd(ptr::from_mut(mem_addr), a) }
+    }
+
+    /// Stores the upper 64 bits of a 128-bit vector of `[2 x double]` to a
+    /// memory location.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_storeh_pd)
+    #[method = mm_storeh_pd]
+    #[inline]
+    #[target_feature(enable = "sse2")]
+    pub fn _mm_storeh_pd(mem_addr: &mut f64, a: __m128d) {
+        unsafe { arch::_mm_storeh_pd(ptr::from_mut(mem_addr), a) }
+    }
+
+    /// Stores the lower 64-bit integer `a` to a memory location.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_storel_epi64)
+    #[method = mm_storel_epi64]
+    #[inline]
+    #[target_feature(enable = "sse2")]
+    pub fn _mm_storel_epi64<T: Is128BitsUnaligned>(mem_addr: &mut T, a: __m128i) {
+        unsafe { arch::_mm_storel_epi64(ptr::from_mut(mem_addr).cast(), a) }
+    }
+
+    /// Stores the lower 64 bits of a 128-bit vector of `[2 x double]` to a
+    /// memory location.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_storel_pd)
+    #[method = mm_storel_pd]
+    #[inline]
+    #[target_feature(enable = "sse2")]
+    pub fn _mm_storel_pd(mem_addr: &mut f64, a: __m128d) {
+        unsafe { arch::_mm_storel_pd(ptr::from_mut(mem_addr), a) }
+    }
+
+    /// Stores 128-bits (composed of 2 packed double-precision (64-bit)
+    /// floating-point elements) from `a` into memory.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_storeu_pd)
+    #[method = mm_storeu_pd]
+    #[inline]
+    #[target_feature(enable = "sse2")]
+    pub fn _mm_storeu_pd(mem_addr: &mut [f64; 2], a: __m128d) {
+        unsafe { arch::_mm_storeu_pd(mem_addr.as_mut_ptr().cast(), a) }
+    }
+
+    /// Stores 128-bits of integer data from `a` into memory.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_storeu_si128)
+    #[method = mm_storeu_si128]
+    #[inline]
+    #[target_feature(enable = "sse2")]
+    pub fn _mm_storeu_si128<T: Is128BitsUnaligned>(mem_addr: &mut T, a: __m128i) {
+        unsafe { arch::_mm_storeu_si128(ptr::from_mut(mem_addr).cast(), a) }
+    }
+
+    /// Store 16-bit integer from the first element of `a` into memory.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_storeu_si16)
+    #[method = mm_storeu_si16]
+    #[inline]
+    #[target_feature(enable = "sse2")]
+    pub fn _mm_storeu_si16<T: Is16BitsUnaligned>(mem_addr: &mut T, a: __m128i) {
+        unsafe { arch::_mm_storeu_si16(ptr::from_mut(mem_addr).cast(), a) }
+    }
+
+    /// Store 32-bit integer from the first element of `a` into memory.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_storeu_si32)
+    #[method = mm_storeu_si32]
+    #[inline]
+    #[target_feature(enable = "sse2")]
+    pub fn _mm_storeu_si32<T: Is32BitsUnaligned>(mem_addr: &mut T, a: __m128i) {
+        unsafe { arch::_mm_storeu_si32(ptr::from_mut(mem_addr).cast(), a) }
+    }
+
+    /// Store 64-bit integer from the first element of `a` into memory.
+    ///
+    /// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_storeu_si64)
+    #[method = mm_storeu_si64]
+    #[inline]
+    #[target_feature(enable = "sse2")]
+    pub fn _mm_storeu_si64<T: Is64BitsUnaligned>(mem_addr: &mut T, a: __m128i) {
+        unsafe { arch::_mm_storeu_si64(ptr::from_mut(mem_addr).cast(), a) }
+    }
 }
 
 #[cfg(test)]
@@ -208,17 +238,19 @@ mod tests {
     #[cfg(target_arch = "x86_64")]
     use core::arch::x86_64::{self as arch, __m128d, __m128i};
 
+    use crate::Register;
+
     // SAFETY: The `x86_64` target baseline includes `sse` and `sse2`.
 
     fn assert_eq_m128d(a: __m128d, b: __m128d) {
-        let a: [u8; 16] = unsafe { core::mem::transmute(a) };
-        let b: [u8; 16] = unsafe { core::mem::transmute(b) };
+        let a: [u8; 16] = a.to_array();
+        let b: [u8; 16] = b.to_array();
         assert_eq!(a, b)
     }
 
     fn assert_eq_m128i(a: __m128i, b: __m128i) {
-        let a: [u8; 16] = unsafe { core::mem::transmute(a) };
-        let b: [u8; 16] = unsafe { core::mem::transmute(b) };
+        let a: [u8; 16] = a.to_array();
+        let b: [u8; 16] = b.to_array();
         assert_eq!(a, b)
     }
 
@@ -1109,6 +1141,23 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_mm_loadu_si128_bool() {
+        let a = [
+            true, false, true, false, false, true, false, true, true, true, false, false, false,
+            false, true, true,
+        ];
+        unsafe { test(&a) }
+
+        #[target_feature(enable = "sse2")]
+        fn test(a: &[bool; 16]) {
+            let r = super::_mm_loadu_si128(a);
+            let target = arch::_mm_setr_epi8(1, 0, 1, 0, 0, 1, 0, 1, 1, 1, 0, 0, 0, 0, 1, 1);
+
+            assert_eq_m128i(r, target);
+        }
+    }
+
     #[test]
     fn test_mm_loadu_si128_u16() {
         let a = [1, 2, 3, 4, 5, 6, 7, 8];
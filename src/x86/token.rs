@@ -0,0 +1,192 @@
+//! Capability tokens for the `x86` target features.
+//!
+//! A token can only be obtained when its target features are available, so its methods call the
+//! corresponding wrappers without `unsafe`. Tokens of wider feature sets dereference to the
+//! narrower ones, e.g. an [`Avx`] token also provides the methods of [`Sse2`] and [`Sse`].
+//!
+//! ```rust
+//! #[cfg(target_arch = "x86")]
+//! use safe_unaligned_simd::x86::token::Avx;
+//! #[cfg(target_arch = "x86_64")]
+//! use safe_unaligned_simd::x86_64::token::Avx;
+//!
+//! fn copy(data: &[f32; 8], out: &mut [f32; 8]) {
+//!     if let Some(avx) = Avx::try_new() {
+//!         let v = avx.mm256_loadu_ps(data);
+//!         avx.mm256_storeu_ps(out, v);
+//!     } else {
+//!         *out = *data;
+//!     }
+//! }
+//! # let mut out = [0.0; 8];
+//! # copy(&[1.0; 8], &mut out);
+//! # assert_eq!(out, [1.0; 8]);
+//! ```
+token! {
+    /// Proof that the `sse` target feature is available.
+    pub struct Sse: "sse";
+}
+
+token! {
+    /// Proof that the `sse` and `sse2` target features are available.
+    pub struct Sse2(Sse): "sse", "sse2";
+}
+
+token! {
+    /// Proof that the `sse`, `sse2` and `avx` target features are available.
+    pub struct Avx(Sse2): "sse", "sse2", "avx";
+}
+
+#[cfg(feature = "avx512")]
+token! {
+    /// Proof that the `avx512f` and `avx512vl` target features are available, along with those
+    /// of [`Avx`].
+    pub struct Avx512f(Avx): "sse", "sse2", "avx", "avx512f", "avx512vl";
+}
+
+#[cfg(feature = "avx512")]
+token! {
+    /// Proof that the `avx512bw` target feature is available, along with those of [`Avx512f`].
+    pub struct Avx512Bw(Avx512f): "sse", "sse2", "avx", "avx512f", "avx512vl", "avx512bw";
+}
+
+#[cfg(feature = "avx512")]
+token! {
+    /// Proof that the `avx512vbmi2` target feature is available, along with those of
+    /// [`Avx512Bw`].
+    pub struct Avx512Vbmi2(Avx512Bw):
+        "sse", "sse2", "avx", "avx512f", "avx512vl", "avx512bw", "avx512vbmi2";
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Avx, Sse, Sse2};
+    use crate::x86;
+
+    #[test]
+    fn test_sse_token() {
+        // The `x86_64` target baseline includes `sse` and `sse2`.
+        let sse = Sse::try_new().unwrap();
+
+        let a = [1.0f32, 2.0, 3.0, 4.0];
+        let mut b = [0.0f32; 4];
+        let mut c = [0.0f32; 4];
+        sse.mm_storeu_ps(&mut b, sse.mm_load_ss(&a[1]));
+        // Safety: the token proves that `sse` is available.
+        unsafe { x86::_mm_storeu_ps(&mut c, x86::_mm_load_ss(&a[1])) };
+        assert_eq!(b, c);
+        assert_eq!(b, [2.0, 0.0, 0.0, 0.0]);
+    }
+
+    #[test]
+    fn test_sse2_token() {
+        let sse2 = Sse2::try_new().unwrap();
+        assert_eq!(Sse::try_new(), Some(Sse::from(sse2)));
+
+        let a = [[1u16, 2, 3, 4], [5, 6, 7, 8]];
+        let mut b = [0u16; 8];
+        let mut c = [0u16; 8];
+        sse2.mm_storeu_si128(&mut b, sse2.mm_loadu_si64(&a[1]));
+        // Safety: the token proves that `sse2` is available.
+        unsafe { x86::_mm_storeu_si128(&mut c, x86::_mm_loadu_si64(&a[1])) };
+        assert_eq!(b, c);
+        assert_eq!(b, [5, 6, 7, 8, 0, 0, 0, 0]);
+
+        // Methods of `Sse` are reachable through `Deref`.
+        let mut d = [0.0f32; 4];
+        sse2.mm_storeu_ps(&mut d, sse2.mm_load1_ps(&1.5));
+        assert_eq!(d, [1.5; 4]);
+    }
+
+    #[test]
+    fn test_avx_token() {
+        let Some(avx) = Avx::try_new() else {
+            return;
+        };
+
+        let a: [f64; 4] = core::array::from_fn(|i| i as f64);
+        let mut b = [0.0f64; 4];
+        let mut c = [0.0f64; 4];
+        avx.mm256_storeu_pd(&mut b, avx.mm256_broadcast_sd(&a[3]));
+        // Safety: the token proves that `avx` is available.
+        unsafe { x86::_mm256_storeu_pd(&mut c, x86::_mm256_broadcast_sd(&a[3])) };
+        assert_eq!(b, c);
+        assert_eq!(b, [3.0; 4]);
+
+        let mut d = [0u8; 16];
+        avx.mm_storeu_si128(&mut d, avx.mm_loadu_si128(&[7u8; 16]));
+        assert_eq!(d, [7; 16]);
+    }
+
+    #[test]
+    #[cfg(feature = "avx512")]
+    fn test_avx512f_token() {
+        let Some(avx512f) = super::Avx512f::try_new() else {
+            return;
+        };
+
+        let a: [i32; 16] = core::array::from_fn(|i| i as i32 + 1);
+        let mut b = [0i32; 16];
+        let mut c = [0i32; 16];
+        avx512f.mm512_storeu_si512(&mut b, avx512f.mm512_maskz_loadu_epi32(0x00ff, &a));
+        // Safety: the token proves that `avx512f` is available.
+        unsafe {
+            x86::_mm512_storeu_si512(&mut c, x86::_mm512_maskz_loadu_epi32(0x00ff, &a));
+        }
+        assert_eq!(b, c);
+        assert_eq!(b[..8], a[..8]);
+        assert_eq!(b[8..], [0; 8]);
+    }
+
+    #[test]
+    #[cfg(feature = "avx512")]
+    fn test_avx512bw_token() {
+        let Some(avx512bw) = super::Avx512Bw::try_new() else {
+            return;
+        };
+
+        let a: [u8; 64] = core::array::from_fn(|i| i as u8 + 1);
+        let mut b = [0u8; 64];
+        let mut c = [0u8; 64];
+        let k = 0x5555_5555_5555_5555;
+        avx512bw.mm512_storeu_si512(&mut b, avx512bw.mm512_maskz_loadu_epi8(k, &a));
+        // Safety: the token proves that `avx512f` and `avx512bw` are available.
+        unsafe { x86::_mm512_storeu_si512(&mut c, x86::_mm512_maskz_loadu_epi8(k, &a)) };
+        assert_eq!(b, c);
+        assert_eq!(b[..4], [1, 0, 3, 0]);
+    }
+
+    #[test]
+    #[cfg(feature = "avx512")]
+    fn test_avx512vbmi2_token() {
+        let Some(avx512vbmi2) = super::Avx512Vbmi2::try_new() else {
+            return;
+        };
+
+        let a: [u16; 32] = core::array::from_fn(|i| i as u16 + 1);
+        let mut b = [0u16; 32];
+        let mut c = [0u16; 32];
+        let k = 0xaaaa_aaaa;
+        avx512vbmi2.mm512_storeu_si512(&mut b, avx512vbmi2.mm512_maskz_expandloadu_epi16(k, &a));
+        // Safety: the token proves that `avx512f` and `avx512vbmi2` are available.
+        unsafe { x86::_mm512_storeu_si512(&mut c, x86::_mm512_maskz_expandloadu_epi16(k, &a)) };
+        assert_eq!(b, c);
+        assert_eq!(b[..4], [0, 1, 0, 2]);
+    }
+
+    #[test]
+    fn test_new_unchecked() {
+        if std::arch::is_x86_feature_detected!("avx") {
+            unsafe { test() }
+        }
+
+        #[target_feature(enable = "avx")]
+        fn test() {
+            // Safety: the function enables the features of the token.
+            let avx = unsafe { Avx::new_unchecked() };
+            let mut b = [0i32; 8];
+            avx.mm256_storeu_si256(&mut b, avx.mm256_loadu_si256(&[-1i32; 8]));
+            assert_eq!(b, [-1; 8]);
+        }
+    }
+}
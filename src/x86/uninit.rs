@@ -0,0 +1,416 @@
+//! Store wrappers that write into [`MaybeUninit`] destinations.
+//!
+//! Each function performs the store of the same name in the parent module and
+//! returns the now initialized reference, so output buffers do not need to be
+//! zeroed before they are written. Use [`array_mut`] to pass arrays of
+//! uninitialized elements and [`chunks_mut`] to store into the spare capacity
+//! of a vector.
+//!
+//! ```rust
+//! # unsafe { encode() }
+//! use core::mem::MaybeUninit;
+//!
+//! #[cfg(target_arch = "x86")]
+//! use safe_unaligned_simd::x86::uninit;
+//! #[cfg(target_arch = "x86_64")]
+//! use safe_unaligned_simd::x86_64::uninit;
+//! #[cfg(target_arch = "x86")]
+//! use core::arch::x86::_mm_set1_epi8;
+//! #[cfg(target_arch = "x86_64")]
+//! use core::arch::x86_64::_mm_set1_epi8;
+//!
+//! #[target_feature(enable = "sse2")]
+//! fn encode() {
+//!     let mut out = [MaybeUninit::<u8>::uninit(); 16];
+//!
+//!     let init = uninit::_mm_storeu_si128(uninit::array_mut(&mut out), _mm_set1_epi8(7));
+//!
+//!     assert_eq!(*init, [7u8; 16]);
+//! }
+//! ```
+#[cfg(target_arch = "x86")]
+use core::arch::x86::{self as arch, __m128, __m128d, __m128i, __m256, __m256d, __m256i};
+#[cfg(all(target_arch = "x86", feature = "avx512"))]
+use core::arch::x86::{__m512, __m512d, __m512i};
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::{self as arch, __m128, __m128d, __m128i, __m256, __m256d, __m256i};
+#[cfg(all(target_arch = "x86_64", feature = "avx512"))]
+use core::arch::x86_64::{__m512, __m512d, __m512i};
+use core::mem::MaybeUninit;
+
+#[cfg(all(target_arch = "x86", feature = "avx512"))]
+use crate::x86::Is512BitsUnaligned;
+#[cfg(target_arch = "x86")]
+use crate::x86::{
+    Is16BitsUnaligned, Is32BitsUnaligned, Is64BitsUnaligned, Is128BitsUnaligned, Is256BitsUnaligned,
+};
+#[cfg(all(target_arch = "x86_64", feature = "avx512"))]
+use crate::x86_64::Is512BitsUnaligned;
+#[cfg(target_arch = "x86_64")]
+use crate::x86_64::{
+    Is16BitsUnaligned, Is32BitsUnaligned, Is64BitsUnaligned, Is128BitsUnaligned, Is256BitsUnaligned,
+};
+
+pub use crate::common_uninit::{array_mut, chunks_mut};
+
+/// Stores the lowest 32-bit float of `a` into uninitialized memory.
+///
+/// See [`_mm_store_ss`](super::_mm_store_ss) and [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_store_ss).
+#[inline]
+#[target_feature(enable = "sse")]
+pub fn _mm_store_ss(mem_addr: &mut MaybeUninit<f32>, a: __m128) -> &mut f32 {
+    unsafe {
+        arch::_mm_store_ss(mem_addr.as_mut_ptr().cast(), a);
+        mem_addr.assume_init_mut()
+    }
+}
+
+/// Stores four 32-bit floats into uninitialized memory.
+///
+/// See [`_mm_storeu_ps`](super::_mm_storeu_ps) and [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_storeu_ps).
+#[inline]
+#[target_feature(enable = "sse")]
+pub fn _mm_storeu_ps(mem_addr: &mut MaybeUninit<[f32; 4]>, a: __m128) -> &mut [f32; 4] {
+    unsafe {
+        arch::_mm_storeu_ps(mem_addr.as_mut_ptr().cast(), a);
+        mem_addr.assume_init_mut()
+    }
+}
+
+/// Stores the lower 64 bits of a 128-bit vector of `[2 x double]` to uninitialized memory.
+///
+/// See [`_mm_store_sd`](super::_mm_store_sd) and [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_store_sd).
+#[inline]
+#[target_feature(enable = "sse2")]
+pub fn _mm_store_sd(mem_addr: &mut MaybeUninit<f64>, a: __m128d) -> &mut f64 {
+    unsafe {
+        arch::_mm_store_sd(mem_addr.as_mut_ptr().cast(), a);
+        mem_addr.assume_init_mut()
+    }
+}
+
+/// Stores 128-bits (composed of 2 packed double-precision (64-bit)
+/// floating-point elements) from `a` into uninitialized memory.
+///
+/// See [`_mm_storeu_pd`](super::_mm_storeu_pd) and [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_storeu_pd).
+#[inline]
+#[target_feature(enable = "sse2")]
+pub fn _mm_storeu_pd(mem_addr: &mut MaybeUninit<[f64; 2]>, a: __m128d) -> &mut [f64; 2] {
+    unsafe {
+        arch::_mm_storeu_pd(mem_addr.as_mut_ptr().cast(), a);
+        mem_addr.assume_init_mut()
+    }
+}
+
+/// Stores 128-bits of integer data from `a` into uninitialized memory.
+///
+/// See [`_mm_storeu_si128`](super::_mm_storeu_si128) and [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_storeu_si128).
+#[inline]
+#[target_feature(enable = "sse2")]
+pub fn _mm_storeu_si128<T: Is128BitsUnaligned>(
+    mem_addr: &mut MaybeUninit<T>,
+    a: __m128i,
+) -> &mut T {
+    unsafe {
+        arch::_mm_storeu_si128(mem_addr.as_mut_ptr().cast(), a);
+        mem_addr.assume_init_mut()
+    }
+}
+
+/// Store 16-bit integer from the first element of `a` into uninitialized memory.
+///
+/// See [`_mm_storeu_si16`](super::_mm_storeu_si16) and [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_storeu_si16).
+#[inline]
+#[target_feature(enable = "sse2")]
+pub fn _mm_storeu_si16<T: Is16BitsUnaligned>(mem_addr: &mut MaybeUninit<T>, a: __m128i) -> &mut T {
+    unsafe {
+        arch::_mm_storeu_si16(mem_addr.as_mut_ptr().cast(), a);
+        mem_addr.assume_init_mut()
+    }
+}
+
+/// Store 32-bit integer from the first element of `a` into uninitialized memory.
+///
+/// See [`_mm_storeu_si32`](super::_mm_storeu_si32) and [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_storeu_si32).
+#[inline]
+#[target_feature(enable = "sse2")]
+pub fn _mm_storeu_si32<T: Is32BitsUnaligned>(mem_addr: &mut MaybeUninit<T>, a: __m128i) -> &mut T {
+    unsafe {
+        arch::_mm_storeu_si32(mem_addr.as_mut_ptr().cast(), a);
+        mem_addr.assume_init_mut()
+    }
+}
+
+/// Store 64-bit integer from the first element of `a` into uninitialized memory.
+///
+/// See [`_mm_storeu_si64`](super::_mm_storeu_si64) and [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_storeu_si64).
+#[inline]
+#[target_feature(enable = "sse2")]
+pub fn _mm_storeu_si64<T: Is64BitsUnaligned>(mem_addr: &mut MaybeUninit<T>, a: __m128i) -> &mut T {
+    unsafe {
+        arch::_mm_storeu_si64(mem_addr.as_mut_ptr().cast(), a);
+        mem_addr.assume_init_mut()
+    }
+}
+
+/// Stores 256-bits (composed of 4 packed double-precision (64-bit)
+/// floating-point elements) from `a` into uninitialized memory.
+///
+/// See [`_mm256_storeu_pd`](super::_mm256_storeu_pd) and [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_storeu_pd).
+#[inline]
+#[target_feature(enable = "avx")]
+pub fn _mm256_storeu_pd(mem_addr: &mut MaybeUninit<[f64; 4]>, a: __m256d) -> &mut [f64; 4] {
+    unsafe {
+        arch::_mm256_storeu_pd(mem_addr.as_mut_ptr().cast(), a);
+        mem_addr.assume_init_mut()
+    }
+}
+
+/// Stores 256-bits (composed of 8 packed single-precision (32-bit)
+/// floating-point elements) from `a` into uninitialized memory.
+///
+/// See [`_mm256_storeu_ps`](super::_mm256_storeu_ps) and [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_storeu_ps).
+#[inline]
+#[target_feature(enable = "avx")]
+pub fn _mm256_storeu_ps(mem_addr: &mut MaybeUninit<[f32; 8]>, a: __m256) -> &mut [f32; 8] {
+    unsafe {
+        arch::_mm256_storeu_ps(mem_addr.as_mut_ptr().cast(), a);
+        mem_addr.assume_init_mut()
+    }
+}
+
+/// Stores 256-bits of integer data from `a` into uninitialized memory.
+///
+/// See [`_mm256_storeu_si256`](super::_mm256_storeu_si256) and [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_storeu_si256).
+#[inline]
+#[target_feature(enable = "avx")]
+pub fn _mm256_storeu_si256<T: Is256BitsUnaligned>(
+    mem_addr: &mut MaybeUninit<T>,
+    a: __m256i,
+) -> &mut T {
+    unsafe {
+        arch::_mm256_storeu_si256(mem_addr.as_mut_ptr().cast(), a);
+        mem_addr.assume_init_mut()
+    }
+}
+
+/// Stores 512-bits (composed of 8 packed double-precision (64-bit)
+/// floating-point elements) from `a` into uninitialized memory.
+///
+/// See [`_mm512_storeu_pd`](super::_mm512_storeu_pd) and [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_storeu_pd).
+#[inline]
+#[cfg(feature = "avx512")]
+#[target_feature(enable = "avx512f")]
+pub fn _mm512_storeu_pd(mem_addr: &mut MaybeUninit<[f64; 8]>, a: __m512d) -> &mut [f64; 8] {
+    unsafe {
+        arch::_mm512_storeu_pd(mem_addr.as_mut_ptr().cast(), a);
+        mem_addr.assume_init_mut()
+    }
+}
+
+/// Stores 512-bits (composed of 16 packed single-precision (32-bit)
+/// floating-point elements) from `a` into uninitialized memory.
+///
+/// See [`_mm512_storeu_ps`](super::_mm512_storeu_ps) and [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_storeu_ps).
+#[inline]
+#[cfg(feature = "avx512")]
+#[target_feature(enable = "avx512f")]
+pub fn _mm512_storeu_ps(mem_addr: &mut MaybeUninit<[f32; 16]>, a: __m512) -> &mut [f32; 16] {
+    unsafe {
+        arch::_mm512_storeu_ps(mem_addr.as_mut_ptr().cast(), a);
+        mem_addr.assume_init_mut()
+    }
+}
+
+/// Stores 512-bits of integer data from `a` into uninitialized memory.
+///
+/// See [`_mm512_storeu_si512`](super::_mm512_storeu_si512) and [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_storeu_si512).
+#[inline]
+#[cfg(feature = "avx512")]
+#[target_feature(enable = "avx512f")]
+pub fn _mm512_storeu_si512<T: Is512BitsUnaligned>(
+    mem_addr: &mut MaybeUninit<T>,
+    a: __m512i,
+) -> &mut T {
+    unsafe {
+        arch::_mm512_storeu_si512(mem_addr.as_mut_ptr().cast(), a);
+        mem_addr.assume_init_mut()
+    }
+}
+
+/// Stores 512-bits (composed of 16 packed 32-bit integers) from `a` into uninitialized memory.
+///
+/// See [`_mm512_storeu_epi32`](super::_mm512_storeu_epi32) and [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_storeu_epi32).
+#[inline]
+#[cfg(feature = "avx512")]
+#[target_feature(enable = "avx512f")]
+pub fn _mm512_storeu_epi32<T: Is512BitsUnaligned>(
+    mem_addr: &mut MaybeUninit<T>,
+    a: __m512i,
+) -> &mut T {
+    unsafe {
+        arch::_mm512_storeu_epi32(mem_addr.as_mut_ptr().cast(), a);
+        mem_addr.assume_init_mut()
+    }
+}
+
+/// Stores 512-bits (composed of 8 packed 64-bit integers) from `a` into uninitialized memory.
+///
+/// See [`_mm512_storeu_epi64`](super::_mm512_storeu_epi64) and [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_storeu_epi64).
+#[inline]
+#[cfg(feature = "avx512")]
+#[target_feature(enable = "avx512f")]
+pub fn _mm512_storeu_epi64<T: Is512BitsUnaligned>(
+    mem_addr: &mut MaybeUninit<T>,
+    a: __m512i,
+) -> &mut T {
+    unsafe {
+        arch::_mm512_storeu_epi64(mem_addr.as_mut_ptr().cast(), a);
+        mem_addr.assume_init_mut()
+    }
+}
+
+/// Stores 512-bits (composed of 32 packed 16-bit integers) from `a` into uninitialized memory.
+///
+/// See [`_mm512_storeu_epi16`](super::_mm512_storeu_epi16) and [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_storeu_epi16).
+#[inline]
+#[cfg(feature = "avx512")]
+#[target_feature(enable = "avx512bw")]
+pub fn _mm512_storeu_epi16<T: Is512BitsUnaligned>(
+    mem_addr: &mut MaybeUninit<T>,
+    a: __m512i,
+) -> &mut T {
+    unsafe {
+        arch::_mm512_storeu_epi16(mem_addr.as_mut_ptr().cast(), a);
+        mem_addr.assume_init_mut()
+    }
+}
+
+/// Stores 512-bits (composed of 64 packed 8-bit integers) from `a` into uninitialized memory.
+///
+/// See [`_mm512_storeu_epi8`](super::_mm512_storeu_epi8) and [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_storeu_epi8).
+#[inline]
+#[cfg(feature = "avx512")]
+#[target_feature(enable = "avx512bw")]
+pub fn _mm512_storeu_epi8<T: Is512BitsUnaligned>(
+    mem_addr: &mut MaybeUninit<T>,
+    a: __m512i,
+) -> &mut T {
+    unsafe {
+        arch::_mm512_storeu_epi8(mem_addr.as_mut_ptr().cast(), a);
+        mem_addr.assume_init_mut()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(target_arch = "x86")]
+    use core::arch::x86 as arch;
+    #[cfg(target_arch = "x86_64")]
+    use core::arch::x86_64 as arch;
+    use core::mem::MaybeUninit;
+
+    // SAFETY: The `x86_64` target baseline includes `sse` and `sse2`.
+
+    #[test]
+    fn test_mm_storeu_ps() {
+        unsafe { test() }
+
+        #[target_feature(enable = "sse")]
+        fn test() {
+            let mut a = MaybeUninit::uninit();
+            let r = super::_mm_storeu_ps(&mut a, arch::_mm_setr_ps(1.0, 2.0, 3.0, 4.0));
+            assert_eq!(*r, [1.0, 2.0, 3.0, 4.0]);
+
+            let mut a = MaybeUninit::uninit();
+            let r = super::_mm_store_ss(&mut a, arch::_mm_setr_ps(5.0, 6.0, 7.0, 8.0));
+            assert_eq!(*r, 5.0);
+        }
+    }
+
+    #[test]
+    fn test_mm_storeu_pd() {
+        unsafe { test() }
+
+        #[target_feature(enable = "sse2")]
+        fn test() {
+            let mut a = MaybeUninit::uninit();
+            let r = super::_mm_storeu_pd(&mut a, arch::_mm_setr_pd(1.0, 2.0));
+            assert_eq!(*r, [1.0, 2.0]);
+
+            let mut a = MaybeUninit::uninit();
+            let r = super::_mm_store_sd(&mut a, arch::_mm_setr_pd(3.0, 4.0));
+            assert_eq!(*r, 3.0);
+        }
+    }
+
+    #[test]
+    fn test_mm_storeu_si() {
+        unsafe { test() }
+
+        #[target_feature(enable = "sse2")]
+        fn test() {
+            let v = arch::_mm_setr_epi16(0, 1, 2, 3, 4, 5, 6, 7);
+
+            let mut a = [MaybeUninit::<u8>::uninit(); 16];
+            let r = super::_mm_storeu_si128(super::array_mut(&mut a), v);
+            assert_eq!(*r, [0, 0, 1, 0, 2, 0, 3, 0, 4, 0, 5, 0, 6, 0, 7, 0]);
+
+            let mut a = MaybeUninit::<[u16; 8]>::uninit();
+            assert_eq!(
+                *super::_mm_storeu_si128(&mut a, v),
+                [0, 1, 2, 3, 4, 5, 6, 7]
+            );
+
+            let mut a = MaybeUninit::<u16>::uninit();
+            assert_eq!(*super::_mm_storeu_si16(&mut a, v), 0);
+
+            let mut a = MaybeUninit::<[u16; 2]>::uninit();
+            assert_eq!(*super::_mm_storeu_si32(&mut a, v), [0, 1]);
+
+            let mut a = MaybeUninit::<[u16; 4]>::uninit();
+            assert_eq!(*super::_mm_storeu_si64(&mut a, v), [0, 1, 2, 3]);
+        }
+    }
+
+    #[test]
+    fn test_spare_capacity() {
+        let mut out: Vec<u32> = Vec::with_capacity(10);
+        unsafe { test(&mut out) };
+        assert_eq!(out, [0, 1, 2, 3, 0, 1, 2, 3]);
+
+        #[target_feature(enable = "sse2")]
+        fn test(out: &mut Vec<u32>) {
+            let v = arch::_mm_setr_epi32(0, 1, 2, 3);
+            let (chunks, _) = super::chunks_mut::<u32, 4>(out.spare_capacity_mut());
+
+            for chunk in chunks[..2].iter_mut() {
+                super::_mm_storeu_si128(chunk, v);
+            }
+
+            unsafe { out.set_len(8) };
+        }
+    }
+
+    #[cfg(feature = "_avx_test")]
+    #[test]
+    fn test_mm256_storeu() {
+        assert!(is_x86_feature_detected!("avx"));
+        unsafe { test() }
+
+        #[target_feature(enable = "avx")]
+        fn test() {
+            let mut a = MaybeUninit::uninit();
+            let r = super::_mm256_storeu_pd(&mut a, arch::_mm256_setr_pd(1.0, 2.0, 3.0, 4.0));
+            assert_eq!(*r, [1.0, 2.0, 3.0, 4.0]);
+
+            let mut a = MaybeUninit::uninit();
+            let r = super::_mm256_storeu_ps(&mut a, arch::_mm256_set1_ps(1.5));
+            assert_eq!(*r, [1.5; 8]);
+
+            let mut a = MaybeUninit::<[u64; 4]>::uninit();
+            let r = super::_mm256_storeu_si256(&mut a, arch::_mm256_setr_epi64x(1, 2, 3, 4));
+            assert_eq!(*r, [1, 2, 3, 4]);
+        }
+    }
+}
@@ -0,0 +1,211 @@
+//! Integration with [`zerocopy`], enabled by the `zerocopy` feature.
+//!
+//! [`impl_unaligned_bits_zerocopy!`](crate::impl_unaligned_bits_zerocopy)
+//! implements [`UnalignedBits`](crate::UnalignedBits), or the load-only
+//! [`ReadableBits`](crate::ReadableBits), for types deriving the `zerocopy`
+//! traits, and [`chunks`] / [`chunks_mut`] split byte slices into the
+//! fixed-size arrays taken by the load and store functions.
+//!
+//! A [`Ref`](::zerocopy::Ref) to an array dereferences to the array, so a
+//! header parsed in place can be passed to the load functions directly:
+//!
+//! ```rust
+//! # #[cfg(target_arch = "x86_64")]
+//! # {
+//! use safe_unaligned_simd::x86_64::_mm256_loadu_si256;
+//! use zerocopy::Ref;
+//!
+//! #[target_feature(enable = "avx")]
+//! fn load_header(packet: &[u8]) -> Option<core::arch::x86_64::__m256i> {
+//!     let (header, _body) = Ref::<_, [u8; 32]>::from_prefix(packet).ok()?;
+//!     Some(_mm256_loadu_si256(&*header))
+//! }
+//! # }
+//! ```
+use ::zerocopy::{FromBytes, Immutable, IntoBytes, KnownLayout, Unaligned};
+
+/// Splits a byte slice into chunks of type `C` and the remaining bytes.
+///
+/// `C` is typically a byte array such as `[u8; 32]`, or any other type without
+/// alignment requirements.
+#[inline]
+pub fn chunks<C: FromBytes + Immutable + KnownLayout + Unaligned>(bytes: &[u8]) -> (&[C], &[u8]) {
+    let count = bytes.len() / const { chunk_size::<C>() };
+    match <[C]>::ref_from_prefix_with_elems(bytes, count) {
+        Ok(split) => split,
+        // `C` is unaligned and `count` chunks fit into `bytes`.
+        Err(_) => unreachable!(),
+    }
+}
+
+/// Splits a mutable byte slice into chunks of type `C` and the remaining bytes.
+///
+/// See [`chunks`] for the requirements on `C`.
+#[inline]
+pub fn chunks_mut<C: FromBytes + IntoBytes + KnownLayout + Unaligned>(
+    bytes: &mut [u8],
+) -> (&mut [C], &mut [u8]) {
+    let count = bytes.len() / const { chunk_size::<C>() };
+    match <[C]>::mut_from_prefix_with_elems(bytes, count) {
+        Ok(split) => split,
+        // `C` is unaligned and `count` chunks fit into `bytes`.
+        Err(_) => unreachable!(),
+    }
+}
+
+const fn chunk_size<C>() -> usize {
+    assert!(size_of::<C>() != 0, "chunk type must not be zero-sized");
+    size_of::<C>()
+}
+
+/// Implements [`UnalignedBits`](crate::UnalignedBits) for types implementing
+/// [`FromBytes`], [`IntoBytes`] and [`Immutable`].
+///
+/// Loads read the bytes of the source, which requires [`IntoBytes`] (no
+/// padding) and [`Immutable`] (no interior mutability). Stores write arbitrary
+/// bytes into the destination, which requires [`FromBytes`]. Only the size is
+/// checked against `Bits`. With a leading `readable:`, the macro instead
+/// implements the load-only [`ReadableBits`](crate::ReadableBits) for types
+/// implementing only [`IntoBytes`] and [`Immutable`].
+///
+/// ```rust
+/// # #[cfg(target_arch = "x86_64")]
+/// # {
+/// use safe_unaligned_simd::impl_unaligned_bits_zerocopy;
+/// use zerocopy::{FromBytes, Immutable, IntoBytes};
+///
+/// #[derive(Clone, Copy, FromBytes, IntoBytes, Immutable)]
+/// #[repr(C)]
+/// struct Rgba {
+///     r: u8,
+///     g: u8,
+///     b: u8,
+///     a: u8,
+/// }
+///
+/// impl_unaligned_bits_zerocopy! {
+///     Rgba as i32,
+/// }
+///
+/// #[target_feature(enable = "sse2")]
+/// fn load_pixels(pixels: &[Rgba; 4]) -> core::arch::x86_64::__m128i {
+///     safe_unaligned_simd::x86_64::_mm_loadu_si128(pixels)
+/// }
+///
+/// #[derive(Clone, Copy, IntoBytes, Immutable)]
+/// #[repr(u8)]
+/// enum Channel {
+///     Red,
+///     Green,
+///     Blue,
+/// }
+///
+/// impl_unaligned_bits_zerocopy! {
+///     readable: Channel as i8,
+/// }
+///
+/// #[target_feature(enable = "sse2")]
+/// fn load_channels(channels: &[Channel; 16]) -> core::arch::x86_64::__m128i {
+///     safe_unaligned_simd::x86_64::_mm_loadu_si128(channels)
+/// }
+/// # }
+/// ```
+#[macro_export]
+macro_rules! impl_unaligned_bits_zerocopy {
+    (readable: $($ty:ty as $bits:ty),* $(,)?) => {
+        $(
+            const _: () = {
+                fn zerocopy<T: $crate::__private::IntoBytes + $crate::__private::Immutable>() {}
+
+                #[allow(dead_code)]
+                fn is_zerocopy() {
+                    zerocopy::<$ty>()
+                }
+
+                assert!(
+                    size_of::<$ty>() == size_of::<$bits>(),
+                    "type size does not match `Bits`",
+                );
+            };
+
+            // SAFETY: `IntoBytes` types have no padding and `Immutable` types have no interior
+            // mutability. The size is checked above.
+            unsafe impl $crate::ReadableBits for $ty {
+                type Bits = $bits;
+            }
+        )*
+    };
+
+    ($($ty:ty as $bits:ty),* $(,)?) => {
+        $(
+            const _: () = {
+                fn zerocopy<
+                    T: $crate::__private::FromBytes
+                        + $crate::__private::IntoBytes
+                        + $crate::__private::Immutable,
+                >() {
+                }
+
+                #[allow(dead_code)]
+                fn is_zerocopy() {
+                    zerocopy::<$ty>()
+                }
+
+                assert!(
+                    size_of::<$ty>() == size_of::<$bits>(),
+                    "type size does not match `Bits`",
+                );
+            };
+
+            // SAFETY: `IntoBytes` types have no padding, `FromBytes` types are valid for any bit
+            // pattern and `Immutable` types have no interior mutability. The size is checked
+            // above.
+            unsafe impl $crate::UnalignedBits for $ty {
+                type Bits = $bits;
+            }
+        )*
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{chunks, chunks_mut};
+
+    #[test]
+    fn chunks_split_remainder() {
+        let data: [u8; 70] = core::array::from_fn(|i| i as u8);
+        let (blocks, rest): (&[[u8; 32]], _) = chunks(&data);
+
+        assert_eq!(blocks.len(), 2);
+        assert_eq!(blocks[1][0], 32);
+        assert_eq!(rest, &data[64..]);
+    }
+
+    #[test]
+    fn chunks_mut_write_through() {
+        let mut data = [0u8; 10];
+        let (blocks, rest): (&mut [[u8; 4]], _) = chunks_mut(&mut data);
+
+        assert_eq!(blocks.len(), 2);
+        assert_eq!(rest.len(), 2);
+        blocks[1] = [1, 2, 3, 4];
+        rest[0] = 5;
+
+        assert_eq!(data, [0, 0, 0, 0, 1, 2, 3, 4, 5, 0]);
+    }
+
+    // The derives cannot expand inside this crate, so a `zerocopy` type stands in for a user type.
+    crate::impl_unaligned_bits_zerocopy!(readable: ::zerocopy::byteorder::big_endian::U32 as i32);
+
+    #[test]
+    fn into_bytes_types_implement_readable_traits() {
+        use crate::common_traits::{Is64BitsReadable, Is128BitsReadable};
+        use ::zerocopy::byteorder::big_endian::U32;
+
+        fn assert_is_64_bits_readable<T: Is64BitsReadable>() {}
+        fn assert_is_128_bits_readable<T: Is128BitsReadable>() {}
+
+        assert_is_64_bits_readable::<[U32; 2]>();
+        assert_is_128_bits_readable::<[U32; 4]>();
+    }
+}